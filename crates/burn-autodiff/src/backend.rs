@@ -4,7 +4,7 @@ use crate::{
     runtime::AutodiffClient,
     tensor::AutodiffTensor,
 };
-use alloc::{format, string::String};
+use alloc::{format, string::String, vec::Vec};
 use burn_tensor::{
     backend::{AutodiffBackend, Backend},
     ops::{BoolTensor, IntTensor, QuantizedTensor},
@@ -48,6 +48,14 @@ impl<B: Backend, C: CheckpointStrategy> Backend for Autodiff<B, C> {
         B::seed(seed)
     }
 
+    fn rng_state() -> Option<Vec<u8>> {
+        B::rng_state()
+    }
+
+    fn set_rng_state(state: &[u8]) {
+        B::set_rng_state(state)
+    }
+
     fn sync(device: &B::Device) {
         B::sync(device)
     }
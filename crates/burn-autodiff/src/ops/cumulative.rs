@@ -0,0 +1,68 @@
+use super::{Backward, Ops, unary};
+use crate::{checkpoint::base::Checkpointer, grads::Gradients};
+use burn_tensor::{ElementConversion, Shape, backend::Backend};
+
+#[derive(Debug)]
+pub(crate) struct CumMaxMin;
+
+impl<B: Backend> Backward<B, 1> for CumMaxMin {
+    type State = (B::IntTensorPrimitive, Shape, usize);
+
+    fn backward(
+        self,
+        ops: Ops<Self::State, 1>,
+        grads: &mut Gradients,
+        _checkpointer: &mut Checkpointer,
+    ) {
+        unary::<B, _>(ops.parents, ops.node, grads, |grad| {
+            let (indices, shape, dim) = ops.state;
+            let device = B::float_device(&grad);
+            let zeros = B::float_zeros(shape, &device);
+
+            B::float_scatter(dim, zeros, indices, grad)
+        });
+    }
+}
+
+/// Cumulative sum starting from the end of the dimension, used to propagate gradients back
+/// through cumulative operations.
+pub(crate) fn reverse_cumsum<B: Backend>(
+    tensor: B::FloatTensorPrimitive,
+    dim: usize,
+) -> B::FloatTensorPrimitive {
+    let tensor = B::float_flip(tensor, &[dim]);
+    let tensor = B::float_cumsum(tensor, dim);
+
+    B::float_flip(tensor, &[dim])
+}
+
+/// Gradient of the cumulative product along `dim`, which stays finite when the input has zeros.
+///
+/// The gradient of `x_i` is `sum_{j >= i} grad_j * prod_{k <= j, k != i} x_k`. Before the first
+/// zero of the dimension, it is `reverse_cumsum(grad * output) / x_i` since `x_i` isn't zero.
+/// After the first zero, every product includes it so the gradient is zero. At the first zero,
+/// the products are the cumulative product of the input where that zero is replaced by one.
+pub(crate) fn cumprod_backward<B: Backend>(
+    input: B::FloatTensorPrimitive,
+    output: B::FloatTensorPrimitive,
+    grad: B::FloatTensorPrimitive,
+    dim: usize,
+) -> B::FloatTensorPrimitive {
+    let zero = 0.elem();
+    let is_zero = B::float_equal_elem(input.clone(), zero);
+    let num_zeros = B::float_cumsum(B::bool_into_float(is_zero.clone()), dim);
+    let before_zero = B::float_equal_elem(num_zeros.clone(), zero);
+    let first_zero = B::bool_and(is_zero, B::float_equal_elem(num_zeros, 1.elem()));
+
+    let input_safe = B::float_mask_fill(input.clone(), B::bool_not(before_zero.clone()), 1.elem());
+    let grad_before = reverse_cumsum::<B>(B::float_mul(grad.clone(), output), dim);
+    let grad_before = B::float_div(grad_before, input_safe);
+    let grad_before = B::float_mask_fill(grad_before, B::bool_not(before_zero), zero);
+
+    let output_first =
+        B::float_cumprod(B::float_mask_fill(input, first_zero.clone(), 1.elem()), dim);
+    let grad_first = reverse_cumsum::<B>(B::float_mul(grad, output_first), dim);
+    let grad_first = B::float_mask_fill(grad_first, B::bool_not(first_zero), zero);
+
+    B::float_add(grad_before, grad_first)
+}
@@ -330,6 +330,28 @@ impl<B: Backend, C: CheckpointStrategy> IntTensorOps<Self> for Autodiff<B, C> {
         B::int_prod_dim(tensor, dim)
     }
 
+    fn int_cumsum(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn int_cumprod(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
+        B::int_cumprod(tensor, dim)
+    }
+
+    fn int_cummax_with_indices(
+        tensor: IntTensor<Self>,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        B::int_cummax_with_indices(tensor, dim)
+    }
+
+    fn int_cummin_with_indices(
+        tensor: IntTensor<Self>,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        B::int_cummin_with_indices(tensor, dim)
+    }
+
     fn int_expand(tensor: IntTensor<B>, shape: Shape) -> IntTensor<B> {
         B::int_expand(tensor, shape)
     }
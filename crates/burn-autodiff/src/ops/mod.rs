@@ -8,6 +8,7 @@ mod qtensor;
 mod tensor;
 mod transaction;
 
+pub(crate) mod cumulative;
 pub(crate) mod maxmin;
 pub(crate) mod sort;
 
@@ -1609,6 +1609,113 @@ impl<B: Backend, C: CheckpointStrategy> ModuleOps<Autodiff<B, C>> for Autodiff<B
         panic!("Can't differentiate adaptive avg pool2d backward.");
     }
 
+    fn attention(
+        query: AutodiffTensor<B>,
+        key: AutodiffTensor<B>,
+        value: AutodiffTensor<B>,
+        mask: Option<BoolTensor<B>>,
+        options: AttentionOptions,
+    ) -> AutodiffTensor<B> {
+        #[derive(Debug)]
+        struct Attention;
+
+        impl<B: Backend> Backward<B, 3> for Attention {
+            type State = (
+                NodeID,
+                NodeID,
+                NodeID,
+                Option<BoolTensor<B>>,
+                B::FloatTensorPrimitive,
+                AttentionOptions,
+            );
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 3>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let [node_query, node_key, node_value] = ops.parents;
+                let grad = grads.consume::<B>(&ops.node);
+
+                let (query_state, key_state, value_state, mask, output, options) = ops.state;
+                let query = checkpointer.retrieve_node_output(query_state);
+                let key = checkpointer.retrieve_node_output(key_state);
+                let value = checkpointer.retrieve_node_output(value_state);
+
+                let backward =
+                    B::attention_backward(query, key, value, mask, output, grad, options);
+
+                if let Some(node) = node_query {
+                    grads.register::<B>(node.id, backward.query_grad);
+                }
+                if let Some(node) = node_key {
+                    grads.register::<B>(node.id, backward.key_grad);
+                }
+                if let Some(node) = node_value {
+                    grads.register::<B>(node.id, backward.value_grad);
+                }
+            }
+        }
+
+        // The dropped attention weights can't be replayed by the backward pass, so the attention
+        // is decomposed into differentiable operations instead, which materializes the attention
+        // weights of all the keys.
+        let tracked = [&query, &key, &value]
+            .iter()
+            .any(|tensor| !tensor.node.requirement.is_none());
+        if tracked && options.dropout > 0.0 {
+            return attention::attention_decomposed::<Self>(query, key, value, mask, &options);
+        }
+
+        match Attention
+            .prepare::<C>([query.node.clone(), key.node.clone(), value.node.clone()])
+            .compute_bound()
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let query_state = prep.checkpoint(&query);
+                let key_state = prep.checkpoint(&key);
+                let value_state = prep.checkpoint(&value);
+                let output = B::attention(
+                    query.primitive,
+                    key.primitive,
+                    value.primitive,
+                    mask.clone(),
+                    options.clone(),
+                );
+                prep.finish(
+                    (
+                        query_state,
+                        key_state,
+                        value_state,
+                        mask,
+                        output.clone(),
+                        options,
+                    ),
+                    output,
+                )
+            }
+            // The inner backend doesn't drop the attention weights, autodiff being disabled.
+            OpsKind::UnTracked(prep) if options.dropout > 0.0 => {
+                prep.finish(attention::attention_tiled::<B>(
+                    query.primitive,
+                    key.primitive,
+                    value.primitive,
+                    mask,
+                    &options,
+                ))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::attention(
+                query.primitive,
+                key.primitive,
+                value.primitive,
+                mask,
+                options,
+            )),
+        }
+    }
+
     fn interpolate(
         x: AutodiffTensor<B>,
         output_size: [usize; 2],
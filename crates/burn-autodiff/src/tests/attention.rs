@@ -0,0 +1,169 @@
+#[burn_tensor_testgen::testgen(ad_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::{
+        Bool, Int, Tensor, activation::softmax, module::scaled_dot_product_attention,
+        ops::AttentionOptions,
+    };
+
+    #[test]
+    fn test_attention_grad() {
+        let test = AttentionTestCase {
+            num_heads: 2,
+            num_kv_heads: 2,
+            seq_length_q: 4,
+            seq_length_k: 6,
+            causal: false,
+        };
+
+        test.assert_grads_match_reference(None);
+    }
+
+    #[test]
+    fn test_attention_grad_causal_grouped_query_multiple_blocks() {
+        let test = AttentionTestCase {
+            num_heads: 4,
+            num_kv_heads: 2,
+            seq_length_q: 70,
+            seq_length_k: 70,
+            causal: true,
+        };
+
+        test.assert_grads_match_reference(None);
+    }
+
+    #[test]
+    fn test_attention_grad_padding_mask() {
+        let test = AttentionTestCase {
+            num_heads: 2,
+            num_kv_heads: 1,
+            seq_length_q: 3,
+            seq_length_k: 5,
+            causal: false,
+        };
+        let mask =
+            Tensor::<TestAutodiffBackend, 4, Bool>::from([[[[false, false, false, true, true]]]]);
+
+        test.assert_grads_match_reference(Some(mask));
+    }
+
+    #[test]
+    fn test_attention_grad_with_dropout() {
+        let query = sequence([1, 2, 3, 4], 0).require_grad();
+        let key = sequence([1, 2, 5, 4], 1).require_grad();
+        let value = sequence([1, 2, 5, 3], 2).require_grad();
+
+        let output = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            AttentionOptions::new(None, false, 0.5),
+        );
+        let grads = output.sum().backward();
+
+        assert_eq!(query.grad(&grads).unwrap().dims(), [1, 2, 3, 4]);
+        assert_eq!(key.grad(&grads).unwrap().dims(), [1, 2, 5, 4]);
+        assert_eq!(value.grad(&grads).unwrap().dims(), [1, 2, 5, 3]);
+    }
+
+    struct AttentionTestCase {
+        num_heads: usize,
+        num_kv_heads: usize,
+        seq_length_q: usize,
+        seq_length_k: usize,
+        causal: bool,
+    }
+
+    impl AttentionTestCase {
+        fn assert_grads_match_reference(self, mask: Option<Tensor<TestAutodiffBackend, 4, Bool>>) {
+            let shape_q = [1, self.num_heads, self.seq_length_q, 4];
+            let shape_k = [1, self.num_kv_heads, self.seq_length_k, 4];
+            let shape_v = [1, self.num_kv_heads, self.seq_length_k, 3];
+            let weights = sequence([1, self.num_heads, self.seq_length_q, 3], 3);
+
+            let query = sequence(shape_q, 0).require_grad();
+            let key = sequence(shape_k, 1).require_grad();
+            let value = sequence(shape_v, 2).require_grad();
+            let output = scaled_dot_product_attention(
+                query.clone(),
+                key.clone(),
+                value.clone(),
+                mask.clone(),
+                AttentionOptions::new(None, self.causal, 0.0),
+            );
+            let grads = (output * weights.clone()).sum().backward();
+
+            let query_ref = sequence(shape_q, 0).require_grad();
+            let key_ref = sequence(shape_k, 1).require_grad();
+            let value_ref = sequence(shape_v, 2).require_grad();
+            let output_ref =
+                self.reference(query_ref.clone(), key_ref.clone(), value_ref.clone(), mask);
+            let grads_ref = (output_ref * weights).sum().backward();
+
+            for (tensor, tensor_ref) in [(query, query_ref), (key, key_ref), (value, value_ref)] {
+                tensor
+                    .grad(&grads)
+                    .unwrap()
+                    .into_data()
+                    .assert_approx_eq(&tensor_ref.grad(&grads_ref).unwrap().into_data(), 3);
+            }
+        }
+
+        /// Attention with the key-value heads repeated and the attention weights materialized.
+        fn reference(
+            &self,
+            query: Tensor<TestAutodiffBackend, 4>,
+            key: Tensor<TestAutodiffBackend, 4>,
+            value: Tensor<TestAutodiffBackend, 4>,
+            mask: Option<Tensor<TestAutodiffBackend, 4, Bool>>,
+        ) -> Tensor<TestAutodiffBackend, 4> {
+            let key = self.repeat_heads(key);
+            let value = self.repeat_heads(value);
+            let shape = [1, self.num_heads, self.seq_length_q, self.seq_length_k];
+
+            let mut scores = query.matmul(key.transpose()).div_scalar(2.0);
+            if let Some(mask) = mask {
+                scores = scores.mask_fill(mask.expand(shape), f32::NEG_INFINITY);
+            }
+            if self.causal {
+                let offset = (self.seq_length_k - self.seq_length_q) as i64;
+                let causal = Tensor::<TestAutodiffBackend, 2, Bool>::tril_mask(
+                    [self.seq_length_q, self.seq_length_k],
+                    offset,
+                    &scores.device(),
+                );
+                let causal = causal.unsqueeze::<4>().expand(shape);
+                scores = scores.mask_fill(causal, f32::NEG_INFINITY);
+            }
+
+            softmax(scores, 3).matmul(value)
+        }
+
+        fn repeat_heads(
+            &self,
+            tensor: Tensor<TestAutodiffBackend, 4>,
+        ) -> Tensor<TestAutodiffBackend, 4> {
+            let [batch_size, num_kv_heads, seq_length, d] = tensor.dims();
+            let num_groups = self.num_heads / num_kv_heads;
+
+            tensor
+                .unsqueeze_dim::<5>(2)
+                .expand([batch_size, num_kv_heads, num_groups, seq_length, d])
+                .reshape([batch_size, self.num_heads, seq_length, d])
+        }
+    }
+
+    /// A deterministic tensor with values in `[-1, 1]`.
+    fn sequence(shape: [usize; 4], seed: i64) -> Tensor<TestAutodiffBackend, 4> {
+        let num_elements = shape.iter().product::<usize>() as i64;
+        Tensor::<TestAutodiffBackend, 1, Int>::arange(
+            seed..num_elements + seed,
+            &Default::default(),
+        )
+        .float()
+        .mul_scalar(0.37 * (seed + 1) as f32)
+        .sin()
+        .reshape(shape)
+    }
+}
@@ -0,0 +1,29 @@
+#[burn_tensor_testgen::testgen(ad_cast)]
+mod tests {
+    use super::*;
+    use burn_tensor::{DType, FloatDType, TensorData};
+
+    #[test]
+    fn should_diff_cast() {
+        let data = TensorData::from([[1.0, 7.0], [2.0, 3.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+        let dtype = tensor_1.dtype();
+        let other = match dtype {
+            DType::F64 => FloatDType::F32,
+            _ => FloatDType::F64,
+        };
+
+        let tensor_2 = tensor_1.clone().cast(other);
+        let tensor_3 = tensor_2.clone().mul(tensor_2).cast(dtype);
+        let grads = tensor_3.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.dtype(), dtype);
+        grad_1
+            .to_data()
+            .assert_eq(&TensorData::from([[2.0, 14.0], [4.0, 6.0]]), false);
+    }
+}
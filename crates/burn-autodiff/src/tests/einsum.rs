@@ -0,0 +1,40 @@
+#[burn_tensor_testgen::testgen(ad_einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_diff_einsum_contraction() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 0.0], [2.0, 1.0]], &device).require_grad();
+
+        let tensor_3 = TestAutodiffTensor::<2>::einsum("ij,jk->ik", &[&tensor_1, &tensor_2]);
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.0, 3.0], [1.0, 3.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+        let expected = TensorData::from([[4.0, 4.0], [6.0, 6.0]]);
+        grad_2.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_einsum_trace() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device).require_grad();
+
+        let tensor_2 = TestAutodiffTensor::<1>::einsum("ii->", &[&tensor_1]);
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[1.0, 0.0], [0.0, 1.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,87 @@
+#[burn_tensor_testgen::testgen(ad_linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, linalg};
+
+    #[test]
+    fn should_diff_det() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[4.0, 7.0], [2.0, 6.0]], &device).require_grad();
+
+        let tensor_2 = linalg::det(tensor_1.clone());
+        let grads = tensor_2.backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // d det(A) / dA = det(A) * A^-T
+        let expected = TensorData::from([[6.0, -2.0], [-7.0, 4.0]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_inverse() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[4.0, 7.0], [2.0, 6.0]], &device).require_grad();
+
+        let tensor_2 = linalg::inverse(tensor_1.clone());
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[0.04, -0.08], [-0.03, 0.06]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_solve() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[3.0, 1.0], [1.0, 2.0]], &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_floats([[9.0], [8.0]], &device).require_grad();
+
+        let tensor_3 = linalg::solve(tensor_1.clone(), tensor_2.clone());
+        let grads = tensor_3.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        let expected = TensorData::from([[-0.4, -0.6], [-0.8, -1.2]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+        let expected = TensorData::from([[0.2], [0.4]]);
+        grad_2.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_cholesky() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[4.0, 2.0], [2.0, 5.0]], &device).require_grad();
+
+        let tensor_2 = linalg::cholesky(tensor_1.clone());
+        let grads = tensor_2.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // Only the lower triangle of the input is read.
+        let expected = TensorData::from([[0.1875, 0.0], [0.25, 0.25]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_eigh() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<2>::from_floats([[2.0, 1.0], [1.0, 2.0]], &device).require_grad();
+
+        let (eigenvalues, _eigenvectors) = linalg::eigh(tensor_1.clone());
+        let grads = eigenvalues.slice([0..1, 1..2]).sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // The gradient of an eigenvalue is the outer product of its eigenvector.
+        let expected = TensorData::from([[0.5, 0.5], [0.5, 0.5]]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+}
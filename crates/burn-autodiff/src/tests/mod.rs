@@ -5,11 +5,13 @@ mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
 mod add;
 mod aggregation;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
 mod backward;
 mod bridge;
 mod broadcast;
+mod cast;
 mod cat;
 mod ceil;
 mod checkpoint;
@@ -22,8 +24,10 @@ mod conv_transpose2d;
 mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod cumulative;
 mod deform_conv2d;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
 mod expand;
@@ -32,6 +36,7 @@ mod floor;
 mod gather_scatter;
 mod gelu;
 mod gradients;
+mod linalg;
 mod log;
 mod log1p;
 mod log_sigmoid;
@@ -57,6 +62,7 @@ mod round;
 mod select;
 mod sigmoid;
 mod sign;
+mod signal;
 mod sin;
 mod slice;
 mod softmax;
@@ -181,6 +187,7 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
         burn_autodiff::testgen_module_backward!();
         burn_autodiff::testgen_ad_nearest_interpolate!();
+        burn_autodiff::testgen_ad_attention!();
 
         // Tensor
         burn_autodiff::testgen_ad_complex!();
@@ -188,16 +195,21 @@ macro_rules! testgen_with_float_param {
         burn_autodiff::testgen_ad_add!();
         burn_autodiff::testgen_ad_aggregation!();
         burn_autodiff::testgen_ad_maxmin!();
+        burn_autodiff::testgen_ad_cast!();
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_cumulative!();
         burn_autodiff::testgen_ad_div!();
+        burn_autodiff::testgen_ad_einsum!();
         burn_autodiff::testgen_ad_remainder!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
         burn_autodiff::testgen_ad_slice!();
         burn_autodiff::testgen_ad_gather_scatter!();
         burn_autodiff::testgen_ad_select!();
+        burn_autodiff::testgen_ad_linalg!();
+        burn_autodiff::testgen_ad_signal!();
         burn_autodiff::testgen_ad_log!();
         burn_autodiff::testgen_ad_log1p!();
         burn_autodiff::testgen_ad_mask!();
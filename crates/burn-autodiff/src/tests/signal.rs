@@ -0,0 +1,68 @@
+#[burn_tensor_testgen::testgen(ad_signal)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, signal};
+
+    #[test]
+    fn should_diff_rfft() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+
+        let (re, _im) = signal::rfft(tensor_1.clone(), 0);
+        let grads = re.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+
+        // The sum of the cosines of every bin, cos(2πkm / 4) for k in 0..3.
+        let expected = TensorData::from([3.0, 0.0, 1.0, 0.0]);
+        grad_1.to_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_diff_fft_power() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_floats([1.0, 2.0, 3.0, 4.0], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::<1>::from_floats([0.5, 0.0, -1.0, 1.0], &device).require_grad();
+
+        let (re, im) = signal::fft(tensor_1.clone(), tensor_2.clone(), 0);
+        let power = re.powi_scalar(2).add(im.powi_scalar(2));
+        let grads = power.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        // By Parseval's theorem, the power of the spectrum is n times the power of the signal.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([8.0, 16.0, 24.0, 32.0]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&TensorData::from([4.0, 0.0, -8.0, 8.0]), 3);
+    }
+
+    #[test]
+    fn should_diff_irfft() {
+        let device = Default::default();
+        let tensor_1 =
+            TestAutodiffTensor::<1>::from_floats([10.0, -2.0, -2.0], &device).require_grad();
+        let tensor_2 =
+            TestAutodiffTensor::<1>::from_floats([0.0, 2.0, 0.0], &device).require_grad();
+
+        let output = signal::irfft(tensor_1.clone(), tensor_2.clone(), 0, None);
+        let grads = output.sum().backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        // The sum of the signal only depends on the zero frequency.
+        grad_1
+            .to_data()
+            .assert_approx_eq(&TensorData::from([1.0, 0.0, 0.0]), 3);
+        grad_2
+            .to_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.0, 0.0]), 3);
+    }
+}
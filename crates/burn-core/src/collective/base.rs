@@ -0,0 +1,381 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use burn_tensor::{Tensor, TensorData, backend::Backend};
+
+/// The operation combining the tensors of the members of a group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReduceOp {
+    /// The element-wise sum.
+    Sum,
+    /// The element-wise mean.
+    Mean,
+    /// The element-wise maximum.
+    Max,
+    /// The element-wise minimum.
+    Min,
+}
+
+impl ReduceOp {
+    fn combine<B: Backend, const D: usize>(
+        &self,
+        lhs: Tensor<B, D>,
+        rhs: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        match self {
+            ReduceOp::Sum | ReduceOp::Mean => lhs + rhs,
+            ReduceOp::Max => lhs.max_pair(rhs),
+            ReduceOp::Min => lhs.min_pair(rhs),
+        }
+    }
+}
+
+/// The links of a member of a ring, on which the [collective operations](Communicator) are
+/// implemented.
+///
+/// Every member sends data to the next member, of rank `rank + 1`, and receives data from the
+/// previous member, of rank `rank - 1`, wrapping around the ring.
+pub trait RingTransport: Send {
+    /// The rank of the member in the ring.
+    fn rank(&self) -> usize;
+    /// The number of members in the ring.
+    fn world_size(&self) -> usize;
+    /// Send data to the next member. It shouldn't wait for the data to be received.
+    fn send_next(&mut self, data: TensorData);
+    /// Receive data from the previous member.
+    fn recv_previous(&mut self) -> TensorData;
+}
+
+/// A member of a group running collective operations.
+///
+/// All the members of the group should call the same operations in the same order, with tensors
+/// of the same shape. The operations are implemented with ring algorithms, where the tensors are
+/// always combined in the same order, so the results are deterministic and identical on all the
+/// members.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let handles = Communicator::in_process_group(devices.len())
+///     .into_iter()
+///     .zip(devices)
+///     .map(|(mut communicator, device)| {
+///         std::thread::spawn(move || {
+///             let grads = compute_grads(&device);
+///             communicator.all_reduce(grads, ReduceOp::Mean)
+///         })
+///     })
+///     .collect::<Vec<_>>();
+/// ```
+pub struct Communicator {
+    transport: Box<dyn RingTransport>,
+}
+
+impl core::fmt::Debug for Communicator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Communicator")
+            .field("rank", &self.rank())
+            .field("world_size", &self.world_size())
+            .finish()
+    }
+}
+
+impl Communicator {
+    /// Create a member of a group communicating with the given transport.
+    pub fn new<T: RingTransport + 'static>(transport: T) -> Self {
+        Self {
+            transport: Box::new(transport),
+        }
+    }
+
+    /// The rank of the member in the group.
+    pub fn rank(&self) -> usize {
+        self.transport.rank()
+    }
+
+    /// The number of members in the group.
+    pub fn world_size(&self) -> usize {
+        self.transport.world_size()
+    }
+
+    /// Combine the tensors of all the members, which all receive the result.
+    pub fn all_reduce<B: Backend, const D: usize>(
+        &mut self,
+        tensor: Tensor<B, D>,
+        op: ReduceOp,
+    ) -> Tensor<B, D> {
+        let world_size = self.world_size();
+        if world_size == 1 {
+            return tensor;
+        }
+
+        let shape = tensor.shape();
+        let device = tensor.device();
+        let num_elements = shape.num_elements();
+
+        // The tensor is flattened and padded to be split into one chunk per member.
+        let chunk_len = num_elements.div_ceil(world_size).max(1);
+        let padding = chunk_len * world_size - num_elements;
+        let mut flat = tensor.reshape([num_elements]);
+        if padding > 0 {
+            flat = Tensor::cat(vec![flat, Tensor::zeros([padding], &device)], 0);
+        }
+
+        let mut chunks = flat.chunk(world_size, 0);
+        self.ring_reduce_scatter(&mut chunks, op);
+        self.ring_all_gather(&mut chunks);
+
+        Tensor::cat(chunks, 0)
+            .slice([0..num_elements])
+            .reshape(shape)
+    }
+
+    /// Combine the tensors of all the members, with each member receiving the part of the result
+    /// matching its rank along the first dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the size of the first dimension isn't a multiple of the number of members.
+    pub fn reduce_scatter<B: Backend, const D: usize>(
+        &mut self,
+        tensor: Tensor<B, D>,
+        op: ReduceOp,
+    ) -> Tensor<B, D> {
+        let world_size = self.world_size();
+        let size = tensor.dims()[0];
+        assert!(
+            size % world_size == 0,
+            "The size of the first dimension ({size}) should be a multiple of the number of members ({world_size})."
+        );
+
+        if world_size == 1 {
+            return tensor;
+        }
+
+        let mut chunks = tensor.chunk(world_size, 0);
+        self.ring_reduce_scatter(&mut chunks, op);
+
+        chunks.swap_remove(self.rank())
+    }
+
+    /// Concatenate the tensors of all the members along the first dimension, in rank order.
+    pub fn all_gather<B: Backend, const D: usize>(&mut self, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let world_size = self.world_size();
+        if world_size == 1 {
+            return tensor;
+        }
+
+        // Only the chunk of the member is sent, the others are placeholders until received.
+        let mut chunks = vec![tensor; world_size];
+        self.ring_all_gather(&mut chunks);
+
+        Tensor::cat(chunks, 0)
+    }
+
+    /// Send the tensor of the member of rank `root` to all the other members.
+    ///
+    /// The tensors given by the other members are ignored, and replaced by the tensor of the root
+    /// member on their own device.
+    pub fn broadcast<B: Backend, const D: usize>(
+        &mut self,
+        tensor: Tensor<B, D>,
+        root: usize,
+    ) -> Tensor<B, D> {
+        let world_size = self.world_size();
+        let rank = self.rank();
+        assert!(
+            root < world_size,
+            "The root rank {root} should be lower than the number of members {world_size}."
+        );
+
+        if world_size == 1 {
+            return tensor;
+        }
+
+        if rank == root {
+            self.transport.send_next(tensor.clone().into_data());
+            return tensor;
+        }
+
+        // The data is passed along the ring until it reaches the member before the root.
+        let data = self.transport.recv_previous();
+        if (rank + 1) % world_size != root {
+            self.transport.send_next(data.clone());
+        }
+
+        Tensor::from_data(data, &tensor.device())
+    }
+
+    /// After the reduce-scatter, the chunk of index `rank` holds the combined chunks of all the
+    /// members.
+    fn ring_reduce_scatter<B: Backend, const D: usize>(
+        &mut self,
+        chunks: &mut [Tensor<B, D>],
+        op: ReduceOp,
+    ) {
+        let world_size = self.world_size();
+        let index = self.ring_index();
+
+        // Each chunk starts its trip around the ring after the member of the same rank, and is
+        // combined by every member it goes through, always in the same order.
+        for step in 0..world_size - 1 {
+            let sent = index(step + 1);
+            let received = index(step + 2);
+
+            let chunk = self.exchange(chunks[sent].clone());
+            chunks[received] = op.combine(chunk, chunks[received].clone());
+        }
+
+        let rank = self.rank();
+        if op == ReduceOp::Mean {
+            chunks[rank] = chunks[rank].clone().div_scalar(world_size as f64);
+        }
+    }
+
+    /// Before the all-gather, only the chunk of index `rank` is used.
+    fn ring_all_gather<B: Backend, const D: usize>(&mut self, chunks: &mut [Tensor<B, D>]) {
+        let index = self.ring_index();
+
+        for step in 0..self.world_size() - 1 {
+            chunks[index(step + 1)] = self.exchange(chunks[index(step)].clone());
+        }
+    }
+
+    /// The index of the chunk `offset` ranks before the member.
+    fn ring_index(&self) -> impl Fn(usize) -> usize + use<> {
+        let rank = self.rank();
+        let world_size = self.world_size();
+
+        move |offset| (rank + world_size - offset % world_size) % world_size
+    }
+
+    /// Send a chunk to the next member and receive a chunk from the previous member.
+    fn exchange<B: Backend, const D: usize>(&mut self, chunk: Tensor<B, D>) -> Tensor<B, D> {
+        let device = chunk.device();
+        self.transport.send_next(chunk.into_data());
+
+        Tensor::from_data(self.transport.recv_previous(), &device)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    /// Run the function on a thread per member of an in-process group, returning the results in
+    /// rank order.
+    fn run<F, R>(world_size: usize, func: F) -> Vec<R>
+    where
+        F: Fn(&mut Communicator) -> R + Send + Sync + Copy + 'static,
+        R: Send + 'static,
+    {
+        let handles = Communicator::in_process_group(world_size)
+            .into_iter()
+            .map(|mut communicator| std::thread::spawn(move || func(&mut communicator)))
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    }
+
+    fn tensor(rank: usize, shape: [usize; 2]) -> Tensor<TestBackend, 2> {
+        let values = (0..shape[0] * shape[1])
+            .map(|i| (rank * 100 + i) as f32)
+            .collect::<Vec<_>>();
+
+        Tensor::<TestBackend, 1>::from_floats(values.as_slice(), &Default::default()).reshape(shape)
+    }
+
+    #[test]
+    fn all_reduce_should_combine_the_tensors_of_all_members() {
+        for world_size in [1, 2, 3, 4] {
+            for (op, expected) in [
+                (ReduceOp::Sum, sum(world_size)),
+                (
+                    ReduceOp::Mean,
+                    sum(world_size).div_scalar(world_size as f64),
+                ),
+                (ReduceOp::Max, tensor(world_size - 1, [3, 3])),
+                (ReduceOp::Min, tensor(0, [3, 3])),
+            ] {
+                let results = run(world_size, move |communicator| {
+                    let rank = communicator.rank();
+                    communicator
+                        .all_reduce(tensor(rank, [3, 3]), op)
+                        .into_data()
+                });
+
+                for result in results {
+                    result.assert_eq(&expected.clone().into_data(), true);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn all_reduce_should_be_identical_on_all_members() {
+        let results = run(3, |communicator| {
+            let rank = communicator.rank();
+            let tensor = tensor(rank, [4, 5]).div_scalar(7.0).exp();
+            communicator.all_reduce(tensor, ReduceOp::Sum).into_data()
+        });
+
+        results[1].assert_eq(&results[0], true);
+        results[2].assert_eq(&results[0], true);
+    }
+
+    #[test]
+    fn reduce_scatter_should_give_each_member_its_part() {
+        let results = run(3, |communicator| {
+            let rank = communicator.rank();
+            communicator
+                .reduce_scatter(tensor(rank, [6, 2]), ReduceOp::Sum)
+                .into_data()
+        });
+
+        let expected = (0..3)
+            .map(|rank| tensor(rank, [6, 2]))
+            .reduce(|a, b| a + b)
+            .unwrap()
+            .chunk(3, 0);
+        for (result, expected) in results.into_iter().zip(expected) {
+            result.assert_eq(&expected.into_data(), true);
+        }
+    }
+
+    #[test]
+    fn all_gather_should_concatenate_in_rank_order() {
+        let results = run(3, |communicator| {
+            let rank = communicator.rank();
+            communicator.all_gather(tensor(rank, [1, 2])).into_data()
+        });
+
+        let expected = Tensor::cat((0..3).map(|rank| tensor(rank, [1, 2])).collect(), 0);
+        for result in results {
+            result.assert_eq(&expected.clone().into_data(), true);
+        }
+    }
+
+    #[test]
+    fn broadcast_should_send_the_tensor_of_the_root() {
+        let results = run(4, |communicator| {
+            let rank = communicator.rank();
+            communicator.broadcast(tensor(rank, [2, 2]), 2).into_data()
+        });
+
+        for result in results {
+            result.assert_eq(&tensor(2, [2, 2]).into_data(), true);
+        }
+    }
+
+    fn sum(world_size: usize) -> Tensor<TestBackend, 2> {
+        (0..world_size)
+            .map(|rank| tensor(rank, [3, 3]))
+            .reduce(|a, b| a + b)
+            .unwrap()
+    }
+}
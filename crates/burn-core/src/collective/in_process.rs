@@ -0,0 +1,70 @@
+use alloc::vec::Vec;
+use std::sync::mpsc::{Receiver, Sender, channel};
+
+use burn_tensor::TensorData;
+
+use super::{Communicator, RingTransport};
+
+/// A [ring transport](RingTransport) between threads of the same process, linked with channels.
+pub struct InProcessTransport {
+    rank: usize,
+    world_size: usize,
+    next: Sender<TensorData>,
+    previous: Receiver<TensorData>,
+}
+
+impl InProcessTransport {
+    /// Create the transports of all the members of a ring, in rank order.
+    pub fn ring(world_size: usize) -> Vec<Self> {
+        assert!(world_size > 0, "A ring should have at least one member.");
+
+        let (senders, mut receivers): (Vec<_>, Vec<_>) = (0..world_size).map(|_| channel()).unzip();
+        // Each member receives from the previous one, so the receivers are rotated by one rank.
+        receivers.rotate_right(1);
+
+        receivers
+            .into_iter()
+            .enumerate()
+            .map(|(rank, previous)| Self {
+                rank,
+                world_size,
+                next: senders[(rank + 1) % world_size].clone(),
+                previous,
+            })
+            .collect()
+    }
+}
+
+impl RingTransport for InProcessTransport {
+    fn rank(&self) -> usize {
+        self.rank
+    }
+
+    fn world_size(&self) -> usize {
+        self.world_size
+    }
+
+    fn send_next(&mut self, data: TensorData) {
+        self.next
+            .send(data)
+            .expect("The next member of the ring should be alive.");
+    }
+
+    fn recv_previous(&mut self) -> TensorData {
+        self.previous
+            .recv()
+            .expect("The previous member of the ring should be alive.")
+    }
+}
+
+impl Communicator {
+    /// Create all the members of a group of threads of the same process, in rank order.
+    ///
+    /// Each member should be moved to its own thread.
+    pub fn in_process_group(world_size: usize) -> Vec<Self> {
+        InProcessTransport::ring(world_size)
+            .into_iter()
+            .map(Communicator::new)
+            .collect()
+    }
+}
@@ -0,0 +1,9 @@
+mod base;
+mod in_process;
+#[cfg(feature = "collective-tcp")]
+mod tcp;
+
+pub use base::*;
+pub use in_process::*;
+#[cfg(feature = "collective-tcp")]
+pub use tcp::*;
@@ -0,0 +1,189 @@
+use alloc::format;
+use alloc::vec;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::mpsc::{Sender, channel};
+use std::time::{Duration, Instant};
+
+use burn_tensor::TensorData;
+
+use super::{Communicator, RingTransport};
+
+/// How long to wait for the next member of the ring to start listening.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+const CONNECT_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// A [ring transport](RingTransport) between processes, linked with TCP connections.
+///
+/// The websocket transport of `burn-remote` connects clients to a server running on an async
+/// runtime, while every member of a ring both accepts a connection from the previous member and
+/// connects to the next one, and exchanges data from the blocking collective operations. The ring
+/// is thus built on plain TCP streams, without an async runtime.
+///
+/// The data is serialized with [MessagePack](rmp_serde), and sent on a background thread, so all
+/// the members of the ring can send before receiving without blocking each other.
+pub struct TcpTransport {
+    rank: usize,
+    world_size: usize,
+    sender: Sender<TensorData>,
+    previous: TcpStream,
+}
+
+impl TcpTransport {
+    /// Join the ring formed by the given addresses, one per member in rank order.
+    ///
+    /// Every member listens on its own address and connects to the address of the next member,
+    /// so all the members of the ring should call this function with the same addresses.
+    pub fn connect(rank: usize, addresses: &[SocketAddr]) -> io::Result<Self> {
+        let world_size = addresses.len();
+        assert!(
+            rank < world_size,
+            "The rank {rank} should be lower than the number of addresses {world_size}."
+        );
+
+        let listener = TcpListener::bind(addresses[rank])?;
+        let mut next = connect_with_retry(addresses[(rank + 1) % world_size])?;
+        next.set_nodelay(true)?;
+        next.write_all(&(rank as u64).to_le_bytes())?;
+
+        let (mut previous, address) = listener.accept()?;
+        previous.set_nodelay(true)?;
+        let previous_rank = read_u64(&mut previous)? as usize;
+        let expected_rank = (rank + world_size - 1) % world_size;
+        if previous_rank != expected_rank {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Expected a connection from the rank {expected_rank}, got the rank {previous_rank} from {address}."
+                ),
+            ));
+        }
+
+        let (sender, receiver) = channel::<TensorData>();
+        std::thread::spawn(move || {
+            for data in receiver {
+                let bytes = rmp_serde::to_vec(&data).expect("Can serialize tensor data to bytes.");
+                let result = next
+                    .write_all(&(bytes.len() as u64).to_le_bytes())
+                    .and_then(|_| next.write_all(&bytes));
+
+                if let Err(err) = result {
+                    log::error!("Closing the connection to the next member of the ring: {err}");
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            rank,
+            world_size,
+            sender,
+            previous,
+        })
+    }
+
+    fn read_previous(&mut self) -> io::Result<TensorData> {
+        let len = read_u64(&mut self.previous)? as usize;
+        let mut bytes = vec![0; len];
+        self.previous.read_exact(&mut bytes)?;
+
+        rmp_serde::from_slice(&bytes).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+impl RingTransport for TcpTransport {
+    fn rank(&self) -> usize {
+        self.rank
+    }
+
+    fn world_size(&self) -> usize {
+        self.world_size
+    }
+
+    fn send_next(&mut self, data: TensorData) {
+        self.sender
+            .send(data)
+            .expect("The connection to the next member of the ring should be open.");
+    }
+
+    fn recv_previous(&mut self) -> TensorData {
+        self.read_previous()
+            .expect("Should receive data from the previous member of the ring.")
+    }
+}
+
+fn connect_with_retry(address: SocketAddr) -> io::Result<TcpStream> {
+    let start = Instant::now();
+
+    loop {
+        match TcpStream::connect(address) {
+            Ok(stream) => return Ok(stream),
+            Err(err) if start.elapsed() < CONNECT_TIMEOUT => {
+                log::debug!("Waiting for {address} to accept connections: {err}");
+                std::thread::sleep(CONNECT_RETRY_DELAY);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+fn read_u64(stream: &mut TcpStream) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    stream.read_exact(&mut bytes)?;
+
+    Ok(u64::from_le_bytes(bytes))
+}
+
+impl Communicator {
+    /// Join a group of processes communicating over TCP, with one address per member in rank
+    /// order.
+    ///
+    /// Every member listens on its own address, and waits for the other members to start, so all
+    /// the members should call this function with the same addresses.
+    pub fn tcp(rank: usize, addresses: &[SocketAddr]) -> io::Result<Self> {
+        TcpTransport::connect(rank, addresses).map(Communicator::new)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::collective::ReduceOp;
+    use alloc::vec::Vec;
+    use burn_tensor::Tensor;
+    use std::net::TcpListener;
+
+    #[test]
+    fn tcp_group_should_all_reduce() {
+        // Reserve free ports for the members of the group.
+        let addresses = (0..3)
+            .map(|_| {
+                TcpListener::bind("127.0.0.1:0")
+                    .unwrap()
+                    .local_addr()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let handles = (0..3)
+            .map(|rank| {
+                let addresses = addresses.clone();
+                std::thread::spawn(move || {
+                    let mut communicator = Communicator::tcp(rank, &addresses).unwrap();
+                    let tensor = Tensor::<TestBackend, 1>::from_floats(
+                        [rank as f32, 1.0, 2.0],
+                        &Default::default(),
+                    );
+                    communicator.all_reduce(tensor, ReduceOp::Sum).into_data()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            handle
+                .join()
+                .unwrap()
+                .assert_eq(&TensorData::from([3.0f32, 3.0, 6.0]), true);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use burn_tensor::backend::Backend;
+use serde::{Deserialize, Serialize};
 
 pub use crate::data::dataset::{Dataset, DatasetIterator};
 use core::iter::Iterator;
@@ -13,10 +14,28 @@ pub struct Progress {
     pub items_total: usize,
 }
 
+/// The position of an iteration over a data loader, from which it can be
+/// [resumed](DataLoader::iter_resume) at the same batch.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataLoaderState {
+    /// The index of the iteration over the data loader, which determines how the dataset is
+    /// shuffled.
+    pub iteration: usize,
+
+    /// The number of items already loaded by each worker thread in the iteration.
+    pub items_processed: Vec<usize>,
+}
+
 /// A data loader iterator that can be used to iterate over a data loader.
 pub trait DataLoaderIterator<O>: Iterator<Item = O> {
     /// Returns the progress of the data loader.
     fn progress(&self) -> Progress;
+
+    /// Returns the position of the iteration, after the last loaded batch, or `None` when the
+    /// iterator doesn't track it.
+    fn state(&self) -> Option<DataLoaderState> {
+        None
+    }
 }
 
 /// A data loader that can be used to iterate over a dataset.
@@ -24,6 +43,27 @@ pub trait DataLoader<B: Backend, O>: Send {
     /// Returns a boxed [iterator](DataLoaderIterator) to iterate over the data loader.
     fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a>;
 
+    /// Returns a boxed [iterator](DataLoaderIterator) resuming an iteration at the given
+    /// [state](DataLoaderIterator::state).
+    ///
+    /// The dataset is shuffled the same way as in the resumed iteration, and the batches that
+    /// were already loaded are skipped without being loaded again. The following iterations
+    /// continue from there, as if the data loader was never interrupted.
+    ///
+    /// The batches of several worker threads are interleaved in the order the threads load
+    /// them, so the remaining batches are only returned in the same order with at most one
+    /// worker thread.
+    ///
+    /// By default, a new iteration is started and loaded until the items of the state are
+    /// processed, so the batches are only the same when the data loader isn't shuffled.
+    fn iter_resume<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let items_processed = state.items_processed.iter().sum::<usize>();
+        let mut iterator = self.iter();
+        while iterator.progress().items_processed < items_processed && iterator.next().is_some() {}
+
+        iterator
+    }
+
     /// The number of items (not the number of batches nor the number of iterations),
     /// corresponding to the items_total of the progress returned by the iterator.
     fn num_items(&self) -> usize;
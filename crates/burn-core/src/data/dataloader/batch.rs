@@ -1,10 +1,13 @@
-use super::{BatchStrategy, DataLoader, DataLoaderIterator, Progress, batcher::DynBatcher};
+use super::{
+    BatchStrategy, DataLoader, DataLoaderIterator, DataLoaderState, DistributedSampler, Progress,
+    batcher::DynBatcher,
+};
 use burn_dataset::{
     Dataset,
     transform::{PartialDataset, ShuffledDataset},
 };
 use burn_tensor::backend::Backend;
-use rand::{Rng, distr::StandardUniform};
+use rand::{Rng, distr::StandardUniform, rngs::StdRng};
 use std::sync::Arc;
 
 /// A data loader that can be used to iterate over a dataset in batches.
@@ -13,7 +16,50 @@ pub struct BatchDataLoader<B: Backend, I, O> {
     dataset: Arc<dyn Dataset<I>>,
     batcher: Box<dyn DynBatcher<B, I, O>>,
     device: B::Device,
-    rng: Option<Arc<spin::Mutex<rand::rngs::StdRng>>>,
+    shuffle: Arc<spin::Mutex<ShuffleState>>,
+    sampler: Option<DistributedSampler>,
+    /// The part of the shard loaded by a slice of a distributed data loader.
+    range: Option<(usize, usize)>,
+}
+
+/// The rng shuffling the dataset each iteration, which can be rewound to shuffle the dataset the
+/// same way as a previous iteration.
+#[derive(Clone)]
+struct ShuffleState {
+    initial: Option<StdRng>,
+    rng: Option<StdRng>,
+    iteration: usize,
+}
+
+impl ShuffleState {
+    fn new(rng: Option<StdRng>) -> Self {
+        Self {
+            initial: rng.clone(),
+            rng,
+            iteration: 0,
+        }
+    }
+
+    /// Start a new iteration, returning its index and the seed shuffling the dataset.
+    fn next(&mut self) -> (usize, Option<u64>) {
+        let seed = self.rng.as_mut().map(|rng| rng.sample(StandardUniform));
+        let iteration = self.iteration;
+        self.iteration += 1;
+
+        (iteration, seed)
+    }
+
+    /// Move back or forward to the start of the given iteration.
+    fn rewind(&mut self, iteration: usize) {
+        if iteration < self.iteration {
+            self.rng = self.initial.clone();
+            self.iteration = 0;
+        }
+
+        while self.iteration < iteration {
+            self.next();
+        }
+    }
 }
 
 impl<B: Backend, I, O> Clone for BatchDataLoader<B, I, O> {
@@ -23,7 +69,9 @@ impl<B: Backend, I, O> Clone for BatchDataLoader<B, I, O> {
             dataset: self.dataset.clone(),
             batcher: self.batcher.clone_dyn(),
             device: self.device.clone(),
-            rng: self.rng.clone(),
+            shuffle: self.shuffle.clone(),
+            sampler: self.sampler,
+            range: self.range,
         }
     }
 }
@@ -55,13 +103,82 @@ impl<B: Backend, I, O> BatchDataLoader<B, I, O> {
             dataset,
             batcher,
             device,
-            rng: rng.map(|rng| Arc::new(spin::Mutex::new(rng))),
+            shuffle: Arc::new(spin::Mutex::new(ShuffleState::new(rng))),
+            sampler: None,
+            range: None,
         }
     }
+
+    /// Only load the shard of the dataset of a process of distributed training.
+    ///
+    /// The dataset is shuffled before being split, so every process should use the same seed.
+    pub fn with_distributed(mut self, sampler: DistributedSampler) -> Self {
+        self.sampler = Some(sampler);
+        self
+    }
+
+    /// Only load the items `start..end` of the shard of a distributed data loader.
+    ///
+    /// The dataset is still shuffled and split every iteration before taking the range, with the
+    /// same seeds as this data loader, so the ranges of the shard stay disjoint and change every
+    /// iteration, and the shard is only padded once.
+    pub(crate) fn with_range(&self, start: usize, end: usize) -> Self {
+        let offset = self.range.map_or(0, |(start, _)| start);
+        let mut dataloader = self.clone();
+        // The range shuffles the dataset on its own, starting from the same state.
+        dataloader.shuffle = Arc::new(spin::Mutex::new(self.shuffle.lock().clone()));
+        dataloader.range = Some((offset + start, offset + end));
+
+        dataloader
+    }
+
+    /// Create the iterator of the next iteration, or of the given one when resuming.
+    fn iterator(
+        &self,
+        iteration: Option<usize>,
+        current_index: usize,
+    ) -> BatchDataloaderIterator<B, I, O>
+    where
+        I: Send + Sync + Clone + 'static,
+    {
+        // When starting a new iteration, the dataset is shuffled if the dataloader was created
+        // with an rng, while advancing the rng to ensure that each new iteration shuffles the
+        // dataset differently.
+        let (iteration, seed) = {
+            let mut shuffle = self.shuffle.lock();
+            if let Some(iteration) = iteration {
+                shuffle.rewind(iteration);
+            }
+            shuffle.next()
+        };
+        let dataset = match seed {
+            Some(seed) => Arc::new(ShuffledDataset::with_seed(self.dataset.clone(), seed)),
+            None => self.dataset.clone(),
+        };
+        let dataset = match &self.sampler {
+            Some(sampler) => sampler.shard(dataset),
+            None => dataset,
+        };
+        let dataset = match self.range {
+            Some((start, end)) => Arc::new(PartialDataset::new(dataset, start, end)),
+            None => dataset,
+        };
+        let mut iterator = BatchDataloaderIterator::new(
+            self.strategy.clone_dyn(),
+            dataset,
+            self.batcher.clone_dyn(),
+            self.device.clone(),
+        );
+        iterator.iteration = iteration;
+        iterator.current_index = current_index;
+
+        iterator
+    }
 }
 
 /// A data loader iterator that can be used to iterate over a data loader.
 struct BatchDataloaderIterator<B: Backend, I, O> {
+    iteration: usize,
     current_index: usize,
     strategy: Box<dyn BatchStrategy<I>>,
     dataset: Arc<dyn Dataset<I>>,
@@ -76,30 +193,29 @@ where
     O: Send + 'static,
 {
     fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
-        // When starting a new iteration, we first check if the dataloader was created with an rng,
-        // implying that we should shuffle the dataset beforehand, while advancing the current
-        // rng to ensure that each new iteration shuffles the dataset differently.
-        let dataset = match &self.rng {
-            Some(rng) => {
-                let mut rng = rng.lock();
-
-                Arc::new(ShuffledDataset::with_seed(
-                    self.dataset.clone(),
-                    rng.sample(StandardUniform),
-                ))
-            }
-            None => self.dataset.clone(),
-        };
-        Box::new(BatchDataloaderIterator::new(
-            self.strategy.clone_dyn(),
-            dataset,
-            self.batcher.clone_dyn(),
-            self.device.clone(),
-        ))
+        Box::new(self.iterator(None, 0))
+    }
+
+    fn iter_resume<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        assert_eq!(
+            state.items_processed.len(),
+            1,
+            "The state should have the progress of a single worker thread."
+        );
+
+        Box::new(self.iterator(Some(state.iteration), state.items_processed[0]))
     }
 
     fn num_items(&self) -> usize {
-        self.dataset.len()
+        let num_items = match &self.sampler {
+            Some(sampler) => sampler.num_items(self.dataset.len()),
+            None => self.dataset.len(),
+        };
+
+        match self.range {
+            Some((start, end)) => end.min(num_items).saturating_sub(start),
+            None => num_items,
+        }
     }
 
     fn set_device(&mut self, device: B::Device) {
@@ -107,10 +223,11 @@ where
     }
 
     fn slice(&self, start: usize, end: usize) -> Box<dyn DataLoader<B, O>> {
-        let rng = self.rng.as_ref().map(|rng| {
-            let rng = rng.lock();
-            rng.clone()
-        });
+        if self.sampler.is_some() {
+            return Box::new(self.with_range(start, end));
+        }
+
+        let rng = self.shuffle.lock().rng.clone();
         let dataloader = Self::new(
             self.strategy.clone_dyn(),
             Arc::new(PartialDataset::new(self.dataset.clone(), start, end)),
@@ -142,6 +259,7 @@ impl<B: Backend, I, O> BatchDataloaderIterator<B, I, O> {
         device: B::Device,
     ) -> Self {
         BatchDataloaderIterator {
+            iteration: 0,
             current_index: 0,
             strategy,
             dataset,
@@ -176,6 +294,13 @@ impl<B: Backend, I, O> DataLoaderIterator<O> for BatchDataloaderIterator<B, I, O
     fn progress(&self) -> Progress {
         Progress::new(self.current_index, self.dataset.len())
     }
+
+    fn state(&self) -> Option<DataLoaderState> {
+        Some(DataLoaderState {
+            iteration: self.iteration,
+            items_processed: vec![self.current_index],
+        })
+    }
 }
 
 #[cfg(test)]
@@ -249,4 +374,39 @@ mod tests {
 
         assert_eq!(items_dataloader, items_dataloader_slice);
     }
+
+    #[test]
+    fn test_batch_dataloader_resume() {
+        let dataset = Arc::new(FakeDataset::<String>::new(20));
+        let dataloader = || {
+            BatchDataLoader::new(
+                Box::new(FixBatchStrategy::new(3)),
+                dataset.clone(),
+                Box::new(TestBatcher::new()),
+                Default::default(),
+                Some(rand::SeedableRng::seed_from_u64(42)),
+            )
+        };
+
+        let dataloader_interrupted = dataloader();
+        dataloader_interrupted.iter().for_each(drop);
+        let mut iterator = dataloader_interrupted.iter();
+        iterator.next();
+        iterator.next();
+        let state = iterator.state().unwrap();
+        assert_eq!(state.iteration, 1);
+        assert_eq!(state.items_processed, vec![6]);
+        let expected = (
+            iterator.collect::<Vec<_>>(),
+            dataloader_interrupted.iter().collect::<Vec<_>>(),
+        );
+
+        let dataloader_resumed = dataloader();
+        let resumed = (
+            dataloader_resumed.iter_resume(&state).collect::<Vec<_>>(),
+            dataloader_resumed.iter().collect::<Vec<_>>(),
+        );
+
+        assert_eq!(expected, resumed);
+    }
 }
@@ -1,6 +1,6 @@
 use super::{
-    BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy, MultiThreadDataLoader,
-    batcher::DynBatcher,
+    BatchDataLoader, BatchStrategy, DataLoader, DistributedSampler, FixBatchStrategy,
+    MultiThreadDataLoader, batcher::DynBatcher,
 };
 use burn_dataset::Dataset;
 use burn_tensor::backend::Backend;
@@ -13,6 +13,7 @@ pub struct DataLoaderBuilder<B: Backend, I, O> {
     batcher: Box<dyn DynBatcher<B, I, O>>,
     num_threads: Option<usize>,
     shuffle: Option<u64>,
+    distributed: Option<DistributedSampler>,
     device: Option<B::Device>,
 }
 
@@ -40,6 +41,7 @@ where
             strategy: None,
             num_threads: None,
             shuffle: None,
+            distributed: None,
             device: None,
         }
     }
@@ -75,6 +77,23 @@ where
         self
     }
 
+    /// Only loads the shard of the dataset of a process of distributed training.
+    ///
+    /// When the dataset is [shuffled](Self::shuffle), it is shuffled before being split, so all the
+    /// processes should use the same seed to load disjoint shards.
+    ///
+    /// # Arguments
+    ///
+    /// * `sampler` - The distributed sampler of the process.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn distributed(mut self, sampler: DistributedSampler) -> Self {
+        self.distributed = Some(sampler);
+        self
+    }
+
     /// Sets the number of workers.
     ///
     /// # Arguments
@@ -125,23 +144,25 @@ where
             None => Box::new(FixBatchStrategy::new(1)),
         };
         if let Some(num_threads) = self.num_threads {
-            return Arc::new(MultiThreadDataLoader::new(
+            let dataloader = MultiThreadDataLoader::new(
                 strategy,
                 dataset,
                 self.batcher,
                 num_threads,
                 device,
                 rng,
-            ));
+            );
+            return match self.distributed {
+                Some(sampler) => Arc::new(dataloader.with_distributed(sampler)),
+                None => Arc::new(dataloader),
+            };
         }
 
-        Arc::new(BatchDataLoader::new(
-            strategy,
-            dataset,
-            self.batcher,
-            device,
-            rng,
-        ))
+        let dataloader = BatchDataLoader::new(strategy, dataset, self.batcher, device, rng);
+        match self.distributed {
+            Some(sampler) => Arc::new(dataloader.with_distributed(sampler)),
+            None => Arc::new(dataloader),
+        }
     }
 }
 
@@ -0,0 +1,214 @@
+use burn_dataset::Dataset;
+use std::sync::Arc;
+
+/// Split of a dataset between the processes of distributed training.
+///
+/// Each process loads a disjoint shard of the dataset: the process of rank `rank` gets the
+/// items `rank`, `rank + world_size`, `rank + 2 * world_size` and so on. When the dataset is
+/// shuffled, it is shuffled before being split, so all the processes should use the same seed
+/// to get disjoint shards that change every epoch.
+///
+/// When the number of items isn't a multiple of the world size, the shards are padded with the
+/// first items of the dataset, so all the processes run the same number of steps. With
+/// [drop_last](DistributedSampler::with_drop_last), the last items are dropped instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DistributedSampler {
+    rank: usize,
+    world_size: usize,
+    drop_last: bool,
+}
+
+impl DistributedSampler {
+    /// Create a new distributed sampler for the process of the given rank.
+    pub fn new(rank: usize, world_size: usize) -> Self {
+        assert!(
+            rank < world_size,
+            "The rank {rank} should be lower than the world size {world_size}."
+        );
+
+        Self {
+            rank,
+            world_size,
+            drop_last: false,
+        }
+    }
+
+    /// Drop the last items of the dataset instead of padding the shards when the number of items
+    /// isn't a multiple of the world size.
+    pub fn with_drop_last(mut self, drop_last: bool) -> Self {
+        self.drop_last = drop_last;
+        self
+    }
+
+    /// The rank of the process.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// The number of processes.
+    pub fn world_size(&self) -> usize {
+        self.world_size
+    }
+
+    /// The number of items in the shard of a dataset with `num_items` items.
+    pub fn num_items(&self, num_items: usize) -> usize {
+        match self.drop_last {
+            true => num_items / self.world_size,
+            false => num_items.div_ceil(self.world_size),
+        }
+    }
+
+    /// The shard of the dataset for the process.
+    pub(crate) fn shard<I>(&self, dataset: Arc<dyn Dataset<I>>) -> Arc<dyn Dataset<I>>
+    where
+        I: Send + Sync + 'static,
+    {
+        Arc::new(DistributedDataset {
+            dataset,
+            sampler: *self,
+        })
+    }
+}
+
+struct DistributedDataset<I> {
+    dataset: Arc<dyn Dataset<I>>,
+    sampler: DistributedSampler,
+}
+
+impl<I> Dataset<I> for DistributedDataset<I>
+where
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        if index >= self.len() {
+            return None;
+        }
+
+        // Padded items wrap around to the start of the dataset.
+        let index = index * self.sampler.world_size + self.sampler.rank;
+        self.dataset.get(index % self.dataset.len())
+    }
+
+    fn len(&self) -> usize {
+        self.sampler.num_items(self.dataset.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::DataLoaderBuilder;
+    use crate::data::dataloader::batcher::TestBatcher;
+    use crate::data::dataset::InMemDataset;
+    use std::collections::HashSet;
+
+    fn epochs(sampler: DistributedSampler, num_items: usize) -> Vec<Vec<usize>> {
+        let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+            .batch_size(2)
+            .shuffle(42)
+            .distributed(sampler)
+            .build(InMemDataset::new((0..num_items).collect()));
+
+        (0..2)
+            .map(|_| dataloader.iter().flatten().collect())
+            .collect()
+    }
+
+    #[test]
+    fn shards_should_be_disjoint_and_padded() {
+        let shards = (0..3)
+            .map(|rank| epochs(DistributedSampler::new(rank, 3), 10))
+            .collect::<Vec<_>>();
+
+        for epoch in 0..2 {
+            let items = shards
+                .iter()
+                .flat_map(|shard| shard[epoch].clone())
+                .collect::<Vec<_>>();
+            assert_eq!(items.len(), 12);
+            // Only the two padding items are loaded twice.
+            assert_eq!(items.iter().collect::<HashSet<_>>().len(), 10);
+        }
+        // The shards are shuffled differently every epoch.
+        assert_ne!(shards[0][0], shards[0][1]);
+    }
+
+    #[test]
+    fn shards_should_drop_the_last_items() {
+        let items = (0..3)
+            .flat_map(|rank| {
+                let sampler = DistributedSampler::new(rank, 3).with_drop_last(true);
+                epochs(sampler, 10).remove(0)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(items.len(), 9);
+        assert_eq!(items.iter().collect::<HashSet<_>>().len(), 9);
+    }
+
+    #[test]
+    fn shards_should_be_disjoint_with_multiple_workers() {
+        let items = (0..2)
+            .flat_map(|rank| {
+                let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+                    .batch_size(2)
+                    .shuffle(7)
+                    .num_workers(2)
+                    .distributed(DistributedSampler::new(rank, 2))
+                    .build(InMemDataset::new((0..20).collect::<Vec<usize>>()));
+                assert_eq!(dataloader.num_items(), 10);
+
+                dataloader.iter().flatten().collect::<Vec<_>>()
+            })
+            .collect::<HashSet<_>>();
+
+        assert_eq!(items.len(), 20);
+    }
+
+    #[test]
+    fn shards_should_be_padded_once_with_multiple_workers() {
+        let items = (0..4)
+            .flat_map(|rank| {
+                let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+                    .batch_size(2)
+                    .shuffle(7)
+                    .num_workers(2)
+                    .distributed(DistributedSampler::new(rank, 4))
+                    .build(InMemDataset::new((0..10).collect::<Vec<usize>>()));
+                assert_eq!(dataloader.num_items(), 3);
+
+                dataloader.iter().flatten().collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(items.len(), 12);
+        assert_eq!(items.iter().collect::<HashSet<_>>().len(), 10);
+    }
+
+    #[test]
+    fn slices_should_be_reshuffled_every_epoch() {
+        let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+            .batch_size(2)
+            .shuffle(42)
+            .distributed(DistributedSampler::new(0, 2))
+            .build(InMemDataset::new((0..20).collect::<Vec<usize>>()));
+        let slices = [dataloader.slice(0, 5), dataloader.slice(5, 10)];
+
+        let epochs = (0..2)
+            .map(|_| {
+                let shard = dataloader.iter().flatten().collect::<HashSet<_>>();
+                let mut items = slices
+                    .iter()
+                    .map(|slice| slice.iter().flatten().collect::<HashSet<_>>())
+                    .collect::<Vec<_>>();
+
+                // The slices split the shard of the epoch.
+                assert!(items[0].is_disjoint(&items[1]));
+                assert_eq!(&items[0] | &items[1], shard);
+                items.remove(0)
+            })
+            .collect::<Vec<_>>();
+
+        assert_ne!(epochs[0], epochs[1]);
+    }
+}
@@ -1,6 +1,7 @@
 mod base;
 mod batch;
 mod builder;
+mod distributed;
 mod multithread;
 mod strategy;
 
@@ -12,5 +13,6 @@ pub mod split;
 pub use base::*;
 pub use batch::*;
 pub use builder::*;
+pub use distributed::*;
 pub use multithread::*;
 pub use strategy::*;
@@ -6,7 +6,10 @@ use rand::distr::{Distribution, StandardUniform};
 use rand::rngs::StdRng;
 
 use super::batcher::DynBatcher;
-use super::{BatchDataLoader, BatchStrategy, DataLoader, DataLoaderIterator, Progress};
+use super::{
+    BatchDataLoader, BatchStrategy, DataLoader, DataLoaderIterator, DataLoaderState,
+    DistributedSampler, Progress,
+};
 use core::cell::OnceCell;
 use std::sync::{Arc, mpsc};
 use std::thread;
@@ -22,6 +25,10 @@ pub struct MultiThreadDataLoader<B: Backend, I, O> {
     device: B::Device,
     rng: Option<rand::rngs::StdRng>,
     num_threads: usize,
+    sampler: Option<DistributedSampler>,
+    /// The part of the shard loaded by a slice of a distributed data loader.
+    range: Option<(usize, usize)>,
+    iteration: spin::Mutex<usize>,
 
     // The lazily initialized data loaders
     dataloaders: OnceCell<Vec<BatchDataLoader<B, I, O>>>,
@@ -38,6 +45,7 @@ pub enum Message<O> {
 }
 
 struct MultiThreadsDataloaderIterator<O> {
+    iteration: usize,
     num_done: usize,
     workers: Vec<thread::JoinHandle<()>>,
     receiver: mpsc::Receiver<Message<O>>,
@@ -79,14 +87,30 @@ where
             num_threads,
             device,
             rng,
+            sampler: None,
+            range: None,
+            iteration: spin::Mutex::new(0),
             dataloaders: OnceCell::new(),
         }
     }
 
+    /// Only load the shard of the dataset of a process of distributed training.
+    ///
+    /// The whole dataset is shuffled and split every iteration, and each thread loads a part of
+    /// the shard of the process, so every process should use the same seed.
+    pub fn with_distributed(mut self, sampler: DistributedSampler) -> Self {
+        self.sampler = Some(sampler);
+        self
+    }
+
     /// Force initialization if needed.
     fn initialize(&self) -> &[BatchDataLoader<B, I, O>] {
         self.dataloaders
             .get_or_init(|| {
+                if let Some(sampler) = self.sampler {
+                    return self.distributed_dataloaders(sampler);
+                }
+
                 let datasets = PartialDataset::split(self.dataset.clone(), self.num_threads);
 
                 // Create more rngs from the first one, one for each new dataloader.
@@ -114,16 +138,50 @@ where
             })
             .as_ref()
     }
-}
 
-impl<B: Backend, I, O> DataLoader<B, O> for MultiThreadDataLoader<B, I, O>
-where
-    I: Send + Sync + Clone + 'static,
-    O: Send + 'static + std::fmt::Debug,
-{
-    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
-        // This will initialize the loader if it hasn't been initialized yet
+    /// The data loaders of the threads of a distributed data loader, which all shuffle the whole
+    /// dataset with the same seeds before sharding it, and load their own range of the shard.
+    fn distributed_dataloaders(
+        &self,
+        sampler: DistributedSampler,
+    ) -> Vec<BatchDataLoader<B, I, O>> {
+        let dataloader = BatchDataLoader::new(
+            self.strategy.clone_dyn(),
+            self.dataset.clone(),
+            self.batcher.clone_dyn(),
+            self.device.clone(),
+            self.rng.clone(),
+        )
+        .with_distributed(sampler);
+        let (start, end) = self.range.unwrap_or((0, dataloader.num_items()));
+        let end = end.min(dataloader.num_items()).max(start);
+        let size = (end - start) / self.num_threads;
+
+        (0..self.num_threads)
+            .map(|i| {
+                let start_thread = start + i * size;
+                let end_thread = match i == self.num_threads - 1 {
+                    true => end,
+                    false => start_thread + size,
+                };
+                dataloader.with_range(start_thread, end_thread)
+            })
+            .collect()
+    }
+
+    /// Spawn a thread per data loader, each resuming the iteration at its own cursor.
+    fn iterator(
+        &self,
+        iteration: usize,
+        items_processed: Vec<usize>,
+    ) -> MultiThreadsDataloaderIterator<O> {
         let dataloaders = self.initialize();
+        assert_eq!(
+            items_processed.len(),
+            dataloaders.len(),
+            "The state should have the progress of each worker thread."
+        );
+        *self.iteration.lock() = iteration + 1;
 
         let (sender, receiver) = mpsc::sync_channel::<Message<O>>(MAX_QUEUED_ITEMS);
 
@@ -131,14 +189,24 @@ where
 
         let handlers: Vec<_> = dataloaders
             .iter()
+            .zip(items_processed)
             .enumerate()
-            .map(|(index, dataloader)| {
+            .map(|(index, (dataloader, items_processed))| {
                 let dataloader_cloned = dataloader.clone();
                 let sender_cloned = sender.clone();
-                progresses.push(Progress::new(0, dataloader_cloned.num_items()));
+                progresses.push(Progress::new(
+                    items_processed,
+                    dataloader_cloned.num_items(),
+                ));
 
                 thread::spawn(move || {
-                    let mut iterator = dataloader_cloned.iter();
+                    // The data loaders of the threads are always resumed, so they stay in sync
+                    // with the iteration even when it is rewound.
+                    let state = DataLoaderState {
+                        iteration,
+                        items_processed: vec![items_processed],
+                    };
+                    let mut iterator = dataloader_cloned.iter_resume(&state);
                     while let Some(item) = iterator.next() {
                         let progress = iterator.progress();
 
@@ -155,12 +223,32 @@ where
             })
             .collect();
 
-        Box::new(MultiThreadsDataloaderIterator::new(
-            receiver, handlers, progresses,
-        ))
+        MultiThreadsDataloaderIterator::new(iteration, receiver, handlers, progresses)
+    }
+}
+
+impl<B: Backend, I, O> DataLoader<B, O> for MultiThreadDataLoader<B, I, O>
+where
+    I: Send + Sync + Clone + 'static,
+    O: Send + 'static + std::fmt::Debug,
+{
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let iteration = *self.iteration.lock();
+        let num_threads = self.initialize().len();
+
+        Box::new(self.iterator(iteration, vec![0; num_threads]))
+    }
+
+    fn iter_resume<'a>(&'a self, state: &DataLoaderState) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        Box::new(self.iterator(state.iteration, state.items_processed.clone()))
     }
 
     fn num_items(&self) -> usize {
+        if self.sampler.is_some() {
+            // The shard is padded before being split between the threads.
+            return self.initialize().iter().map(|d| d.num_items()).sum();
+        }
+
         // For num_items, we can directly use the dataset size without
         // necessarily initializing the full loader
         self.dataset.len()
@@ -171,6 +259,24 @@ where
     }
 
     fn slice(&self, start: usize, end: usize) -> Box<dyn DataLoader<B, O>> {
+        if self.sampler.is_some() {
+            // The slice is taken every iteration from the shard of the shuffled dataset.
+            let offset = self.range.map_or(0, |(start, _)| start);
+            let mut dataloader = Self::new(
+                self.strategy.clone_dyn(),
+                self.dataset.clone(),
+                self.batcher.clone_dyn(),
+                self.num_threads,
+                self.device.clone(),
+                self.rng.clone(),
+            );
+            dataloader.sampler = self.sampler;
+            dataloader.range = Some((offset + start, offset + end));
+            *dataloader.iteration.lock() = *self.iteration.lock();
+
+            return Box::new(dataloader);
+        }
+
         let dataloader = Self::new(
             self.strategy.clone_dyn(),
             Arc::new(PartialDataset::new(self.dataset.clone(), start, end)),
@@ -185,11 +291,13 @@ where
 
 impl<O> MultiThreadsDataloaderIterator<O> {
     pub fn new(
+        iteration: usize,
         receiver: mpsc::Receiver<Message<O>>,
         workers: Vec<thread::JoinHandle<()>>,
         progresses: Vec<Progress>,
     ) -> Self {
         MultiThreadsDataloaderIterator {
+            iteration,
             num_done: 0,
             workers,
             receiver,
@@ -209,6 +317,17 @@ impl<O: std::fmt::Debug> DataLoaderIterator<O> for MultiThreadsDataloaderIterato
 
         Progress::new(items_processed, items_total)
     }
+
+    fn state(&self) -> Option<DataLoaderState> {
+        Some(DataLoaderState {
+            iteration: self.iteration,
+            items_processed: self
+                .progresses
+                .iter()
+                .map(|progress| progress.items_processed)
+                .collect(),
+        })
+    }
 }
 
 impl<O: std::fmt::Debug> Iterator for MultiThreadsDataloaderIterator<O> {
@@ -1,7 +1,11 @@
 use crate as burn;
 
+use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
+use crate::optim::GradientsParams;
 use crate::{config::Config, tensor::Tensor};
-use burn_tensor::backend::Backend;
+use burn_tensor::ElementConversion;
+use burn_tensor::backend::{AutodiffBackend, Backend};
+use core::marker::PhantomData;
 
 /// Gradient Clipping provides a way to mitigate exploding gradients
 #[derive(Config)]
@@ -11,6 +15,9 @@ pub enum GradientClippingConfig {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients of all the parameters by their global norm.
+    GlobalNorm(f32),
 }
 
 impl GradientClippingConfig {
@@ -23,6 +30,7 @@ impl GradientClippingConfig {
         match self {
             GradientClippingConfig::Value(val) => GradientClipping::Value(*val),
             GradientClippingConfig::Norm(val) => GradientClipping::Norm(*val),
+            GradientClippingConfig::GlobalNorm(val) => GradientClipping::GlobalNorm(*val),
         }
     }
 }
@@ -37,6 +45,10 @@ pub enum GradientClipping {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients of all the parameters by their global norm, which is the L2 norm of
+    /// all the gradients concatenated, with [clip_global_norm](GradientClipping::clip_global_norm).
+    GlobalNorm(f32),
 }
 
 impl GradientClipping {
@@ -49,13 +61,66 @@ impl GradientClipping {
     /// # Returns
     ///
     /// The clipped gradient.
+    ///
+    /// # Panics
+    ///
+    /// When clipping by global norm, which depends on the gradients of all the parameters: use
+    /// [clip_global_norm](GradientClipping::clip_global_norm) instead.
     pub fn clip_gradient<B: Backend, const D: usize>(&self, grad: Tensor<B, D>) -> Tensor<B, D> {
         match self {
             GradientClipping::Value(threshold) => self.clip_by_value(grad, *threshold),
             GradientClipping::Norm(max_norm) => self.clip_by_norm(grad, *max_norm),
+            GradientClipping::GlobalNorm(_) => panic!(
+                "A gradient can't be clipped by the global norm on its own, use `clip_global_norm`."
+            ),
         }
     }
 
+    /// Clip the gradients of the parameters of the module by their global norm.
+    ///
+    /// The norm is computed in a first pass over the gradients, and the gradients are scaled
+    /// down in a second pass when it exceeds the maximum norm. The parameters that don't require
+    /// gradients are excluded. Other clipping modes return the gradients as is.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module owning the parameters.
+    /// * `grads` - The gradients to clip.
+    ///
+    /// # Returns
+    ///
+    /// The clipped gradients and their global norm before clipping.
+    pub fn clip_global_norm<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+        grads: GradientsParams,
+    ) -> (GradientsParams, f32) {
+        let mut norm = GlobalNormVisitor::<B, M> {
+            grads: &grads,
+            sum: None,
+            phantom: PhantomData,
+        };
+        module.visit(&mut norm);
+        let norm = norm
+            .sum
+            .map_or(0.0, |sum| sum.sqrt().into_scalar().elem::<f32>());
+
+        let max_norm = match self {
+            GradientClipping::GlobalNorm(max_norm) if norm > *max_norm => *max_norm,
+            _ => return (grads, norm),
+        };
+
+        let mut scaler = GradientsScaler::<M> {
+            grads,
+            // Avoid overflows when the norm is close to zero, as in PyTorch.
+            factor: max_norm / (norm + 1e-6),
+            phantom: PhantomData,
+        };
+        module.visit(&mut scaler);
+
+        (scaler.grads, norm)
+    }
+
     fn clip_by_value<B: Backend, const D: usize>(
         &self,
         grad: Tensor<B, D>,
@@ -74,8 +139,6 @@ impl GradientClipping {
         grad: Tensor<B, D>,
         threshold: f32,
     ) -> Tensor<B, D> {
-        use burn_tensor::ElementConversion;
-
         let norm = Self::l2_norm(grad.clone());
         let norm_float = norm.into_scalar().elem::<f32>();
 
@@ -94,11 +157,59 @@ impl GradientClipping {
     }
 }
 
+struct GlobalNormVisitor<'a, B: AutodiffBackend, M> {
+    grads: &'a GradientsParams,
+    // The sum of the squared gradients.
+    sum: Option<Tensor<B::InnerBackend, 1>>,
+    phantom: PhantomData<M>,
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModuleVisitor<B> for GlobalNormVisitor<'_, B, M> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        if !tensor.is_require_grad() {
+            return;
+        }
+        let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) else {
+            return;
+        };
+
+        let squared = grad.powf_scalar(2.0).sum();
+        self.sum = Some(match self.sum.take() {
+            Some(sum) => sum + squared,
+            None => squared,
+        });
+    }
+}
+
+struct GradientsScaler<M> {
+    grads: GradientsParams,
+    factor: f32,
+    phantom: PhantomData<M>,
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModuleVisitor<B> for GradientsScaler<M> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        if !tensor.is_require_grad() {
+            return;
+        }
+        let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) else {
+            return;
+        };
+
+        self.grads
+            .register::<B::InnerBackend, D>(id, grad.mul_scalar(self.factor));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::TestBackend;
-    use crate::tensor::Tensor;
+    use crate::tensor::{Tensor, TensorData};
+    use crate::{
+        TestAutodiffBackend,
+        nn::{Linear, LinearConfig},
+    };
 
     #[test]
     fn test_clip_by_value() {
@@ -135,4 +246,73 @@ mod tests {
             assert!(value <= 0.88);
         }
     }
+
+    #[test]
+    fn test_clip_by_global_norm() {
+        type InnerBackend = <TestAutodiffBackend as AutodiffBackend>::InnerBackend;
+        let device = Default::default();
+        let layer: Linear<TestAutodiffBackend> = LinearConfig::new(2, 2).init(&device);
+        let mut grads = GradientsParams::new();
+        // A global norm of 5, while the norm of each gradient is at most 4.
+        grads.register(
+            layer.weight.id,
+            Tensor::<InnerBackend, 2>::from_floats([[0.0, 3.0], [0.0, 0.0]], &device),
+        );
+        grads.register(
+            layer.bias.as_ref().unwrap().id,
+            Tensor::<InnerBackend, 1>::from_floats([4.0, 0.0], &device),
+        );
+
+        let (grads, norm) = GradientClipping::GlobalNorm(1.0).clip_global_norm(&layer, grads);
+
+        assert!((norm - 5.0).abs() < 1e-4);
+        grads
+            .get::<InnerBackend, 2>(layer.weight.id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 0.6], [0.0, 0.0]]), 4);
+        grads
+            .get::<InnerBackend, 1>(layer.bias.as_ref().unwrap().id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.8, 0.0]), 4);
+
+        let (grads, norm) = GradientClipping::GlobalNorm(10.0).clip_global_norm(&layer, grads);
+        assert!((norm - 1.0).abs() < 1e-4);
+        assert_eq!(grads.len(), 2);
+    }
+
+    #[test]
+    fn global_norm_should_exclude_params_not_requiring_grad() {
+        type InnerBackend = <TestAutodiffBackend as AutodiffBackend>::InnerBackend;
+        let device = Default::default();
+        let mut layer: Linear<TestAutodiffBackend> = LinearConfig::new(2, 2).init(&device);
+        layer.bias = layer.bias.map(|bias| bias.set_require_grad(false));
+        let mut grads = GradientsParams::new();
+        grads.register(
+            layer.weight.id,
+            Tensor::<InnerBackend, 2>::from_floats([[0.0, 3.0], [0.0, 0.0]], &device),
+        );
+        grads.register(
+            layer.bias.as_ref().unwrap().id,
+            Tensor::<InnerBackend, 1>::from_floats([4.0, 0.0], &device),
+        );
+
+        let (grads, norm) = GradientClipping::GlobalNorm(1.0).clip_global_norm(&layer, grads);
+
+        assert!((norm - 3.0).abs() < 1e-4);
+        grads
+            .get::<InnerBackend, 2>(layer.weight.id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 1.0], [0.0, 0.0]]), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clip_gradient_by_global_norm_should_panic() {
+        let gradient: Tensor<TestBackend, 1> = Tensor::from_floats([3.0, 4.0], &Default::default());
+
+        GradientClipping::GlobalNorm(1.0).clip_gradient(gradient);
+    }
 }
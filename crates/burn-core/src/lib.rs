@@ -21,6 +21,10 @@ pub mod data;
 /// Optimizer module.
 pub mod optim;
 
+/// Collective communication module.
+#[cfg(feature = "std")]
+pub mod collective;
+
 /// Learning rate scheduler module.
 #[cfg(feature = "std")]
 pub mod lr_scheduler;
@@ -18,6 +18,13 @@ pub trait LrScheduler: Send + Sync {
 
     /// Load the state of the scheduler as a [record](Record).
     fn load_record<B: Backend>(self, record: Self::Record<B>) -> Self;
+
+    /// Report the value of a monitored metric, usually at the end of an epoch.
+    ///
+    /// Only schedulers that adapt the learning rate to the training progress, such as
+    /// [ReduceLrOnPlateauScheduler](super::reduce_on_plateau::ReduceLrOnPlateauScheduler), use
+    /// it. The others ignore it.
+    fn report_metric(&mut self, _value: f64) {}
 }
 
 #[cfg(test)]
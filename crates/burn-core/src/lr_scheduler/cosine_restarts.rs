@@ -0,0 +1,206 @@
+use super::{LrScheduler, String};
+use crate as burn;
+use crate::{LearningRate, config::Config};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a [cosine annealing learning rate scheduler with growing restart
+/// periods](CosineAnnealingWarmRestartsLrScheduler).
+///
+/// This scheduler returns the learning rate `initial_lr` at the first step, then changes it by
+/// following a cosine function until reaching `min_lr` after `num_iters` iterations. The learning
+/// rate is then reset, and the number of iterations of every following cycle is multiplied by
+/// `period_multiplier`, while the learning rate at the start of every cycle is multiplied by
+/// `restart_decay`.
+#[derive(Config)]
+pub struct CosineAnnealingWarmRestartsLrSchedulerConfig {
+    // The initial learning rate.
+    initial_lr: LearningRate,
+    // The final learning rate of every cycle.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+    // The number of iterations of the first cycle. The restart iterations themselves are not
+    // included.
+    num_iters: usize,
+    // The factor by which the number of iterations grows after every restart.
+    #[config(default = 1)]
+    period_multiplier: usize,
+    // The factor by which the learning rate at the start of a cycle decays after every restart.
+    #[config(default = 1.0)]
+    restart_decay: f64,
+}
+
+impl CosineAnnealingWarmRestartsLrSchedulerConfig {
+    /// Initializes a [cosine annealing learning rate scheduler with growing restart
+    /// periods](CosineAnnealingWarmRestartsLrScheduler).
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if any of the following conditions is true:
+    ///
+    /// * `initial_lr` is out of range (0.0, 1.0]
+    /// * `min_lr` is out of range [0.0, `initial_lr`]
+    /// * `num_iters` is 0
+    /// * `period_multiplier` is 0
+    /// * `restart_decay` is out of range (0.0, 1.0]
+    pub fn init(&self) -> Result<CosineAnnealingWarmRestartsLrScheduler, String> {
+        if self.initial_lr <= 0. || self.initial_lr > 1. {
+            return Err("Initial learning rate must be greater than 0 and at most 1".into());
+        }
+        if self.min_lr < 0.0 || self.min_lr > self.initial_lr {
+            return Err(
+                "Minimum learning rate must be at least 0 and at most equal to the initial \
+                 learning rate"
+                    .into(),
+            );
+        }
+        if self.num_iters == 0 {
+            return Err("Number of iterations must be at least 1".into());
+        }
+        if self.period_multiplier == 0 {
+            return Err("Period multiplier must be at least 1".into());
+        }
+        if self.restart_decay <= 0. || self.restart_decay > 1. {
+            return Err("Restart decay must be greater than 0 and at most 1".into());
+        }
+
+        Ok(CosineAnnealingWarmRestartsLrScheduler {
+            min_lr: self.min_lr,
+            max_lr: self.initial_lr,
+            num_iters: self.num_iters,
+            period_multiplier: self.period_multiplier,
+            restart_decay: self.restart_decay,
+            current_iter: usize::MAX,
+            cycle: 0,
+        })
+    }
+}
+
+/// A cosine annealing learning rate scheduler with growing restart periods.
+///
+/// This scheduler is described in [SGDR: Stochastic Gradient Descent with Warm
+/// Restarts](https://arxiv.org/abs/1608.03983). See [CosineAnnealingWarmRestartsLrSchedulerConfig]
+/// for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct CosineAnnealingWarmRestartsLrScheduler {
+    min_lr: LearningRate,
+    max_lr: LearningRate,
+    num_iters: usize,
+    period_multiplier: usize,
+    restart_decay: f64,
+    // The iteration in the current cycle.
+    current_iter: usize,
+    // The number of restarts so far.
+    cycle: usize,
+}
+
+impl CosineAnnealingWarmRestartsLrScheduler {
+    fn period(&self) -> usize {
+        self.period_multiplier
+            .saturating_pow(self.cycle as u32)
+            .saturating_mul(self.num_iters)
+    }
+}
+
+impl LrScheduler for CosineAnnealingWarmRestartsLrScheduler {
+    type Record<B: Backend> = (usize, usize);
+
+    fn step(&mut self) -> LearningRate {
+        // Overflow from usize::MAX to 0 on the first call to get the initial learning rate.
+        self.current_iter = self.current_iter.wrapping_add(1);
+        if self.current_iter > self.period() {
+            self.current_iter = 0;
+            self.cycle += 1;
+        }
+
+        let period = self.period();
+        let max_lr = (self.max_lr * self.restart_decay.powi(self.cycle as i32)).max(self.min_lr);
+        self.min_lr
+            + 0.5
+                * (max_lr - self.min_lr)
+                * (1.0 + (self.current_iter as f64 / period as f64 * std::f64::consts::PI).cos())
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        (self.current_iter, self.cycle)
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        (self.current_iter, self.cycle) = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils;
+    use super::*;
+
+    #[test]
+    fn config_period_multiplier_too_low() {
+        let r = CosineAnnealingWarmRestartsLrSchedulerConfig::new(0.5, 10)
+            .with_period_multiplier(0)
+            .init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Period multiplier must be at least 1",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn config_restart_decay_too_high() {
+        let r = CosineAnnealingWarmRestartsLrSchedulerConfig::new(0.5, 10)
+            .with_restart_decay(1.5)
+            .init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Restart decay must be greater than 0 and at most 1",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn test_lr_change() {
+        const INITIAL_LR: LearningRate = 0.5;
+        const MIN_LR: LearningRate = 0.1;
+        let quarter = 0.5 * (INITIAL_LR - MIN_LR) * core::f64::consts::FRAC_1_SQRT_2;
+
+        let scheduler = CosineAnnealingWarmRestartsLrSchedulerConfig::new(INITIAL_LR, 2)
+            .with_min_lr(MIN_LR)
+            .with_period_multiplier(2)
+            .init()
+            .unwrap();
+        let expected_lrs = [
+            INITIAL_LR,                            // cos(0)
+            (INITIAL_LR + MIN_LR) * 0.5,           // cos(PI/2)
+            MIN_LR,                                // cos(PI)
+            INITIAL_LR,                            // restart with a period of 4
+            (INITIAL_LR + MIN_LR) * 0.5 + quarter, // cos(PI/4)
+            (INITIAL_LR + MIN_LR) * 0.5,           // cos(PI/2)
+            (INITIAL_LR + MIN_LR) * 0.5 - quarter, // cos(3PI/4)
+            MIN_LR,                                // cos(PI)
+            INITIAL_LR,                            // restart with a period of 8
+        ];
+        test_utils::check_lr_sequence(scheduler, expected_lrs);
+    }
+
+    #[test]
+    fn test_lr_change_with_restart_decay() {
+        let scheduler = CosineAnnealingWarmRestartsLrSchedulerConfig::new(0.8, 1)
+            .with_restart_decay(0.5)
+            .init()
+            .unwrap();
+        test_utils::check_lr_sequence(scheduler, [0.8, 0.0, 0.4, 0.0, 0.2]);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let scheduler = CosineAnnealingWarmRestartsLrSchedulerConfig::new(1.0, 3)
+            .with_period_multiplier(2)
+            .init()
+            .unwrap();
+        test_utils::check_save_load(scheduler, 6);
+    }
+}
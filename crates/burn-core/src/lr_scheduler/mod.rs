@@ -16,6 +16,21 @@ pub mod cosine;
 /// Step learning rate scheduler
 pub mod step;
 
+/// Cosine annealing learning rate scheduler with growing restart periods
+pub mod cosine_restarts;
+
+/// One cycle learning rate scheduler
+pub mod one_cycle;
+
+/// Learning rate scheduler reducing the learning rate when a metric stops improving
+pub mod reduce_on_plateau;
+
+/// Learning rate scheduler chaining other schedulers
+pub mod sequential;
+
+/// Learning rate warmup before another scheduler
+pub mod warmup;
+
 mod base;
 
 pub use base::*;
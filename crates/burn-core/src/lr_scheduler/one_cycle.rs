@@ -0,0 +1,181 @@
+use super::{LrScheduler, String};
+use crate as burn;
+use crate::{LearningRate, config::Config};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a [one cycle learning rate scheduler](OneCycleLrScheduler).
+///
+/// This scheduler starts with the learning rate `max_lr / div_factor`, increases it by following
+/// a cosine function until reaching `max_lr` after the fraction `pct_start` of the `num_iters`
+/// iterations, then decreases it by following a cosine function until reaching
+/// `max_lr / div_factor / final_div_factor` at the last iteration. The final learning rate is
+/// kept after `num_iters` iterations.
+#[derive(Config)]
+pub struct OneCycleLrSchedulerConfig {
+    // The maximum learning rate.
+    max_lr: LearningRate,
+    // The total number of iterations of the cycle.
+    num_iters: usize,
+    // The fraction of the cycle spent increasing the learning rate.
+    #[config(default = 0.3)]
+    pct_start: f64,
+    // The initial learning rate is `max_lr / div_factor`.
+    #[config(default = 25.0)]
+    div_factor: f64,
+    // The final learning rate is `max_lr / div_factor / final_div_factor`.
+    #[config(default = 1e4)]
+    final_div_factor: f64,
+}
+
+impl OneCycleLrSchedulerConfig {
+    /// Initializes a [one cycle learning rate scheduler](OneCycleLrScheduler).
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if any of the following conditions is true:
+    ///
+    /// * `max_lr` is out of range (0.0, 1.0]
+    /// * `num_iters` is 0
+    /// * `pct_start` is out of range (0.0, 1.0)
+    /// * `div_factor` or `final_div_factor` is not greater than 0
+    pub fn init(&self) -> Result<OneCycleLrScheduler, String> {
+        if self.max_lr <= 0. || self.max_lr > 1. {
+            return Err("Maximum learning rate must be greater than 0 and at most 1".into());
+        }
+        if self.num_iters == 0 {
+            return Err("Number of iterations must be at least 1".into());
+        }
+        if self.pct_start <= 0. || self.pct_start >= 1. {
+            return Err("Percentage of the cycle must be greater than 0 and less than 1".into());
+        }
+        if self.div_factor <= 0. || self.final_div_factor <= 0. {
+            return Err("Division factors must be greater than 0".into());
+        }
+
+        let initial_lr = self.max_lr / self.div_factor;
+
+        Ok(OneCycleLrScheduler {
+            initial_lr,
+            max_lr: self.max_lr,
+            final_lr: initial_lr / self.final_div_factor,
+            warmup_end: self.pct_start * self.num_iters as f64 - 1.0,
+            end: self.num_iters as f64 - 1.0,
+            current_iter: usize::MAX,
+        })
+    }
+}
+
+/// A one cycle learning rate scheduler.
+///
+/// This scheduler is described in [Super-Convergence: Very Fast Training of Neural Networks Using
+/// Large Learning Rates](https://arxiv.org/abs/1708.07120). See [OneCycleLrSchedulerConfig] for
+/// more information.
+#[derive(Clone, Copy, Debug)]
+pub struct OneCycleLrScheduler {
+    initial_lr: LearningRate,
+    max_lr: LearningRate,
+    final_lr: LearningRate,
+    // The iteration at which the maximum learning rate is reached.
+    warmup_end: f64,
+    // The iteration at which the final learning rate is reached.
+    end: f64,
+    current_iter: usize,
+}
+
+impl LrScheduler for OneCycleLrScheduler {
+    type Record<B: Backend> = usize;
+
+    fn step(&mut self) -> LearningRate {
+        // Overflow from usize::MAX to 0 on the first call to get the initial learning rate.
+        self.current_iter = self.current_iter.wrapping_add(1);
+        let iter = self.current_iter as f64;
+
+        if iter < self.warmup_end {
+            let pct = iter / self.warmup_end;
+            cosine_annealing(self.initial_lr, self.max_lr, pct)
+        } else {
+            let pct = ((iter - self.warmup_end) / (self.end - self.warmup_end)).min(1.0);
+            cosine_annealing(self.max_lr, self.final_lr, pct)
+        }
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        self.current_iter
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        self.current_iter = record;
+        self
+    }
+}
+
+/// Goes from `start` to `end` by following a cosine function as `pct` goes from 0 to 1.
+fn cosine_annealing(start: LearningRate, end: LearningRate, pct: f64) -> LearningRate {
+    end + 0.5 * (start - end) * (1.0 + (pct * std::f64::consts::PI).cos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils;
+    use super::*;
+
+    #[test]
+    fn config_max_lr_too_high() {
+        let r = OneCycleLrSchedulerConfig::new(1.5, 10).init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Maximum learning rate must be greater than 0 and at most 1",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn config_num_iters_too_low() {
+        let r = OneCycleLrSchedulerConfig::new(0.5, 0).init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Number of iterations must be at least 1",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn config_pct_start_out_of_range() {
+        let r = OneCycleLrSchedulerConfig::new(0.5, 10)
+            .with_pct_start(1.0)
+            .init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Percentage of the cycle must be greater than 0 and less than 1",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn test_lr_change() {
+        let scheduler = OneCycleLrSchedulerConfig::new(0.5, 10).init().unwrap();
+        let expected_lrs = [
+            0.02, // max_lr / div_factor
+            0.26,
+            0.5, // max_lr
+            0.475242316006736,
+            0.405872826974881,
+            0.305631010968144,
+            0.194370989031855,
+            0.094129173025118,
+            0.024759683993263,
+            2e-6, // max_lr / div_factor / final_div_factor
+            2e-6,
+        ];
+        test_utils::check_lr_sequence(scheduler, expected_lrs);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let scheduler = OneCycleLrSchedulerConfig::new(0.5, 10).init().unwrap();
+        test_utils::check_save_load(scheduler, 5);
+    }
+}
@@ -0,0 +1,281 @@
+use super::{LrScheduler, String};
+use crate as burn;
+use crate::{LearningRate, config::Config};
+use burn_tensor::backend::Backend;
+
+/// Whether the monitored metric of a [ReduceLrOnPlateauScheduler] improves when it decreases or
+/// when it increases.
+#[derive(Config, Debug, PartialEq, Eq)]
+pub enum PlateauMode {
+    /// The metric improves when it decreases, like a loss.
+    Min,
+    /// The metric improves when it increases, like an accuracy.
+    Max,
+}
+
+/// The configuration for creating a [learning rate scheduler reducing the learning rate on
+/// plateaus](ReduceLrOnPlateauScheduler).
+///
+/// This scheduler returns the learning rate `initial_lr` until the metric
+/// [reported](LrScheduler::report_metric) to it stops improving for more than `patience`
+/// reports. The learning rate is then multiplied by `factor`, without going below `min_lr`, and
+/// the scheduler waits for `cooldown` reports before monitoring the metric again.
+///
+/// A value is an improvement when it is better than the best value by a relative `threshold`.
+#[derive(Config)]
+pub struct ReduceLrOnPlateauSchedulerConfig {
+    // The initial learning rate.
+    initial_lr: LearningRate,
+    // Whether the metric improves when it decreases or increases.
+    #[config(default = "PlateauMode::Min")]
+    mode: PlateauMode,
+    // The factor by which the learning rate is multiplied on a plateau.
+    #[config(default = 0.1)]
+    factor: f64,
+    // The number of reports without improvement before reducing the learning rate.
+    #[config(default = 10)]
+    patience: usize,
+    // The relative change of the metric considered as an improvement.
+    #[config(default = 1e-4)]
+    threshold: f64,
+    // The number of reports ignored after reducing the learning rate.
+    #[config(default = 0)]
+    cooldown: usize,
+    // The minimum learning rate.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+}
+
+impl ReduceLrOnPlateauSchedulerConfig {
+    /// Initializes a [learning rate scheduler reducing the learning rate on
+    /// plateaus](ReduceLrOnPlateauScheduler).
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if any of the following conditions is true:
+    ///
+    /// * `initial_lr` is out of range (0.0, 1.0]
+    /// * `factor` is out of range (0.0, 1.0)
+    /// * `threshold` is negative
+    /// * `min_lr` is out of range [0.0, `initial_lr`]
+    pub fn init(&self) -> Result<ReduceLrOnPlateauScheduler, String> {
+        if self.initial_lr <= 0. || self.initial_lr > 1. {
+            return Err("Initial learning rate must be greater than 0 and at most 1".into());
+        }
+        if self.factor <= 0. || self.factor >= 1. {
+            return Err("Factor must be greater than 0 and less than 1".into());
+        }
+        if self.threshold < 0. {
+            return Err("Threshold must be at least 0".into());
+        }
+        if self.min_lr < 0.0 || self.min_lr > self.initial_lr {
+            return Err(
+                "Minimum learning rate must be at least 0 and at most equal to the initial \
+                 learning rate"
+                    .into(),
+            );
+        }
+
+        Ok(ReduceLrOnPlateauScheduler {
+            lr: self.initial_lr,
+            mode: self.mode.clone(),
+            factor: self.factor,
+            patience: self.patience,
+            threshold: self.threshold,
+            cooldown: self.cooldown,
+            min_lr: self.min_lr,
+            best: None,
+            num_bad_reports: 0,
+            cooldown_counter: 0,
+        })
+    }
+}
+
+/// A learning rate scheduler reducing the learning rate when a metric stops improving.
+///
+/// The metric has to be [reported](LrScheduler::report_metric) to the scheduler, which
+/// `burn-train`'s learner does at the end of every epoch when it is configured to monitor a
+/// metric. See [ReduceLrOnPlateauSchedulerConfig] for more information.
+#[derive(Clone, Debug)]
+pub struct ReduceLrOnPlateauScheduler {
+    lr: LearningRate,
+    mode: PlateauMode,
+    factor: f64,
+    patience: usize,
+    threshold: f64,
+    cooldown: usize,
+    min_lr: LearningRate,
+    // The best value reported so far.
+    best: Option<f64>,
+    // The number of reports since the last improvement.
+    num_bad_reports: usize,
+    // The number of reports to ignore.
+    cooldown_counter: usize,
+}
+
+impl ReduceLrOnPlateauScheduler {
+    fn is_improvement(&self, value: f64) -> bool {
+        let Some(best) = self.best else {
+            return true;
+        };
+
+        match self.mode {
+            PlateauMode::Min => value < best * (1.0 - self.threshold),
+            PlateauMode::Max => value > best * (1.0 + self.threshold),
+        }
+    }
+}
+
+impl LrScheduler for ReduceLrOnPlateauScheduler {
+    type Record<B: Backend> = (LearningRate, Option<f64>, usize, usize);
+
+    fn step(&mut self) -> LearningRate {
+        self.lr
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        (
+            self.lr,
+            self.best,
+            self.num_bad_reports,
+            self.cooldown_counter,
+        )
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        (
+            self.lr,
+            self.best,
+            self.num_bad_reports,
+            self.cooldown_counter,
+        ) = record;
+        self
+    }
+
+    fn report_metric(&mut self, value: f64) {
+        if self.is_improvement(value) {
+            self.best = Some(value);
+            self.num_bad_reports = 0;
+        } else {
+            self.num_bad_reports += 1;
+        }
+
+        if self.cooldown_counter > 0 {
+            self.cooldown_counter -= 1;
+            self.num_bad_reports = 0;
+        }
+
+        if self.num_bad_reports > self.patience {
+            let lr = (self.lr * self.factor).max(self.min_lr);
+            log::info!("Reducing the learning rate from {} to {lr}", self.lr);
+
+            self.lr = lr;
+            self.cooldown_counter = self.cooldown;
+            self.num_bad_reports = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils;
+    use super::*;
+
+    #[test]
+    fn config_factor_out_of_range() {
+        let r = ReduceLrOnPlateauSchedulerConfig::new(0.1)
+            .with_factor(1.0)
+            .init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Factor must be greater than 0 and less than 1",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn config_min_lr_too_high() {
+        let r = ReduceLrOnPlateauSchedulerConfig::new(0.1)
+            .with_min_lr(0.2)
+            .init();
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Minimum learning rate must be at least 0 and at most equal to the initial learning \
+             rate",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn test_lr_reduced_after_patience() {
+        let mut scheduler = ReduceLrOnPlateauSchedulerConfig::new(0.1)
+            .with_factor(0.5)
+            .with_patience(1)
+            .with_threshold(0.0)
+            .init()
+            .unwrap();
+
+        for (value, expected) in [(1.0, 0.1), (0.9, 0.1), (0.95, 0.1), (0.96, 0.05)] {
+            scheduler.report_metric(value);
+            assert_eq!(scheduler.step(), expected);
+        }
+    }
+
+    #[test]
+    fn test_lr_not_reduced_during_cooldown() {
+        let mut scheduler = ReduceLrOnPlateauSchedulerConfig::new(0.1)
+            .with_mode(PlateauMode::Max)
+            .with_factor(0.5)
+            .with_patience(0)
+            .with_cooldown(2)
+            .with_min_lr(0.02)
+            .init()
+            .unwrap();
+
+        let values = [0.5, 0.4, 0.4, 0.4, 0.4, 0.4, 0.4];
+        let expected = [0.1, 0.05, 0.05, 0.05, 0.025, 0.025, 0.025];
+        for (value, expected) in values.into_iter().zip(expected) {
+            scheduler.report_metric(value);
+            assert_eq!(scheduler.step(), expected);
+        }
+    }
+
+    #[test]
+    fn test_lr_clamped_to_min_lr() {
+        let mut scheduler = ReduceLrOnPlateauSchedulerConfig::new(0.1)
+            .with_patience(0)
+            .with_min_lr(0.05)
+            .init()
+            .unwrap();
+
+        scheduler.report_metric(1.0);
+        scheduler.report_metric(1.0);
+        test_utils::check_lr_sequence(scheduler, [0.05]);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let mut scheduler = ReduceLrOnPlateauSchedulerConfig::new(0.1)
+            .with_patience(0)
+            .init()
+            .unwrap();
+        scheduler.report_metric(1.0);
+        scheduler.report_metric(2.0);
+
+        let record = scheduler.to_record::<crate::TestBackend>();
+        let mut loaded = ReduceLrOnPlateauSchedulerConfig::new(0.1)
+            .with_patience(0)
+            .init()
+            .unwrap()
+            .load_record::<crate::TestBackend>(record);
+
+        test_utils::compare_steps(&mut loaded, &mut scheduler, 1);
+        loaded.report_metric(0.5);
+        scheduler.report_metric(0.5);
+        loaded.report_metric(0.6);
+        scheduler.report_metric(0.6);
+        test_utils::compare_steps(&mut loaded, &mut scheduler, 1);
+    }
+}
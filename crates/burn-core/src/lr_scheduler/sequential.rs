@@ -0,0 +1,111 @@
+use super::LrScheduler;
+use crate::LearningRate;
+use burn_tensor::backend::Backend;
+
+/// A learning rate scheduler following a first scheduler until a given iteration, then a second
+/// one.
+///
+/// The second scheduler starts from its own first step at the milestone. More schedulers can be
+/// chained with [then](SequentialLrScheduler::then).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // Constant for 1000 iterations, linear decay until iteration 5000, then constant again.
+/// let scheduler = SequentialLrScheduler::new(1e-3, linear, 1000).then(5000, 1e-5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SequentialLrScheduler<S1, S2> {
+    first: S1,
+    second: S2,
+    milestone: usize,
+    // The number of steps performed so far.
+    current_iter: usize,
+}
+
+impl<S1: LrScheduler, S2: LrScheduler> SequentialLrScheduler<S1, S2> {
+    /// Creates a scheduler following `first` for `milestone` iterations, then `second`.
+    pub fn new(first: S1, second: S2, milestone: usize) -> Self {
+        Self {
+            first,
+            second,
+            milestone,
+            current_iter: 0,
+        }
+    }
+
+    /// Follows the current schedulers until the iteration `milestone`, counted from the start of
+    /// the training, then `next`.
+    pub fn then<S3: LrScheduler>(
+        self,
+        milestone: usize,
+        next: S3,
+    ) -> SequentialLrScheduler<Self, S3> {
+        SequentialLrScheduler::new(self, next, milestone)
+    }
+}
+
+impl<S1: LrScheduler, S2: LrScheduler> LrScheduler for SequentialLrScheduler<S1, S2> {
+    type Record<B: Backend> = (usize, S1::Record<B>, S2::Record<B>);
+
+    fn step(&mut self) -> LearningRate {
+        let lr = match self.current_iter < self.milestone {
+            true => self.first.step(),
+            false => self.second.step(),
+        };
+        self.current_iter += 1;
+        lr
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        (
+            self.current_iter,
+            self.first.to_record::<B>(),
+            self.second.to_record::<B>(),
+        )
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        let (current_iter, first, second) = record;
+        self.current_iter = current_iter;
+        self.first = self.first.load_record::<B>(first);
+        self.second = self.second.load_record::<B>(second);
+        self
+    }
+
+    fn report_metric(&mut self, value: f64) {
+        // The metric is reported to the scheduler that gave the last learning rate.
+        match self.current_iter <= self.milestone {
+            true => self.first.report_metric(value),
+            false => self.second.report_metric(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::linear::LinearLrSchedulerConfig;
+    use super::super::test_utils;
+    use super::*;
+
+    #[test]
+    fn test_lr_change() {
+        let linear = LinearLrSchedulerConfig::new(0.5, 0.1, 2).init().unwrap();
+        let scheduler = SequentialLrScheduler::new(0.1, linear, 2);
+        test_utils::check_lr_sequence(scheduler, [0.1, 0.1, 0.5, 0.3, 0.1, 0.1]);
+    }
+
+    #[test]
+    fn test_lr_change_chained() {
+        let linear = LinearLrSchedulerConfig::new(0.5, 0.1, 2).init().unwrap();
+        let scheduler = SequentialLrScheduler::new(0.1, linear, 1).then(3, 0.05);
+        test_utils::check_lr_sequence(scheduler, [0.1, 0.5, 0.3, 0.05, 0.05]);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let linear = LinearLrSchedulerConfig::new(0.5, 0.1, 4).init().unwrap();
+        let scheduler = SequentialLrScheduler::new(0.1, linear, 2);
+        test_utils::check_save_load(scheduler, 3);
+    }
+}
@@ -0,0 +1,150 @@
+use super::{LrScheduler, String};
+use crate as burn;
+use crate::{LearningRate, config::Config};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a [warmup learning rate scheduler](WarmupLrScheduler).
+///
+/// This scheduler returns the learning rate `initial_lr` at the first step, then increases it
+/// linearly until reaching the first learning rate of the wrapped scheduler after `num_iters`
+/// iterations. The wrapped scheduler is followed from then on.
+#[derive(Config)]
+pub struct WarmupLrSchedulerConfig {
+    // The learning rate at the first step.
+    #[config(default = 0.0)]
+    initial_lr: LearningRate,
+    // The number of iterations before reaching the first learning rate of the wrapped scheduler.
+    num_iters: usize,
+}
+
+impl WarmupLrSchedulerConfig {
+    /// Initializes a [warmup learning rate scheduler](WarmupLrScheduler) wrapping the given
+    /// scheduler.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if any of the following conditions is true:
+    ///
+    /// * `initial_lr` is out of range [0.0, 1.0]
+    /// * `num_iters` is 0
+    pub fn init<S: LrScheduler>(&self, scheduler: S) -> Result<WarmupLrScheduler<S>, String> {
+        if self.initial_lr < 0. || self.initial_lr > 1. {
+            return Err("Initial learning rate must be at least 0 and at most 1".into());
+        }
+        if self.num_iters == 0 {
+            return Err("Number of iterations must be at least 1".into());
+        }
+
+        Ok(WarmupLrScheduler {
+            scheduler,
+            initial_lr: self.initial_lr,
+            num_iters: self.num_iters,
+            current_iter: usize::MAX,
+            target_lr: None,
+        })
+    }
+}
+
+/// A learning rate scheduler with a linear warmup.
+///
+/// See [WarmupLrSchedulerConfig] for more information.
+#[derive(Clone, Debug)]
+pub struct WarmupLrScheduler<S> {
+    scheduler: S,
+    initial_lr: LearningRate,
+    num_iters: usize,
+    current_iter: usize,
+    // The first learning rate of the wrapped scheduler, which is reached at the end of the
+    // warmup.
+    target_lr: Option<LearningRate>,
+}
+
+impl<S: LrScheduler> LrScheduler for WarmupLrScheduler<S> {
+    type Record<B: Backend> = (usize, Option<LearningRate>, S::Record<B>);
+
+    fn step(&mut self) -> LearningRate {
+        // Overflow from usize::MAX to 0 on the first call, like the cosine annealing scheduler.
+        self.current_iter = self.current_iter.wrapping_add(1);
+        if self.current_iter > self.num_iters {
+            return self.scheduler.step();
+        }
+
+        let target_lr = *self.target_lr.get_or_insert_with(|| self.scheduler.step());
+        self.initial_lr
+            + (target_lr - self.initial_lr) * self.current_iter as f64 / self.num_iters as f64
+    }
+
+    fn to_record<B: Backend>(&self) -> Self::Record<B> {
+        (
+            self.current_iter,
+            self.target_lr,
+            self.scheduler.to_record::<B>(),
+        )
+    }
+
+    fn load_record<B: Backend>(mut self, record: Self::Record<B>) -> Self {
+        let (current_iter, target_lr, scheduler) = record;
+        self.current_iter = current_iter;
+        self.target_lr = target_lr;
+        self.scheduler = self.scheduler.load_record::<B>(scheduler);
+        self
+    }
+
+    fn report_metric(&mut self, value: f64) {
+        self.scheduler.report_metric(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::linear::LinearLrSchedulerConfig;
+    use super::super::test_utils;
+    use super::*;
+
+    #[test]
+    fn config_initial_lr_too_high() {
+        let r = WarmupLrSchedulerConfig::new(10)
+            .with_initial_lr(1.5)
+            .init(0.5);
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Initial learning rate must be at least 0 and at most 1",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn config_num_iters_too_low() {
+        let r = WarmupLrSchedulerConfig::new(0).init(0.5);
+        assert!(r.is_err(), "Should return an error");
+        assert_eq!(
+            r.unwrap_err(),
+            "Number of iterations must be at least 1",
+            "Error messages should match",
+        );
+    }
+
+    #[test]
+    fn test_lr_change() {
+        let scheduler = WarmupLrSchedulerConfig::new(4).init(0.8).unwrap();
+        test_utils::check_lr_sequence(scheduler, [0.0, 0.2, 0.4, 0.6, 0.8, 0.8]);
+    }
+
+    #[test]
+    fn test_lr_change_then_follow_scheduler() {
+        let linear = LinearLrSchedulerConfig::new(0.8, 0.4, 2).init().unwrap();
+        let scheduler = WarmupLrSchedulerConfig::new(2)
+            .with_initial_lr(0.2)
+            .init(linear)
+            .unwrap();
+        test_utils::check_lr_sequence(scheduler, [0.2, 0.5, 0.8, 0.6, 0.4, 0.4]);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let linear = LinearLrSchedulerConfig::new(0.8, 0.4, 4).init().unwrap();
+        let scheduler = WarmupLrSchedulerConfig::new(4).init(linear).unwrap();
+        test_utils::check_save_load(scheduler, 3);
+    }
+}
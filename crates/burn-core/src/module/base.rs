@@ -1,4 +1,4 @@
-use super::{ParamId, Quantizer};
+use super::{ParamId, Quantizer, path::RequireGradMapper};
 use crate::{
     record::Record,
     tensor::backend::{AutodiffBackend, Backend},
@@ -136,6 +136,44 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
             init = || 0
         )
     }
+
+    /// Get the number of parameters that require gradients, including all of the sub-modules.
+    ///
+    /// Only modules on an [autodiff backend](AutodiffBackend) have trainable parameters.
+    fn num_trainable_params(&self) -> usize {
+        module!(
+            visit_float = self,
+            ops = |tensor: &Tensor<B, D>, state: &mut usize| {
+                if tensor.is_require_grad() {
+                    *state += tensor.shape().num_elements();
+                }
+            },
+            state = usize,
+            init = || 0
+        )
+    }
+
+    /// Freeze the float parameters whose [path](super::ModulePath::matches) matches the glob
+    /// pattern: they no longer require gradients, so they get no gradients and the optimizers
+    /// skip them without allocating state.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Only fine-tune the head.
+    /// let model = model.freeze("*").unfreeze("head");
+    /// // Freeze the biases of the encoder.
+    /// let model = model.freeze("encoder.*.bias");
+    /// ```
+    fn freeze(self, pattern: &str) -> Self {
+        self.map(&mut RequireGradMapper::new(pattern, false))
+    }
+
+    /// Unfreeze the float parameters whose [path](super::ModulePath::matches) matches the glob
+    /// pattern, so they require gradients again.
+    fn unfreeze(self, pattern: &str) -> Self {
+        self.map(&mut RequireGradMapper::new(pattern, true))
+    }
     /// Visit each tensor parameter in the module with a [visitor](ModuleVisitor).
     fn visit<Visitor: ModuleVisitor<B>>(&self, visitor: &mut Visitor);
 
@@ -143,6 +181,10 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
     fn map<Mapper: ModuleMapper<B>>(self, mapper: &mut Mapper) -> Self;
 
     /// Load the module state from a record.
+    ///
+    /// The lazy parameters of the record, such as the ones of a record loaded with the
+    /// [sharded file recorder](crate::record::ShardedFileRecorder), are read and moved to the
+    /// device of the module one at a time.
     fn load_record(self, record: Self::Record) -> Self;
 
     /// Convert the module into a record containing the state.
@@ -210,7 +252,19 @@ pub trait Module<B: Backend>: Clone + Send + core::fmt::Debug {
 }
 
 /// Module visitor trait.
+///
+/// The visitor is notified when entering and leaving each field of the module tree, which
+/// allows it to track the [path](ModulePath) of the visited tensors.
 pub trait ModuleVisitor<B: Backend> {
+    /// Called before visiting a sub-module.
+    ///
+    /// The name is the field name for structs, the variant name for enums and the index for
+    /// vectors, arrays and tuples. The container type is `Struct:<name>` or `Enum:<name>` for
+    /// derived modules, and `Vec`, `Array` or `Tuple` otherwise.
+    fn enter_module(&mut self, _name: &str, _container_type: &str) {}
+    /// Called after visiting a sub-module, with the same arguments as
+    /// [enter_module](ModuleVisitor::enter_module).
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {}
     /// Visit a float tensor in the module.
     fn visit_float<const D: usize>(&mut self, _id: ParamId, _tensor: &Tensor<B, D>) {}
     /// Visit an int tensor in the module.
@@ -220,7 +274,17 @@ pub trait ModuleVisitor<B: Backend> {
 }
 
 /// Module mapper trait.
+///
+/// Like the [visitor](ModuleVisitor), the mapper is notified when entering and leaving each
+/// field of the module tree.
 pub trait ModuleMapper<B: Backend> {
+    /// Called before mapping a sub-module.
+    ///
+    /// See [ModuleVisitor::enter_module] for the meaning of the arguments.
+    fn enter_module(&mut self, _name: &str, _container_type: &str) {}
+    /// Called after mapping a sub-module, with the same arguments as
+    /// [enter_module](ModuleMapper::enter_module).
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {}
     /// Map a float tensor in the module.
     fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
         tensor
@@ -0,0 +1,319 @@
+use super::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, ParamId};
+use crate as burn;
+use crate::config::Config;
+use crate::record::Record;
+use burn_tensor::{
+    Tensor,
+    backend::{AutodiffBackend, Backend},
+    container::TensorContainer,
+};
+use core::marker::PhantomData;
+
+/// How the decay of a [module EMA](ModuleEma) grows with the number of updates `t`, counted
+/// from the end of the warmup.
+#[derive(Config, Debug, PartialEq)]
+pub enum EmaDecaySchedule {
+    /// Use the maximum decay from the first update.
+    Constant,
+    /// Use `(1 + t) / (10 + t)`, as in TensorFlow.
+    Fraction,
+    /// Use `1 - (1 + t / inv_gamma) ^ -power`.
+    ///
+    /// A power of `2/3` suits trainings of about a million steps, and a power of `3/4` shorter
+    /// trainings.
+    Power {
+        /// Inverse multiplicative factor of the number of updates.
+        inv_gamma: f64,
+        /// Exponential factor of the schedule.
+        power: f64,
+    },
+}
+
+/// Configuration to create a [module EMA](ModuleEma).
+#[derive(Config)]
+pub struct ModuleEmaConfig {
+    /// The maximum decay.
+    #[config(default = 0.9999)]
+    decay: f64,
+    /// The minimum decay, once the warmup is over.
+    #[config(default = 0.0)]
+    min_decay: f64,
+    /// How the decay grows from the minimum to the maximum decay.
+    #[config(default = "EmaDecaySchedule::Fraction")]
+    schedule: EmaDecaySchedule,
+    /// The number of updates during which the averaged weights are a copy of the weights.
+    #[config(default = 0)]
+    warmup_steps: usize,
+}
+
+impl ModuleEmaConfig {
+    /// Initialize a [module EMA](ModuleEma) starting from the weights of the given module.
+    pub fn init<B: Backend, M: Module<B>>(&self, module: &M) -> ModuleEma<B, M> {
+        ModuleEma {
+            module: module.clone().no_grad(),
+            decay: self.decay,
+            min_decay: self.min_decay,
+            schedule: self.schedule.clone(),
+            warmup_steps: self.warmup_steps,
+            step: 0,
+            backend: PhantomData,
+        }
+    }
+}
+
+/// Exponential moving average of the weights of a module.
+///
+/// After each call to [update](ModuleEma::update), every float parameter of the averaged module
+/// becomes `decay * average + (1 - decay) * weight`, where the decay follows the
+/// [schedule](EmaDecaySchedule) of the [config](ModuleEmaConfig). The parameters are matched by
+/// [id](ParamId), so the module should be updated with the module it was created from, once
+/// trained.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut ema = ModuleEmaConfig::new().with_decay(0.999).init(&model);
+///
+/// for batch in dataloader.iter() {
+///     let grads = model.forward(batch).backward();
+///     let grads = GradientsParams::from_grads(grads, &model);
+///     model = optim.step(lr, model, grads);
+///     ema.update(&model);
+/// }
+///
+/// let model = ema.into_module();
+/// ```
+#[derive(Clone, Debug)]
+pub struct ModuleEma<B: Backend, M: Module<B>> {
+    module: M,
+    decay: f64,
+    min_decay: f64,
+    schedule: EmaDecaySchedule,
+    warmup_steps: usize,
+    step: usize,
+    backend: PhantomData<B>,
+}
+
+/// [Module EMA](ModuleEma) record.
+#[derive(Record)]
+pub struct ModuleEmaRecord<B: Backend, M: Module<B>> {
+    /// The record of the averaged module.
+    pub module: M::Record,
+    /// The number of updates.
+    pub step: usize,
+}
+
+impl<B: Backend, M: Module<B>> ModuleEma<B, M> {
+    /// Update the averaged weights with the weights of the given module.
+    pub fn update(&mut self, module: &M) {
+        self.step += 1;
+
+        let mut collector = EmaTensorCollector {
+            tensors: TensorContainer::new(),
+            backend: PhantomData,
+        };
+        module.visit(&mut collector);
+
+        let mut mapper = EmaMapper {
+            tensors: collector.tensors,
+            decay: self.decay_at(self.step),
+            backend: PhantomData,
+        };
+        self.module = self.module.clone().map(&mut mapper);
+    }
+
+    /// The decay used by the last update.
+    pub fn decay(&self) -> f64 {
+        self.decay_at(self.step)
+    }
+
+    /// The number of updates.
+    pub fn num_updates(&self) -> usize {
+        self.step
+    }
+
+    /// The averaged module.
+    pub fn module(&self) -> &M {
+        &self.module
+    }
+
+    /// Consume the EMA and return the averaged module.
+    pub fn into_module(self) -> M {
+        self.module
+    }
+
+    /// Get the current state of the EMA.
+    pub fn to_record(&self) -> ModuleEmaRecord<B, M> {
+        ModuleEmaRecord {
+            module: self.module.clone().into_record(),
+            step: self.step,
+        }
+    }
+
+    /// Load the state of the EMA.
+    pub fn load_record(mut self, record: ModuleEmaRecord<B, M>) -> Self {
+        self.module = self.module.load_record(record.module);
+        self.step = record.step;
+        self
+    }
+
+    fn decay_at(&self, step: usize) -> f64 {
+        // The first update after the warmup copies the weights.
+        let t = match step.checked_sub(self.warmup_steps + 1) {
+            Some(t) if t > 0 => t as f64,
+            _ => return 0.0,
+        };
+
+        let decay = match &self.schedule {
+            EmaDecaySchedule::Constant => self.decay,
+            EmaDecaySchedule::Fraction => (1.0 + t) / (10.0 + t),
+            EmaDecaySchedule::Power { inv_gamma, power } => {
+                1.0 - (1.0 + t / inv_gamma).powf(-power)
+            }
+        };
+
+        decay.min(self.decay).max(self.min_decay)
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModuleEma<B, M> {
+    /// The averaged module on the inner backend, to be used for validation.
+    pub fn valid(&self) -> M::InnerModule {
+        self.module.valid()
+    }
+}
+
+struct EmaTensorCollector<B: Backend> {
+    tensors: TensorContainer<ParamId>,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleVisitor<B> for EmaTensorCollector<B> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        self.tensors
+            .register::<B>(id, tensor.clone().detach().into_primitive());
+    }
+}
+
+struct EmaMapper<B: Backend> {
+    tensors: TensorContainer<ParamId>,
+    decay: f64,
+    backend: PhantomData<B>,
+}
+
+impl<B: Backend> ModuleMapper<B> for EmaMapper<B> {
+    fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let Some(value) = self.tensors.remove::<B>(&id) else {
+            return tensor;
+        };
+        let value = Tensor::<B, D>::from_primitive(value);
+
+        tensor
+            .mul_scalar(self.decay)
+            .add(value.mul_scalar(1.0 - self.decay))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TestAutodiffBackend,
+        nn::{Linear, LinearConfig},
+    };
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn first_update_should_copy_the_weights_then_average_them() {
+        let layer = layer();
+        let mut ema = ModuleEmaConfig::new().init(&layer);
+
+        ema.update(&fill(layer.clone(), 1.0));
+        assert_eq!(ema.decay(), 0.0);
+        assert_weights(&ema, 1.0);
+
+        ema.update(&fill(layer, 3.0));
+        // (1 + 1) / (10 + 1) * 1 + (1 - 2 / 11) * 3
+        assert_weights(&ema, 29.0 / 11.0);
+        assert!(!ema.module().weight.val().is_require_grad());
+    }
+
+    #[test]
+    fn decay_should_follow_the_schedule_after_the_warmup() {
+        let ema = ModuleEmaConfig::new()
+            .with_decay(0.999)
+            .with_schedule(EmaDecaySchedule::Power {
+                inv_gamma: 1.0,
+                power: 0.75,
+            })
+            .with_warmup_steps(2)
+            .init(&layer());
+
+        let decays = [1, 2, 3, 4, 5, 1_000_000].map(|step| ema.decay_at(step));
+
+        let expected = [0.0, 0.0, 0.0, 0.405396, 0.561309, 0.999];
+        for (decay, expected) in decays.into_iter().zip(expected) {
+            assert!(
+                (decay - expected).abs() < 1e-6,
+                "Expected decay {expected}, got {decay}"
+            );
+        }
+    }
+
+    #[test]
+    fn constant_decay_should_be_clamped_by_the_minimum() {
+        let ema = ModuleEmaConfig::new()
+            .with_decay(0.9)
+            .with_min_decay(0.95)
+            .with_schedule(EmaDecaySchedule::Constant)
+            .init(&layer());
+
+        assert_eq!(ema.decay_at(1), 0.0);
+        assert_eq!(ema.decay_at(2), 0.95);
+    }
+
+    #[test]
+    fn ema_should_be_restored_from_record() {
+        let layer = layer();
+        let config = ModuleEmaConfig::new();
+        let mut ema = config.init(&layer);
+        ema.update(&fill(layer.clone(), 1.0));
+        ema.update(&fill(layer.clone(), 3.0));
+
+        let ema_restored = config.init(&layer).load_record(ema.to_record());
+
+        assert_eq!(ema_restored.num_updates(), 2);
+        assert_weights(&ema_restored, 29.0 / 11.0);
+    }
+
+    fn layer() -> Linear<TestAutodiffBackend> {
+        LinearConfig::new(2, 2).init(&Default::default())
+    }
+
+    fn fill(layer: Linear<TestAutodiffBackend>, value: f32) -> Linear<TestAutodiffBackend> {
+        struct Fill(f32);
+
+        impl<B: Backend> ModuleMapper<B> for Fill {
+            fn map_float<const D: usize>(
+                &mut self,
+                _id: ParamId,
+                tensor: Tensor<B, D>,
+            ) -> Tensor<B, D> {
+                tensor.zeros_like().add_scalar(self.0).require_grad()
+            }
+        }
+
+        layer.map(&mut Fill(value))
+    }
+
+    fn assert_weights(
+        ema: &ModuleEma<TestAutodiffBackend, Linear<TestAutodiffBackend>>,
+        value: f32,
+    ) {
+        ema.module()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[value, value], [value, value]]), 5);
+    }
+}
@@ -1,9 +1,13 @@
 mod base;
 mod display;
+mod ema;
 mod param;
+mod path;
 mod quantize;
 
 pub use base::*;
 pub use display::*;
+pub use ema::*;
 pub use param::*;
+pub use path::*;
 pub use quantize::*;
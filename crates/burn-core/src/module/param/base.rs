@@ -126,6 +126,12 @@ impl<T: Parameter> Param<T> {
             .clone()
     }
 
+    /// Whether the parameter value has already been initialized, which is not the case for lazy
+    /// parameters that haven't been used yet.
+    pub fn is_initialized(&self) -> bool {
+        self.state.get().is_some()
+    }
+
     /// Gets the parameter's value while consuming the parameter.
     pub fn into_value(self) -> T {
         self.consume().1
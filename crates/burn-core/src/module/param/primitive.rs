@@ -100,13 +100,25 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(i, module)| {
+            let name = format!("{i}");
+            visitor.enter_module(&name, "Vec");
             module.visit(visitor);
+            visitor.exit_module(&name, "Vec");
         });
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        self.into_iter().map(|module| module.map(mapper)).collect()
+        self.into_iter()
+            .enumerate()
+            .map(|(i, module)| {
+                let name = format!("{i}");
+                mapper.enter_module(&name, "Vec");
+                let module = module.map(mapper);
+                mapper.exit_module(&name, "Vec");
+                module
+            })
+            .collect()
     }
 
     fn into_record(self) -> Self::Record {
@@ -199,13 +211,24 @@ where
     }
 
     fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-        self.iter().for_each(|module| {
+        self.iter().enumerate().for_each(|(i, module)| {
+            let name = format!("{i}");
+            visitor.enter_module(&name, "Array");
             module.visit(visitor);
+            visitor.exit_module(&name, "Array");
         });
     }
 
     fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-        self.map(|module| module.map(mapper))
+        let mut i = 0;
+        self.map(|module| {
+            let name = format!("{i}");
+            i += 1;
+            mapper.enter_module(&name, "Array");
+            let module = module.map(mapper);
+            mapper.exit_module(&name, "Array");
+            module
+        })
     }
 
     fn load_record(self, record: Self::Record) -> Self {
@@ -288,11 +311,22 @@ macro_rules! impl_module_tuple {
             }
 
             fn visit<V: ModuleVisitor<B>>(&self, visitor: &mut V) {
-                $(self.$i.visit(visitor);)*
+                $(
+                    visitor.enter_module(stringify!($i), "Tuple");
+                    self.$i.visit(visitor);
+                    visitor.exit_module(stringify!($i), "Tuple");
+                )*
             }
 
             fn map<M: ModuleMapper<B>>(self, mapper: &mut M) -> Self {
-                ($(self.$i.map(mapper),)*)
+                ($(
+                    {
+                        mapper.enter_module(stringify!($i), "Tuple");
+                        let module = self.$i.map(mapper);
+                        mapper.exit_module(stringify!($i), "Tuple");
+                        module
+                    },
+                )*)
             }
 
             fn load_record(self, record: Self::Record) -> Self {
@@ -0,0 +1,382 @@
+use super::{Module, ModuleMapper, ModuleVisitor, ParamId};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use burn_tensor::{Bool, Int, Shape, Tensor, backend::Backend};
+use core::marker::PhantomData;
+
+/// The path of a sub-module or a parameter in a module tree, such as
+/// `encoder.layers.3.attn.query.weight`.
+///
+/// [Visitors](ModuleVisitor) and [mappers](super::ModuleMapper) can keep a path up to date by
+/// calling [enter](ModulePath::enter) and [exit](ModulePath::exit) from their `enter_module` and
+/// `exit_module` methods.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ModulePath {
+    // The name and the container type of each entered field.
+    segments: Vec<(String, String)>,
+}
+
+impl ModulePath {
+    /// Create an empty path, pointing to the root module.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enter a field of the current module.
+    pub fn enter(&mut self, name: &str, container_type: &str) {
+        self.segments
+            .push((name.to_string(), container_type.to_string()));
+    }
+
+    /// Leave the last entered field.
+    pub fn exit(&mut self) {
+        self.segments.pop();
+    }
+
+    /// The names of the entered fields, from the root module.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Whether the path points to the root module.
+    pub fn is_root(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Whether the path, or the path of one of its parent modules, matches the glob pattern.
+    ///
+    /// In the pattern, `*` matches any sequence of characters, including dots, and `?` matches
+    /// any single character. For instance, `encoder`, `encoder.*` and `*.bias` all match
+    /// `encoder.layers.3.attn.query.bias`.
+    pub fn matches(&self, pattern: &str) -> bool {
+        let mut path = String::new();
+
+        self.names().any(|name| {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(name);
+            glob_match(pattern.as_bytes(), path.as_bytes())
+        })
+    }
+
+    /// The type name of the innermost struct or enum module containing the current field, such
+    /// as `Linear` for the path `encoder.layers.3.attn.query.weight`.
+    pub fn module_type(&self) -> Option<&str> {
+        self.segments.iter().rev().find_map(|(_, container_type)| {
+            container_type
+                .strip_prefix("Struct:")
+                .or_else(|| container_type.strip_prefix("Enum:"))
+        })
+    }
+}
+
+impl core::fmt::Display for ModulePath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, name) in self.names().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            f.write_str(name)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    // The position of the last star in the pattern, and of the text it is matched against.
+    let mut star = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                // Let the last star match one more character.
+                Some((star_p, star_t)) => {
+                    star = Some((star_p, star_t + 1));
+                    p = star_p + 1;
+                    t = star_t + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+/// Set whether the float parameters matching a path pattern require gradients.
+pub(crate) struct RequireGradMapper<'a> {
+    pattern: &'a str,
+    require_grad: bool,
+    path: ModulePath,
+}
+
+impl<'a> RequireGradMapper<'a> {
+    pub(crate) fn new(pattern: &'a str, require_grad: bool) -> Self {
+        Self {
+            pattern,
+            require_grad,
+            path: ModulePath::new(),
+        }
+    }
+}
+
+impl<B: Backend> ModuleMapper<B> for RequireGradMapper<'_> {
+    fn enter_module(&mut self, name: &str, container_type: &str) {
+        self.path.enter(name, container_type);
+    }
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.exit();
+    }
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if tensor.is_require_grad() == self.require_grad || !self.path.matches(self.pattern) {
+            return tensor;
+        }
+
+        tensor.set_require_grad(self.require_grad)
+    }
+}
+
+/// A parameter of a module tree with its path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NamedParam {
+    /// The path of the parameter, such as `encoder.layers.3.attn.query.weight`.
+    pub path: String,
+    /// The type name of the module owning the parameter, such as `Linear`.
+    pub module_type: String,
+    /// The parameter id.
+    pub id: ParamId,
+    /// The shape of the parameter.
+    pub shape: Shape,
+}
+
+struct NamedParamCollector<'a, M> {
+    params: &'a mut Vec<NamedParam>,
+    path: ModulePath,
+    phantom: PhantomData<M>,
+}
+
+impl<M> NamedParamCollector<'_, M> {
+    fn collect(&mut self, id: ParamId, shape: Shape) {
+        self.params.push(NamedParam {
+            path: format!("{}", self.path),
+            module_type: self.path.module_type().unwrap_or_default().to_string(),
+            id,
+            shape,
+        });
+    }
+}
+
+impl<B, M> ModuleVisitor<B> for NamedParamCollector<'_, M>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    fn enter_module(&mut self, name: &str, container_type: &str) {
+        self.path.enter(name, container_type);
+    }
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.exit();
+    }
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        self.collect(id, tensor.shape());
+    }
+    fn visit_int<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D, Int>) {
+        self.collect(id, tensor.shape());
+    }
+    fn visit_bool<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D, Bool>) {
+        self.collect(id, tensor.shape());
+    }
+}
+
+/// List all the parameters in a module with their paths, in visiting order.
+pub fn list_named_params<M: Module<B>, B: Backend>(module: &M) -> Vec<NamedParam> {
+    let mut params = Vec::new();
+    let mut visitor = NamedParamCollector {
+        params: &mut params,
+        path: ModulePath::new(),
+        phantom: PhantomData::<M>,
+    };
+    module.visit(&mut visitor);
+
+    params
+}
+
+struct MatchingParamIdCollector<'a, M> {
+    param_ids: &'a mut Vec<ParamId>,
+    pattern: &'a str,
+    path: ModulePath,
+    phantom: PhantomData<M>,
+}
+
+impl<B, M> ModuleVisitor<B> for MatchingParamIdCollector<'_, M>
+where
+    B: Backend,
+    M: Module<B>,
+{
+    fn enter_module(&mut self, name: &str, container_type: &str) {
+        self.path.enter(name, container_type);
+    }
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.exit();
+    }
+    fn visit_float<const D: usize>(&mut self, id: ParamId, _tensor: &Tensor<B, D>) {
+        if self.path.matches(self.pattern) {
+            self.param_ids.push(id);
+        }
+    }
+}
+
+/// List the ids of the float parameters whose [path](ModulePath::matches) matches the glob
+/// pattern, in visiting order.
+pub fn list_param_ids_matching<M: Module<B>, B: Backend>(
+    module: &M,
+    pattern: &str,
+) -> Vec<ParamId> {
+    let mut param_ids = Vec::new();
+    let mut visitor = MatchingParamIdCollector {
+        param_ids: &mut param_ids,
+        pattern,
+        path: ModulePath::new(),
+        phantom: PhantomData::<M>,
+    };
+    module.visit(&mut visitor);
+
+    param_ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::TestBackend;
+    use crate::module::ModuleMapper;
+    use crate::nn::{Linear, LinearConfig, RmsNorm, RmsNormConfig};
+    use alloc::vec;
+
+    #[derive(Module, Debug)]
+    struct Block<B: Backend> {
+        linears: Vec<Linear<B>>,
+        norms: (RmsNorm<B>, Option<RmsNorm<B>>),
+    }
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        blocks: [Block<B>; 2],
+        head: Linear<B>,
+    }
+
+    fn model() -> Model<TestBackend> {
+        let device = Default::default();
+        let block = || Block {
+            linears: vec![
+                LinearConfig::new(2, 3).init(&device),
+                LinearConfig::new(3, 2).with_bias(false).init(&device),
+            ],
+            norms: (RmsNormConfig::new(2).init(&device), None),
+        };
+
+        Model {
+            blocks: [block(), block()],
+            head: LinearConfig::new(2, 1).init(&device),
+        }
+    }
+
+    #[test]
+    fn named_params_should_have_the_field_paths() {
+        let params = list_named_params(&model());
+
+        let paths = params
+            .iter()
+            .map(|param| (param.path.as_str(), param.module_type.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                ("blocks.0.linears.0.weight", "Linear"),
+                ("blocks.0.linears.0.bias", "Linear"),
+                ("blocks.0.linears.1.weight", "Linear"),
+                ("blocks.0.norms.0.gamma", "RmsNorm"),
+                ("blocks.1.linears.0.weight", "Linear"),
+                ("blocks.1.linears.0.bias", "Linear"),
+                ("blocks.1.linears.1.weight", "Linear"),
+                ("blocks.1.norms.0.gamma", "RmsNorm"),
+                ("head.weight", "Linear"),
+                ("head.bias", "Linear"),
+            ]
+        );
+        assert_eq!(params[0].shape, Shape::new([2, 3]));
+    }
+
+    #[test]
+    fn mapper_should_be_notified_of_the_field_paths() {
+        struct ZeroBiases {
+            path: ModulePath,
+        }
+
+        impl<B: Backend> ModuleMapper<B> for ZeroBiases {
+            fn enter_module(&mut self, name: &str, container_type: &str) {
+                self.path.enter(name, container_type);
+            }
+            fn exit_module(&mut self, _name: &str, _container_type: &str) {
+                self.path.exit();
+            }
+            fn map_float<const D: usize>(
+                &mut self,
+                _id: ParamId,
+                tensor: Tensor<B, D>,
+            ) -> Tensor<B, D> {
+                match self.path.names().last() {
+                    Some("bias") => tensor.zeros_like(),
+                    _ => tensor,
+                }
+            }
+        }
+
+        let mut mapper = ZeroBiases {
+            path: ModulePath::new(),
+        };
+        let model = model().map(&mut mapper);
+
+        assert!(mapper.path.is_root());
+        model
+            .head
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&burn_tensor::TensorData::from([0.0f32]), false);
+    }
+
+    #[test]
+    fn path_should_be_displayed_with_dots() {
+        let mut path = ModulePath::new();
+        path.enter("encoder", "Struct:Model");
+        path.enter("3", "Vec");
+        path.enter("weight", "Struct:Linear");
+
+        assert_eq!(format!("{path}"), "encoder.3.weight");
+        for pattern in ["encoder", "encoder.*", "*.weight", "*.?.*", "*"] {
+            assert!(path.matches(pattern), "{pattern} should match {path}");
+        }
+        for pattern in ["decoder", "encoder.1", "*.bias", "enc", "encoder.3.w"] {
+            assert!(!path.matches(pattern), "{pattern} shouldn't match {path}");
+        }
+        assert_eq!(path.module_type(), Some("Linear"));
+        path.exit();
+        assert_eq!(path.module_type(), Some("Model"));
+    }
+}
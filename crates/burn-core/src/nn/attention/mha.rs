@@ -6,7 +6,10 @@ use crate::nn::cache::TensorCache;
 use crate::{
     config::Config,
     nn,
-    tensor::{Bool, Tensor, activation, backend::Backend},
+    tensor::{
+        Bool, Tensor, activation, backend::Backend, module::scaled_dot_product_attention,
+        ops::AttentionOptions,
+    },
 };
 
 #[cfg(not(feature = "std"))]
@@ -25,6 +28,8 @@ pub struct MultiHeadAttentionConfig {
     /// The minimum value a float can take. Default: -1.0e4
     /// This is used to mask attention scores before calculating attention weights.
     /// A value too low might result in NaN.
+    /// It is only used when the attention weights are materialized, the fused attention masking
+    /// the scores with negative infinity instead.
     #[config(default = -1.0e4)]
     pub min_float: f64,
     /// Use "quiet softmax" instead of regular softmax.
@@ -109,6 +114,7 @@ pub struct MhaInput<B: Backend> {
     value: Tensor<B, 3>,
     mask_pad: Option<Tensor<B, 2, Bool>>,
     mask_attn: Option<Tensor<B, 3, Bool>>,
+    need_weights: bool,
 }
 
 impl MultiHeadAttentionConfig {
@@ -149,6 +155,7 @@ impl<B: Backend> MhaInput<B> {
             value: tensor,
             mask_pad: None,
             mask_attn: None,
+            need_weights: false,
         }
     }
 
@@ -160,6 +167,7 @@ impl<B: Backend> MhaInput<B> {
             value,
             mask_pad: None,
             mask_attn: None,
+            need_weights: false,
         }
     }
 
@@ -174,13 +182,24 @@ impl<B: Backend> MhaInput<B> {
         self.mask_attn = Some(mask_attn);
         self
     }
+
+    /// Return the attention weights in the [output](MhaOutput).
+    ///
+    /// The weights are materialized with shape `[batch_size, n_heads, seq_length_1, seq_length_2]`
+    /// instead of using the fused [attention](crate::tensor::module::scaled_dot_product_attention),
+    /// so they should only be requested when needed.
+    pub fn need_weights(mut self, need_weights: bool) -> Self {
+        self.need_weights = need_weights;
+        self
+    }
 }
 
 /// [Multihead attention](MultiHeadAttention) outputs.
 #[derive(Debug, Clone)]
 pub struct MhaOutput<B: Backend> {
-    /// The attention weights `[batch_size, n_heads, seq_length_1, seq_length_2]`.
-    pub weights: Tensor<B, 4>,
+    /// The attention weights `[batch_size, n_heads, seq_length_1, seq_length_2]`, only returned
+    /// when [requested](MhaInput::need_weights).
+    pub weights: Option<Tensor<B, 4>>,
     /// The context tensor `[batch_size, seq_length_1, d_model]`.
     pub context: Tensor<B, 3>,
 }
@@ -197,19 +216,18 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward(&self, input: MhaInput<B>) -> MhaOutput<B> {
-        let [batch_size, seq_length_1, d_model] = input.query.dims();
-
         let query = self.attention_linear(input.query, &self.query);
         let key = self.attention_linear(input.key, &self.key);
         let value = self.attention_linear(input.value, &self.value);
 
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
-
-        let context = weights.clone().matmul(value);
-        let context = context
-            .swap_dims(1, 2)
-            .reshape([batch_size, seq_length_1, d_model]);
+        let (context, weights) = self.attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            input.need_weights,
+        );
         let context = self.output.forward(context);
 
         MhaOutput { weights, context }
@@ -224,8 +242,6 @@ impl<B: Backend> MultiHeadAttention<B> {
     /// - value: `[batch_size, seq_length_2, d_model]`
     /// - output: `[batch_size, seq_length_1, d_model]`
     pub fn forward_cache(&self, input: MhaInput<B>, cache: &mut MhaCache<B>) -> MhaOutput<B> {
-        let [batch_size, seq_length_1, d_model] = input.query.dims();
-
         let query = cache
             .query
             .forward(input.query, |t| self.attention_linear(t, &self.query));
@@ -236,17 +252,62 @@ impl<B: Backend> MultiHeadAttention<B> {
             .value
             .forward(input.value, |t| self.attention_linear(t, &self.value));
 
-        let attn_scores = self.attn_scores(query, key);
-        let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
+        let (context, weights) = self.attention(
+            query,
+            key,
+            value,
+            input.mask_pad,
+            input.mask_attn,
+            input.need_weights,
+        );
+        let context = cache.output.forward(context, |t| self.output.forward(t));
+
+        MhaOutput { weights, context }
+    }
+
+    /// Computes the attention of the heads, concatenated as `[batch_size, seq_length_1, d_model]`.
+    ///
+    /// The fused [attention](crate::tensor::module::scaled_dot_product_attention) is used unless
+    /// the weights are requested or the quiet softmax is enabled, since it doesn't materialize
+    /// the weights and only supports the regular softmax.
+    fn attention(
+        &self,
+        query: Tensor<B, 4>,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        mask_pad: Option<Tensor<B, 2, Bool>>,
+        mask_attn: Option<Tensor<B, 3, Bool>>,
+        need_weights: bool,
+    ) -> (Tensor<B, 3>, Option<Tensor<B, 4>>) {
+        let [batch_size, _n_heads, seq_length_1, _d_k] = query.dims();
+
+        let (context, weights) = if need_weights || self.quiet_softmax {
+            let attn_scores = self.attn_scores(query, key);
+            let weights = self.attn_weights(attn_scores, mask_pad, mask_attn);
+            let context = weights.clone().matmul(value);
+
+            (context, need_weights.then_some(weights))
+        } else {
+            let mask_shape = [batch_size, 1, seq_length_1, key.dims()[2]];
+            let mask_pad = mask_pad.map(|mask| mask.reshape([batch_size, 1, 1, mask_shape[3]]));
+            let mask_attn = mask_attn.map(|mask| mask.reshape(mask_shape));
+            let mask = match (mask_pad, mask_attn) {
+                (Some(mask_pad), Some(mask_attn)) => {
+                    Some(mask_attn.bool_or(mask_pad.expand(mask_shape)))
+                }
+                (mask_pad, mask_attn) => mask_pad.or(mask_attn),
+            };
+            let options = AttentionOptions::new(None, false, self.dropout.prob);
+            let context = scaled_dot_product_attention(query, key, value, mask, options);
+
+            (context, None)
+        };
 
-        let context = weights.clone().matmul(value);
         let context = context
             .swap_dims(1, 2)
-            .reshape([batch_size, seq_length_1, d_model]);
+            .reshape([batch_size, seq_length_1, self.d_model]);
 
-        let context = cache.output.forward(context, |t| self.output.forward(t));
-
-        MhaOutput { weights, context }
+        (context, weights)
     }
 
     fn attn_scores(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
@@ -367,7 +428,8 @@ mod tests {
             [batch_size, seq_length, d_model],
             Distribution::Default,
             &device,
-        ));
+        ))
+        .need_weights(true);
 
         let output = mha.forward(input);
 
@@ -377,7 +439,7 @@ mod tests {
             "Context should have the correct shape",
         );
         assert_eq!(
-            output.weights.shape(),
+            output.weights.unwrap().shape(),
             Shape::new([batch_size, n_heads, seq_length, seq_length]),
             "Weights should have the correct shape",
         );
@@ -405,7 +467,8 @@ mod tests {
                 Distribution::Default,
                 &device,
             ),
-        );
+        )
+        .need_weights(true);
 
         let output = mha.forward(input);
 
@@ -415,7 +478,7 @@ mod tests {
             "Context should have the correct shape",
         );
         assert_eq!(
-            output.weights.shape(),
+            output.weights.unwrap().shape(),
             Shape::new([batch_size, n_heads, seq_length_1, seq_length_2]),
             "Weights should have the correct shape",
         );
@@ -475,6 +538,40 @@ mod tests {
             );
     }
 
+    #[test]
+    fn test_fused_attention_should_match_the_materialized_weights() {
+        let [batch_size, seq_length, d_model, n_heads, num_padded] = [2, 5, 16, 4, 1];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads)
+            .with_dropout(0.0)
+            .init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_pad = Tensor::<TestBackend, 2, Int>::zeros([batch_size, seq_length], &device)
+            .slice_assign(
+                [0..batch_size, seq_length - num_padded..seq_length],
+                Tensor::ones([batch_size, num_padded], &device),
+            )
+            .equal_elem(1);
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let input = MhaInput::self_attn(tensor)
+            .mask_pad(mask_pad)
+            .mask_attn(mask_attn);
+
+        let fused = mha.forward(input.clone());
+        let materialized = mha.forward(input.need_weights(true));
+
+        assert!(fused.weights.is_none());
+        fused
+            .context
+            .into_data()
+            .assert_approx_eq(&materialized.context.into_data(), 3);
+    }
+
     #[test]
     fn test_autoregressive_mask_should_have_same_output_as_autoregressive_decoding() {
         let [batch_size, seq_length, d_model, n_heads] = [3, 4, 12, 2];
@@ -0,0 +1,564 @@
+use alloc::{format, string::String, vec::Vec};
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{Module, ModuleMapper, ModulePath, ModuleVisitor, Param, ParamId};
+use crate::nn::{Dropout, DropoutConfig, Initializer};
+use crate::tensor::{Shape, Tensor, backend::Backend};
+
+/// Configuration to add [low-rank adapters](Lora) to a module, using the
+/// [init function](LoraConfig::init).
+#[derive(Config, Debug)]
+pub struct LoraConfig {
+    /// The rank of the adapters.
+    pub rank: usize,
+    /// The scaling numerator of the adapters: the low-rank update is multiplied by
+    /// `alpha / rank`. Default: 8.0
+    #[config(default = 8.0)]
+    pub alpha: f64,
+    /// The dropout probability applied to the input of the adapters by their
+    /// [forward path](LoraAdapters::forward), which can't be used with [model](Lora::model) when
+    /// it isn't zero. Default: 0.0
+    #[config(default = 0.0)]
+    pub dropout: f64,
+    /// The [glob pattern](crate::module::ModulePath::matches) selecting the layers to adapt.
+    /// Default: every layer
+    #[config(default = "String::from(\"*\")")]
+    pub pattern: String,
+    /// The initializer of the down projection of the adapters. The up projection is initialized
+    /// with zeros, so the adapters start as an identity.
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// A module whose linear and convolution weights are adapted with trainable low-rank updates, as
+/// introduced in [LoRA: Low-Rank Adaptation of Large Language Models](https://arxiv.org/abs/2106.09685).
+///
+/// The weight `W` of each adapted layer becomes `W + (alpha / rank) * A B`, where `A` and `B`
+/// are the [adapter](LoraAdapter) projections. The base module is frozen, so only the adapters
+/// are trained. They can be saved and loaded on their own with the record of the
+/// [adapters](Lora::adapters), and merged into the base weights for inference with
+/// [merge](Lora::merge).
+///
+/// The adapted weights of [model](Lora::model) can't apply dropout, which drops the input of the
+/// adapters, so it panics when the dropout is enabled. To regularize the adapters of linear
+/// layers with dropout, the base layers are called with the [forward path](LoraAdapters::forward)
+/// of their adapter instead, computing `x W + (alpha / rank) * dropout(x) A B` without
+/// materializing the adapted weight.
+///
+/// Should be created with [LoraConfig].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let lora = LoraConfig::new(8)
+///     .with_pattern("*.attn.*".into())
+///     .init(model);
+///
+/// // Training: the adapters are the only parameters requiring gradients.
+/// let output = lora.model().forward(input);
+///
+/// // Training with dropout on the input of the adapter of a linear layer.
+/// let query = lora.base.attn.query.forward(x.clone())
+///     + lora.adapters.forward("attn.query.weight", x);
+///
+/// // Save the adapters only, then merge them for inference.
+/// recorder.record(lora.adapters.clone().into_record(), "adapters".into())?;
+/// let model = lora.merge();
+/// ```
+#[derive(Module, Debug)]
+pub struct Lora<B: Backend, M> {
+    /// The frozen base module.
+    pub base: M,
+    /// The adapters of the base module.
+    pub adapters: LoraAdapters<B>,
+}
+
+/// The [low-rank adapters](LoraAdapter) of a [module](Lora).
+#[derive(Module, Debug)]
+pub struct LoraAdapters<B: Backend> {
+    /// The adapter of each adapted weight, in visiting order.
+    pub adapters: Vec<LoraAdapter<B>>,
+    /// The dropout applied to the input of the adapters by their [forward path](Self::forward).
+    pub dropout: Dropout,
+    /// The factor of the low-rank updates, `alpha / rank`.
+    pub scaling: f64,
+}
+
+/// The low-rank adapter of a linear or convolution weight.
+#[derive(Module, Debug)]
+pub struct LoraAdapter<B: Backend> {
+    /// Down projection of shape `[fan_in, rank]`, where `fan_in` is the number of input features
+    /// of the layer times the size of its kernel.
+    pub lora_a: Param<Tensor<B, 2>>,
+    /// Up projection of shape `[rank, fan_out]`, initialized with zeros.
+    pub lora_b: Param<Tensor<B, 2>>,
+    /// The path of the adapted weight, such as `encoder.layers.3.attn.query.weight`.
+    pub path: String,
+    /// Whether the weight stores its output features first, as for convolutions.
+    pub output_first: bool,
+}
+
+impl LoraConfig {
+    /// Add [low-rank adapters](Lora) to the weights of the [linear](crate::nn::Linear) and
+    /// convolution layers of the module whose path matches the [pattern](LoraConfig::pattern),
+    /// and freeze the module.
+    ///
+    /// # Panics
+    ///
+    /// If the rank is zero or if no layer matches the pattern.
+    pub fn init<B: Backend, M: Module<B>>(&self, module: M) -> Lora<B, M> {
+        assert!(self.rank > 0, "The LoRA rank must be at least 1.");
+
+        let mut collector = LoraTargetCollector {
+            pattern: &self.pattern,
+            path: ModulePath::new(),
+            adapters: Vec::new(),
+            config: self,
+        };
+        module.visit(&mut collector);
+
+        assert!(
+            !collector.adapters.is_empty(),
+            "No linear or convolution layer matches the LoRA pattern '{}'.",
+            self.pattern
+        );
+
+        Lora {
+            base: module.no_grad(),
+            adapters: LoraAdapters {
+                adapters: collector.adapters,
+                dropout: DropoutConfig::new(self.dropout).init(),
+                scaling: self.alpha / self.rank as f64,
+            },
+        }
+    }
+
+    fn init_adapter<B: Backend>(
+        &self,
+        path: String,
+        output_first: bool,
+        shape: Shape,
+        device: &B::Device,
+    ) -> LoraAdapter<B> {
+        let (fan_in, fan_out) = if output_first {
+            (shape.num_elements() / shape.dims[0], shape.dims[0])
+        } else {
+            (shape.dims[0], shape.dims[1])
+        };
+
+        LoraAdapter {
+            lora_a: self.initializer.init_with(
+                [fan_in, self.rank],
+                Some(fan_in),
+                Some(self.rank),
+                device,
+            ),
+            lora_b: Initializer::Zeros.init([self.rank, fan_out], device),
+            path,
+            output_first,
+        }
+    }
+}
+
+impl<B: Backend, M: Module<B>> Lora<B, M> {
+    /// The base module with the low-rank updates added to its adapted weights.
+    ///
+    /// The weights are computed from the adapters, so the gradients of the output flow back to
+    /// the adapters. Each call materializes the dense adapted weights, so the module should be
+    /// computed once after each optimizer step rather than for every forward pass.
+    ///
+    /// # Panics
+    ///
+    /// If the dropout of the adapters is enabled, since it drops their input and can't be
+    /// applied to the weights. The [forward path](LoraAdapters::forward) should be used instead.
+    pub fn model(&self) -> M {
+        assert!(
+            self.adapters.dropout.prob == 0.0,
+            "The LoRA dropout can't be applied to the adapted weights: call the base layers with \
+             the forward path of the adapters instead."
+        );
+
+        self.adapted()
+    }
+
+    /// Merge the adapters into the weights of the base module, for inference.
+    ///
+    /// The merged module runs as fast as the base module and keeps its parameters frozen. The
+    /// dropout of the adapters is never applied, even with an autodiff backend.
+    pub fn merge(self) -> M {
+        self.adapted().no_grad()
+    }
+
+    fn adapted(&self) -> M {
+        self.base.clone().map(&mut LoraMapper {
+            adapters: &self.adapters,
+            path: ModulePath::new(),
+        })
+    }
+}
+
+impl<B: Backend> LoraAdapters<B> {
+    /// The low-rank update of the output of the linear layer whose weight is at `path`, such as
+    /// `encoder.layers.3.attn.query.weight`, with the dropout applied to the input.
+    ///
+    /// Added to the output of the frozen [base](Lora::base) layer, it gives the output of the
+    /// adapted layer.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., d_input]`
+    /// - output: `[..., d_output]`
+    ///
+    /// # Panics
+    ///
+    /// If no linear layer at `path` is adapted.
+    pub fn forward<const D: usize>(&self, path: &str, input: Tensor<B, D>) -> Tensor<B, D> {
+        let adapter = self
+            .adapters
+            .iter()
+            .find(|adapter| adapter.path == path && !adapter.output_first)
+            .unwrap_or_else(|| panic!("No linear layer at '{path}' is adapted."));
+
+        let input = self.dropout.forward(input);
+        let lora_a = adapter.lora_a.val().unsqueeze();
+        let lora_b = adapter.lora_b.val().unsqueeze();
+
+        input.matmul(lora_a).matmul(lora_b).mul_scalar(self.scaling)
+    }
+}
+
+impl<B: Backend> LoraAdapter<B> {
+    /// The low-rank update of the weight, with the shape of the weight.
+    fn delta<const D: usize>(&self, scaling: f64, shape: Shape) -> Tensor<B, D> {
+        let delta = self
+            .lora_a
+            .val()
+            .matmul(self.lora_b.val())
+            .mul_scalar(scaling);
+        let delta = match self.output_first {
+            true => delta.transpose(),
+            false => delta,
+        };
+
+        delta.reshape(shape)
+    }
+}
+
+/// Whether a weight of the given module type can be adapted, and whether it stores its output
+/// features first.
+fn adapted_layout(module_type: Option<&str>) -> Option<bool> {
+    match module_type? {
+        "Linear" => Some(false),
+        "Conv1d" | "Conv2d" | "Conv3d" => Some(true),
+        _ => None,
+    }
+}
+
+struct LoraTargetCollector<'a, B: Backend> {
+    pattern: &'a str,
+    path: ModulePath,
+    adapters: Vec<LoraAdapter<B>>,
+    config: &'a LoraConfig,
+}
+
+impl<B: Backend> ModuleVisitor<B> for LoraTargetCollector<'_, B> {
+    fn enter_module(&mut self, name: &str, container_type: &str) {
+        self.path.enter(name, container_type);
+    }
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.exit();
+    }
+    fn visit_float<const D: usize>(&mut self, _id: ParamId, tensor: &Tensor<B, D>) {
+        if self.path.names().last() != Some("weight") || !self.path.matches(self.pattern) {
+            return;
+        }
+
+        if let Some(output_first) = adapted_layout(self.path.module_type()) {
+            let adapter = self.config.init_adapter(
+                format!("{}", self.path),
+                output_first,
+                tensor.shape(),
+                &tensor.device(),
+            );
+            self.adapters.push(adapter);
+        }
+    }
+}
+
+struct LoraMapper<'a, B: Backend> {
+    adapters: &'a LoraAdapters<B>,
+    path: ModulePath,
+}
+
+impl<B: Backend> ModuleMapper<B> for LoraMapper<'_, B> {
+    fn enter_module(&mut self, name: &str, container_type: &str) {
+        self.path.enter(name, container_type);
+    }
+    fn exit_module(&mut self, _name: &str, _container_type: &str) {
+        self.path.exit();
+    }
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        let path = format!("{}", self.path);
+
+        match self.adapters.adapters.iter().find(|a| a.path == path) {
+            Some(adapter) => {
+                let delta = adapter.delta(self.adapters.scaling, tensor.shape());
+                tensor + delta
+            }
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestAutodiffBackend;
+    use crate::TestBackend;
+    use crate::nn::conv::{Conv2d, Conv2dConfig};
+    use crate::nn::{Linear, LinearConfig};
+    use crate::record::{FullPrecisionSettings, Record};
+    use crate::tensor::Distribution;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        conv: Conv2d<B>,
+        query: Linear<B>,
+        head: Linear<B>,
+    }
+
+    impl<B: Backend> Model<B> {
+        fn new(device: &B::Device) -> Self {
+            Self {
+                conv: Conv2dConfig::new([2, 3], [3, 3]).init(device),
+                query: LinearConfig::new(4, 5).init(device),
+                head: LinearConfig::new(5, 2).init(device),
+            }
+        }
+
+        fn forward(&self, images: Tensor<B, 4>, features: Tensor<B, 2>) -> Tensor<B, 2> {
+            let images = self.conv.forward(images).sum();
+            let features = self.head.forward(self.query.forward(features));
+
+            features + images.unsqueeze()
+        }
+    }
+
+    /// Give non-zero values to the up projections, as after some training.
+    fn trained<B: Backend>(mut lora: Lora<B, Model<B>>) -> Lora<B, Model<B>> {
+        for adapter in lora.adapters.adapters.iter_mut() {
+            let lora_b = adapter.lora_b.val();
+            let lora_b = lora_b.random_like(Distribution::Default);
+            adapter.lora_b = Param::from_tensor(lora_b);
+        }
+
+        lora
+    }
+
+    fn inputs<B: Backend>(device: &B::Device) -> (Tensor<B, 4>, Tensor<B, 2>) {
+        (
+            Tensor::random([1, 2, 4, 4], Distribution::Default, device),
+            Tensor::random([3, 4], Distribution::Default, device),
+        )
+    }
+
+    #[test]
+    fn adapters_should_only_target_matching_layers() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+
+        let lora = LoraConfig::new(2).init(model.clone());
+        let paths = lora.adapters.adapters.iter().map(|a| a.path.as_str());
+        assert_eq!(
+            paths.collect::<Vec<_>>(),
+            ["conv.weight", "query.weight", "head.weight"]
+        );
+        let shapes = lora.adapters.adapters.iter().map(|a| {
+            let [fan_in, rank] = a.lora_a.dims();
+            let [_, fan_out] = a.lora_b.dims();
+            (fan_in, rank, fan_out)
+        });
+        assert_eq!(
+            shapes.collect::<Vec<_>>(),
+            [(18, 2, 3), (4, 2, 5), (5, 2, 2)]
+        );
+
+        let lora = LoraConfig::new(2).with_pattern("query".into()).init(model);
+        assert_eq!(lora.adapters.adapters.len(), 1);
+        assert_eq!(lora.adapters.adapters[0].path, "query.weight");
+    }
+
+    #[test]
+    fn adapters_should_not_change_the_output_before_training() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let (images, features) = inputs(&device);
+        let expected = model.forward(images.clone(), features.clone());
+
+        let lora = LoraConfig::new(4).init(model);
+        let output = lora.model().forward(images, features);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn merge_should_add_the_scaled_low_rank_updates() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let (images, features) = inputs(&device);
+        let base_query = model.query.weight.val();
+        let base_conv = model.conv.weight.val();
+
+        let lora = trained(LoraConfig::new(2).with_alpha(4.0).init(model));
+        let expected = lora.model().forward(images.clone(), features.clone());
+        let [conv, query, _] = [0, 1, 2].map(|i| {
+            let adapter = &lora.adapters.adapters[i];
+            adapter.lora_a.val().matmul(adapter.lora_b.val()) * 2.0
+        });
+        let merged = lora.merge();
+
+        merged
+            .forward(images, features)
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+        merged
+            .query
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&(base_query + query).into_data(), 3);
+        merged.conv.weight.val().into_data().assert_approx_eq(
+            &(base_conv + conv.transpose().reshape([3, 2, 3, 3])).into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn merge_should_not_apply_dropout() {
+        let device = Default::default();
+        let model = Model::<TestAutodiffBackend>::new(&device);
+        let base_query = model.query.weight.val();
+
+        let lora = trained(LoraConfig::new(2).with_dropout(0.5).init(model));
+        let adapter = &lora.adapters.adapters[1];
+        let query = adapter.lora_a.val().matmul(adapter.lora_b.val()) * 4.0;
+        let merged = lora.merge();
+
+        merged
+            .query
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&(base_query + query).into_data(), 3);
+    }
+
+    #[test]
+    fn forward_should_add_the_low_rank_update_to_the_output() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+        let (_, features) = inputs(&device);
+
+        let lora = trained(LoraConfig::new(2).init(model));
+        let expected = lora.model().query.forward(features.clone());
+        let output = lora.base.query.forward(features.clone())
+            + lora.adapters.forward("query.weight", features);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn forward_should_drop_the_input_with_autodiff() {
+        let device = Default::default();
+        let model = Model::<TestAutodiffBackend>::new(&device);
+        let features = Tensor::ones([64, 4], &device);
+
+        let lora = trained(LoraConfig::new(2).with_dropout(0.5).init(model));
+        let mut adapters = lora.adapters.clone();
+        adapters.dropout = DropoutConfig::new(0.0).init();
+        let without_dropout = adapters.forward("query.weight", features.clone());
+        let output = lora.adapters.forward("query.weight", features);
+
+        assert_ne!(output.into_data(), without_dropout.into_data());
+    }
+
+    #[test]
+    fn only_adapters_should_be_trained() {
+        let device = Default::default();
+        let model = Model::<TestAutodiffBackend>::new(&device);
+        let (images, features) = inputs(&device);
+
+        let lora = trained(LoraConfig::new(2).init(model));
+        assert_eq!(lora.num_trainable_params(), 2 * (18 + 3 + 4 + 5 + 5 + 2));
+
+        let model = lora.model();
+        let grads = model.forward(images, features).sum().backward();
+
+        assert!(lora.base.query.weight.grad(&grads).is_none());
+        assert!(
+            lora.base
+                .query
+                .bias
+                .as_ref()
+                .unwrap()
+                .grad(&grads)
+                .is_none()
+        );
+        for adapter in lora.adapters.adapters.iter() {
+            assert!(adapter.lora_a.grad(&grads).is_some());
+            assert!(adapter.lora_b.grad(&grads).is_some());
+        }
+    }
+
+    #[test]
+    fn adapters_record_should_be_loaded_on_its_own() {
+        let device = Default::default();
+        let config = LoraConfig::new(2);
+        let lora = trained(config.init(Model::<TestBackend>::new(&device)));
+        let item = lora
+            .adapters
+            .clone()
+            .into_record()
+            .into_item::<FullPrecisionSettings>();
+
+        let mut other = config.init(lora.base.clone());
+        let record = LoraAdaptersRecord::from_item::<FullPrecisionSettings>(item, &device);
+        other.adapters = other.adapters.load_record(record);
+
+        for (adapter, loaded) in lora.adapters.adapters.iter().zip(other.adapters.adapters) {
+            loaded
+                .lora_b
+                .val()
+                .into_data()
+                .assert_eq(&adapter.lora_b.val().into_data(), true);
+        }
+    }
+
+    #[test]
+    #[should_panic = "The LoRA dropout can't be applied to the adapted weights"]
+    fn model_with_dropout_should_panic() {
+        let device = Default::default();
+        let model = Model::<TestAutodiffBackend>::new(&device);
+
+        let lora = LoraConfig::new(2).with_dropout(0.1).init(model);
+        let _model = lora.model();
+    }
+
+    #[test]
+    #[should_panic = "No linear or convolution layer matches the LoRA pattern 'decoder'."]
+    fn unmatched_pattern_should_panic() {
+        let device = Default::default();
+        let model = Model::<TestBackend>::new(&device);
+
+        LoraConfig::new(2)
+            .with_pattern("decoder".into())
+            .init(model);
+    }
+}
@@ -26,6 +26,7 @@ mod hard_sigmoid;
 mod initializer;
 mod leaky_relu;
 mod linear;
+mod lora;
 mod norm;
 mod padding;
 mod pos_encoding;
@@ -45,6 +46,7 @@ pub use hard_sigmoid::*;
 pub use initializer::*;
 pub use leaky_relu::*;
 pub use linear::*;
+pub use lora::*;
 pub use norm::*;
 pub use padding::*;
 pub use pos_encoding::*;
@@ -128,21 +128,25 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
             flatten_size *= dim;
         }
 
-        let mean = input
+        let dtype = input.dtype();
+        let input_stats = input.clone().cast(super::stats_dtype(dtype));
+
+        let mean = input_stats
             .clone()
             .swap_dims(0, 1)
             .reshape([channels, flatten_size])
             .mean_dim(1)
             .reshape(shape_unsqueeze);
 
-        let var = input
-            .clone()
+        let var = input_stats
             .sub(mean.clone())
             .powf_scalar(2.0)
             .swap_dims(0, 1)
             .reshape([channels, flatten_size])
             .mean_dim(1)
-            .reshape(shape_unsqueeze);
+            .reshape(shape_unsqueeze)
+            .cast(dtype);
+        let mean = mean.cast(dtype);
 
         let running_mean = self.running_mean.value_sync().to_device(&device);
         let running_var = self.running_var.value_sync().to_device(&device);
@@ -165,13 +165,16 @@ pub(crate) fn group_norm<B: Backend, const D: usize>(
     let num_channels = shape.dims[1];
 
     let hidden_size = shape.dims[2..].iter().product::<usize>() * num_channels / num_groups;
-    let input = input.reshape([batch_size, num_groups, hidden_size]);
+    let dtype = input.dtype();
+    let input = input
+        .reshape([batch_size, num_groups, hidden_size])
+        .cast(super::stats_dtype(dtype));
 
     let mean = input.clone().sum_dim(2) / hidden_size as f64;
     let input = input.sub(mean);
 
     let var = input.clone().powf_scalar(2.).sum_dim(2) / hidden_size as f64;
-    let input_normalized = input.div(var.add_scalar(epsilon).sqrt());
+    let input_normalized = input.div(var.add_scalar(epsilon).sqrt()).cast(dtype);
 
     if affine {
         let mut affine_shape = [1; D];
@@ -65,9 +65,14 @@ impl<B: Backend> LayerNorm<B> {
     /// - input: `[..., any, d_model]`
     /// - output: `[..., any, d_model]`
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let dtype = input.dtype();
+        let input = input.cast(super::stats_dtype(dtype));
         let (var, mean) = input.clone().var_mean_bias(D - 1);
 
-        let input_normalized = input.sub(mean).div(var.add_scalar(self.epsilon).sqrt());
+        let input_normalized = input
+            .sub(mean)
+            .div(var.add_scalar(self.epsilon).sqrt())
+            .cast(dtype);
 
         input_normalized
             .mul(self.gamma.val().unsqueeze())
@@ -9,3 +9,14 @@ pub use group::*;
 pub use instance::*;
 pub use layer::*;
 pub use rms::*;
+
+use burn_tensor::DType;
+
+/// The data type in which the statistics of a normalization are computed: half precision inputs
+/// are upcast to 32-bit floats, whose sums don't overflow and keep enough precision.
+fn stats_dtype(dtype: DType) -> DType {
+    match dtype {
+        DType::F16 | DType::BF16 => DType::F32,
+        dtype => dtype,
+    }
+}
@@ -0,0 +1,606 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{GenerationConfig, apply_repetition_penalty, sample};
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::{Content, DisplaySettings, Module, ModuleDisplay};
+use crate::nn::{
+    Embedding, EmbeddingConfig, Initializer, Linear, LinearConfig, RmsNorm, RmsNormConfig,
+    RotaryEncoding, RotaryEncodingConfig, SwiGlu, SwiGluConfig,
+};
+use crate::tensor::module::scaled_dot_product_attention;
+use crate::tensor::ops::AttentionOptions;
+use crate::tensor::{Int, Tensor, backend::Backend};
+
+/// Configuration to create a [decoder-only transformer](TransformerDecoderOnly) using the
+/// [init function](TransformerDecoderOnlyConfig::init).
+#[derive(Config)]
+pub struct TransformerDecoderOnlyConfig {
+    /// The size of the vocabulary.
+    pub vocab_size: usize,
+    /// The size of the model.
+    pub d_model: usize,
+    /// The size of the hidden layer of the SwiGLU feed-forward network.
+    pub d_ff: usize,
+    /// The number of query heads.
+    pub n_heads: usize,
+    /// The number of key-value heads, which must divide the number of query heads.
+    /// Defaults to the number of query heads, i.e. no grouped-query attention.
+    pub n_kv_heads: Option<usize>,
+    /// The number of layers.
+    pub n_layers: usize,
+    /// The maximum sequence length, used to precompute the rotary encoding.
+    pub max_seq_len: usize,
+    /// Scaling factor for the frequencies of the rotary encoding. Default: 10000.0
+    #[config(default = "10000.0")]
+    pub rope_theta: f32,
+    /// A value required for numerical stability of the RMS norms. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub norm_eps: f64,
+    /// The type of function used to initialize the linear layers.
+    #[config(
+        default = "Initializer::KaimingUniform{gain:1.0/num_traits::Float::sqrt(3.0), fan_out_only:false}"
+    )]
+    pub initializer: Initializer,
+}
+
+/// A decoder-only transformer, as used by GPT and Llama style language models.
+///
+/// Each layer applies a pre-norm self-attention block with rotary position encoding and
+/// grouped-query attention, followed by a pre-norm SwiGLU feed-forward block. The output of
+/// the last layer is normalized and projected onto the vocabulary.
+///
+/// Tokens can be decoded incrementally with a [preallocated key-value cache](TransformerDecoderOnlyCache),
+/// and sampled with [generate](TransformerDecoderOnly::generate).
+///
+/// Should be created using [TransformerDecoderOnlyConfig].
+#[derive(Module, Debug)]
+#[module(custom_display)]
+pub struct TransformerDecoderOnly<B: Backend> {
+    /// The token embedding.
+    pub embedding: Embedding<B>,
+    /// Transformer decoder layers.
+    pub layers: Vec<TransformerDecoderOnlyLayer<B>>,
+    /// The norm applied to the output of the last layer.
+    pub norm: RmsNorm<B>,
+    /// The projection onto the vocabulary.
+    pub lm_head: Linear<B>,
+    /// The rotary encoding shared by the layers.
+    pub rope: RotaryEncoding<B>,
+    /// The number of query heads.
+    pub n_heads: usize,
+    /// The number of key-value heads.
+    pub n_kv_heads: usize,
+    /// The maximum sequence length.
+    pub max_seq_len: usize,
+}
+
+impl<B: Backend> ModuleDisplay for TransformerDecoderOnly<B> {
+    fn custom_settings(&self) -> Option<DisplaySettings> {
+        DisplaySettings::new()
+            .with_new_line_after_attribute(false)
+            .optional()
+    }
+
+    fn custom_content(&self, content: Content) -> Option<Content> {
+        let [vocab_size, d_model] = self.embedding.weight.shape().dims();
+        content
+            .add("vocab_size", &vocab_size)
+            .add("d_model", &d_model)
+            .add("n_heads", &self.n_heads)
+            .add("n_kv_heads", &self.n_kv_heads)
+            .add("n_layers", &self.layers.len())
+            .add("max_seq_len", &self.max_seq_len)
+            .optional()
+    }
+}
+
+impl TransformerDecoderOnlyConfig {
+    /// Initialize a new [decoder-only transformer](TransformerDecoderOnly).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the model size is not divisible by the number of heads, or if the number of
+    /// heads is not divisible by the number of key-value heads.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> TransformerDecoderOnly<B> {
+        let n_kv_heads = self.n_kv_heads.unwrap_or(self.n_heads);
+        assert_eq!(
+            self.d_model % self.n_heads,
+            0,
+            "The model size must be divisible by the number of heads."
+        );
+        assert_eq!(
+            self.n_heads % n_kv_heads,
+            0,
+            "The number of heads must be divisible by the number of key-value heads."
+        );
+
+        let d_head = self.d_model / self.n_heads;
+        let layers = (0..self.n_layers)
+            .map(|_| TransformerDecoderOnlyLayer::new(self, n_kv_heads, device))
+            .collect();
+
+        TransformerDecoderOnly {
+            embedding: EmbeddingConfig::new(self.vocab_size, self.d_model).init(device),
+            layers,
+            norm: RmsNormConfig::new(self.d_model)
+                .with_epsilon(self.norm_eps)
+                .init(device),
+            lm_head: self.linear(self.d_model, self.vocab_size, device),
+            rope: RotaryEncodingConfig::new(self.max_seq_len, d_head)
+                .with_theta(self.rope_theta)
+                .init(device),
+            n_heads: self.n_heads,
+            n_kv_heads,
+            max_seq_len: self.max_seq_len,
+        }
+    }
+
+    fn linear<B: Backend>(&self, d_input: usize, d_output: usize, device: &B::Device) -> Linear<B> {
+        LinearConfig::new(d_input, d_output)
+            .with_bias(false)
+            .with_initializer(self.initializer.clone())
+            .init(device)
+    }
+}
+
+/// [Decoder-only transformer](TransformerDecoderOnly) layer module.
+#[derive(Module, Debug)]
+pub struct TransformerDecoderOnlyLayer<B: Backend> {
+    query: Linear<B>,
+    key: Linear<B>,
+    value: Linear<B>,
+    output: Linear<B>,
+    ffn: SwiGlu<B>,
+    ffn_output: Linear<B>,
+    norm_attn: RmsNorm<B>,
+    norm_ffn: RmsNorm<B>,
+    n_heads: usize,
+    n_kv_heads: usize,
+    d_head: usize,
+}
+
+impl<B: Backend> TransformerDecoderOnlyLayer<B> {
+    fn new(config: &TransformerDecoderOnlyConfig, n_kv_heads: usize, device: &B::Device) -> Self {
+        let d_head = config.d_model / config.n_heads;
+
+        Self {
+            query: config.linear(config.d_model, config.n_heads * d_head, device),
+            key: config.linear(config.d_model, n_kv_heads * d_head, device),
+            value: config.linear(config.d_model, n_kv_heads * d_head, device),
+            output: config.linear(config.n_heads * d_head, config.d_model, device),
+            ffn: SwiGluConfig::new(config.d_model, config.d_ff)
+                .with_initializer(config.initializer.clone())
+                .init(device),
+            ffn_output: config.linear(config.d_ff, config.d_model, device),
+            norm_attn: RmsNormConfig::new(config.d_model)
+                .with_epsilon(config.norm_eps)
+                .init(device),
+            norm_ffn: RmsNormConfig::new(config.d_model)
+                .with_epsilon(config.norm_eps)
+                .init(device),
+            n_heads: config.n_heads,
+            n_kv_heads,
+            d_head,
+        }
+    }
+
+    fn forward(
+        &self,
+        x: Tensor<B, 3>,
+        rope: &RotaryEncoding<B>,
+        cache: Option<(KeyValueCache<B>, usize)>,
+    ) -> (Tensor<B, 3>, Option<KeyValueCache<B>>) {
+        let [batch_size, seq_length, _] = x.dims();
+        let start = cache.as_ref().map(|(_, start)| *start).unwrap_or(0);
+
+        let x_norm = self.norm_attn.forward(x.clone());
+        let heads = |linear: &Linear<B>, n_heads: usize| {
+            linear
+                .forward(x_norm.clone())
+                .reshape([batch_size, seq_length, n_heads, self.d_head])
+                .swap_dims(1, 2)
+        };
+        let query = rope.apply(heads(&self.query, self.n_heads), start);
+        let key = rope.apply(heads(&self.key, self.n_kv_heads), start);
+        let value = heads(&self.value, self.n_kv_heads);
+
+        let (cache, key, value) = match cache {
+            Some((cache, start)) => {
+                let (cache, key, value) = cache.append(key, value, start);
+                (Some(cache), key, value)
+            }
+            None => (None, key, value),
+        };
+
+        // The causal mask is aligned to the last keys, so queries only attend to the cached
+        // positions and the positions before them.
+        let attn = scaled_dot_product_attention(
+            query,
+            key,
+            value,
+            None,
+            AttentionOptions::new(None, true, 0.0),
+        )
+        .swap_dims(1, 2)
+        .reshape([batch_size, seq_length, self.n_heads * self.d_head]);
+        let x = x + self.output.forward(attn);
+
+        let x_norm = self.norm_ffn.forward(x.clone());
+        let x = x + self.ffn_output.forward(self.ffn.forward(x_norm));
+
+        (x, cache)
+    }
+}
+
+/// Key-value cache of a [decoder-only transformer](TransformerDecoderOnly) layer.
+struct KeyValueCache<B: Backend> {
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+}
+
+impl<B: Backend> KeyValueCache<B> {
+    fn new(
+        batch_size: usize,
+        n_kv_heads: usize,
+        max_seq_len: usize,
+        d_head: usize,
+        device: &B::Device,
+    ) -> Self {
+        let shape = [batch_size, n_kv_heads, max_seq_len, d_head];
+
+        Self {
+            key: Tensor::zeros(shape, device),
+            value: Tensor::zeros(shape, device),
+        }
+    }
+
+    /// Write the keys and values of the new positions, and return the keys and values of all
+    /// the positions so far.
+    ///
+    /// The cache is taken by value so the preallocated tensors can be written in place.
+    fn append(
+        self,
+        key: Tensor<B, 4>,
+        value: Tensor<B, 4>,
+        start: usize,
+    ) -> (Self, Tensor<B, 4>, Tensor<B, 4>) {
+        let [batch_size, n_kv_heads, seq_length, d_head] = key.dims();
+        let end = start + seq_length;
+        let ranges = |positions| [0..batch_size, 0..n_kv_heads, positions, 0..d_head];
+
+        let cache = Self {
+            key: self.key.slice_assign(ranges(start..end), key),
+            value: self.value.slice_assign(ranges(start..end), value),
+        };
+        let key = cache.key.clone().slice(ranges(0..end));
+        let value = cache.value.clone().slice(ranges(0..end));
+
+        (cache, key, value)
+    }
+}
+
+/// Preallocated key-value cache for the [decoder-only transformer](TransformerDecoderOnly).
+///
+/// The keys and values of every layer are allocated for the maximum sequence length of the
+/// model, so decoding a token only writes its keys and values in place.
+///
+/// To be used during inference when decoding tokens.
+pub struct TransformerDecoderOnlyCache<B: Backend> {
+    layers: Vec<KeyValueCache<B>>,
+    len: usize,
+    max_seq_len: usize,
+}
+
+impl<B: Backend> TransformerDecoderOnlyCache<B> {
+    /// The number of cached positions.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no position is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Forget the cached positions, keeping the allocated memory.
+    pub fn reset(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<B: Backend> TransformerDecoderOnly<B> {
+    /// Applies the forward pass on the tokens, returning the logits of the next token at each
+    /// position.
+    ///
+    /// # Shapes
+    ///
+    /// - tokens: `[batch_size, seq_length]`
+    /// - output: `[batch_size, seq_length, vocab_size]`
+    pub fn forward(&self, tokens: Tensor<B, 2, Int>) -> Tensor<B, 3> {
+        let mut x = self.embedding.forward(tokens);
+
+        for layer in self.layers.iter() {
+            (x, _) = layer.forward(x, &self.rope, None);
+        }
+
+        self.lm_head.forward(self.norm.forward(x))
+    }
+
+    /// Applies the forward pass on the tokens following the cached positions, and caches their
+    /// keys and values.
+    ///
+    /// # Shapes
+    ///
+    /// - tokens: `[batch_size, seq_length]`
+    /// - output: `[batch_size, seq_length, vocab_size]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cache can't hold the new positions.
+    pub fn forward_cache(
+        &self,
+        tokens: Tensor<B, 2, Int>,
+        cache: &mut TransformerDecoderOnlyCache<B>,
+    ) -> Tensor<B, 3> {
+        let [_, seq_length] = tokens.dims();
+        assert!(
+            cache.len + seq_length <= cache.max_seq_len,
+            "The cache can hold {} positions, but {} are required.",
+            cache.max_seq_len,
+            cache.len + seq_length
+        );
+
+        let mut x = self.embedding.forward(tokens);
+
+        let layer_caches = core::mem::take(&mut cache.layers);
+        for (layer, layer_cache) in self.layers.iter().zip(layer_caches) {
+            let (output, layer_cache) =
+                layer.forward(x, &self.rope, Some((layer_cache, cache.len)));
+            x = output;
+            cache.layers.extend(layer_cache);
+        }
+        cache.len += seq_length;
+
+        self.lm_head.forward(self.norm.forward(x))
+    }
+
+    /// Create an empty key-value cache for the given batch size, preallocated for the maximum
+    /// sequence length.
+    pub fn new_cache(&self, batch_size: usize) -> TransformerDecoderOnlyCache<B> {
+        let device = self.embedding.weight.device();
+        let [_, d_model] = self.embedding.weight.shape().dims();
+        let d_head = d_model / self.n_heads;
+
+        TransformerDecoderOnlyCache {
+            layers: (0..self.layers.len())
+                .map(|_| {
+                    KeyValueCache::new(
+                        batch_size,
+                        self.n_kv_heads,
+                        self.max_seq_len,
+                        d_head,
+                        &device,
+                    )
+                })
+                .collect(),
+            len: 0,
+            max_seq_len: self.max_seq_len,
+        }
+    }
+
+    /// Generate tokens following the prompts.
+    ///
+    /// Returns the generated tokens of each prompt. The generation of a prompt ends before
+    /// the first [stop token](GenerationConfig::stop_tokens), which isn't returned, or after
+    /// the [maximum number of new tokens](GenerationConfig::max_new_tokens).
+    ///
+    /// # Shapes
+    ///
+    /// - prompts: `[batch_size, seq_length]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configuration is invalid, or if the prompts and the new tokens exceed the
+    /// maximum sequence length.
+    pub fn generate(
+        &self,
+        prompts: Tensor<B, 2, Int>,
+        config: &GenerationConfig,
+    ) -> Vec<Vec<usize>> {
+        config.validate();
+        let [batch_size, seq_length] = prompts.dims();
+        let [vocab_size, _] = self.embedding.weight.shape().dims();
+        let device = prompts.device();
+
+        let mut tokens = vec![Vec::new(); batch_size];
+        let mut finished = vec![false; batch_size];
+        if config.max_new_tokens == 0 {
+            return tokens;
+        }
+
+        let mut cache = self.new_cache(batch_size);
+        let mut seen = Tensor::<B, 2>::zeros([batch_size, vocab_size], &device).scatter(
+            1,
+            prompts.clone(),
+            Tensor::ones([batch_size, seq_length], &device),
+        );
+        let mut logits = self
+            .forward_cache(prompts, &mut cache)
+            .slice([0..batch_size, seq_length - 1..seq_length, 0..vocab_size])
+            .squeeze::<2>(1);
+
+        for step in 0..config.max_new_tokens {
+            if config.repetition_penalty != 1.0 {
+                logits = apply_repetition_penalty(
+                    logits,
+                    seen.clone().greater_elem(0.0),
+                    config.repetition_penalty,
+                );
+            }
+            let next = sample(logits, &config.sampling);
+
+            let values = next.to_data();
+            for (i, token) in values.iter::<i64>().enumerate() {
+                let token = token as usize;
+                if finished[i] {
+                    continue;
+                }
+                if config.stop_tokens.contains(&token) {
+                    finished[i] = true;
+                } else {
+                    tokens[i].push(token);
+                }
+            }
+
+            if step + 1 == config.max_new_tokens || finished.iter().all(|finished| *finished) {
+                break;
+            }
+
+            seen = seen.scatter(1, next.clone(), Tensor::ones([batch_size, 1], &device));
+            logits = self.forward_cache(next, &mut cache).squeeze::<2>(1);
+        }
+
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::nn::transformer::SamplingStrategy;
+    use burn_tensor::Device;
+
+    fn config() -> TransformerDecoderOnlyConfig {
+        TransformerDecoderOnlyConfig::new(16, 8, 12, 4, 2, 16).with_n_kv_heads(Some(2))
+    }
+
+    #[test]
+    fn forward_cache_should_match_forward() {
+        TestBackend::seed(0);
+        let device: Device<TestBackend> = Default::default();
+        let model = config().init::<TestBackend>(&device);
+        let tokens =
+            Tensor::<TestBackend, 2, Int>::from_ints([[1, 5, 2, 9, 3], [4, 4, 0, 15, 7]], &device);
+
+        let output_1 = model.forward(tokens.clone());
+
+        // Forward the prompt, then the remaining tokens one at a time.
+        let mut cache = model.new_cache(2);
+        let mut output_2 =
+            vec![model.forward_cache(tokens.clone().slice([0..2, 0..2]), &mut cache)];
+        for i in 2..5 {
+            output_2.push(model.forward_cache(tokens.clone().slice([0..2, i..i + 1]), &mut cache));
+        }
+        let output_2 = Tensor::cat(output_2, 1);
+
+        assert_eq!(cache.len(), 5);
+        output_1
+            .into_data()
+            .assert_approx_eq(&output_2.into_data(), 3);
+    }
+
+    #[test]
+    fn greedy_generation_should_follow_the_most_likely_tokens() {
+        TestBackend::seed(0);
+        let device: Device<TestBackend> = Default::default();
+        let model = config().init::<TestBackend>(&device);
+        let prompt = Tensor::<TestBackend, 2, Int>::from_ints([[1, 5, 2]], &device);
+
+        let generated = model.generate(
+            prompt.clone(),
+            &GenerationConfig::new().with_max_new_tokens(4),
+        );
+
+        // Decode without cache, appending the most likely token at each step.
+        let mut tokens = prompt;
+        for _ in 0..4 {
+            let [_, seq_length] = tokens.dims();
+            let next = model
+                .forward(tokens.clone())
+                .slice([0..1, seq_length - 1..seq_length, 0..16])
+                .squeeze::<2>(1)
+                .argmax(1);
+            tokens = Tensor::cat(vec![tokens, next], 1);
+        }
+        let expected = tokens
+            .slice([0..1, 3..7])
+            .into_data()
+            .iter::<i64>()
+            .map(|token| token as usize)
+            .collect::<Vec<_>>();
+
+        assert_eq!(generated, vec![expected]);
+    }
+
+    #[test]
+    fn generation_should_end_before_stop_token() {
+        TestBackend::seed(0);
+        let device: Device<TestBackend> = Default::default();
+        let model = config().init::<TestBackend>(&device);
+        let prompt = Tensor::<TestBackend, 2, Int>::from_ints([[1, 5, 2]], &device);
+        let generation = GenerationConfig::new().with_max_new_tokens(4);
+        let generated = model.generate(prompt.clone(), &generation);
+
+        let stop_token = generated[0][2];
+        let stopped = model.generate(prompt, &generation.with_stop_tokens(vec![stop_token]));
+
+        let end = generated[0]
+            .iter()
+            .position(|token| *token == stop_token)
+            .unwrap();
+        assert_eq!(stopped, vec![generated[0][..end].to_vec()]);
+    }
+
+    #[test]
+    fn sampling_with_top_k_of_one_should_be_greedy() {
+        TestBackend::seed(0);
+        let device: Device<TestBackend> = Default::default();
+        let model = config().init::<TestBackend>(&device);
+        let prompt = Tensor::<TestBackend, 2, Int>::from_ints([[1, 5, 2], [3, 3, 8]], &device);
+
+        let greedy = model.generate(
+            prompt.clone(),
+            &GenerationConfig::new().with_max_new_tokens(3),
+        );
+        let sampled = model.generate(
+            prompt,
+            &GenerationConfig::new()
+                .with_max_new_tokens(3)
+                .with_sampling(SamplingStrategy::Sample {
+                    temperature: 0.7,
+                    top_k: Some(1),
+                    top_p: None,
+                }),
+        );
+
+        assert_eq!(greedy, sampled);
+    }
+
+    #[test]
+    fn display() {
+        let model = config().init::<TestBackend>(&Default::default());
+
+        assert_eq!(
+            alloc::format!("{}", model),
+            "TransformerDecoderOnly {vocab_size: 16, d_model: 8, n_heads: 4, n_kv_heads: 2, \
+            n_layers: 2, max_seq_len: 16, params: 1256}"
+        );
+    }
+
+    #[test]
+    fn cache_should_be_reset() {
+        let device: Device<TestBackend> = Default::default();
+        let model = config().init::<TestBackend>(&device);
+        let mut cache = model.new_cache(1);
+
+        model.forward_cache(Tensor::from_ints([[1, 2]], &device), &mut cache);
+        assert_eq!(cache.len(), 2);
+        cache.reset();
+
+        assert!(cache.is_empty());
+    }
+}
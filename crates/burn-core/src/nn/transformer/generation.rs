@@ -0,0 +1,233 @@
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::config::Config;
+use crate::tensor::activation::softmax;
+use crate::tensor::{Bool, Distribution, Int, Tensor, backend::Backend};
+
+/// How the next token is chosen from the logits.
+#[derive(Config, Debug, PartialEq)]
+pub enum SamplingStrategy {
+    /// Choose the most likely token.
+    Greedy,
+    /// Sample the token from the softmax of the logits divided by the temperature.
+    Sample {
+        /// The temperature, where lower values make likely tokens even more likely.
+        temperature: f64,
+        /// Only sample from the `k` most likely tokens.
+        top_k: Option<usize>,
+        /// Only sample from the smallest set of most likely tokens whose cumulative
+        /// probability reaches `p` (nucleus sampling).
+        top_p: Option<f64>,
+    },
+}
+
+/// Configuration of the token generation of a
+/// [decoder-only transformer](super::TransformerDecoderOnly::generate).
+#[derive(Config, Debug)]
+pub struct GenerationConfig {
+    /// The maximum number of tokens to generate for each prompt. Default: 32
+    #[config(default = 32)]
+    pub max_new_tokens: usize,
+    /// How the next token is chosen from the logits. Default: greedy
+    #[config(default = "SamplingStrategy::Greedy")]
+    pub sampling: SamplingStrategy,
+    /// The penalty applied to the logits of the tokens already present in the sequence, as
+    /// introduced in [CTRL](https://arxiv.org/abs/1909.05858). A value of 1.0 disables it.
+    /// Default: 1.0
+    #[config(default = 1.0)]
+    pub repetition_penalty: f64,
+    /// The tokens ending the generation of a sequence, such as the end-of-sequence token.
+    #[config(default = "Vec::new()")]
+    pub stop_tokens: Vec<usize>,
+}
+
+impl GenerationConfig {
+    /// Panics if any of the settings is out of range.
+    pub(crate) fn validate(&self) {
+        assert!(
+            self.repetition_penalty > 0.0,
+            "The repetition penalty must be positive."
+        );
+
+        if let SamplingStrategy::Sample {
+            temperature,
+            top_k,
+            top_p,
+        } = &self.sampling
+        {
+            assert!(*temperature > 0.0, "The temperature must be positive.");
+            if let Some(top_k) = top_k {
+                assert!(*top_k > 0, "Top-k must be at least 1.");
+            }
+            if let Some(top_p) = top_p {
+                assert!(
+                    *top_p > 0.0 && *top_p <= 1.0,
+                    "Top-p must be greater than 0 and at most 1."
+                );
+            }
+        }
+    }
+}
+
+/// Penalize the logits of the tokens already present in the sequences.
+///
+/// Positive logits are divided by the penalty and negative logits are multiplied by it, so a
+/// penalty greater than 1 makes the tokens less likely.
+///
+/// # Shapes
+///
+/// - logits: `[batch_size, vocab_size]`
+/// - seen: `[batch_size, vocab_size]`
+/// - output: `[batch_size, vocab_size]`
+pub fn apply_repetition_penalty<B: Backend>(
+    logits: Tensor<B, 2>,
+    seen: Tensor<B, 2, Bool>,
+    penalty: f64,
+) -> Tensor<B, 2> {
+    let positive = logits.clone().greater_elem(0.0);
+    let penalized = logits
+        .clone()
+        .mul_scalar(penalty)
+        .mask_where(positive, logits.clone().div_scalar(penalty));
+
+    logits.mask_where(seen, penalized)
+}
+
+/// Choose the next token of each sequence from its logits.
+///
+/// Random sampling uses the random number generator of the backend, which can be seeded with
+/// [Backend::seed].
+///
+/// # Shapes
+///
+/// - logits: `[batch_size, vocab_size]`
+/// - output: `[batch_size, 1]`
+pub fn sample<B: Backend>(logits: Tensor<B, 2>, strategy: &SamplingStrategy) -> Tensor<B, 2, Int> {
+    let (temperature, top_k, top_p) = match strategy {
+        SamplingStrategy::Greedy => return logits.argmax(1),
+        SamplingStrategy::Sample {
+            temperature,
+            top_k,
+            top_p,
+        } => (*temperature, *top_k, *top_p),
+    };
+
+    let [batch_size, vocab_size] = logits.dims();
+    let num_candidates = top_k.map_or(vocab_size, |top_k| top_k.min(vocab_size));
+
+    let (logits, indices) = logits
+        .div_scalar(temperature)
+        .sort_descending_with_indices(1);
+    let logits = logits.slice([0..batch_size, 0..num_candidates]);
+    let indices = indices.slice([0..batch_size, 0..num_candidates]);
+    let mut probs = softmax(logits, 1);
+
+    if let Some(top_p) = top_p {
+        // Remove the tokens whose more likely tokens already reach the cumulative probability.
+        let preceding = probs.clone().cumsum(1) - probs.clone();
+        probs = probs.mask_fill(preceding.greater_equal_elem(top_p), 0.0);
+        probs = probs.clone() / probs.sum_dim(1);
+    }
+
+    // Inverse transform sampling: the chosen candidate is the number of candidates whose
+    // cumulative probability is below a uniform sample.
+    let uniform = Tensor::<B, 2>::random([batch_size, 1], Distribution::Default, &probs.device());
+    let candidate = probs
+        .cumsum(1)
+        .lower(uniform.expand([batch_size, num_candidates]))
+        .int()
+        .sum_dim(1)
+        .clamp_max(num_candidates as i64 - 1);
+
+    indices.gather(1, candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn greedy_should_choose_most_likely_tokens() {
+        let logits = Tensor::<TestBackend, 2>::from_floats(
+            [[0.1, 2.0, -1.0, 0.5], [3.0, 0.0, 0.0, 2.9]],
+            &Default::default(),
+        );
+
+        let tokens = sample(logits, &SamplingStrategy::Greedy);
+
+        tokens
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [0]]), false);
+    }
+
+    #[test]
+    fn top_p_should_only_sample_the_nucleus() {
+        TestBackend::seed(0);
+        // Probabilities of about [0.04, 0.88, 0.04, 0.04].
+        let logits =
+            Tensor::<TestBackend, 2>::from_floats([[0.0, 3.0, 0.0, 0.0]; 32], &Default::default());
+        let strategy = SamplingStrategy::Sample {
+            temperature: 1.0,
+            top_k: None,
+            top_p: Some(0.8),
+        };
+
+        let tokens = sample(logits, &strategy);
+
+        tokens
+            .into_data()
+            .assert_eq(&TensorData::from([[1]; 32]), false);
+    }
+
+    #[test]
+    fn top_k_should_only_sample_the_most_likely_tokens() {
+        TestBackend::seed(0);
+        let logits = Tensor::<TestBackend, 2>::from_floats(
+            [[1.0, 0.0, 1.1, -1.0, 0.2]; 64],
+            &Default::default(),
+        );
+        let strategy = SamplingStrategy::Sample {
+            temperature: 2.0,
+            top_k: Some(2),
+            top_p: None,
+        };
+
+        let tokens = sample(logits, &strategy).into_data();
+
+        assert!(
+            tokens.iter::<i64>().all(|token| token == 0 || token == 2),
+            "Expected only tokens 0 and 2, got {tokens}"
+        );
+    }
+
+    #[test]
+    fn repetition_penalty_should_make_seen_tokens_less_likely() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 2>::from_floats([[2.0, -1.0, 2.0, -1.0]], &device);
+        let seen = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, true, false, false]]),
+            &device,
+        );
+
+        let logits = apply_repetition_penalty(logits, seen, 2.0);
+
+        logits
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[1.0, -2.0, 2.0, -1.0]]), 5);
+    }
+
+    #[test]
+    #[should_panic = "Top-p must be greater than 0 and at most 1."]
+    fn invalid_top_p_should_panic() {
+        GenerationConfig::new()
+            .with_sampling(SamplingStrategy::Sample {
+                temperature: 1.0,
+                top_k: None,
+                top_p: Some(1.5),
+            })
+            .validate();
+    }
+}
@@ -1,7 +1,11 @@
 mod decoder;
+mod decoder_only;
 mod encoder;
+mod generation;
 mod pwff;
 
 pub use decoder::*;
+pub use decoder_only::*;
 pub use encoder::*;
+pub use generation::*;
 pub use pwff::*;
@@ -0,0 +1,304 @@
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{Tensor, backend::AutodiffBackend};
+use crate::{
+    self as burn, LearningRate, grad_clipping::GradientClippingConfig, module::AutodiffModule,
+    record::Record,
+};
+use burn_tensor::{backend::Backend, ops::Device};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// Adafactor configuration.
+#[derive(Config)]
+pub struct AdafactorConfig {
+    /// Regularization constant added to the squared gradients.
+    #[config(default = 1e-30)]
+    epsilon_1: f32,
+    /// Lower bound of the parameter scale when `scale_parameter` is enabled.
+    #[config(default = 1e-3)]
+    epsilon_2: f32,
+    /// Threshold of the root mean square of the update, above which it is scaled down.
+    #[config(default = 1.0)]
+    clip_threshold: f32,
+    /// Exponent of the decay rate of the second moment, `beta_2 = 1 - t^decay_rate`.
+    #[config(default = -0.8)]
+    decay_rate: f32,
+    /// Decay rate of the first moment, no first moment is kept when `None`.
+    beta_1: Option<f32>,
+    /// Decoupled weight decay.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// Whether the learning rate is scaled by the root mean square of the parameter.
+    #[config(default = true)]
+    scale_parameter: bool,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Adafactor optimizer as described in the paper
+/// [Adafactor: Adaptive Learning Rates with Sublinear Memory Cost, Shazeer & Stern, 2018](https://arxiv.org/abs/1804.04235).
+///
+/// The second moment of tensors with at least two dimensions is factored into the moving
+/// averages of its rows and columns, so the memory of the state grows with the sum of the last
+/// two dimensions instead of their product.
+#[derive(Clone)]
+pub struct Adafactor {
+    epsilon_1: f32,
+    epsilon_2: f32,
+    clip_threshold: f32,
+    decay_rate: f32,
+    beta_1: Option<f32>,
+    weight_decay: f32,
+    scale_parameter: bool,
+}
+
+/// Adafactor state.
+#[derive(Record, Clone, new)]
+pub struct AdafactorState<B: Backend, const D: usize> {
+    /// The number of iterations aggregated.
+    pub time: usize,
+    /// The first moment of the updates, when `beta_1` is set.
+    pub moment_1: Option<Tensor<B, D>>,
+    /// The second moment of tensors with less than two dimensions.
+    pub exp_avg_sq: Option<Tensor<B, D>>,
+    /// The row factor of the second moment of tensors with at least two dimensions.
+    pub exp_avg_sq_row: Option<Tensor<B, D>>,
+    /// The column factor of the second moment of tensors with at least two dimensions.
+    pub exp_avg_sq_col: Option<Tensor<B, D>>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Adafactor {
+    type State<const D: usize> = AdafactorState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let mut state = state.unwrap_or_else(|| AdafactorState::new(0, None, None, None, None));
+        state.time += 1;
+
+        let beta_2 = 1.0 - (state.time as f32).powf(self.decay_rate);
+        let grad_squared = grad.clone().powi_scalar(2).add_scalar(self.epsilon_1);
+
+        let update = if D >= 2 {
+            let row = grad_squared.clone().mean_dim(D - 1);
+            let col = grad_squared.mean_dim(D - 2);
+            let row = ema(state.exp_avg_sq_row.take(), row, beta_2);
+            let col = ema(state.exp_avg_sq_col.take(), col, beta_2);
+
+            let row_factor = row.clone().div(row.clone().mean_dim(D - 2)).sqrt().recip();
+            let col_factor = col.clone().sqrt().recip();
+
+            state.exp_avg_sq_row = Some(row);
+            state.exp_avg_sq_col = Some(col);
+
+            grad.mul(row_factor).mul(col_factor)
+        } else {
+            let exp_avg_sq = ema(state.exp_avg_sq.take(), grad_squared, beta_2);
+            let update = grad.div(exp_avg_sq.clone().sqrt());
+
+            state.exp_avg_sq = Some(exp_avg_sq);
+
+            update
+        };
+
+        let clip = root_mean_square(update.clone())
+            .div_scalar(self.clip_threshold)
+            .clamp_min(1.0);
+        let mut update = update.div(clip.unsqueeze());
+
+        if let Some(beta_1) = self.beta_1 {
+            let moment_1 = ema(state.moment_1.take(), update, beta_1);
+            update = moment_1.clone();
+            state.moment_1 = Some(moment_1);
+        }
+
+        let mut delta = update
+            .add(tensor.clone().mul_scalar(self.weight_decay))
+            .mul_scalar(lr);
+        if self.scale_parameter {
+            let scale = root_mean_square(tensor.clone()).clamp_min(self.epsilon_2);
+            delta = delta.mul(scale.unsqueeze());
+        }
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
+        state.moment_1 = state.moment_1.map(|tensor| tensor.to_device(device));
+        state.exp_avg_sq = state.exp_avg_sq.map(|tensor| tensor.to_device(device));
+        state.exp_avg_sq_row = state.exp_avg_sq_row.map(|tensor| tensor.to_device(device));
+        state.exp_avg_sq_col = state.exp_avg_sq_col.map(|tensor| tensor.to_device(device));
+        state
+    }
+}
+
+/// Exponential moving average, starting from zero.
+fn ema<B: Backend, const D: usize>(
+    average: Option<Tensor<B, D>>,
+    value: Tensor<B, D>,
+    beta: f32,
+) -> Tensor<B, D> {
+    let value = value.mul_scalar(1.0 - beta);
+    match average {
+        Some(average) => average.mul_scalar(beta).add(value),
+        None => value,
+    }
+}
+
+fn root_mean_square<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 1> {
+    tensor.powi_scalar(2).mean().sqrt()
+}
+
+impl AdafactorConfig {
+    /// Initialize Adafactor optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+    ) -> OptimizerAdaptor<Adafactor, M, B> {
+        let optim = Adafactor {
+            epsilon_1: self.epsilon_1,
+            epsilon_2: self.epsilon_2,
+            clip_threshold: self.clip_threshold,
+            decay_rate: self.decay_rate,
+            beta_1: self.beta_1,
+            weight_decay: self.weight_decay,
+            scale_parameter: self.scale_parameter,
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Distribution, Tensor, TensorData};
+    use crate::{TestAutodiffBackend, nn};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+    const ASSERT_PRECISION: usize = 5;
+
+    #[test]
+    fn test_adafactor_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = AdafactorConfig::new().init();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer: OptimizerAdaptor<Adafactor, nn::Linear<TestAutodiffBackend>, _> =
+            AdafactorConfig::new().init();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(
+            state_optim_before.states.len(),
+            state_optim_after.states.len()
+        );
+    }
+
+    #[test]
+    fn test_adafactor_optimizer_with_numbers() {
+        let linear = given_linear_layer(
+            TensorData::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            TensorData::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let device = Default::default();
+        let x_1 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+                [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+            ],
+            &device,
+        )
+        .require_grad();
+        let x_2 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+                [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let mut optimizer = AdafactorConfig::new()
+            .with_beta_1(Some(0.9))
+            .with_weight_decay(0.1)
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let weights_expected = TensorData::from([
+            [-0.321134, 0.136644, 0.403414, 0.319155, 0.085169, 0.066378],
+            [
+                0.077115, -0.019038, -0.367069, 0.254329, 0.194858, -0.292605,
+            ],
+            [
+                -0.019684, 0.033890, -0.296749, 0.247587, -0.278558, 0.312155,
+            ],
+            [
+                -0.298556, -0.221993, -0.372020, -0.298656, -0.076764, 0.161821,
+            ],
+            [
+                0.329163, -0.218770, 0.370843, -0.173492, 0.378739, -0.031062,
+            ],
+            [-0.016582, -0.012684, 0.125049, 0.191317, 0.028596, 0.382424],
+        ]);
+        let bias_expected =
+            TensorData::from([-0.390889, 0.087835, -0.097497, 0.117024, 0.136017, 0.012463]);
+
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, ASSERT_PRECISION);
+        weight_updated.assert_approx_eq(&weights_expected, ASSERT_PRECISION);
+    }
+
+    fn given_linear_layer(weight: TensorData, bias: TensorData) -> nn::Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let record = nn::LinearRecord {
+            weight: Param::from_data(weight, &device),
+            bias: Some(Param::from_data(bias, &device)),
+        };
+
+        nn::LinearConfig::new(6, 6)
+            .init(&device)
+            .load_record(record)
+    }
+}
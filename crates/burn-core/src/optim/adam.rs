@@ -117,10 +117,10 @@ pub struct AdaptiveMomentumState<B: Backend, const D: usize> {
 }
 
 #[derive(Clone)]
-struct AdaptiveMomentum {
-    beta_1: f32,
-    beta_2: f32,
-    epsilon: f32,
+pub(crate) struct AdaptiveMomentum {
+    pub(crate) beta_1: f32,
+    pub(crate) beta_2: f32,
+    pub(crate) epsilon: f32,
 }
 
 impl AdaptiveMomentum {
@@ -235,7 +235,10 @@ mod tests {
         let optimizer = optimizer.load_record(state_optim_before_copy);
         let state_optim_after = optimizer.to_record();
 
-        assert_eq!(state_optim_before.len(), state_optim_after.len());
+        assert_eq!(
+            state_optim_before.states.len(),
+            state_optim_after.states.len()
+        );
     }
     const ASSERT_PRECISION: usize = 2;
 
@@ -0,0 +1,317 @@
+use core::marker::PhantomData;
+
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::{AutodiffModule, Module, ModuleMapper, ModuleVisitor, ParamId};
+
+use burn_tensor::{
+    ElementConversion, FloatDType, Int, Tensor,
+    backend::{AutodiffBackend, Backend},
+};
+
+use super::GradientsParams;
+
+/// The half precision float type used for the forward and backward passes of mixed precision
+/// training.
+#[derive(Config, Debug, PartialEq)]
+pub enum HalfPrecision {
+    /// 16-bit float with a 5-bit exponent, which needs [loss scaling](GradScaler) to avoid
+    /// gradient underflows.
+    F16,
+    /// 16-bit brain float with the exponent range of 32-bit floats.
+    BF16,
+}
+
+impl HalfPrecision {
+    /// The float data type of the precision.
+    pub fn dtype(&self) -> FloatDType {
+        match self {
+            HalfPrecision::F16 => FloatDType::F16,
+            HalfPrecision::BF16 => FloatDType::BF16,
+        }
+    }
+
+    /// Copy the module with its float parameters cast to half precision.
+    ///
+    /// The parameters requiring gradients become new leaves of the autodiff graph with the same
+    /// [ids](ParamId), so the [gradients](GradientsParams) computed with the copy apply to the
+    /// full precision parameters of the module, which stay the master weights.
+    ///
+    /// The [softmax](crate::tensor::activation::softmax) activations and the normalization
+    /// layers compute in full precision and cast their outputs back, but the other reductions
+    /// run in half precision: the outputs of the model should be cast back to full precision
+    /// before computing the loss.
+    pub fn cast<B: Backend, M: Module<B>>(&self, module: &M) -> M {
+        module.clone().map(&mut HalfPrecisionMapper {
+            dtype: self.dtype(),
+        })
+    }
+}
+
+struct HalfPrecisionMapper {
+    dtype: FloatDType,
+}
+
+impl<B: Backend> ModuleMapper<B> for HalfPrecisionMapper {
+    fn map_float<const D: usize>(&mut self, _id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        if !tensor.is_require_grad() {
+            return tensor.cast(self.dtype.clone());
+        }
+
+        tensor.detach().cast(self.dtype.clone()).require_grad()
+    }
+}
+
+/// Configuration to create a [gradient scaler](GradScaler).
+#[derive(Config, Debug)]
+pub struct GradScalerConfig {
+    /// The initial loss scale.
+    #[config(default = 65536.0)]
+    pub init_scale: f64,
+    /// The factor multiplying the scale after `growth_interval` steps without overflow.
+    #[config(default = 2.0)]
+    pub growth_factor: f64,
+    /// The factor multiplying the scale when the gradients overflow.
+    #[config(default = 0.5)]
+    pub backoff_factor: f64,
+    /// The number of consecutive steps without overflow before the scale grows.
+    #[config(default = 2000)]
+    pub growth_interval: usize,
+}
+
+impl GradScalerConfig {
+    /// Initialize a new [gradient scaler](GradScaler).
+    pub fn init(&self) -> GradScaler {
+        assert!(
+            self.growth_factor > 1.0,
+            "The growth factor of the gradient scaler must be greater than 1."
+        );
+        assert!(
+            self.backoff_factor > 0.0 && self.backoff_factor < 1.0,
+            "The backoff factor of the gradient scaler must be between 0 and 1."
+        );
+
+        GradScaler {
+            scale: self.init_scale,
+            growth_factor: self.growth_factor,
+            backoff_factor: self.backoff_factor,
+            growth_interval: self.growth_interval,
+            steps_without_overflow: 0,
+        }
+    }
+}
+
+/// Dynamic loss scaling for mixed precision training.
+///
+/// Multiplying the loss by a large scale before the backward pass keeps small half precision
+/// gradients from underflowing. The gradients are then [unscaled](GradScaler::unscale) before
+/// the optimizer step, which is skipped when they overflow: the scale is then reduced, and
+/// it grows again after a number of steps without overflow.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut scaler = GradScalerConfig::new().init();
+///
+/// let model_half = HalfPrecision::F16.cast(&model);
+/// let output = model_half.forward(input.cast(FloatDType::F16));
+/// // The loss is computed in full precision.
+/// let loss = loss_fn.forward(output.cast(FloatDType::F32), targets);
+/// let grads = GradientsParams::from_grads(scaler.scale_loss(loss).backward(), &model_half);
+///
+/// if let Some(grads) = scaler.unscale(&model, grads) {
+///     model = optim.step(lr, model, grads);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GradScaler {
+    scale: f64,
+    growth_factor: f64,
+    backoff_factor: f64,
+    growth_interval: usize,
+    steps_without_overflow: usize,
+}
+
+/// The [record](crate::record::Record) of a [gradient scaler](GradScaler), with its current
+/// scale and number of steps without overflow.
+pub type GradScalerRecord = (f64, usize);
+
+impl GradScaler {
+    /// The current loss scale.
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Get the current state of the scaler.
+    pub fn to_record(&self) -> GradScalerRecord {
+        (self.scale, self.steps_without_overflow)
+    }
+
+    /// Load the state of the scaler from a [record](GradScaler::to_record).
+    pub fn load_record(mut self, record: GradScalerRecord) -> Self {
+        (self.scale, self.steps_without_overflow) = record;
+        self
+    }
+
+    /// Multiply the loss by the current scale.
+    pub fn scale_loss<B: Backend, const D: usize>(&self, loss: Tensor<B, D>) -> Tensor<B, D> {
+        loss.mul_scalar(self.scale)
+    }
+
+    /// Divide the gradients of the parameters of the module by the current scale, and cast them
+    /// to the data type of the parameters.
+    ///
+    /// Returns `None` when any gradient is infinite or NaN, in which case the optimizer step
+    /// should be skipped. The scale is updated in both cases.
+    pub fn unscale<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &mut self,
+        module: &M,
+        grads: GradientsParams,
+    ) -> Option<GradientsParams> {
+        let mut visitor = GradientsUnscaler::<B, M> {
+            grads,
+            factor: 1.0 / self.scale,
+            non_finite: None,
+            phantom: PhantomData,
+        };
+        module.visit(&mut visitor);
+
+        let overflow = match visitor.non_finite {
+            Some(count) => count.into_scalar().elem::<i64>() > 0,
+            None => false,
+        };
+        self.update(overflow);
+
+        match overflow {
+            true => None,
+            false => Some(visitor.grads),
+        }
+    }
+
+    fn update(&mut self, overflow: bool) {
+        if overflow {
+            self.scale *= self.backoff_factor;
+            self.steps_without_overflow = 0;
+            return;
+        }
+
+        self.steps_without_overflow += 1;
+        if self.steps_without_overflow >= self.growth_interval {
+            self.scale *= self.growth_factor;
+            self.steps_without_overflow = 0;
+        }
+    }
+}
+
+struct GradientsUnscaler<B: AutodiffBackend, M> {
+    grads: GradientsParams,
+    factor: f64,
+    // The number of gradients with infinite or NaN values.
+    non_finite: Option<Tensor<B::InnerBackend, 1, Int>>,
+    phantom: PhantomData<M>,
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModuleVisitor<B> for GradientsUnscaler<B, M> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) else {
+            return;
+        };
+
+        let grad = grad.cast(tensor.dtype()).mul_scalar(self.factor);
+        // NaN values are neither lower nor equal to the maximum.
+        let non_finite = grad
+            .clone()
+            .abs()
+            .lower_equal_elem(f32::MAX)
+            .bool_not()
+            .any()
+            .int();
+
+        self.non_finite = Some(match self.non_finite.take() {
+            Some(count) => count + non_finite,
+            None => non_finite,
+        });
+        self.grads.register::<B::InnerBackend, D>(id, grad);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TestAutodiffBackend,
+        nn::{Linear, LinearConfig},
+    };
+
+    type InnerBackend = <TestAutodiffBackend as AutodiffBackend>::InnerBackend;
+
+    #[test]
+    fn unscale_should_divide_the_gradients_by_the_scale() {
+        let device = Default::default();
+        let layer = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let mut scaler = GradScalerConfig::new().with_init_scale(8.0).init();
+
+        let expected = GradientsParams::from_grads(loss(&layer).backward(), &layer);
+        let loss = scaler.scale_loss(loss(&layer));
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let grads = scaler.unscale(&layer, grads).unwrap();
+
+        let id = layer.weight.id;
+        let expected = expected.get::<InnerBackend, 2>(id).unwrap();
+        grads
+            .get::<InnerBackend, 2>(id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn overflow_should_skip_the_step_and_reduce_the_scale() {
+        let device = Default::default();
+        let layer = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let mut scaler = GradScalerConfig::new().with_growth_interval(2).init();
+
+        let mut grads = GradientsParams::from_grads(loss(&layer).backward(), &layer);
+        let infinite = Tensor::<InnerBackend, 1>::from_floats([f32::INFINITY, 0.0, 0.0], &device);
+        grads.register(layer.bias.as_ref().unwrap().id, infinite);
+
+        assert!(scaler.unscale(&layer, grads).is_none());
+        assert_eq!(scaler.scale(), 32768.0);
+
+        for _ in 0..2 {
+            let grads = GradientsParams::from_grads(loss(&layer).backward(), &layer);
+            assert!(scaler.unscale(&layer, grads).is_some());
+        }
+        assert_eq!(scaler.scale(), 65536.0);
+    }
+
+    #[test]
+    fn half_precision_copy_should_keep_the_param_ids() {
+        let device = Default::default();
+        let layer = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let frozen = LinearConfig::new(4, 3)
+            .init::<TestAutodiffBackend>(&device)
+            .no_grad();
+
+        let mapper = &mut HalfPrecisionMapper {
+            // The test backend may not support half precision.
+            dtype: FloatDType::F32,
+        };
+        let copy = layer.clone().map(mapper);
+        let frozen_copy = frozen.clone().map(mapper);
+
+        assert_eq!(copy.weight.id, layer.weight.id);
+        assert!(copy.weight.is_require_grad());
+        assert!(!frozen_copy.weight.is_require_grad());
+        let grads = loss(&copy).backward();
+        assert!(copy.weight.grad(&grads).is_some());
+        assert!(layer.weight.grad(&grads).is_none());
+    }
+
+    fn loss(layer: &Linear<TestAutodiffBackend>) -> Tensor<TestAutodiffBackend, 1> {
+        let input = Tensor::ones([2, 4], &layer.weight.device());
+
+        layer.forward(input).sum()
+    }
+}
@@ -17,6 +17,12 @@ where
     /// The updated module is returned.
     fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M;
 
+    /// The global L2 norm of the gradients of the last step, before clipping, when the optimizer
+    /// [clips the gradients by global norm](crate::grad_clipping::GradientClipping::GlobalNorm).
+    fn grad_norm(&self) -> Option<f64> {
+        None
+    }
+
     /// Get the current state of the optimizer as a [record](Record).
     fn to_record(&self) -> Self::Record;
 
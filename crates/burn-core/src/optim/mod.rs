@@ -4,23 +4,33 @@ pub mod decay;
 /// Momentum module for optimizers.
 pub mod momentum;
 
+mod adafactor;
 mod adagrad;
 mod adam;
 mod adamw;
+mod amp;
 mod base;
 mod grad_accum;
 mod grads;
+mod lamb;
+mod lion;
+mod muon;
 mod rmsprop;
 mod sgd;
 mod simple;
 mod visitor;
 
+pub use adafactor::*;
 pub use adagrad::*;
 pub use adam::*;
 pub use adamw::*;
+pub use amp::*;
 pub use base::*;
 pub use grad_accum::*;
 pub use grads::*;
+pub use lamb::*;
+pub use lion::*;
+pub use muon::*;
 pub use rmsprop::*;
 pub use sgd::*;
 pub use simple::*;
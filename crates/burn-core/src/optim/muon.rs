@@ -0,0 +1,386 @@
+use super::SimpleOptimizer;
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{Tensor, backend::AutodiffBackend};
+use crate::{
+    self as burn, LearningRate, grad_clipping::GradientClippingConfig, module::AutodiffModule,
+    record::Record,
+};
+use burn_tensor::{backend::Backend, ops::Device};
+
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// How the learning rate of [Muon](Muon) is adjusted to the shape of the parameters.
+#[derive(Config, Debug, PartialEq)]
+pub enum AdjustLrFn {
+    /// Scale the learning rate by `sqrt(max(1, fan_out / fan_in))`, as in the original
+    /// implementation.
+    Original,
+    /// Scale the learning rate by `0.2 * sqrt(max(fan_out, fan_in))`, so the root mean square of
+    /// the update matches the one of AdamW and the same learning rate can be used for both.
+    MatchRmsAdamW,
+}
+
+/// Muon configuration.
+#[derive(Config)]
+pub struct MuonConfig {
+    /// Momentum factor.
+    #[config(default = 0.95)]
+    momentum: f64,
+    /// Whether the Nesterov momentum is used.
+    #[config(default = true)]
+    nesterov: bool,
+    /// Decoupled weight decay.
+    #[config(default = 0.1)]
+    weight_decay: f64,
+    /// Number of Newton-Schulz iterations.
+    #[config(default = 5)]
+    ns_steps: usize,
+    /// Coefficients of the quintic Newton-Schulz iteration.
+    #[config(default = "(3.4445, -4.7750, 2.0315)")]
+    ns_coefficients: (f32, f32, f32),
+    /// A value required for numerical stability.
+    #[config(default = 1e-7)]
+    epsilon: f32,
+    /// How the learning rate is adjusted to the shape of the parameters.
+    #[config(default = "AdjustLrFn::Original")]
+    adjust_lr: AdjustLrFn,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Muon optimizer as described in
+/// [Muon: An optimizer for hidden layers in neural networks, Jordan et al., 2024](https://kellerjordan.github.io/posts/muon/).
+///
+/// The momentum of every matrix is orthogonalized with Newton-Schulz iterations before being
+/// applied. Tensors with more than two dimensions are flattened to a matrix with their first
+/// dimension as rows, and tensors with a single dimension are updated with the momentum only.
+///
+/// The learning rate is adjusted with the fan-in and fan-out of the parameters in the layout of
+/// Burn: matrices are `[d_input, d_output]`, as the weights of [Linear](crate::nn::Linear), while
+/// the first dimension of higher-rank tensors is the output, as the weights of convolutions.
+///
+/// Muon is designed for the weights of hidden layers: embeddings and output heads are usually
+/// trained with [AdamW](crate::optim::AdamW).
+#[derive(Clone)]
+pub struct Muon {
+    momentum: f64,
+    nesterov: bool,
+    weight_decay: f64,
+    ns_steps: usize,
+    ns_coefficients: (f32, f32, f32),
+    epsilon: f32,
+    adjust_lr: AdjustLrFn,
+}
+
+/// Muon state.
+#[derive(Record, Clone, new)]
+pub struct MuonState<B: Backend, const D: usize> {
+    /// The exponential moving average of the gradients.
+    pub momentum: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Muon {
+    type State<const D: usize> = MuonState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let momentum = grad.clone().mul_scalar(1.0 - self.momentum);
+        let momentum = match state {
+            Some(state) => state.momentum.mul_scalar(self.momentum).add(momentum),
+            None => momentum,
+        };
+
+        let update = match self.nesterov {
+            true => grad
+                .mul_scalar(1.0 - self.momentum)
+                .add(momentum.clone().mul_scalar(self.momentum)),
+            false => momentum.clone(),
+        };
+
+        let (update, lr_adjusted) = if D >= 2 {
+            let shape = update.shape();
+            let rows = shape.dims[0];
+            let cols = shape.num_elements() / rows;
+
+            let update = self
+                .orthogonalize(update.reshape([rows, cols]))
+                .reshape(shape);
+
+            let (fan_in, fan_out) = match D {
+                2 => (rows, cols),
+                _ => (cols, rows),
+            };
+
+            (update, lr * self.lr_factor(fan_in, fan_out))
+        } else {
+            (update, lr)
+        };
+
+        let tensor = tensor.mul_scalar(1.0 - lr * self.weight_decay);
+
+        (
+            tensor - update.mul_scalar(lr_adjusted),
+            Some(MuonState::new(momentum)),
+        )
+    }
+
+    fn to_device<const D: usize>(mut state: Self::State<D>, device: &Device<B>) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+}
+
+impl Muon {
+    /// Approximate the closest semi-orthogonal matrix with the quintic Newton-Schulz iteration.
+    fn orthogonalize<B: Backend>(&self, matrix: Tensor<B, 2>) -> Tensor<B, 2> {
+        let [rows, cols] = matrix.dims();
+        let transposed = rows > cols;
+        let matrix = match transposed {
+            true => matrix.transpose(),
+            false => matrix,
+        };
+
+        let norm = matrix
+            .clone()
+            .powi_scalar(2)
+            .sum()
+            .sqrt()
+            .clamp_min(self.epsilon);
+        let mut x = matrix.div(norm.unsqueeze());
+
+        let (a, b, c) = self.ns_coefficients;
+        for _ in 0..self.ns_steps {
+            let gram = x.clone().matmul(x.clone().transpose());
+            let poly = gram
+                .clone()
+                .mul_scalar(b)
+                .add(gram.clone().matmul(gram).mul_scalar(c));
+            x = x.clone().mul_scalar(a).add(poly.matmul(x));
+        }
+
+        match transposed {
+            true => x.transpose(),
+            false => x,
+        }
+    }
+
+    fn lr_factor(&self, fan_in: usize, fan_out: usize) -> f64 {
+        let (fan_in, fan_out) = (fan_in as f64, fan_out as f64);
+        match self.adjust_lr {
+            AdjustLrFn::Original => (fan_out / fan_in).max(1.0).sqrt(),
+            AdjustLrFn::MatchRmsAdamW => 0.2 * fan_out.max(fan_in).sqrt(),
+        }
+    }
+}
+
+impl MuonConfig {
+    /// Initialize Muon optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> OptimizerAdaptor<Muon, M, B> {
+        let optim = Muon {
+            momentum: self.momentum,
+            nesterov: self.nesterov,
+            weight_decay: self.weight_decay,
+            ns_steps: self.ns_steps,
+            ns_coefficients: self.ns_coefficients,
+            epsilon: self.epsilon,
+            adjust_lr: self.adjust_lr.clone(),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{Module, Param};
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::tensor::{Distribution, Tensor, TensorData};
+    use crate::{TestAutodiffBackend, nn};
+
+    const LEARNING_RATE: LearningRate = 0.01;
+    const ASSERT_PRECISION: usize = 4;
+
+    #[test]
+    fn test_muon_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = MuonConfig::new().init();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer: OptimizerAdaptor<Muon, nn::Linear<TestAutodiffBackend>, _> =
+            MuonConfig::new().init();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(
+            state_optim_before.states.len(),
+            state_optim_after.states.len()
+        );
+    }
+
+    #[test]
+    fn test_muon_optimizer_with_numbers() {
+        let linear = given_linear_layer(
+            TensorData::from([
+                [-0.3206, 0.1374, 0.4043, 0.3200, 0.0859, 0.0671],
+                [0.0777, -0.0185, -0.3667, 0.2550, 0.1955, -0.2922],
+                [-0.0190, 0.0346, -0.2962, 0.2484, -0.2780, 0.3130],
+                [-0.2980, -0.2214, -0.3715, -0.2981, -0.0761, 0.1626],
+                [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
+                [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
+            ]),
+            TensorData::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+        );
+        let device = Default::default();
+        let x_1 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.6294, 0.0940, 0.8176, 0.8824, 0.5228, 0.4310],
+                [0.7152, 0.9559, 0.7893, 0.5684, 0.5939, 0.8883],
+            ],
+            &device,
+        )
+        .require_grad();
+        let x_2 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [
+                [0.8491, 0.2108, 0.8939, 0.4433, 0.5527, 0.2528],
+                [0.3270, 0.0412, 0.5538, 0.9605, 0.3195, 0.9085],
+            ],
+            &device,
+        )
+        .require_grad();
+
+        let mut optimizer = MuonConfig::new()
+            .with_momentum(0.95)
+            .with_nesterov(true)
+            .with_weight_decay(0.1)
+            .init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let state_updated = linear.into_record();
+        let weights_expected = TensorData::from([
+            [-0.322340, 0.134745, 0.401111, 0.316980, 0.083348, 0.064585],
+            [
+                0.076133, -0.019875, -0.367379, 0.253079, 0.193697, -0.293028,
+            ],
+            [
+                -0.021835, 0.031658, -0.298481, 0.245030, -0.280317, 0.309501,
+            ],
+            [
+                -0.300063, -0.223616, -0.373416, -0.300163, -0.078607, 0.159616,
+            ],
+            [
+                0.327433, -0.219671, 0.369050, -0.174461, 0.376934, -0.032246,
+            ],
+            [-0.018209, -0.014317, 0.123208, 0.189375, 0.026901, 0.380193],
+        ]);
+        let bias_expected =
+            TensorData::from([-0.394520, 0.083423, -0.101607, 0.112564, 0.131526, 0.008173]);
+
+        let (weight_updated, bias_updated) = (
+            state_updated.weight.to_data(),
+            state_updated.bias.unwrap().to_data(),
+        );
+
+        bias_updated.assert_approx_eq(&bias_expected, ASSERT_PRECISION);
+        weight_updated.assert_approx_eq(&weights_expected, ASSERT_PRECISION);
+    }
+
+    #[test]
+    fn test_muon_optimizer_with_numbers_non_square() {
+        let device = Default::default();
+        let record = nn::LinearRecord {
+            weight: Param::from_data(
+                TensorData::from([
+                    [-0.3206, 0.1374, 0.4043, 0.3200],
+                    [0.0777, -0.0185, -0.3667, 0.2550],
+                ]),
+                &device,
+            ),
+            bias: Some(Param::from_data(
+                TensorData::from([-0.3905, 0.0884, -0.0970, 0.1176]),
+                &device,
+            )),
+        };
+        let linear = nn::LinearConfig::new(2, 4)
+            .init::<TestAutodiffBackend>(&device)
+            .load_record(record);
+        let x_1 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[0.6294, 0.0940], [0.7152, 0.9559]],
+            &device,
+        )
+        .require_grad();
+        let x_2 = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[0.8491, 0.2108], [0.3270, 0.0412]],
+            &device,
+        )
+        .require_grad();
+
+        let mut optimizer = MuonConfig::new().init();
+
+        let grads = linear.forward(x_1).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let grads = linear.forward(x_2).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        // The learning rate of the weight is scaled by sqrt(fan_out / fan_in) = sqrt(2).
+        let weights_expected = TensorData::from([
+            [-0.328393, 0.128692, 0.395058, 0.310927],
+            [0.072648, -0.023360, -0.370864, 0.249593],
+        ]);
+        let bias_expected = TensorData::from([-0.394520, 0.083423, -0.101607, 0.112564]);
+
+        let state_updated = linear.into_record();
+        state_updated
+            .weight
+            .to_data()
+            .assert_approx_eq(&weights_expected, ASSERT_PRECISION);
+        state_updated
+            .bias
+            .unwrap()
+            .to_data()
+            .assert_approx_eq(&bias_expected, ASSERT_PRECISION);
+    }
+
+    fn given_linear_layer(weight: TensorData, bias: TensorData) -> nn::Linear<TestAutodiffBackend> {
+        let device = Default::default();
+        let record = nn::LinearRecord {
+            weight: Param::from_data(weight, &device),
+            bias: Some(Param::from_data(bias, &device)),
+        };
+
+        nn::LinearConfig::new(6, 6)
+            .init(&device)
+            .load_record(record)
+    }
+}
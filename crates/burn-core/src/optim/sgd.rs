@@ -120,14 +120,14 @@ mod tests {
 
         let record = optim.to_record();
 
-        assert!(!record.is_empty());
+        assert!(!record.states.is_empty());
     }
 
     #[test]
     fn without_updated_params_should_not_have_state() {
         let optim = sgd_with_all();
         let record = optim.to_record();
-        assert!(record.is_empty());
+        assert!(record.states.is_empty());
     }
 
     #[test]
@@ -136,6 +136,20 @@ mod tests {
         assert!(optim.has_gradient_clipping());
     }
 
+    #[test]
+    fn global_norm_clipping_should_report_the_norm() {
+        let device = Default::default();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let mut optim = sgd_with_all().with_grad_clipping(GradientClipping::GlobalNorm(1e-3));
+        assert_eq!(optim.grad_norm(), None);
+
+        let loss = layer.forward(random_tensor(&device));
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let _layer = optim.step(LEARNING_RATE, layer, grads);
+
+        assert!(optim.grad_norm().unwrap() > 1e-3);
+    }
+
     #[test]
     fn should_load_state() {
         let device = Default::default();
@@ -152,8 +166,8 @@ mod tests {
         let optim_new = optim_new.load_record(record.clone());
         let state_restored = optim_new.to_record();
 
-        assert_ne!(record.len(), record_new.len());
-        assert_eq!(record.len(), state_restored.len());
+        assert_ne!(record.states.len(), record_new.states.len());
+        assert_eq!(record.states.len(), state_restored.states.len());
     }
 
     fn random_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 2> {
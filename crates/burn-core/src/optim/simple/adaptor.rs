@@ -1,10 +1,14 @@
-use super::{SimpleOptimizer, record::AdaptorRecord};
+use super::{
+    ParamGroup, SimpleOptimizer,
+    record::{AdaptorRecord, OptimizerAdaptorRecord},
+};
 use crate::{
     LearningRate,
     grad_clipping::GradientClipping,
     module::{AutodiffModule, ModuleMapper, ParamId},
     optim::{GradientsParams, Optimizer},
 };
+use alloc::vec::Vec;
 use burn_tensor::{Tensor, backend::AutodiffBackend};
 use core::marker::PhantomData;
 use hashbrown::HashMap;
@@ -20,8 +24,10 @@ where
 {
     optim: O,
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
+    groups: Vec<ParamGroup<O, M, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    grad_norm: Option<f32>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -34,8 +40,10 @@ where
         Self {
             optim,
             records: HashMap::new(),
+            groups: Vec::new(),
             module: PhantomData,
             grad_clipping: None,
+            grad_norm: None,
         }
     }
 }
@@ -60,6 +68,32 @@ where
         self
     }
 
+    /// Adds a parameter group, optimized with its own settings.
+    ///
+    /// Parameters are optimized with the settings of the first group that contains them, so
+    /// groups should be added from the most specific to the most general.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The parameter group.
+    ///
+    /// # Returns
+    ///
+    /// The optimizer.
+    pub fn with_group(mut self, group: ParamGroup<O, M, B>) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// The parameter groups, in the order they were added.
+    pub fn groups(&self) -> &[ParamGroup<O, M, B>] {
+        &self.groups
+    }
+
+    pub(crate) fn into_optimizer(self) -> O {
+        self.optim
+    }
+
     #[cfg(test)]
     pub(crate) fn has_gradient_clipping(&self) -> bool {
         self.grad_clipping.is_some()
@@ -72,25 +106,62 @@ where
     M: AutodiffModule<B>,
     O: SimpleOptimizer<B::InnerBackend>,
 {
-    type Record = HashMap<ParamId, AdaptorRecord<O, B>>;
+    type Record = OptimizerAdaptorRecord<O, B>;
 
     fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+        if let Some(clipping @ GradientClipping::GlobalNorm(_)) = &self.grad_clipping {
+            let (clipped, norm) = clipping.clip_global_norm(&module, grads);
+            grads = clipped;
+            self.grad_norm = Some(norm);
+        }
+
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
             &mut self.records,
+            &self.groups,
             &mut grads,
             lr,
-            self.grad_clipping.as_ref(),
+            // The gradients are already clipped by their global norm.
+            self.grad_clipping
+                .as_ref()
+                .filter(|clipping| !matches!(clipping, GradientClipping::GlobalNorm(_))),
         );
         module.map(&mut mapper)
     }
 
+    fn grad_norm(&self) -> Option<f64> {
+        self.grad_norm.map(f64::from)
+    }
+
     fn to_record(&self) -> Self::Record {
-        self.records.clone()
+        OptimizerAdaptorRecord {
+            states: self.records.clone(),
+            groups: self.groups.iter().map(ParamGroup::to_record).collect(),
+        }
     }
 
     fn load_record(mut self, record: Self::Record) -> Self {
-        self.records = record;
+        self.records = record.states;
+
+        // Records saved before the parameter groups were introduced have none, in which case the
+        // groups keep the settings they are created with.
+        if record.groups.is_empty() {
+            return self;
+        }
+
+        // Groups are matched by position, since their selection and optimizer settings are
+        // defined in code, so the optimizer must be created with the same groups.
+        assert_eq!(
+            self.groups.len(),
+            record.groups.len(),
+            "The optimizer has {} parameter groups, but the record has {}: add the same groups, \
+             in the same order, as when the record was saved.",
+            self.groups.len(),
+            record.groups.len(),
+        );
+        for (group, record) in self.groups.iter_mut().zip(record.groups) {
+            group.load_record(record);
+        }
         self
     }
 }
@@ -104,6 +175,7 @@ where
 {
     optimizer: &'a O,
     records: &'a mut HashMap<ParamId, AdaptorRecord<O, B>>,
+    groups: &'a [ParamGroup<O, M, B>],
     grads: &'a mut GradientsParams,
     lr: LearningRate,
     phantom: PhantomData<M>,
@@ -118,8 +190,18 @@ where
 {
     fn map_float<const D: usize>(&mut self, id: ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
         let grad = self.grads.remove(id);
+        let group = self.groups.iter().find(|group| group.contains(&id));
+
+        // Frozen parameters are neither updated nor given a state.
+        if !tensor.is_require_grad() || group.is_some_and(|group| group.is_frozen()) {
+            return tensor;
+        }
 
         if let Some(grad) = grad {
+            let optimizer = group
+                .and_then(|group| group.optimizer())
+                .unwrap_or(self.optimizer);
+            let lr = group.map_or(self.lr, |group| self.lr * group.lr_multiplier());
             let device = grad.device();
             let is_require_grad = tensor.is_require_grad();
             let (key, record) = self.records.remove_entry(&id).unzip();
@@ -130,8 +212,8 @@ where
                 grad
             };
 
-            let (tensor, state) = self.optimizer.step(
-                self.lr,
+            let (tensor, state) = optimizer.step(
+                lr,
                 tensor.inner(),
                 clipped_grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
@@ -0,0 +1,385 @@
+use super::{SimpleOptimizer, adaptor::OptimizerAdaptor};
+use crate::{
+    module::{AutodiffModule, Module, ParamId, list_param_ids, list_param_ids_matching},
+    record::{PrecisionSettings, Record},
+};
+use alloc::{string::String, sync::Arc, vec::Vec};
+use burn_tensor::backend::{AutodiffBackend, Backend};
+use core::marker::PhantomData;
+use hashbrown::HashSet;
+use serde::{Deserialize, Serialize};
+
+type ParamFilter = Arc<dyn Fn(&ParamId) -> bool + Send + Sync>;
+
+/// A group of parameters optimized with their own settings.
+///
+/// Each parameter is optimized with the settings of the first group of the
+/// [optimizer adaptor](OptimizerAdaptor) that contains it, or with the default
+/// settings of the optimizer when no group contains it.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let optim = AdamWConfig::new()
+///     .with_weight_decay(0.1)
+///     .init()
+///     // Train the head ten times faster.
+///     .with_group(ParamGroup::from_module(&model.head).with_lr_multiplier(10.0))
+///     // No weight decay for the biases.
+///     .with_group(
+///         ParamGroup::from_path(&model, "*.bias")
+///             .with_optimizer(AdamWConfig::new().with_weight_decay(0.0).init()),
+///     )
+///     // Don't train the embeddings.
+///     .with_group(ParamGroup::from_module(&model.embedding).with_frozen(true));
+/// ```
+pub struct ParamGroup<O, M, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    params: HashSet<ParamId>,
+    filter: Option<ParamFilter>,
+    optim: Option<O>,
+    lr_multiplier: f64,
+    frozen: bool,
+    module: PhantomData<M>,
+    backend: PhantomData<B>,
+}
+
+impl<O, M, B> Clone for ParamGroup<O, M, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    fn clone(&self) -> Self {
+        Self {
+            params: self.params.clone(),
+            filter: self.filter.clone(),
+            optim: self.optim.clone(),
+            lr_multiplier: self.lr_multiplier,
+            frozen: self.frozen,
+            module: PhantomData,
+            backend: PhantomData,
+        }
+    }
+}
+
+impl<O, M, B> ParamGroup<O, M, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    M: AutodiffModule<B>,
+    B: AutodiffBackend,
+{
+    /// Create a group with the given parameters.
+    pub fn from_ids<I: IntoIterator<Item = ParamId>>(ids: I) -> Self {
+        Self {
+            params: ids.into_iter().collect(),
+            filter: None,
+            optim: None,
+            lr_multiplier: 1.0,
+            frozen: false,
+            module: PhantomData,
+            backend: PhantomData,
+        }
+    }
+
+    /// Create a group with all the parameters of a module, such as a field of the model.
+    pub fn from_module<SM: Module<B>>(module: &SM) -> Self {
+        Self::from_ids(list_param_ids(module))
+    }
+
+    /// Create a group with the float parameters of a module whose
+    /// [path](crate::module::ModulePath::matches) matches the glob pattern, such as `*.bias`.
+    pub fn from_path<SM: Module<B>>(module: &SM, pattern: &str) -> Self {
+        Self::from_ids(list_param_ids_matching(module, pattern))
+    }
+
+    /// Create a group with the parameters whose id matches the predicate.
+    pub fn from_filter<F>(filter: F) -> Self
+    where
+        F: Fn(&ParamId) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            filter: Some(Arc::new(filter)),
+            ..Self::from_ids([])
+        }
+    }
+
+    /// Optimize the parameters of the group with the settings of another optimizer of the same
+    /// type, such as one created with a config without weight decay.
+    ///
+    /// Only the settings of the optimizer are used: its state and its gradient clipping are
+    /// ignored.
+    pub fn with_optimizer(mut self, optim: OptimizerAdaptor<O, M, B>) -> Self {
+        self.optim = Some(optim.into_optimizer());
+        self
+    }
+
+    /// Multiply the learning rate of the parameters of the group.
+    pub fn with_lr_multiplier(mut self, lr_multiplier: f64) -> Self {
+        self.lr_multiplier = lr_multiplier;
+        self
+    }
+
+    /// Freeze the parameters of the group: they aren't updated and no state is allocated for
+    /// them.
+    pub fn with_frozen(mut self, frozen: bool) -> Self {
+        self.frozen = frozen;
+        self
+    }
+
+    /// Whether the parameter belongs to the group.
+    pub fn contains(&self, id: &ParamId) -> bool {
+        self.params.contains(id) || self.filter.as_ref().is_some_and(|filter| filter(id))
+    }
+
+    /// The learning rate multiplier of the group.
+    pub fn lr_multiplier(&self) -> f64 {
+        self.lr_multiplier
+    }
+
+    /// Whether the parameters of the group are frozen.
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
+    /// The optimizer of the group, if it overrides the default one.
+    pub(crate) fn optimizer(&self) -> Option<&O> {
+        self.optim.as_ref()
+    }
+
+    pub(crate) fn to_record(&self) -> ParamGroupRecord {
+        ParamGroupRecord {
+            lr_multiplier: self.lr_multiplier,
+            frozen: self.frozen,
+            params: self.params.iter().copied().collect(),
+        }
+    }
+
+    pub(crate) fn load_record(&mut self, record: ParamGroupRecord) {
+        self.lr_multiplier = record.lr_multiplier;
+        self.frozen = record.frozen;
+        self.params.extend(record.params);
+    }
+}
+
+/// [Parameter group](ParamGroup) record.
+///
+/// The parameters selected with a [filter](ParamGroup::from_filter) are not recorded, since
+/// the filter is evaluated again after loading.
+#[derive(Clone, Debug)]
+pub struct ParamGroupRecord {
+    /// The learning rate multiplier of the group.
+    pub lr_multiplier: f64,
+    /// Whether the parameters of the group are frozen.
+    pub frozen: bool,
+    /// The parameters of the group.
+    pub params: Vec<ParamId>,
+}
+
+/// [Parameter group](ParamGroup) record item.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ParamGroupRecordItem {
+    lr_multiplier: f64,
+    frozen: bool,
+    params: Vec<String>,
+}
+
+impl<B: Backend> Record<B> for ParamGroupRecord {
+    type Item<S: PrecisionSettings> = ParamGroupRecordItem;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        ParamGroupRecordItem {
+            lr_multiplier: self.lr_multiplier,
+            frozen: self.frozen,
+            params: self.params.into_iter().map(ParamId::serialize).collect(),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, _device: &B::Device) -> Self {
+        Self {
+            lr_multiplier: item.lr_multiplier,
+            frozen: item.frozen,
+            params: item
+                .params
+                .iter()
+                .map(|id| ParamId::deserialize(id))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::{
+        TestAutodiffBackend, TestBackend,
+        nn::{LayerNorm, LayerNormConfig, Linear, LinearConfig},
+        optim::{AdamWConfig, GradientsParams, Optimizer, Sgd, SgdConfig},
+        tensor::Tensor,
+    };
+
+    type TestGroup = ParamGroup<Sgd<TestBackend>, Linear<TestAutodiffBackend>, TestAutodiffBackend>;
+
+    const LEARNING_RATE: f64 = 0.02;
+
+    #[test]
+    fn frozen_group_should_not_update_params_nor_allocate_state() {
+        let layer = layer();
+        let bias = layer.bias.as_ref().unwrap();
+        let mut optim = SgdConfig::new()
+            .init()
+            .with_group(TestGroup::from_ids([bias.id]).with_frozen(true));
+        let bias_before = bias.val().into_data();
+        let weight_before = layer.weight.val().into_data();
+
+        let grads = gradients(&layer);
+        let layer = optim.step(LEARNING_RATE, layer, grads);
+
+        layer
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&bias_before, true);
+        assert_ne!(layer.weight.val().into_data(), weight_before);
+        assert_eq!(optim.to_record().states.len(), 1);
+    }
+
+    #[test]
+    fn frozen_params_should_not_have_gradients_nor_state() {
+        let layer = layer().freeze("bias");
+        let bias_before = layer.bias.as_ref().unwrap().val().into_data();
+        let mut optim = SgdConfig::new().init();
+
+        let grads = gradients(&layer);
+        assert_eq!(grads.len(), 1);
+        let layer = optim.step(LEARNING_RATE, layer, grads);
+
+        layer
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_eq(&bias_before, true);
+        assert_eq!(optim.to_record().states.len(), 1);
+    }
+
+    #[test]
+    fn lr_multiplier_should_scale_the_learning_rate() {
+        let layer = layer();
+        let grads = gradients(&layer);
+        let mut optim = SgdConfig::new()
+            .init()
+            .with_group(TestGroup::from_module(&layer).with_lr_multiplier(2.0));
+        let mut optim_expected = SgdConfig::new().init();
+
+        let layer_expected = optim_expected.step(2.0 * LEARNING_RATE, layer.clone(), grads);
+        let grads = gradients(&layer);
+        let layer = optim.step(LEARNING_RATE, layer, grads);
+
+        layer
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&layer_expected.weight.val().into_data(), 5);
+    }
+
+    #[test]
+    fn groups_should_be_restored_from_record() {
+        let layer = layer();
+        let optim = SgdConfig::new().init().with_group(
+            TestGroup::from_module(&layer)
+                .with_lr_multiplier(0.1)
+                .with_frozen(true),
+        );
+
+        let record = optim.to_record();
+        let optim = SgdConfig::new()
+            .init()
+            .with_group(TestGroup::from_ids([]))
+            .load_record(record);
+
+        let group = &optim.groups()[0];
+        assert_eq!(group.lr_multiplier(), 0.1);
+        assert!(group.is_frozen());
+        assert!(group.contains(&layer.weight.id));
+    }
+
+    #[derive(Module, Debug)]
+    struct Net<B: Backend> {
+        linear: Linear<B>,
+        norm: LayerNorm<B>,
+    }
+
+    #[test]
+    fn group_from_path_should_exclude_biases_and_norms_from_weight_decay() {
+        let device = Default::default();
+        let net = Net::<TestAutodiffBackend> {
+            linear: LinearConfig::new(4, 4).init(&device),
+            norm: LayerNormConfig::new(4).init(&device),
+        };
+        let no_decay = || AdamWConfig::new().with_weight_decay(0.0).init();
+        let mut optim = AdamWConfig::new()
+            .with_weight_decay(0.5)
+            .init()
+            .with_group(ParamGroup::from_path(&net, "*.bias").with_optimizer(no_decay()))
+            .with_group(ParamGroup::from_path(&net, "norm").with_optimizer(no_decay()));
+        let mut optim_no_decay = no_decay();
+        let mut optim_decay = AdamWConfig::new().with_weight_decay(0.5).init();
+
+        let expected_no_decay =
+            optim_no_decay.step(LEARNING_RATE, net.clone(), net_gradients(&net));
+        let expected_decay = optim_decay.step(LEARNING_RATE, net.clone(), net_gradients(&net));
+        let grads = net_gradients(&net);
+        let net = optim.step(LEARNING_RATE, net, grads);
+
+        assert!(!optim.groups()[0].contains(&net.linear.weight.id));
+        net.linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected_decay.linear.weight.val().into_data(), 5);
+        net.linear
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected_no_decay.linear.bias.unwrap().val().into_data(), 5);
+        net.norm
+            .gamma
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected_no_decay.norm.gamma.val().into_data(), 5);
+        net.norm
+            .beta
+            .val()
+            .into_data()
+            .assert_approx_eq(&expected_no_decay.norm.beta.val().into_data(), 5);
+    }
+
+    fn net_gradients(net: &Net<TestAutodiffBackend>) -> GradientsParams {
+        let x = Tensor::ones([2, 4], &Default::default());
+        // Only keep the first feature, since the features of a layer norm output sum to 0.
+        let grads = net
+            .norm
+            .forward(net.linear.forward(x))
+            .slice([0..2, 0..1])
+            .backward();
+        GradientsParams::from_grads(grads, net)
+    }
+
+    fn layer() -> Linear<TestAutodiffBackend> {
+        LinearConfig::new(4, 4).init(&Default::default())
+    }
+
+    fn gradients(layer: &Linear<TestAutodiffBackend>) -> GradientsParams {
+        let x = Tensor::ones([2, 4], &Default::default());
+        let grads = layer.forward(x).backward();
+        GradientsParams::from_grads(grads, layer)
+    }
+}
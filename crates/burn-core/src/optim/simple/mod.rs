@@ -1,5 +1,8 @@
 mod base;
+mod group;
+
 pub use base::*;
+pub use group::*;
 
 /// Adaptor module for optimizers.
 pub mod adaptor;
@@ -1,10 +1,140 @@
 use super::{AdaptorRecordItemV1, AdaptorRecordV1};
 use crate::{
-    optim::SimpleOptimizer,
+    module::ParamId,
+    optim::{ParamGroupRecord, ParamGroupRecordItem, SimpleOptimizer},
     record::{PrecisionSettings, Record},
 };
+use alloc::{string::String, vec::Vec};
 use burn_tensor::backend::AutodiffBackend;
-use serde::{Deserialize, Serialize};
+use core::marker::PhantomData;
+use hashbrown::HashMap;
+use serde::{
+    Deserialize, Deserializer, Serialize,
+    de::{self, MapAccess, SeqAccess, Visitor},
+};
+
+/// [Optimizer adaptor](crate::optim::simple::adaptor::OptimizerAdaptor) record, with the state of
+/// every parameter and the [parameter groups](crate::optim::ParamGroup).
+pub struct OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    /// The state of every parameter.
+    pub states: HashMap<ParamId, AdaptorRecord<O, B>>,
+    /// The parameter groups, in the order they were added to the optimizer.
+    pub groups: Vec<ParamGroupRecord>,
+}
+
+/// [Optimizer adaptor](crate::optim::simple::adaptor::OptimizerAdaptor) record item.
+///
+/// Records saved before the parameter groups were introduced only hold the map of the states.
+/// They can still be loaded with self-describing formats, such as named MessagePack or JSON, and
+/// then have no groups.
+#[derive(Serialize)]
+#[serde(bound = "")]
+pub struct OptimizerAdaptorRecordItem<
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+    S: PrecisionSettings,
+> {
+    states: HashMap<String, AdaptorRecordItem<O, B, S>>,
+    groups: Vec<ParamGroupRecordItem>,
+}
+
+impl<'de, O, B, S> Deserialize<'de> for OptimizerAdaptorRecordItem<O, B, S>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+    S: PrecisionSettings,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct(
+            "OptimizerAdaptorRecordItem",
+            &["states", "groups"],
+            OptimizerAdaptorRecordItemVisitor(PhantomData),
+        )
+    }
+}
+
+struct OptimizerAdaptorRecordItemVisitor<O, B, S>(PhantomData<(O, B, S)>);
+
+impl<'de, O, B, S> Visitor<'de> for OptimizerAdaptorRecordItemVisitor<O, B, S>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+    S: PrecisionSettings,
+{
+    type Value = OptimizerAdaptorRecordItem<O, B, S>;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("an optimizer adaptor record")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let states = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let groups = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        Ok(OptimizerAdaptorRecordItem { states, groups })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut states = HashMap::new();
+        let mut groups = Vec::new();
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "states" => states.extend(map.next_value::<HashMap<_, _>>()?),
+                "groups" => groups = map.next_value()?,
+                // The legacy records map the parameter ids to their state.
+                _ => {
+                    states.insert(key, map.next_value()?);
+                }
+            }
+        }
+
+        Ok(OptimizerAdaptorRecordItem { states, groups })
+    }
+}
+
+impl<O, B> Record<B> for OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    type Item<S: PrecisionSettings> = OptimizerAdaptorRecordItem<O, B, S>;
+
+    fn into_item<S: PrecisionSettings>(self) -> Self::Item<S> {
+        OptimizerAdaptorRecordItem {
+            states: Record::<B>::into_item::<S>(self.states),
+            groups: Record::<B>::into_item::<S>(self.groups),
+        }
+    }
+
+    fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        Self {
+            states: Record::<B>::from_item::<S>(item.states, device),
+            groups: Record::<B>::from_item::<S>(item.groups, device),
+        }
+    }
+}
+
+impl<O, B> Clone for OptimizerAdaptorRecord<O, B>
+where
+    O: SimpleOptimizer<B::InnerBackend>,
+    B: AutodiffBackend,
+{
+    fn clone(&self) -> Self {
+        Self {
+            states: self.states.clone(),
+            groups: self.groups.clone(),
+        }
+    }
+}
 
 /// [Optimizer adaptor](crate::optim::simple::adaptor::OptimizerAdaptor) record.
 ///
@@ -91,3 +221,74 @@ where
         Self::V1(AdaptorRecordV1::from_state(state))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TestAutodiffBackend,
+        nn::LinearConfig,
+        optim::{Adam, AdamConfig, GradientsParams, Optimizer, ParamGroup},
+        record::{BinBytesRecorder, FullPrecisionSettings, Recorder},
+        tensor::Tensor,
+    };
+
+    type TestRecord = OptimizerAdaptorRecord<Adam, TestAutodiffBackend>;
+    type TestItem = OptimizerAdaptorRecordItem<Adam, TestAutodiffBackend, FullPrecisionSettings>;
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn should_load_legacy_records_without_groups() {
+        use crate::{nn::Linear, record::NamedMpkBytesRecorder};
+
+        let device = Default::default();
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+        // Before the parameter groups were introduced, the record item was the map of the states.
+        let legacy_item = Record::<TestAutodiffBackend>::into_item::<FullPrecisionSettings>(
+            record_with_states().states,
+        );
+        let mut bytes =
+            Recorder::<TestAutodiffBackend>::save_item(&recorder, legacy_item, ()).unwrap();
+
+        let item: TestItem =
+            Recorder::<TestAutodiffBackend>::load_item(&recorder, &mut bytes).unwrap();
+        let record: TestRecord = Record::<TestAutodiffBackend>::from_item(item, &device);
+
+        assert_eq!(record.states.len(), 2);
+        assert!(record.groups.is_empty());
+
+        let optim = AdamConfig::new()
+            .init::<TestAutodiffBackend, Linear<TestAutodiffBackend>>()
+            .with_group(ParamGroup::from_ids([]).with_lr_multiplier(0.5))
+            .load_record(record);
+        assert_eq!(optim.groups()[0].lr_multiplier(), 0.5);
+        assert_eq!(optim.to_record().states.len(), 2);
+    }
+
+    #[test]
+    fn should_load_records_from_non_self_describing_formats() {
+        let device = Default::default();
+        let recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+
+        let bytes = recorder.record(record_with_states(), ()).unwrap();
+        let record: TestRecord = recorder.load(bytes, &device).unwrap();
+
+        assert_eq!(record.states.len(), 2);
+        assert_eq!(record.groups.len(), 1);
+        assert_eq!(record.groups[0].lr_multiplier, 0.5);
+    }
+
+    fn record_with_states() -> TestRecord {
+        let device = Default::default();
+        let layer = LinearConfig::new(4, 4).init::<TestAutodiffBackend>(&device);
+        let mut optim = AdamConfig::new()
+            .init()
+            .with_group(ParamGroup::from_ids([]).with_lr_multiplier(0.5));
+
+        let grads = layer.forward(Tensor::ones([2, 4], &device)).backward();
+        let grads = GradientsParams::from_grads(grads, &layer);
+        let _layer = optim.step(0.01, layer, grads);
+
+        optim.to_record()
+    }
+}
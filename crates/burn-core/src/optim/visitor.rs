@@ -32,6 +32,10 @@ where
                 return;
             }
         }
+        // Frozen parameters don't require gradients.
+        if !tensor.is_require_grad() {
+            return;
+        }
         let Some(grad) = tensor.grad_remove(self.grads) else {
             return;
         };
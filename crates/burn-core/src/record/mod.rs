@@ -16,6 +16,11 @@ mod file;
 #[cfg(feature = "std")]
 pub use file::*;
 
+#[cfg(feature = "std")]
+mod sharded;
+#[cfg(feature = "std")]
+pub use sharded::{MappedTensor, MappedTensorFile, ShardedFileRecorder};
+
 pub use primitive::ParamSerde;
 
 #[cfg(feature = "record-item-custom-serde")]
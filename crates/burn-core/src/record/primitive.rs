@@ -5,6 +5,16 @@ use super::tensor::{BoolTensorSerde, FloatTensorSerde, IntTensorSerde};
 use super::{PrecisionSettings, Record};
 use crate::module::{Param, ParamId};
 
+#[cfg(feature = "std")]
+use super::{
+    sharded::LazyTensorData,
+    tensor::{bool_from_data, float_from_data, int_from_data},
+};
+#[cfg(feature = "std")]
+use crate::module::Parameter;
+#[cfg(feature = "std")]
+use burn_tensor::TensorData;
+
 use burn_tensor::{Bool, Int, Tensor, backend::Backend};
 
 use hashbrown::HashMap;
@@ -160,8 +170,15 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
+        let id = ParamId::deserialize(&item.id);
+
+        #[cfg(feature = "std")]
+        if let Some(data) = item.param.data.lazy() {
+            return lazy_param(id, data, device, true, float_from_data::<B, D>);
+        }
+
         Param::initialized(
-            ParamId::deserialize(&item.id),
+            id,
             Tensor::from_item(item.param, device).require_grad(), // Same behavior as when we create a new
                                                                   // Param from a tensor.
         )
@@ -180,10 +197,14 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
-            ParamId::deserialize(&item.id),
-            Tensor::from_item(item.param, device),
-        )
+        let id = ParamId::deserialize(&item.id);
+
+        #[cfg(feature = "std")]
+        if let Some(data) = item.param.data.lazy() {
+            return lazy_param(id, data, device, false, int_from_data::<B, D>);
+        }
+
+        Param::initialized(id, Tensor::from_item(item.param, device))
     }
 }
 
@@ -199,13 +220,41 @@ where
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Param::initialized(
-            ParamId::deserialize(&item.id),
-            Tensor::from_item::<S>(item.param, device),
-        )
+        let id = ParamId::deserialize(&item.id);
+
+        #[cfg(feature = "std")]
+        if let Some(data) = item.param.data.lazy() {
+            return lazy_param(id, data, device, false, bool_from_data::<B, D>);
+        }
+
+        Param::initialized(id, Tensor::from_item::<S>(item.param, device))
     }
 }
 
+/// Creates a parameter whose value is read from its shard when it is first used.
+///
+/// Loading a [sharded record](super::ShardedFileRecorder) into a module thus moves its parameters
+/// to the device one at a time, without holding a copy of the whole record in memory.
+#[cfg(feature = "std")]
+fn lazy_param<B, T>(
+    id: ParamId,
+    data: LazyTensorData,
+    device: &B::Device,
+    is_require_grad: bool,
+    init: fn(TensorData, &B::Device) -> T,
+) -> Param<T>
+where
+    B: Backend,
+    T: Parameter<Device = B::Device> + 'static,
+{
+    Param::uninitialized(
+        id,
+        move |device, is_require_grad| init(data.load(), device).set_require_grad(is_require_grad),
+        device.clone(),
+        is_require_grad,
+    )
+}
+
 // Type that can be serialized as is without any conversion.
 macro_rules! primitive {
     ($type:ty) => {
@@ -92,6 +92,10 @@ impl SerializerTrait for Serializer {
         Ok(NestedValue::U64(v))
     }
 
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(NestedValue::Bool(v))
+    }
+
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
         Ok(NestedValue::F32(v))
     }
@@ -117,9 +121,6 @@ impl SerializerTrait for Serializer {
     fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
         unimplemented!()
     }
-    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
-    }
 
     fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
         unimplemented!()
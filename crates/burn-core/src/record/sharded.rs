@@ -0,0 +1,705 @@
+use super::{FileRecorder, PrecisionSettings, Recorder, RecorderError};
+use burn_tensor::{DType, TensorData, backend::Backend};
+use core::cell::RefCell;
+use core::marker::PhantomData;
+use core::ops::Range;
+use memmap2::Mmap;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread::LocalKey;
+
+/// The default maximum size of a shard, in bytes.
+const DEFAULT_MAX_SHARD_SIZE: usize = 2 << 30;
+
+/// File recorder splitting a record into shards of raw tensor data, described by an index file.
+///
+/// Recording `model` writes the index `model.index.json`, which holds the record metadata and
+/// structure, along with the shards `model-00000.bin`, `model-00001.bin`, and so on, each holding
+/// at most [max_shard_size](Self::with_max_shard_size) bytes, unless a single tensor is larger.
+///
+/// When loading, the shards are memory-mapped and no tensor is read up front. The parameters of
+/// the record are lazy: loading the record into a module with
+/// [load_record](crate::module::Module::load_record) reads every parameter from its shard and
+/// moves it to the device one at a time, so the peak memory usage stays close to the size of the
+/// largest tensor instead of the size of the whole record.
+///
+/// # Notes
+///
+/// The shards must not be modified while a record loaded from them is in use.
+#[derive(Debug, Clone)]
+pub struct ShardedFileRecorder<S: PrecisionSettings> {
+    max_shard_size: usize,
+    _settings: PhantomData<S>,
+}
+
+impl<S: PrecisionSettings> ShardedFileRecorder<S> {
+    /// Creates a new sharded file recorder.
+    pub fn new() -> Self {
+        Self {
+            max_shard_size: DEFAULT_MAX_SHARD_SIZE,
+            _settings: PhantomData,
+        }
+    }
+
+    /// Sets the maximum size of a shard, in bytes (2 GiB by default).
+    pub fn with_max_shard_size(mut self, max_shard_size: usize) -> Self {
+        self.max_shard_size = max_shard_size;
+        self
+    }
+}
+
+impl<S: PrecisionSettings> Default for ShardedFileRecorder<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: PrecisionSettings, B: Backend> FileRecorder<B> for ShardedFileRecorder<S> {
+    fn file_extension() -> &'static str {
+        "index.json"
+    }
+}
+
+/// The index of a sharded record.
+#[derive(Serialize, Deserialize)]
+struct ShardIndex<I> {
+    /// The file names of the shards, relative to the index.
+    shards: Vec<String>,
+    /// The record, where every tensor is replaced by a [reference](TensorReference) to its data.
+    record: I,
+}
+
+impl<S: PrecisionSettings, B: Backend> Recorder<B> for ShardedFileRecorder<S> {
+    type Settings = S;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        let (directory, stem) = shard_location(&file)?;
+        std::fs::create_dir_all(&directory)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        let writer = ShardWriter::new(directory.clone(), stem.clone(), self.max_shard_size);
+        let (record, writer) = with_scope(&WRITER, writer, || serde_json::to_value(&item));
+        let record = record.map_err(|err| RecorderError::Unknown(err.to_string()))?;
+        let shards = writer
+            .finish()
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        let writer = File::create(&file).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+            _ => RecorderError::Unknown(err.to_string()),
+        })?;
+        let num_shards = shards.len();
+        serde_json::to_writer(BufWriter::new(writer), &ShardIndex { shards, record })
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        remove_stale_shards(&directory, &stem, num_shards)
+            .map_err(|err| RecorderError::Unknown(err.to_string()))?;
+
+        Ok(())
+    }
+
+    fn load_item<I: DeserializeOwned>(
+        &self,
+        file: &mut Self::LoadArgs,
+    ) -> Result<I, RecorderError> {
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+        let (directory, stem) = shard_location(file)?;
+
+        let reader = File::open(&file).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => RecorderError::FileNotFound(err.to_string()),
+            _ => RecorderError::Unknown(err.to_string()),
+        })?;
+
+        let (index, _) = with_scope(&READER, ShardReader::new(directory, stem), || {
+            serde_json::from_reader::<_, ShardIndex<I>>(BufReader::new(reader))
+        });
+        let index = index.map_err(|err| RecorderError::DeserializeError(err.to_string()))?;
+
+        Ok(index.record)
+    }
+}
+
+/// Returns the directory of the shards and the prefix of their file names.
+fn shard_location(file: &Path) -> Result<(PathBuf, String), RecorderError> {
+    let name = file
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| RecorderError::Unknown(format!("Invalid record path {file:?}")))?;
+    let stem = name.strip_suffix(".index.json").unwrap_or(name).to_string();
+    let directory = file.parent().map(Path::to_path_buf).unwrap_or_default();
+
+    Ok((directory, stem))
+}
+
+fn shard_file_name(stem: &str, shard: usize) -> String {
+    format!("{stem}-{shard:05}.bin")
+}
+
+/// Removes the shards left after the last one by an earlier save of a larger record.
+fn remove_stale_shards(directory: &Path, stem: &str, num_shards: usize) -> std::io::Result<()> {
+    for shard in num_shards.. {
+        let path = directory.join(shard_file_name(stem, shard));
+        if !path.exists() {
+            break;
+        }
+        std::fs::remove_file(path)?;
+    }
+
+    Ok(())
+}
+
+/// The location of the data of a tensor in a shard, which replaces the data in the index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TensorReference {
+    shard: usize,
+    offset: usize,
+    len: usize,
+    shape: Vec<usize>,
+    pub(crate) dtype: DType,
+    /// Whether the last two dimensions of the stored data are swapped when it is read, `shape`
+    /// being the shape after the swap.
+    #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+    transposed: bool,
+}
+
+/// A memory-mapped file of raw tensor data, such as a Safetensors file, whose tensors are only
+/// read when they are used.
+///
+/// The readers of other formats serialize the [tensors](MappedTensor) of the file in place of
+/// their data, then deserialize the record with [deserialize](Self::deserialize). As with the
+/// [sharded file recorder](ShardedFileRecorder), the parameters of the record are lazy, so
+/// loading it into a module reads and moves the parameters to the device one at a time.
+///
+/// # Notes
+///
+/// The file must not be modified while a record deserialized from it is in use.
+#[derive(Clone)]
+pub struct MappedTensorFile {
+    mmap: Arc<Mmap>,
+}
+
+impl MappedTensorFile {
+    /// Memory-maps the file.
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: The file is expected not to be modified while it is mapped, as stated in the
+        // documentation.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        Ok(Self {
+            mmap: Arc::new(mmap),
+        })
+    }
+
+    /// The content of the file.
+    pub fn bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    /// The tensor stored as raw little-endian data in the `range` of bytes of the file.
+    pub fn tensor(&self, range: Range<usize>, shape: Vec<usize>, dtype: DType) -> MappedTensor {
+        MappedTensor {
+            reference: TensorReference {
+                shard: 0,
+                offset: range.start,
+                len: range.len(),
+                shape,
+                dtype,
+                transposed: false,
+            },
+        }
+    }
+
+    /// Deserializes a record whose tensors are [mapped tensors](MappedTensor) of this file.
+    ///
+    /// The tensors deserialized by `func` are lazy and only read from the file when they are
+    /// used.
+    pub fn deserialize<R>(&self, func: impl FnOnce() -> R) -> R {
+        let reader = ShardReader {
+            directory: PathBuf::new(),
+            stem: String::new(),
+            shards: vec![Some(self.mmap.clone())],
+        };
+        let (output, _) = with_scope(&READER, reader, func);
+
+        output
+    }
+}
+
+/// A tensor of a [mapped file](MappedTensorFile), which is serialized in place of its data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MappedTensor {
+    reference: TensorReference,
+}
+
+impl MappedTensor {
+    /// The shape of the tensor.
+    pub fn shape(&self) -> &[usize] {
+        &self.reference.shape
+    }
+
+    /// The data type of the tensor.
+    pub fn dtype(&self) -> DType {
+        self.reference.dtype
+    }
+
+    /// Swaps the last two dimensions of the tensor, which is only done when its data is read.
+    pub fn transpose(mut self) -> Self {
+        let shape = &mut self.reference.shape;
+        let rank = shape.len();
+        if rank >= 2 {
+            shape.swap(rank - 2, rank - 1);
+            self.reference.transposed = !self.reference.transposed;
+        }
+
+        self
+    }
+}
+
+/// Tensor data read on demand from a memory-mapped shard.
+#[derive(Clone)]
+pub(crate) struct LazyTensorData {
+    shard: Arc<Mmap>,
+    reference: TensorReference,
+    dtype: Option<DType>,
+}
+
+impl LazyTensorData {
+    /// Reads the data from the shard, converted to the element type of the record.
+    pub(crate) fn load(&self) -> TensorData {
+        let TensorReference {
+            offset,
+            len,
+            shape,
+            dtype,
+            transposed,
+            ..
+        } = &self.reference;
+        let bytes = &self.shard[*offset..*offset + *len];
+        let bytes = match transposed {
+            true => transpose_bytes(bytes, shape, dtype.size()),
+            false => bytes.to_vec(),
+        };
+        let data = TensorData::from_bytes(bytes, shape.clone(), *dtype);
+
+        match self.dtype {
+            Some(dtype) => data.convert_dtype(dtype),
+            None => data,
+        }
+    }
+}
+
+/// Swaps the last two dimensions of row-major data of elements of `size` bytes, `shape` being the
+/// shape after the swap.
+fn transpose_bytes(bytes: &[u8], shape: &[usize], size: usize) -> Vec<u8> {
+    let &[.., rows, cols] = shape else {
+        return bytes.to_vec();
+    };
+    let matrix = rows * cols * size;
+    let mut output = vec![0; bytes.len()];
+    if matrix == 0 {
+        return output;
+    }
+
+    for (input, output) in bytes.chunks(matrix).zip(output.chunks_mut(matrix)) {
+        for row in 0..rows {
+            for col in 0..cols {
+                let source = (col * rows + row) * size;
+                let target = (row * cols + col) * size;
+                output[target..target + size].copy_from_slice(&input[source..source + size]);
+            }
+        }
+    }
+
+    output
+}
+
+impl core::fmt::Debug for LazyTensorData {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LazyTensorData")
+            .field("shape", &self.reference.shape)
+            .field("dtype", &self.reference.dtype)
+            .finish()
+    }
+}
+
+/// Writes the tensors of a record to shards while it is serialized.
+struct ShardWriter {
+    directory: PathBuf,
+    stem: String,
+    max_shard_size: usize,
+    shards: Vec<String>,
+    current: Option<BufWriter<File>>,
+    size: usize,
+}
+
+impl ShardWriter {
+    fn new(directory: PathBuf, stem: String, max_shard_size: usize) -> Self {
+        Self {
+            directory,
+            stem,
+            max_shard_size,
+            shards: Vec::new(),
+            current: None,
+            size: 0,
+        }
+    }
+
+    fn write(&mut self, data: &TensorData) -> std::io::Result<TensorReference> {
+        let bytes = data.as_bytes();
+
+        if self.current.is_none()
+            || (self.size > 0 && self.size + bytes.len() > self.max_shard_size)
+        {
+            self.next_shard()?;
+        }
+
+        let writer = self.current.as_mut().expect("A shard should be open");
+        writer.write_all(bytes)?;
+
+        let reference = TensorReference {
+            shard: self.shards.len() - 1,
+            offset: self.size,
+            len: bytes.len(),
+            shape: data.shape.clone(),
+            dtype: data.dtype,
+            transposed: false,
+        };
+        self.size += bytes.len();
+
+        Ok(reference)
+    }
+
+    fn next_shard(&mut self) -> std::io::Result<()> {
+        if let Some(mut writer) = self.current.take() {
+            writer.flush()?;
+        }
+
+        let name = shard_file_name(&self.stem, self.shards.len());
+        let file = File::create(self.directory.join(&name))?;
+
+        self.current = Some(BufWriter::new(file));
+        self.shards.push(name);
+        self.size = 0;
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> std::io::Result<Vec<String>> {
+        if let Some(mut writer) = self.current.take() {
+            writer.flush()?;
+        }
+
+        Ok(self.shards)
+    }
+}
+
+/// Memory-maps the shards of a record while it is deserialized.
+struct ShardReader {
+    directory: PathBuf,
+    stem: String,
+    shards: Vec<Option<Arc<Mmap>>>,
+}
+
+impl ShardReader {
+    fn new(directory: PathBuf, stem: String) -> Self {
+        Self {
+            directory,
+            stem,
+            shards: Vec::new(),
+        }
+    }
+
+    fn shard(&mut self, index: usize) -> std::io::Result<Arc<Mmap>> {
+        if self.shards.len() <= index {
+            self.shards.resize(index + 1, None);
+        }
+
+        if let Some(shard) = &self.shards[index] {
+            return Ok(shard.clone());
+        }
+
+        let file = File::open(self.directory.join(shard_file_name(&self.stem, index)))?;
+        // SAFETY: The shards are expected not to be modified while they are mapped, as stated in
+        // the documentation of the recorder.
+        let shard = Arc::new(unsafe { Mmap::map(&file)? });
+        self.shards[index] = Some(shard.clone());
+
+        Ok(shard)
+    }
+}
+
+std::thread_local! {
+    static WRITER: RefCell<Option<ShardWriter>> = const { RefCell::new(None) };
+    static READER: RefCell<Option<ShardReader>> = const { RefCell::new(None) };
+}
+
+/// Makes the state available to the tensors (de)serialized by `func` on the current thread.
+fn with_scope<T: 'static, R>(
+    key: &'static LocalKey<RefCell<Option<T>>>,
+    state: T,
+    func: impl FnOnce() -> R,
+) -> (R, T) {
+    // Clears the state even when `func` panics.
+    struct Guard<T: 'static>(&'static LocalKey<RefCell<Option<T>>>);
+
+    impl<T: 'static> Drop for Guard<T> {
+        fn drop(&mut self) {
+            self.0.with(|cell| cell.borrow_mut().take());
+        }
+    }
+
+    key.with(|cell| *cell.borrow_mut() = Some(state));
+    let guard = Guard(key);
+    let output = func();
+    let state = key
+        .with(|cell| cell.borrow_mut().take())
+        .expect("The state should be set");
+    core::mem::drop(guard);
+
+    (output, state)
+}
+
+/// Writes the tensor to the current shard when a sharded record is being written.
+pub(crate) fn write_tensor(data: &TensorData) -> Option<Result<TensorReference, String>> {
+    WRITER.with(|cell| {
+        cell.borrow_mut()
+            .as_mut()
+            .map(|writer| writer.write(data).map_err(|err| err.to_string()))
+    })
+}
+
+/// Whether a sharded record is being read.
+pub(crate) fn is_reading() -> bool {
+    READER.with(|cell| cell.borrow().is_some())
+}
+
+/// Maps the shard holding the tensor, whose data is converted to `dtype` when it is read.
+pub(crate) fn read_tensor(
+    reference: TensorReference,
+    dtype: Option<DType>,
+) -> Result<LazyTensorData, String> {
+    READER.with(|cell| {
+        let mut reader = cell.borrow_mut();
+        let reader = reader
+            .as_mut()
+            .ok_or_else(|| "No sharded record is being read".to_string())?;
+        let shard = reader
+            .shard(reference.shard)
+            .map_err(|err| format!("Unable to read shard {}: {err}", reference.shard))?;
+
+        if reference.offset + reference.len > shard.len() {
+            return Err(format!(
+                "Tensor data out of the bounds of shard {}",
+                reference.shard
+            ));
+        }
+
+        Ok(LazyTensorData {
+            shard,
+            reference,
+            dtype,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TestBackend,
+        module::{Module, Param},
+        nn::{Linear, LinearConfig},
+        record::{
+            BinBytesRecorder, FullPrecisionSettings, HalfPrecisionSettings, ParamSerde, Record,
+            tensor::FloatTensorSerde,
+        },
+    };
+    use burn_tensor::{Int, Tensor};
+
+    use crate as burn;
+
+    fn file_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .as_path()
+            .join("burn_test_sharded_recorder")
+            .join(name)
+    }
+
+    #[derive(Module, Debug)]
+    pub struct Model<B: Backend> {
+        linear1: Linear<B>,
+        linear2: Linear<B>,
+        steps: Param<Tensor<B, 1, Int>>,
+    }
+
+    fn create_model(device: &<TestBackend as Backend>::Device) -> Model<TestBackend> {
+        Model {
+            linear1: LinearConfig::new(32, 32).init(device),
+            linear2: LinearConfig::new(32, 8).with_bias(false).init(device),
+            steps: Param::from_tensor(Tensor::from_ints([1, 2, 3], device)),
+        }
+    }
+
+    fn assert_same_records(model_before: Model<TestBackend>, model_after: Model<TestBackend>) {
+        let byte_recorder = BinBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes_before = byte_recorder
+            .record(model_before.into_record(), ())
+            .unwrap();
+        let bytes_after = byte_recorder.record(model_after.into_record(), ()).unwrap();
+
+        assert_eq!(bytes_after, bytes_before);
+    }
+
+    #[test]
+    fn test_can_save_and_load_sharded_format() {
+        let device = Default::default();
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new();
+        let file = file_path("single");
+        let model_before = create_model(&device);
+
+        Recorder::<TestBackend>::record(
+            &recorder,
+            model_before.clone().into_record(),
+            file.clone(),
+        )
+        .unwrap();
+        let model_after = create_model(&device)
+            .load_record(Recorder::<TestBackend>::load(&recorder, file, &device).unwrap());
+
+        assert_same_records(model_before, model_after);
+    }
+
+    #[test]
+    fn test_splits_record_into_shards() {
+        let device = Default::default();
+        // The weight of the first linear fills the first shard, the other tensors the second one.
+        let recorder =
+            ShardedFileRecorder::<FullPrecisionSettings>::new().with_max_shard_size(4096);
+        let file = file_path("multiple");
+        let model_before = create_model(&device);
+
+        Recorder::<TestBackend>::record(
+            &recorder,
+            model_before.clone().into_record(),
+            file.clone(),
+        )
+        .unwrap();
+
+        let index: ShardIndex<serde_json::Value> =
+            serde_json::from_reader(File::open(file.with_extension("index.json")).unwrap())
+                .unwrap();
+        assert_eq!(index.shards.len(), 2);
+        assert!(
+            index
+                .shards
+                .iter()
+                .all(|shard| file.with_file_name(shard).exists())
+        );
+
+        let model_after = create_model(&device)
+            .load_record(Recorder::<TestBackend>::load(&recorder, file, &device).unwrap());
+
+        assert_same_records(model_before, model_after);
+    }
+
+    #[test]
+    fn test_removes_stale_shards() {
+        let device = Default::default();
+        let file = file_path("stale");
+        let model = create_model(&device);
+
+        let recorder =
+            ShardedFileRecorder::<FullPrecisionSettings>::new().with_max_shard_size(4096);
+        Recorder::<TestBackend>::record(&recorder, model.clone().into_record(), file.clone())
+            .unwrap();
+        assert!(file.with_file_name(shard_file_name("stale", 1)).exists());
+
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new();
+        Recorder::<TestBackend>::record(&recorder, model.into_record(), file.clone()).unwrap();
+        assert!(file.with_file_name(shard_file_name("stale", 0)).exists());
+        assert!(!file.with_file_name(shard_file_name("stale", 1)).exists());
+    }
+
+    #[test]
+    fn test_loaded_params_are_lazy() {
+        let device = Default::default();
+        let recorder = ShardedFileRecorder::<FullPrecisionSettings>::new();
+        let file = file_path("lazy");
+        let model = create_model(&device);
+        let weight = model.linear1.weight.val();
+
+        Recorder::<TestBackend>::record(&recorder, model.into_record(), file.clone()).unwrap();
+        let record: ModelRecord<TestBackend> =
+            Recorder::<TestBackend>::load(&recorder, file, &device).unwrap();
+
+        assert!(!record.linear1.weight.is_initialized());
+        weight
+            .into_data()
+            .assert_eq(&record.linear1.weight.val().into_data(), true);
+    }
+
+    #[test]
+    fn test_mapped_tensors_are_lazy_and_transposed_when_read() {
+        let device = Default::default();
+        let path = file_path("mapped.bin");
+        let values = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let bytes: Vec<u8> = values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, bytes).unwrap();
+
+        let file = MappedTensorFile::open(&path).unwrap();
+        let tensor = file.tensor(0..24, vec![2, 3], DType::F32).transpose();
+        assert_eq!(tensor.shape(), [3, 2]);
+
+        let item = serde_json::json!({ "id": "weight", "param": tensor });
+        let item: ParamSerde<FloatTensorSerde<FullPrecisionSettings>> =
+            file.deserialize(|| serde_json::from_value(item)).unwrap();
+        let param = Param::<Tensor<TestBackend, 2>>::from_item(item, &device);
+
+        assert!(!param.is_initialized());
+        param.val().into_data().assert_eq(
+            &TensorData::from([[1.0f32, 4.0], [2.0, 5.0], [3.0, 6.0]]),
+            true,
+        );
+    }
+
+    #[test]
+    fn test_can_load_with_other_precision() {
+        let device = Default::default();
+        let file = file_path("half");
+        let model_before = create_model(&device);
+
+        let recorder = ShardedFileRecorder::<HalfPrecisionSettings>::new();
+        Recorder::<TestBackend>::record(
+            &recorder,
+            model_before.clone().into_record(),
+            file.clone(),
+        )
+        .unwrap();
+        let model_after = create_model(&device)
+            .load_record(Recorder::<TestBackend>::load(&recorder, file, &device).unwrap());
+
+        model_after
+            .linear1
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&model_before.linear1.weight.val().into_data(), 2);
+    }
+}
@@ -6,12 +6,87 @@ use serde::{Deserialize, Serialize};
 
 use alloc::format;
 
-/// Deserialize the value into [`TensorData`].
-fn deserialize_data<'de, E, De>(deserializer: De) -> Result<TensorData, De::Error>
+#[cfg(feature = "std")]
+use super::sharded::{self, LazyTensorData, TensorReference};
+
+/// The data of a tensor record, which is either in memory or read on demand from a shard of a
+/// [sharded record](super::ShardedFileRecorder).
+#[derive(Clone, Debug)]
+pub(crate) enum RecordData {
+    /// The data is in memory.
+    Loaded(TensorData),
+    /// The data is read from a memory-mapped shard when needed.
+    #[cfg(feature = "std")]
+    Lazy(LazyTensorData),
+}
+
+impl RecordData {
+    /// Returns the tensor data, reading it from its shard if needed.
+    pub(crate) fn load(self) -> TensorData {
+        match self {
+            RecordData::Loaded(data) => data,
+            #[cfg(feature = "std")]
+            RecordData::Lazy(data) => data.load(),
+        }
+    }
+
+    /// Returns the data read on demand, if it isn't loaded yet.
+    #[cfg(feature = "std")]
+    pub(crate) fn lazy(&self) -> Option<LazyTensorData> {
+        match self {
+            RecordData::Loaded(_) => None,
+            RecordData::Lazy(data) => Some(data.clone()),
+        }
+    }
+}
+
+impl From<TensorData> for RecordData {
+    fn from(data: TensorData) -> Self {
+        RecordData::Loaded(data)
+    }
+}
+
+/// Serialize the data, or a reference to it when a sharded record is being written.
+fn serialize_data<Se>(data: &RecordData, serializer: Se) -> Result<Se::Ok, Se::Error>
+where
+    Se: serde::Serializer,
+{
+    match data {
+        RecordData::Loaded(data) => {
+            #[cfg(feature = "std")]
+            if let Some(reference) = sharded::write_tensor(data) {
+                return reference
+                    .map_err(<Se::Error as serde::ser::Error>::custom)?
+                    .serialize(serializer);
+            }
+
+            data.serialize(serializer)
+        }
+        #[cfg(feature = "std")]
+        RecordData::Lazy(data) => serialize_data(&RecordData::Loaded(data.load()), serializer),
+    }
+}
+
+/// Deserialize the value into [`TensorData`], or into a reference to it when a sharded record is
+/// being read.
+fn deserialize_data<'de, E, De>(deserializer: De) -> Result<RecordData, De::Error>
 where
     E: Element + Deserialize<'de>,
     De: serde::Deserializer<'de>,
 {
+    #[cfg(feature = "std")]
+    if sharded::is_reading() {
+        let reference = TensorReference::deserialize(deserializer)?;
+        let dtype = match reference.dtype {
+            DType::QFloat(_) => None, // do not convert quantized tensors
+            _ => Some(E::dtype()),
+        };
+        let data = sharded::read_tensor(reference, dtype)
+            .map_err(<De::Error as serde::de::Error>::custom)?;
+
+        return Ok(RecordData::Lazy(data));
+    }
+
     let data = TensorData::deserialize(deserializer).map_err(|e| {
         serde::de::Error::custom(format!(
             "{:?}\nThe internal data format has changed since version 0.14.0. If you are trying to load a record saved in a previous version, use the `record-backward-compat` feature flag with a previous version (<=0.16.0). Once you have saved the record in the new format, you can upgrade back to the current version.\n",
@@ -23,29 +98,71 @@ where
     } else {
         data.convert::<E>()
     };
-    Ok(data)
+    Ok(data.into())
 }
 
 /// This struct implements serde to lazily serialize and deserialize a float tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct FloatTensorSerde<S: PrecisionSettings> {
-    data: TensorData,
+    pub(crate) data: RecordData,
     _e: PhantomData<S::FloatElem>,
 }
 
 /// This struct implements serde to lazily serialize and deserialize an int tensor
 /// using the given [record settings](RecordSettings).
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct IntTensorSerde<S: PrecisionSettings> {
-    data: TensorData,
+    pub(crate) data: RecordData,
     _e: PhantomData<S::IntElem>,
 }
 
 /// This struct implements serde to lazily serialize and deserialize an bool tensor.
-#[derive(new, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct BoolTensorSerde {
-    data: TensorData,
+    pub(crate) data: RecordData,
+}
+
+impl<S: PrecisionSettings> FloatTensorSerde<S> {
+    /// Creates the record of a tensor from its data.
+    pub fn new(data: TensorData) -> Self {
+        Self::from_record_data(data.into())
+    }
+
+    /// Creates the record of a tensor whose data may be read on demand.
+    pub(crate) fn from_record_data(data: RecordData) -> Self {
+        Self {
+            data,
+            _e: PhantomData,
+        }
+    }
+}
+
+impl<S: PrecisionSettings> IntTensorSerde<S> {
+    /// Creates the record of a tensor from its data.
+    pub fn new(data: TensorData) -> Self {
+        Self::from_record_data(data.into())
+    }
+
+    /// Creates the record of a tensor whose data may be read on demand.
+    pub(crate) fn from_record_data(data: RecordData) -> Self {
+        Self {
+            data,
+            _e: PhantomData,
+        }
+    }
+}
+
+impl BoolTensorSerde {
+    /// Creates the record of a tensor from its data.
+    pub fn new(data: TensorData) -> Self {
+        Self::from_record_data(data.into())
+    }
+
+    /// Creates the record of a tensor whose data may be read on demand.
+    pub(crate) fn from_record_data(data: RecordData) -> Self {
+        Self { data }
+    }
 }
 
 // --- SERDE IMPLEMENTATIONS --- //
@@ -55,7 +172,7 @@ impl<S: PrecisionSettings> Serialize for FloatTensorSerde<S> {
     where
         Se: serde::Serializer,
     {
-        self.data.serialize(serializer)
+        serialize_data(&self.data, serializer)
     }
 }
 
@@ -66,7 +183,7 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for FloatTensorSerde<S> {
     {
         let data = deserialize_data::<S::FloatElem, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self::from_record_data(data))
     }
 }
 
@@ -75,7 +192,7 @@ impl<S: PrecisionSettings> Serialize for IntTensorSerde<S> {
     where
         Se: serde::Serializer,
     {
-        self.data.serialize(serializer)
+        serialize_data(&self.data, serializer)
     }
 }
 
@@ -86,7 +203,7 @@ impl<'de, S: PrecisionSettings> Deserialize<'de> for IntTensorSerde<S> {
     {
         let data = deserialize_data::<S::IntElem, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self::from_record_data(data))
     }
 }
 
@@ -95,7 +212,7 @@ impl Serialize for BoolTensorSerde {
     where
         Se: serde::Serializer,
     {
-        self.data.serialize(serializer)
+        serialize_data(&self.data, serializer)
     }
 }
 
@@ -106,10 +223,41 @@ impl<'de> Deserialize<'de> for BoolTensorSerde {
     {
         let data = deserialize_data::<bool, De>(deserializer)?;
 
-        Ok(Self::new(data))
+        Ok(Self::from_record_data(data))
     }
 }
 
+// --- TENSOR CREATION --- //
+
+/// Creates a float tensor from the data of a record.
+pub(crate) fn float_from_data<B: Backend, const D: usize>(
+    data: TensorData,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let data = if let DType::QFloat(_) = data.dtype {
+        data // do not convert quantized tensors
+    } else {
+        data.convert::<B::FloatElem>()
+    };
+    Tensor::from_data(data, device)
+}
+
+/// Creates an int tensor from the data of a record.
+pub(crate) fn int_from_data<B: Backend, const D: usize>(
+    data: TensorData,
+    device: &B::Device,
+) -> Tensor<B, D, Int> {
+    Tensor::from_data(data.convert::<B::IntElem>(), device)
+}
+
+/// Creates a bool tensor from the data of a record.
+pub(crate) fn bool_from_data<B: Backend, const D: usize>(
+    data: TensorData,
+    device: &B::Device,
+) -> Tensor<B, D, Bool> {
+    Tensor::from_data(data, device)
+}
+
 // --- RECORD IMPLEMENTATIONS --- //
 
 impl<B: Backend, const D: usize> Record<B> for Tensor<B, D> {
@@ -126,12 +274,7 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        let data = if let DType::QFloat(_) = item.data.dtype {
-            item.data // do not convert quantized tensors
-        } else {
-            item.data.convert::<B::FloatElem>()
-        };
-        Tensor::from_data(data, device)
+        float_from_data(item.data.load(), device)
     }
 }
 
@@ -143,7 +286,7 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Int> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data.convert::<B::IntElem>(), device)
+        int_from_data(item.data.load(), device)
     }
 }
 
@@ -155,6 +298,6 @@ impl<B: Backend, const D: usize> Record<B> for Tensor<B, D, Bool> {
     }
 
     fn from_item<S: PrecisionSettings>(item: Self::Item<S>, device: &B::Device) -> Self {
-        Tensor::from_data(item.data, device)
+        bool_from_data(item.data.load(), device)
     }
 }
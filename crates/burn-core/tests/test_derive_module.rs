@@ -234,6 +234,33 @@ mod num_params {
     }
 }
 
+mod named_params {
+    use super::*;
+    use burn::module::list_named_params;
+
+    #[test]
+    fn should_name_params_with_field_and_variant_paths() {
+        let device = <TestBackend as Backend>::Device::default();
+        let module = ModuleEnum::Composed(ModuleComposed::<TestBackend>::new(&device));
+
+        let params = list_named_params(&module);
+
+        let paths = params
+            .into_iter()
+            .map(|param| param.path)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "Composed.weight",
+                "Composed.basic.weight_basic",
+                "Composed.tuple.0.weight_basic",
+                "Composed.tuple.1.weight_basic",
+            ]
+        );
+    }
+}
+
 #[cfg(feature = "std")]
 mod require_grad {
     use burn_tensor::backend::AutodiffBackend;
@@ -277,6 +304,25 @@ mod require_grad {
         assert!(grad_x.is_some());
     }
 
+    #[test]
+    fn should_freeze_and_unfreeze_params_matching_pattern() {
+        let device = <TestAutodiffBackend as Backend>::Device::default();
+        let module = ModuleComposed::<TestAutodiffBackend>::new(&device);
+        assert_eq!(module.num_trainable_params(), module.num_params());
+
+        let module = module.freeze("*").unfreeze("tuple.1");
+
+        assert_eq!(module.num_trainable_params(), 20 * 20);
+        assert!(!module.weight.is_require_grad());
+        assert!(!module.tuple.0.weight_basic.is_require_grad());
+        assert!(module.tuple.1.weight_basic.is_require_grad());
+
+        let module = module.unfreeze("*.weight_basic");
+
+        assert_eq!(module.num_trainable_params(), 3 * 20 * 20);
+        assert!(!module.weight.is_require_grad());
+    }
+
     fn calculate_grads(
         module: &ModuleBasic<TestAutodiffBackend>,
     ) -> <TestAutodiffBackend as AutodiffBackend>::Gradients {
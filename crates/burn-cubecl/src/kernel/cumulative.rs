@@ -0,0 +1,198 @@
+use crate::{
+    CubeRuntime, element::CubeElement, kernel::into_contiguous, ops::numeric::empty_device,
+    tensor::CubeTensor,
+};
+use cubecl::{calculate_cube_count_elemwise, prelude::*};
+
+/// The offset of the first element of the line scanned by the unit along `dim`, in a contiguous
+/// tensor.
+#[cube]
+fn line_offset<E: CubePrimitive>(input: &Tensor<E>, dim: u32) -> u32 {
+    let stride = input.stride(dim);
+
+    ABSOLUTE_POS / stride * stride * input.shape(dim) + ABSOLUTE_POS % stride
+}
+
+#[cube(launch_unchecked)]
+fn cumsum_kernel<E: Numeric>(input: &Tensor<E>, output: &mut Tensor<E>, dim: u32, num_lines: u32) {
+    if ABSOLUTE_POS >= num_lines {
+        terminate!();
+    }
+
+    let offset = line_offset(input, dim);
+    let stride = input.stride(dim);
+    let mut acc = E::from_int(0);
+
+    for i in 0..input.shape(dim) {
+        let index = offset + i * stride;
+        acc += input[index];
+        output[index] = acc;
+    }
+}
+
+#[cube(launch_unchecked)]
+fn cumprod_kernel<E: Numeric>(input: &Tensor<E>, output: &mut Tensor<E>, dim: u32, num_lines: u32) {
+    if ABSOLUTE_POS >= num_lines {
+        terminate!();
+    }
+
+    let offset = line_offset(input, dim);
+    let stride = input.stride(dim);
+    let mut acc = E::from_int(1);
+
+    for i in 0..input.shape(dim) {
+        let index = offset + i * stride;
+        acc = acc * input[index];
+        output[index] = acc;
+    }
+}
+
+#[cube(launch_unchecked)]
+fn cum_arg_kernel<E: Numeric, I: Numeric>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    indices: &mut Tensor<I>,
+    dim: u32,
+    num_lines: u32,
+    #[comptime] max: bool,
+) {
+    if ABSOLUTE_POS >= num_lines {
+        terminate!();
+    }
+
+    let offset = line_offset(input, dim);
+    let stride = input.stride(dim);
+    let mut acc = input[offset];
+    let mut acc_index = 0u32;
+
+    for i in 0..input.shape(dim) {
+        let index = offset + i * stride;
+        let value = input[index];
+
+        // Ties select the latest index.
+        let mut replace = value <= acc;
+        if max {
+            replace = value >= acc;
+        }
+
+        if replace {
+            acc = value;
+            acc_index = i;
+        }
+
+        output[index] = acc;
+        indices[index] = I::cast_from(acc_index);
+    }
+}
+
+/// The contiguous input, the output and the launch settings of a scan along `dim`, with a unit
+/// per line of the dimension.
+fn prepare<R: CubeRuntime, E: CubeElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> (CubeTensor<R>, CubeTensor<R>, CubeCount, CubeDim, u32) {
+    let tensor = into_contiguous(tensor);
+    let output = empty_device::<R, E>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    let num_lines = match tensor.shape.dims[dim] {
+        0 => 0,
+        size => tensor.shape.num_elements() / size,
+    };
+    let cube_dim = CubeDim::default();
+    let cube_count = calculate_cube_count_elemwise(num_lines.max(1), cube_dim);
+
+    (tensor, output, cube_count, cube_dim, num_lines as u32)
+}
+
+/// Computes the cumulative sum of the elements of the tensor along `dim`.
+pub(crate) fn cumsum<R: CubeRuntime, E: CubeElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    let (tensor, output, cube_count, cube_dim, num_lines) = prepare::<R, E>(tensor, dim);
+
+    unsafe {
+        cumsum_kernel::launch_unchecked::<E, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(num_lines),
+        )
+    };
+
+    output
+}
+
+/// Computes the cumulative product of the elements of the tensor along `dim`.
+pub(crate) fn cumprod<R: CubeRuntime, E: CubeElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> CubeTensor<R> {
+    let (tensor, output, cube_count, cube_dim, num_lines) = prepare::<R, E>(tensor, dim);
+
+    unsafe {
+        cumprod_kernel::launch_unchecked::<E, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(num_lines),
+        )
+    };
+
+    output
+}
+
+/// Computes the cumulative maximum of the elements of the tensor along `dim` with their indices.
+pub(crate) fn cummax_with_indices<R: CubeRuntime, E: CubeElement, I: CubeElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    cum_arg::<R, E, I>(tensor, dim, true)
+}
+
+/// Computes the cumulative minimum of the elements of the tensor along `dim` with their indices.
+pub(crate) fn cummin_with_indices<R: CubeRuntime, E: CubeElement, I: CubeElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    cum_arg::<R, E, I>(tensor, dim, false)
+}
+
+fn cum_arg<R: CubeRuntime, E: CubeElement, I: CubeElement>(
+    tensor: CubeTensor<R>,
+    dim: usize,
+    max: bool,
+) -> (CubeTensor<R>, CubeTensor<R>) {
+    let (tensor, output, cube_count, cube_dim, num_lines) = prepare::<R, E>(tensor, dim);
+    let indices = empty_device::<R, I>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    unsafe {
+        cum_arg_kernel::launch_unchecked::<E, I, R>(
+            &tensor.client,
+            cube_count,
+            cube_dim,
+            tensor.as_tensor_arg::<E>(1),
+            output.as_tensor_arg::<E>(1),
+            indices.as_tensor_arg::<I>(1),
+            ScalarArg::new(dim as u32),
+            ScalarArg::new(num_lines),
+            max,
+        )
+    };
+
+    (output, indices)
+}
@@ -3,7 +3,8 @@ use cubecl::prelude::*;
 use crate::{CubeElement, CubeRuntime, SEED, ops::numeric::empty_device, tensor::CubeTensor};
 use burn_common::rand::get_seeded_rng;
 use burn_tensor::Shape;
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
 
 pub(crate) const N_VALUES_PER_THREAD: usize = 128;
 
@@ -50,7 +51,7 @@ pub(crate) fn get_seeds() -> [u32; 4] {
     let mut seed = SEED.lock().unwrap();
     let mut rng = match seed.as_ref() {
         Some(rng_seeded) => rng_seeded.clone(),
-        None => get_seeded_rng(),
+        None => ChaCha12Rng::from_rng(&mut get_seeded_rng()),
     };
     let mut seeds: Vec<u32> = Vec::with_capacity(4);
     for _ in 0..4 {
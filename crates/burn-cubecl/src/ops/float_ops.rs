@@ -538,6 +538,36 @@ where
         )
     }
 
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_dtype!(float(tensor.dtype), E, kernel::cumsum::<R, E>(tensor, dim))
+    }
+
+    fn float_cumprod(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_dtype!(float(tensor.dtype), E, kernel::cumprod::<R, E>(tensor, dim))
+    }
+
+    fn float_cummax_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::cummax_with_indices::<R, E, I>(tensor, dim)
+        )
+    }
+
+    fn float_cummin_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        execute_with_dtype!(
+            float(tensor.dtype),
+            E,
+            kernel::cummin_with_indices::<R, E, I>(tensor, dim)
+        )
+    }
+
     fn float_cast(tensor: FloatTensor<Self>, dtype: FloatDType) -> FloatTensor<Self> {
         match (tensor.dtype, dtype) {
             (DType::F64, FloatDType::F64)
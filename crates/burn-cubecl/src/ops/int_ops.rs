@@ -310,6 +310,28 @@ where
         kernel::flip::<R, I, BT>(tensor, axes)
     }
 
+    fn int_cumsum(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
+        kernel::cumsum::<R, I>(tensor, dim)
+    }
+
+    fn int_cumprod(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
+        kernel::cumprod::<R, I>(tensor, dim)
+    }
+
+    fn int_cummax_with_indices(
+        tensor: IntTensor<Self>,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        kernel::cummax_with_indices::<R, I, I>(tensor, dim)
+    }
+
+    fn int_cummin_with_indices(
+        tensor: IntTensor<Self>,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        kernel::cummin_with_indices::<R, I, I>(tensor, dim)
+    }
+
     fn bitwise_and(lhs: IntTensor<Self>, rhs: IntTensor<Self>) -> IntTensor<Self> {
         numeric::bitwise_and::<R, I>(lhs, rhs)
     }
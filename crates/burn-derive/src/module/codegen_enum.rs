@@ -5,6 +5,7 @@ use quote::quote;
 use syn::Visibility;
 
 pub(crate) struct EnumModuleCodegen {
+    pub name: Ident,
     pub variants: Vec<EnumVariant>,
     pub vis: Visibility,
 }
@@ -27,9 +28,15 @@ impl ModuleCodegen for EnumModuleCodegen {
     }
 
     fn gen_visit(&self) -> TokenStream {
-        let match_body = self.gen_variants_match_fn(|_| {
+        let container_type = self.container_type();
+        let match_body = self.gen_variants_match_fn(|variant| {
+            let variant_name = variant.to_string();
             quote! {
-                burn::module::Module::visit(module, visitor)
+                {
+                    visitor.enter_module(#variant_name, #container_type);
+                    burn::module::Module::visit(module, visitor);
+                    visitor.exit_module(#variant_name, #container_type);
+                }
             }
         });
 
@@ -86,9 +93,16 @@ impl ModuleCodegen for EnumModuleCodegen {
     }
 
     fn gen_map(&self) -> TokenStream {
+        let container_type = self.container_type();
         let match_body = self.gen_variants_match_fn(|variant| {
+            let variant_name = variant.to_string();
             quote! {
-                Self::#variant(burn::module::Module::<B>::map(module, mapper))
+                {
+                    mapper.enter_module(#variant_name, #container_type);
+                    let module = burn::module::Module::<B>::map(module, mapper);
+                    mapper.exit_module(#variant_name, #container_type);
+                    Self::#variant(module)
+                }
             }
         });
 
@@ -166,11 +180,17 @@ impl ModuleCodegen for EnumModuleCodegen {
 impl EnumModuleCodegen {
     pub fn from_ast(ast: &syn::DeriveInput) -> Self {
         Self {
+            name: ast.ident.clone(),
             variants: parse_variants(ast),
             vis: ast.vis.clone(),
         }
     }
 
+    /// The container type given to the visitors and mappers when entering a variant.
+    fn container_type(&self) -> String {
+        format!("Enum:{}", self.name)
+    }
+
     /// Generate the enum variants' match arm with the provided function
     fn gen_variants_match_fn<F>(&self, func: F) -> TokenStream
     where
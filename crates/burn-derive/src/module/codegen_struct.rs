@@ -5,6 +5,7 @@ use quote::quote;
 use syn::Visibility;
 
 pub(crate) struct StructModuleCodegen {
+    pub name: Ident,
     pub fields: Vec<FieldTypeAnalyzer>,
     pub vis: Visibility,
 }
@@ -29,9 +30,13 @@ impl ModuleCodegen for StructModuleCodegen {
     }
 
     fn gen_visit(&self) -> TokenStream {
+        let container_type = self.container_type();
         let body = self.gen_fields_fn(|name| {
+            let field_name = name.to_string();
             quote! {
+                visitor.enter_module(#field_name, #container_type);
                 burn::module::Module::visit(&self.#name, visitor);
+                visitor.exit_module(#field_name, #container_type);
             }
         });
 
@@ -98,9 +103,13 @@ impl ModuleCodegen for StructModuleCodegen {
     }
 
     fn gen_map(&self) -> TokenStream {
+        let container_type = self.container_type();
         let (names, body) = self.gen_fields_fn_names(|name| {
+            let field_name = name.to_string();
             quote! {
+                mapper.enter_module(#field_name, #container_type);
                 let #name = burn::module::Module::<B>::map(self.#name, mapper);
+                mapper.exit_module(#field_name, #container_type);
             }
         });
 
@@ -191,6 +200,7 @@ impl ModuleCodegen for StructModuleCodegen {
 impl StructModuleCodegen {
     pub fn from_ast(ast: &syn::DeriveInput) -> Self {
         Self {
+            name: ast.ident.clone(),
             fields: parse_fields(ast)
                 .into_iter()
                 .map(FieldTypeAnalyzer::new)
@@ -199,6 +209,11 @@ impl StructModuleCodegen {
         }
     }
 
+    /// The container type given to the visitors and mappers when entering a field.
+    fn container_type(&self) -> String {
+        format!("Struct:{}", self.name)
+    }
+
     fn gen_fields_fn_names<F>(&self, func: F) -> (Vec<Ident>, TokenStream)
     where
         F: Fn(Ident) -> TokenStream,
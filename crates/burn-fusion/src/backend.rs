@@ -47,6 +47,14 @@ impl<B: FusionBackend> Backend for Fusion<B> {
         B::seed(seed);
     }
 
+    fn rng_state() -> Option<Vec<u8>> {
+        B::rng_state()
+    }
+
+    fn set_rng_state(state: &[u8]) {
+        B::set_rng_state(state);
+    }
+
     fn sync(device: &Self::Device) {
         let client = CLIENTS.client::<B::FusionRuntime>(&device.clone());
         client.drain();
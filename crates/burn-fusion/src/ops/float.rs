@@ -2146,4 +2146,134 @@ impl<B: FusionBackend> FloatTensorOps<Self> for Fusion<B> {
 
         out
     }
+
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce_float_ops!(CumSumOps, B::float_cumsum);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumSum(desc.clone())),
+            CumSumOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_cumprod(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        reduce_float_ops!(CumProdOps, B::float_cumprod);
+
+        let stream = tensor.stream;
+        let dtype = tensor.dtype;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumProd(desc.clone())),
+            CumProdOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn float_cummax_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        #[derive(new)]
+        struct CumMaxWithIndicesOps<B: FusionBackend> {
+            desc: ReduceDimWithIndicesOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for CumMaxWithIndicesOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B>(&self.desc.tensor);
+                let (output, indices) = B::float_cummax_with_indices(tensor, self.desc.dim);
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+                handles.register_int_tensor::<B>(&self.desc.out_indices.id, indices);
+            }
+        }
+
+        let stream = tensor.stream;
+        let shape = tensor.shape.clone();
+        let dtype = tensor.dtype;
+        let client = tensor.client.clone();
+        let out = client.tensor_uninitialized(shape.clone(), dtype);
+        let out_indices = client.tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ReduceDimWithIndicesOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumMaxWithIndices(desc.clone())),
+            CumMaxWithIndicesOps::<B>::new(desc),
+        );
+
+        (out, out_indices)
+    }
+
+    fn float_cummin_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        #[derive(new)]
+        struct CumMinWithIndicesOps<B: FusionBackend> {
+            desc: ReduceDimWithIndicesOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for CumMinWithIndicesOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_float_tensor::<B>(&self.desc.tensor);
+                let (output, indices) = B::float_cummin_with_indices(tensor, self.desc.dim);
+
+                handles.register_float_tensor::<B>(&self.desc.out.id, output);
+                handles.register_int_tensor::<B>(&self.desc.out_indices.id, indices);
+            }
+        }
+
+        let stream = tensor.stream;
+        let shape = tensor.shape.clone();
+        let dtype = tensor.dtype;
+        let client = tensor.client.clone();
+        let out = client.tensor_uninitialized(shape.clone(), dtype);
+        let out_indices = client.tensor_uninitialized(shape, B::IntElem::dtype());
+
+        let desc = ReduceDimWithIndicesOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationIr::NumericFloat(dtype, NumericOperationIr::CumMinWithIndices(desc.clone())),
+            CumMinWithIndicesOps::<B>::new(desc),
+        );
+
+        (out, out_indices)
+    }
 }
@@ -2099,4 +2099,140 @@ impl<B: FusionBackend> IntTensorOps<Self> for Fusion<B> {
 
         out
     }
+
+    fn int_cumsum(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
+        reduce_int_ops!(CumSumOps, B::int_cumsum);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), B::IntElem::dtype());
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericInt(
+                IntElem::<Self>::dtype(),
+                NumericOperationIr::CumSum(desc.clone()),
+            ),
+            CumSumOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_cumprod(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
+        reduce_int_ops!(CumProdOps, B::int_cumprod);
+
+        let stream = tensor.stream;
+        let out = tensor
+            .client
+            .tensor_uninitialized(tensor.shape.clone(), B::IntElem::dtype());
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+        out.client.register(
+            vec![stream],
+            OperationIr::NumericInt(
+                IntElem::<Self>::dtype(),
+                NumericOperationIr::CumProd(desc.clone()),
+            ),
+            CumProdOps::<B>::new(desc),
+        );
+
+        out
+    }
+
+    fn int_cummax_with_indices(
+        tensor: IntTensor<Self>,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        #[derive(new)]
+        struct CumMaxWithIndicesOps<B: FusionBackend> {
+            desc: ReduceDimWithIndicesOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for CumMaxWithIndicesOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_int_tensor::<B>(&self.desc.tensor);
+                let (output, indices) = B::int_cummax_with_indices(tensor, self.desc.dim);
+
+                handles.register_int_tensor::<B>(&self.desc.out.id, output);
+                handles.register_int_tensor::<B>(&self.desc.out_indices.id, indices);
+            }
+        }
+
+        let stream = tensor.stream;
+        let shape = tensor.shape.clone();
+        let client = tensor.client.clone();
+        let out = client.tensor_uninitialized(shape.clone(), B::IntElem::dtype());
+        let out_indices = client.tensor_uninitialized(shape, B::IntElem::dtype());
+        let desc = ReduceDimWithIndicesOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationIr::NumericInt(
+                IntElem::<Self>::dtype(),
+                NumericOperationIr::CumMaxWithIndices(desc.clone()),
+            ),
+            CumMaxWithIndicesOps::<B>::new(desc),
+        );
+
+        (out, out_indices)
+    }
+
+    fn int_cummin_with_indices(
+        tensor: IntTensor<Self>,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        #[derive(new)]
+        struct CumMinWithIndicesOps<B: FusionBackend> {
+            desc: ReduceDimWithIndicesOpIr,
+            _b: PhantomData<B>,
+        }
+
+        impl<B: FusionBackend> Operation<B::FusionRuntime> for CumMinWithIndicesOps<B> {
+            fn execute(self: Box<Self>, handles: &mut HandleContainer<B::Handle>) {
+                let tensor = handles.get_int_tensor::<B>(&self.desc.tensor);
+                let (output, indices) = B::int_cummin_with_indices(tensor, self.desc.dim);
+
+                handles.register_int_tensor::<B>(&self.desc.out.id, output);
+                handles.register_int_tensor::<B>(&self.desc.out_indices.id, indices);
+            }
+        }
+
+        let stream = tensor.stream;
+        let shape = tensor.shape.clone();
+        let client = tensor.client.clone();
+        let out = client.tensor_uninitialized(shape.clone(), B::IntElem::dtype());
+        let out_indices = client.tensor_uninitialized(shape, B::IntElem::dtype());
+        let desc = ReduceDimWithIndicesOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+        client.register(
+            vec![stream],
+            OperationIr::NumericInt(
+                IntElem::<Self>::dtype(),
+                NumericOperationIr::CumMinWithIndices(desc.clone()),
+            ),
+            CumMinWithIndicesOps::<B>::new(desc),
+        );
+
+        (out, out_indices)
+    }
 }
@@ -988,6 +988,32 @@ impl<E: Element> RelativeOpsScalar<E> for NumericOperationIr<E> {
                 rhs: desc.rhs.to_relative(converter),
                 out: desc.out.to_relative(converter),
             }),
+            NumericOperationIr::CumSum(desc) => NumericOperationIr::CumSum(ReduceDimOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+                axis: desc.axis, // Axis should stay the same.
+            }),
+            NumericOperationIr::CumProd(desc) => NumericOperationIr::CumProd(ReduceDimOpIr {
+                input: desc.input.to_relative(converter),
+                out: desc.out.to_relative(converter),
+                axis: desc.axis, // Axis should stay the same.
+            }),
+            NumericOperationIr::CumMaxWithIndices(desc) => {
+                NumericOperationIr::CumMaxWithIndices(ReduceDimWithIndicesOpIr {
+                    tensor: desc.tensor.to_relative(converter),
+                    dim: desc.dim,
+                    out: desc.out.to_relative(converter),
+                    out_indices: desc.out_indices.to_relative(converter),
+                })
+            }
+            NumericOperationIr::CumMinWithIndices(desc) => {
+                NumericOperationIr::CumMinWithIndices(ReduceDimWithIndicesOpIr {
+                    tensor: desc.tensor.to_relative(converter),
+                    dim: desc.dim,
+                    out: desc.out.to_relative(converter),
+                    out_indices: desc.out_indices.to_relative(converter),
+                })
+            }
         }
     }
 }
@@ -0,0 +1,53 @@
+#!/usr/bin/env python3
+
+import torch
+import torch.nn as nn
+import torch.nn.functional as F
+from safetensors.torch import save_file
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+        self.fc1 = nn.Linear(2, 3)
+        self.fc2 = nn.Linear(3, 4, bias=False)
+
+    def forward(self, x):
+        x = self.fc1(x)
+        x = F.relu(x)  # Add relu so that PyTorch optimizer does not combine fc1 and fc2
+        x = self.fc2(x)
+
+        return x
+
+
+def main():
+
+    torch.set_printoptions(precision=8)
+
+    model = Model().to(torch.device("cpu"))
+
+    # Use explicit weights so that the expected outputs are easy to check.
+    with torch.no_grad():
+        model.fc1.weight.copy_(torch.tensor([[0.1, 0.2], [-0.3, 0.4], [0.5, -0.6]]))
+        model.fc1.bias.copy_(torch.tensor([0.01, 0.02, 0.03]))
+        model.fc2.weight.copy_(
+            torch.tensor(
+                [[0.1, -0.2, 0.3], [0.4, 0.5, -0.6], [-0.7, 0.8, 0.9], [1.0, -1.1, 1.2]]
+            )
+        )
+
+    # Prefix the keys, as in many checkpoints of the Hugging Face Hub.
+    state_dict = {f"model.{key}": value for key, value in model.state_dict().items()}
+    save_file(state_dict, "linear.safetensors")
+
+    input = torch.tensor([[[[1.0, 2.0], [-1.0, 0.5]]]])
+    print("Input shape: {}", input.shape)
+    print("Input: {}", input)
+
+    output = model(input)
+    print("Output: {}", output)
+    print("Output Shape: {}", output.shape)
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,120 @@
+use burn::{
+    module::Module,
+    nn::{Linear, LinearConfig, Relu},
+    tensor::{Tensor, backend::Backend},
+};
+
+#[derive(Module, Debug)]
+pub struct Net<B: Backend> {
+    fc1: Linear<B>,
+    fc2: Linear<B>,
+    relu: Relu,
+}
+
+impl<B: Backend> Net<B> {
+    /// Create a new model.
+    pub fn init(device: &B::Device) -> Self {
+        let fc1 = LinearConfig::new(2, 3).init(device);
+        let fc2 = LinearConfig::new(3, 4).with_bias(false).init(device);
+        let relu = Relu;
+
+        Self { fc1, fc2, relu }
+    }
+
+    /// Forward pass of the model.
+    pub fn forward(&self, x: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.fc1.forward(x);
+        let x = self.relu.forward(x);
+
+        self.fc2.forward(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    use burn::record::{FullPrecisionSettings, HalfPrecisionSettings, Recorder};
+
+    use burn_import::safetensors::{LoadArgs, SafetensorsFileRecorder};
+
+    use super::*;
+
+    fn linear_test(record: NetRecord<Backend>, precision: usize) {
+        let device = Default::default();
+        let model = Net::<Backend>::init(&device).load_record(record);
+
+        let input = Tensor::<Backend, 4>::from_data([[[[1.0, 2.0], [-1.0, 0.5]]]], &device);
+
+        let output = model.forward(input);
+        let expected = Tensor::<Backend, 4>::from_data(
+            [[[
+                [-0.053, 0.464, 0.059, -0.062],
+                [-0.103, 0.264, 0.409, -0.562],
+            ]]],
+            &device,
+        );
+
+        output
+            .to_data()
+            .assert_approx_eq(&expected.to_data(), precision);
+    }
+
+    #[test]
+    fn linear_full_precision() {
+        let device = Default::default();
+        let args = LoadArgs::new("tests/linear/linear.safetensors".into())
+            .with_key_remap("model\\.(.*)", "$1");
+        let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .load(args, &device)
+            .expect("Should decode state successfully");
+
+        linear_test(record, 6);
+    }
+
+    #[test]
+    fn linear_half_precision() {
+        let device = Default::default();
+        let args = LoadArgs::new("tests/linear/linear.safetensors".into())
+            .with_key_remap("model\\.(.*)", "$1");
+        let record = SafetensorsFileRecorder::<HalfPrecisionSettings>::default()
+            .load(args, &device)
+            .expect("Should decode state successfully");
+
+        linear_test(record, 2);
+    }
+
+    #[test]
+    fn linear_reads_tensors_when_used() {
+        let device = Default::default();
+        let args = LoadArgs::new("tests/linear/linear.safetensors".into())
+            .with_key_remap("model\\.(.*)", "$1");
+        let record: NetRecord<Backend> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .load(args, &device)
+                .expect("Should decode state successfully");
+
+        assert!(!record.fc1.weight.is_initialized());
+        assert!(!record.fc2.weight.is_initialized());
+
+        // The transposed weight is only read when it is used.
+        assert_eq!(record.fc1.weight.val().dims(), [2, 3]);
+        assert!(record.fc1.weight.is_initialized());
+        assert!(!record.fc2.weight.is_initialized());
+
+        linear_test(record, 6);
+    }
+
+    #[test]
+    fn linear_without_key_remap() {
+        let device = Default::default();
+        let result: Result<NetRecord<Backend>, _> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .load("tests/linear/linear.safetensors".into(), &device);
+
+        assert!(
+            result.is_err(),
+            "The keys shouldn't match without remapping"
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use burn::{
+    module::{Module, Param},
+    nn::{LayerNorm, LayerNormConfig, Linear, LinearConfig},
+    tensor::{Int, Tensor, backend::Backend},
+};
+
+#[derive(Module, Debug)]
+pub struct Net<B: Backend> {
+    fc: Linear<B>,
+    norm: LayerNorm<B>,
+    buffer: Param<Tensor<B, 1, Int>>,
+}
+
+impl<B: Backend> Net<B> {
+    /// Create a new model.
+    pub fn init(device: &B::Device) -> Self {
+        let fc = LinearConfig::new(3, 4).init(device);
+        let norm = LayerNormConfig::new(4).init(device);
+        let buffer = Param::from_tensor(Tensor::from_ints([1, 2, 3], device));
+
+        Self { fc, norm, buffer }
+    }
+
+    /// Forward pass of the model.
+    pub fn forward(&self, x: Tensor<B, 2>) -> Tensor<B, 2> {
+        let x = self.fc.forward(x);
+        self.norm.forward(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    use burn::record::{FullPrecisionSettings, Recorder};
+
+    use burn_import::safetensors::{AdapterType, LoadArgs, SafetensorsFileRecorder};
+
+    use super::*;
+
+    #[test]
+    fn save_and_load() {
+        let device = Default::default();
+        let model = Net::<Backend>::init(&device);
+        let input = Tensor::<Backend, 2>::from_data([[1.0, -2.0, 0.5], [0.3, 0.2, -0.1]], &device);
+        let expected = model.forward(input.clone());
+        let buffer = model.buffer.val();
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("model.safetensors");
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+        recorder
+            .record(model.into_record(), file.clone())
+            .expect("Should save the record successfully");
+
+        let args = LoadArgs::new(file).with_adapter_type(AdapterType::NoAdapter);
+        let record = recorder
+            .load(args, &device)
+            .expect("Should decode state successfully");
+        assert!(!record.fc.weight.is_initialized());
+        assert!(!record.buffer.is_initialized());
+        let model = Net::<Backend>::init(&device).load_record(record);
+
+        model
+            .forward(input)
+            .to_data()
+            .assert_approx_eq(&expected.to_data(), 6);
+        model
+            .buffer
+            .val()
+            .to_data()
+            .assert_eq(&buffer.to_data(), true);
+    }
+
+    #[test]
+    fn should_reject_shapes_overflowing_the_data_size() {
+        let device = Default::default();
+        let header =
+            r#"{"fc.weight":{"dtype":"F32","shape":[4611686018427387904,4],"data_offsets":[0,4]}}"#;
+        let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&[0; 4]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("model.safetensors");
+        std::fs::write(&file, bytes).unwrap();
+
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+        let args = LoadArgs::new(file).with_adapter_type(AdapterType::NoAdapter);
+        let record: Result<NetRecord<Backend>, _> = recorder.load(args, &device);
+
+        assert!(record.is_err());
+    }
+}
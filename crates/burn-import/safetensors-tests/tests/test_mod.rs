@@ -0,0 +1,2 @@
+mod linear;
+mod roundtrip;
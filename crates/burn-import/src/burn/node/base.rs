@@ -6,16 +6,17 @@ use super::{
     constant::ConstantNode, constant_of_shape::ConstantOfShapeNode,
     conv_transpose_1d::ConvTranspose1dNode, conv_transpose_2d::ConvTranspose2dNode,
     conv_transpose_3d::ConvTranspose3dNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
-    conv3d::Conv3dNode, dropout::DropoutNode, expand::ExpandNode, floor::FloorNode,
-    gather::GatherNode, gather_elements::GatherElementsNode, gemm::GemmNode,
-    global_avg_pool::GlobalAvgPoolNode, layer_norm::LayerNormNode, linear::LinearNode,
-    mask_where::WhereNode, matmul::MatmulNode, max_pool1d::MaxPool1dNode,
-    max_pool2d::MaxPool2dNode, mean::MeanNode, one_hot::OneHotNode, pad::PadNode, prelu::PReluNode,
-    random_normal::RandomNormalNode, random_normal_like::RandomNormalLikeNode,
-    random_uniform::RandomUniformNode, random_uniform_like::RandomUniformLikeNode,
-    range::RangeNode, reshape::ReshapeNode, resize::ResizeNode, slice::SliceNode, split::SplitNode,
-    squeeze::SqueezeNode, sum::SumNode, tile::TileNode, top_k::TopKNode, trilu::TriluNode,
-    unary::UnaryNode, unsqueeze::UnsqueezeNode,
+    conv3d::Conv3dNode, cumsum::CumSumNode, det::DetNode, dft::DftNode, dropout::DropoutNode,
+    einsum::EinsumNode, expand::ExpandNode, floor::FloorNode, gather::GatherNode,
+    gather_elements::GatherElementsNode, gemm::GemmNode, global_avg_pool::GlobalAvgPoolNode,
+    layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
+    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, mean::MeanNode, one_hot::OneHotNode,
+    pad::PadNode, prelu::PReluNode, random_normal::RandomNormalNode,
+    random_normal_like::RandomNormalLikeNode, random_uniform::RandomUniformNode,
+    random_uniform_like::RandomUniformLikeNode, range::RangeNode, reshape::ReshapeNode,
+    resize::ResizeNode, slice::SliceNode, split::SplitNode, squeeze::SqueezeNode, stft::StftNode,
+    sum::SumNode, tile::TileNode, top_k::TopKNode, trilu::TriluNode, unary::UnaryNode,
+    unsqueeze::UnsqueezeNode, window::WindowNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::record::PrecisionSettings;
@@ -98,8 +99,12 @@ pub enum Node<PS: PrecisionSettings> {
     ConvTranspose1d(ConvTranspose1dNode),
     ConvTranspose2d(ConvTranspose2dNode),
     ConvTranspose3d(ConvTranspose3dNode),
+    CumSum(CumSumNode),
+    Det(DetNode),
+    Dft(DftNode),
     PRelu(PReluNode),
     Dropout(DropoutNode),
+    Einsum(EinsumNode),
     Expand(ExpandNode),
     Floor(FloorNode),
     Gather(GatherNode),
@@ -119,6 +124,7 @@ pub enum Node<PS: PrecisionSettings> {
     Resize(ResizeNode),
     Slice(SliceNode),
     Squeeze(SqueezeNode),
+    Stft(StftNode),
     Split(SplitNode),
     Sum(SumNode),
     Tile(TileNode),
@@ -127,6 +133,7 @@ pub enum Node<PS: PrecisionSettings> {
     Unary(UnaryNode),
     Unsqueeze(UnsqueezeNode),
     Where(WhereNode),
+    Window(WindowNode),
     RandomNormal(RandomNormalNode),
     RandomNormalLike(RandomNormalLikeNode),
     RandomUniform(RandomUniformNode),
@@ -155,8 +162,12 @@ macro_rules! match_all {
             Node::ConvTranspose1d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
             Node::ConvTranspose3d(node) => $func(node),
+            Node::CumSum(node) => $func(node),
+            Node::Det(node) => $func(node),
+            Node::Dft(node) => $func(node),
             Node::PRelu(node) => $func(node),
             Node::Dropout(node) => $func(node),
+            Node::Einsum(node) => $func(node),
             Node::Expand(node) => $func(node),
             Node::Floor(node) => $func(node),
             Node::Gather(node) => $func(node),
@@ -176,6 +187,7 @@ macro_rules! match_all {
             Node::Resize(node) => $func(node),
             Node::Slice(node) => $func(node),
             Node::Squeeze(node) => $func(node),
+            Node::Stft(node) => $func(node),
             Node::Sum(node) => $func(node),
             Node::Tile(node) => $func(node),
             Node::TopK(node) => $func(node),
@@ -183,6 +195,7 @@ macro_rules! match_all {
             Node::Unary(node) => $func(node),
             Node::Unsqueeze(node) => $func(node),
             Node::Where(node) => $func(node),
+            Node::Window(node) => $func(node),
             Node::RandomNormal(node) => $func(node),
             Node::RandomNormalLike(node) => $func(node),
             Node::RandomUniform(node) => $func(node),
@@ -220,8 +233,12 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::ConvTranspose1d(_) => "conv_transpose1d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
             Node::ConvTranspose3d(_) => "conv_transpose3d",
+            Node::CumSum(_) => "cumsum",
+            Node::Det(_) => "det",
+            Node::Dft(_) => "dft",
             Node::PRelu(_) => "prelu",
             Node::Dropout(_) => "dropout",
+            Node::Einsum(_) => "einsum",
             Node::Expand(_) => "expand",
             Node::Floor(_) => "floor",
             Node::Gather(_) => "gather",
@@ -241,6 +258,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Resize(_) => "resize",
             Node::Slice(_) => "slice",
             Node::Squeeze(_) => "squeeze",
+            Node::Stft(_) => "stft",
             Node::Sum(_) => "add",
             Node::Tile(_) => "tile",
             Node::TopK(_) => "top_k",
@@ -248,6 +266,7 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Unary(unary) => unary.kind.as_str(),
             Node::Unsqueeze(_) => "unsqueeze",
             Node::Where(_) => "where",
+            Node::Window(window) => window.kind.as_str(),
             Node::RandomNormal(_) => "random_normal",
             Node::RandomNormalLike(_) => "random_normal_like",
             Node::RandomUniform(_) => "random_uniform",
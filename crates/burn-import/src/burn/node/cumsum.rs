@@ -0,0 +1,157 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::config::Config;
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Config, Debug)]
+pub struct CumSumConfig {
+    pub axis: usize,
+    pub exclusive: bool,
+    pub reverse: bool,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct CumSumNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: CumSumConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for CumSumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let axis = self.config.axis.to_tokens();
+
+        let reverse = self.config.reverse;
+        let cumsum = |input: TokenStream| match reverse {
+            true => quote! { #input.flip([#axis]).cumsum(#axis).flip([#axis]) },
+            false => quote! { #input.cumsum(#axis) },
+        };
+
+        if self.config.exclusive {
+            // The exclusive sum leaves out the current element
+            let cumsum = cumsum(quote! { input.clone() });
+            quote! {
+                let #output = {
+                    let input = #input;
+                    #cumsum - input
+                };
+            }
+        } else {
+            let cumsum = cumsum(input);
+            quote! {
+                let #output = #cumsum;
+            }
+        }
+    }
+    fn into_node(self) -> super::Node<PS> {
+        Node::CumSum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{cumsum::CumSumConfig, cumsum::CumSumNode, test::assert_tokens},
+    };
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen_cumsum() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = CumSumConfig::new(1, false, false);
+        graph.register(CumSumNode::new(
+            TensorType::new_float("input", 2),
+            TensorType::new_float("output", 2),
+            config,
+        ));
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let output = input.cumsum(1);
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_cumsum_exclusive_reverse() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let config = CumSumConfig::new(0, true, true);
+        graph.register(CumSumNode::new(
+            TensorType::new_float("input", 2),
+            TensorType::new_float("output", 2),
+            config,
+        ));
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model<B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let output = {
+                        let input = input;
+                        input.clone().flip([0]).cumsum(0).flip([0]) - input
+                    };
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
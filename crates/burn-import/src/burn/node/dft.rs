@@ -0,0 +1,188 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::config::Config;
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Config, Debug)]
+pub struct DftConfig {
+    pub axis: usize,
+    pub inverse: bool,
+    pub onesided: bool,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct DftNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub config: DftConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DftNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let axis = self.config.axis.to_tokens();
+        // The last dimension holds the real and, for complex signals, imaginary parts
+        let last = (self.input.rank - 1).to_tokens();
+
+        let transform = match (self.config.onesided, self.config.inverse) {
+            (true, _) => quote! {
+                let (real, imag) = burn::tensor::signal::rfft(real, #axis);
+            },
+            (false, false) => quote! {
+                let (real, imag) = burn::tensor::signal::fft(real, imag, #axis);
+            },
+            (false, true) => quote! {
+                let (real, imag) = burn::tensor::signal::ifft(real, imag, #axis);
+            },
+        };
+
+        let parts = match self.config.onesided {
+            // The onesided transform is only defined for real signals
+            true => quote! {
+                let real = input.narrow(#last, 0, 1);
+            },
+            false => quote! {
+                let real = input.clone().narrow(#last, 0, 1);
+                let imag = match input.dims()[#last] {
+                    2 => input.narrow(#last, 1, 1),
+                    _ => real.zeros_like(),
+                };
+            },
+        };
+
+        quote! {
+            let #output = {
+                let input = #input;
+                #parts
+                #transform
+                Tensor::cat([real, imag].into(), #last)
+            };
+        }
+    }
+    fn into_node(self) -> Node<PS> {
+        Node::Dft(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{dft::DftNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_dft() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DftNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            DftConfig::new(1, false, false),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let real = input.clone().narrow(2, 0, 1);
+                        let imag = match input.dims()[2] {
+                            2 => input.narrow(2, 1, 1),
+                            _ => real.zeros_like(),
+                        };
+                        let (real, imag) = burn::tensor::signal::fft(real, imag, 1);
+                        Tensor::cat([real, imag].into(), 2)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_dft_onesided() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DftNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            DftConfig::new(1, false, true),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = {
+                        let input = tensor1;
+                        let real = input.narrow(2, 0, 1);
+                        let (real, imag) = burn::tensor::signal::rfft(real, 1);
+                        Tensor::cat([real, imag].into(), 2)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
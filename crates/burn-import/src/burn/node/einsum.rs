@@ -0,0 +1,111 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Debug, Clone, new)]
+pub struct EinsumNode {
+    pub inputs: Vec<TensorType>,
+    pub output: TensorType,
+    pub equation: String,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for EinsumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs
+            .iter()
+            .map(|t| Type::Tensor(t.clone()))
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let equation = &self.equation;
+        let rank = self.output.rank.to_tokens();
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|t| scope.tensor_use_owned(t, node_position));
+
+        let output = &self.output.name;
+
+        quote! {
+            let #output = Tensor::<B, #rank>::einsum(#equation, &[#(&#inputs),*]);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Einsum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{einsum::EinsumNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_einsum() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            vec![
+                TensorType::new_float("tensor1", 3),
+                TensorType::new_float("tensor2", 2),
+            ],
+            TensorType::new_float("tensor3", 3),
+            "bij,jk->bik".to_string(),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 3>,
+                    tensor2: Tensor<B, 2>
+                ) -> Tensor<B, 3> {
+                    let tensor3 = Tensor::<B, 3>::einsum("bij,jk->bik", &[&tensor1, &tensor2]);
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -15,7 +15,11 @@ pub(crate) mod conv3d;
 pub(crate) mod conv_transpose_1d;
 pub(crate) mod conv_transpose_2d;
 pub(crate) mod conv_transpose_3d;
+pub(crate) mod cumsum;
+pub(crate) mod det;
+pub(crate) mod dft;
 pub(crate) mod dropout;
+pub(crate) mod einsum;
 pub(crate) mod expand;
 pub(crate) mod floor;
 pub(crate) mod gather;
@@ -42,12 +46,14 @@ pub(crate) mod resize;
 pub(crate) mod slice;
 pub(crate) mod split;
 pub(crate) mod squeeze;
+pub(crate) mod stft;
 pub(crate) mod sum;
 pub(crate) mod tile;
 pub(crate) mod top_k;
 pub(crate) mod trilu;
 pub(crate) mod unary;
 pub(crate) mod unsqueeze;
+pub(crate) mod window;
 pub(crate) use base::*;
 
 #[cfg(test)]
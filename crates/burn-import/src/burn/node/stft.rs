@@ -0,0 +1,202 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::config::Config;
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+#[derive(Config, Debug)]
+pub struct StftConfig {
+    pub frame_step: usize,
+    pub onesided: bool,
+}
+
+/// The window applied to every frame of the signal.
+#[derive(Debug, Clone)]
+pub enum StftWindow {
+    /// The window is computed by the graph.
+    Tensor(TensorType),
+    /// The window is a constant of the graph.
+    Constant(Vec<f32>),
+    /// No window is given, the frames are only cut to the given length.
+    Rectangular(usize),
+}
+
+#[derive(Debug, Clone, new)]
+pub struct StftNode {
+    pub input: TensorType,
+    pub window: StftWindow,
+    pub output: TensorType,
+    pub config: StftConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for StftNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+    fn input_types(&self) -> Vec<Type> {
+        match &self.window {
+            StftWindow::Tensor(window) => vec![
+                Type::Tensor(self.input.clone()),
+                Type::Tensor(window.clone()),
+            ],
+            _ => vec![Type::Tensor(self.input.clone())],
+        }
+    }
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let frame_step = self.config.frame_step.to_tokens();
+        let onesided = self.config.onesided;
+
+        let window = match &self.window {
+            StftWindow::Tensor(window) => scope.tensor_use_owned(window, node_position),
+            StftWindow::Constant(values) => quote! {
+                Tensor::<B, 1>::from_floats([#(#values),*], &*self.device)
+            },
+            StftWindow::Rectangular(length) => {
+                let length = length.to_tokens();
+                quote! {
+                    Tensor::<B, 1>::ones([#length], &*self.device)
+                }
+            }
+        };
+
+        // The signal is real, with shape [batch_size, length, 1], and the output stacks the real
+        // and imaginary parts in the last dimension
+        quote! {
+            let #output = {
+                let signal = #input;
+                let [batch_size, length, _] = signal.dims();
+                let signal = signal.narrow(2, 0, 1).reshape([batch_size, length]);
+                let (real, imag) = burn::tensor::signal::stft(signal, #window, #frame_step, #onesided);
+                Tensor::stack::<4>([real, imag].into(), 3)
+            };
+        }
+    }
+    fn into_node(self) -> Node<PS> {
+        Node::Stft(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        TensorType,
+        graph::BurnGraph,
+        node::{stft::StftNode, test::assert_tokens},
+    };
+
+    #[test]
+    fn test_codegen_stft() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(StftNode::new(
+            TensorType::new_float("tensor1", 3),
+            StftWindow::Tensor(TensorType::new_float("window", 1)),
+            TensorType::new_float("tensor2", 4),
+            StftConfig::new(160, true),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "window".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>, window: Tensor<B, 1>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let signal = tensor1;
+                        let [batch_size, length, _] = signal.dims();
+                        let signal = signal.narrow(2, 0, 1).reshape([batch_size, length]);
+                        let (real, imag) = burn::tensor::signal::stft(signal, window, 160, true);
+                        Tensor::stack::<4>([real, imag].into(), 3)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_stft_without_window() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(StftNode::new(
+            TensorType::new_float("tensor1", 3),
+            StftWindow::Rectangular(400),
+            TensorType::new_float("tensor2", 4),
+            StftConfig::new(160, false),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let signal = tensor1;
+                        let [batch_size, length, _] = signal.dims();
+                        let signal = signal.narrow(2, 0, 1).reshape([batch_size, length]);
+                        let (real, imag) = burn::tensor::signal::stft(
+                            signal,
+                            Tensor::<B, 1>::ones([400], &*self.device),
+                            160,
+                            false
+                        );
+                        Tensor::stack::<4>([real, imag].into(), 3)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,111 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{ScalarType, Scope, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowKind {
+    Blackman,
+    Hamming,
+    Hann,
+}
+
+impl WindowKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            WindowKind::Blackman => "blackman_window",
+            WindowKind::Hamming => "hamming_window",
+            WindowKind::Hann => "hann_window",
+        }
+    }
+}
+
+#[derive(Debug, Clone, new)]
+pub struct WindowNode {
+    pub kind: WindowKind,
+    pub size: ScalarType,
+    pub output: TensorType,
+    pub periodic: bool,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for WindowNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Scalar(self.size.clone())]
+    }
+
+    fn forward(&self, _scope: &mut Scope, _node_position: usize) -> TokenStream {
+        let output = &self.output.name;
+        let size = &self.size.name;
+        let periodic = self.periodic;
+        let function = format_ident!("{}", self.kind.as_str());
+
+        quote! {
+            let #output = burn::tensor::signal::#function::<B>(#size as usize, #periodic, &*self.device);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Window(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::graph::BurnGraph;
+    use crate::burn::node::test::assert_tokens;
+    use crate::burn::{ScalarKind, ScalarType};
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn codegen_nodes_hann_window() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(
+            WindowNode::new(
+                WindowKind::Hann,
+                ScalarType::new("size", ScalarKind::Int64),
+                TensorType::new_float("output", 1),
+                true,
+            )
+            .into_node(),
+        );
+        graph.register_input_output(vec!["size".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, size: i64) -> Tensor<B, 1> {
+                    let output = burn::tensor::signal::hann_window::<B>(size as usize, true, &*self.device);
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -1,16 +1,17 @@
 use burn::{
     module::Param,
-    record::{PrecisionSettings, Record},
+    record::{MappedTensor, PrecisionSettings, Record},
     tensor::{Tensor, backend::Backend},
 };
 
 use burn::record::serde::{
     adapter::{BurnModuleAdapter, DefaultAdapter},
     data::NestedValue,
+    de::Deserializer,
     ser::Serializer,
 };
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// A PyTorch adapter for the Burn module used during deserialization.
 ///
@@ -31,6 +32,12 @@ impl<PS: PrecisionSettings, B: Backend> BurnModuleAdapter for PyTorchAdapter<PS,
             .remove("weight")
             .expect("Failed to find 'weight' key in map");
 
+        // Transpose the weight of a memory-mapped file lazily, when it is read.
+        if let Some(weight) = transpose_mapped(&weight) {
+            map.insert("weight".to_owned(), weight);
+            return NestedValue::Map(map);
+        }
+
         // Convert the weight parameter to a tensor (use default device, since it's quick operation).
         let weight: Param<Tensor<B, 2>> = weight
             .try_into_record::<_, PS, DefaultAdapter, B>(&B::Device::default())
@@ -64,6 +71,29 @@ impl<PS: PrecisionSettings, B: Backend> BurnModuleAdapter for PyTorchAdapter<PS,
     }
 }
 
+/// Transposes a parameter whose data is a [mapped tensor](MappedTensor), without reading it.
+///
+/// Returns `None` when the data of the parameter is in memory.
+fn transpose_mapped(param: &NestedValue) -> Option<NestedValue> {
+    let NestedValue::Map(map) = param else {
+        return None;
+    };
+    let tensor = MappedTensor::deserialize(Deserializer::<DefaultAdapter>::new(
+        map.get("param")?.clone(),
+        false,
+    ))
+    .ok()?;
+
+    let mut map = map.clone();
+    let tensor = tensor
+        .transpose()
+        .serialize(Serializer::new())
+        .expect("Failed to serialize the tensor");
+    map.insert("param".to_owned(), tensor);
+
+    Some(NestedValue::Map(map))
+}
+
 /// Helper function to serialize a param tensor.
 fn serialize<PS, B, const D: usize>(val: Param<Tensor<B, D>>) -> NestedValue
 where
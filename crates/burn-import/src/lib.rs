@@ -10,12 +10,12 @@
 //! aligns the imported model with Burn's model and converts tensor data into a format compatible with
 //! Burn.
 
-#[cfg(any(feature = "pytorch", feature = "onnx"))]
+#[cfg(any(feature = "pytorch", feature = "safetensors", feature = "onnx"))]
 #[macro_use]
 extern crate derive_new;
 
-// Enabled when the `pytorch` or `onnx` feature is enabled.
-#[cfg(any(feature = "pytorch", feature = "onnx"))]
+// Enabled when the `pytorch`, `safetensors` or `onnx` feature is enabled.
+#[cfg(any(feature = "pytorch", feature = "safetensors", feature = "onnx"))]
 mod logger;
 
 /// The onnx module.
@@ -30,5 +30,13 @@ pub mod burn;
 #[cfg(feature = "pytorch")]
 pub mod pytorch;
 
+/// The Safetensors module for recorder.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
+// Enabled when the `pytorch` or `safetensors` feature is enabled.
+#[cfg(any(feature = "pytorch", feature = "safetensors"))]
+mod common;
+
 mod formatter;
 pub use formatter::*;
@@ -9,8 +9,8 @@ use burn::nn::{
 };
 
 use crate::burn::node::{
-    expand::ExpandShape, pad::PadConfig, split::SplitConfig, tile::TileConfig, top_k::TopKConfig,
-    trilu::TriluConfig,
+    cumsum::CumSumConfig, dft::DftConfig, expand::ExpandShape, pad::PadConfig, split::SplitConfig,
+    stft::StftConfig, tile::TileConfig, top_k::TopKConfig, trilu::TriluConfig,
 };
 use onnx_ir::ir::{ArgType, AttributeValue, Data, ElementType, Node};
 
@@ -945,6 +945,130 @@ pub fn top_k_config(node: &Node) -> TopKConfig {
     TopKConfig::new(axis as usize, k as usize)
 }
 
+/// Extract the equation of an Einsum node
+pub fn einsum_config(node: &Node) -> String {
+    match node.attrs.get("equation") {
+        Some(value) => value.clone().into_string(),
+        None => panic!("Einsum: the equation attribute is required"),
+    }
+}
+
+/// Create a CumSumConfig from the attributes of the node
+pub fn cumsum_config(node: &Node) -> CumSumConfig {
+    let rank = match &node.inputs.first().unwrap().ty {
+        ArgType::Tensor(tensor) => tensor.rank,
+        _ => panic!("CumSum: only tensor input is valid"),
+    };
+
+    // The second input of the CumSum node is the axis, coming from a constant node
+    let mut axis = match node.inputs.get(1).and_then(|arg| arg.value.clone()) {
+        Some(Data::Int64(axis)) => axis,
+        Some(Data::Int32(axis)) => axis as i64,
+        Some(data @ (Data::Int64s(_) | Data::Int32s(_))) => data.into_i64s()[0],
+        _ => panic!("CumSum: only constant 'axis' tensor is currently supported"),
+    };
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += rank as i64;
+    }
+
+    let mut exclusive = false;
+    let mut reverse = false;
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "exclusive" => exclusive = value.clone().into_i64() != 0,
+            "reverse" => reverse = value.clone().into_i64() != 0,
+            _ => {}
+        }
+    }
+
+    CumSumConfig::new(axis as usize, exclusive, reverse)
+}
+
+/// Reads a constant integer input of a node, if it is given.
+fn constant_int_input(node: &Node, index: usize) -> Option<i64> {
+    let arg = node.inputs.get(index).filter(|arg| !arg.name.is_empty())?;
+
+    match arg.value.clone() {
+        Some(Data::Int64(value)) => Some(value),
+        Some(Data::Int32(value)) => Some(value as i64),
+        Some(data @ (Data::Int64s(_) | Data::Int32s(_))) => Some(data.into_i64s()[0]),
+        _ => panic!(
+            "{}: only constant '{}' input is currently supported",
+            node.name, arg.name
+        ),
+    }
+}
+
+/// Create a DftConfig from the attributes of the node
+pub fn dft_config(node: &Node) -> DftConfig {
+    let rank = match &node.inputs.first().unwrap().ty {
+        ArgType::Tensor(tensor) => tensor.rank,
+        _ => panic!("DFT: only tensor input is valid"),
+    };
+
+    if constant_int_input(node, 1).is_some() {
+        panic!("DFT: the 'dft_length' input is not supported");
+    }
+
+    // Since opset 20, the axis is given as the third input instead of an attribute, and defaults
+    // to -2. The default of the previous opsets is set as an attribute when parsing the graph.
+    let mut axis = constant_int_input(node, 2).unwrap_or(-2);
+    let mut inverse = false;
+    let mut onesided = false;
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            "inverse" => inverse = value.clone().into_i64() != 0,
+            "onesided" => onesided = value.clone().into_i64() != 0,
+            _ => {}
+        }
+    }
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += rank as i64;
+    }
+
+    if axis as usize >= rank - 1 {
+        panic!("DFT: the axis can't be the last dimension, which holds the complex values");
+    }
+
+    if inverse && onesided {
+        panic!("DFT: the onesided inverse transform is not supported");
+    }
+
+    DftConfig::new(axis as usize, inverse, onesided)
+}
+
+/// Create a StftConfig from the attributes of the node
+pub fn stft_config(node: &Node) -> StftConfig {
+    let frame_step = constant_int_input(node, 1).expect("STFT: the 'frame_step' input is required");
+
+    let mut onesided = true;
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "onesided" {
+            onesided = value.clone().into_i64() != 0;
+        }
+    }
+
+    StftConfig::new(frame_step as usize, onesided)
+}
+
+/// Extract the frame length of a STFT node, only required when no window is given
+pub fn stft_frame_length(node: &Node) -> Option<usize> {
+    constant_int_input(node, 3).map(|length| length as usize)
+}
+
+/// Extract whether the window of a HannWindow, HammingWindow or BlackmanWindow node is periodic
+pub fn window_config(node: &Node) -> bool {
+    match node.attrs.get("periodic") {
+        Some(value) => value.clone().into_i64() != 0,
+        None => true,
+    }
+}
+
 /// Create a TriluConfig from the attributes of the node
 pub fn trilu_config(node: &Node) -> TriluConfig {
     let mut upper = true;
@@ -31,7 +31,11 @@ use crate::{
             conv1d::Conv1dNode,
             conv2d::Conv2dNode,
             conv3d::Conv3dNode,
+            cumsum::CumSumNode,
+            det::DetNode,
+            dft::DftNode,
             dropout::DropoutNode,
+            einsum::EinsumNode,
             expand::{ExpandNode, ExpandShape},
             floor::FloorNode,
             gather::GatherNode,
@@ -57,12 +61,14 @@ use crate::{
             slice::SliceNode,
             split::SplitNode,
             squeeze::SqueezeNode,
+            stft::{StftNode, StftWindow},
             sum::SumNode,
             tile::TileNode,
             top_k::TopKNode,
             trilu::TriluNode,
             unary::UnaryNode,
             unsqueeze::UnsqueezeNode,
+            window::{WindowKind, WindowNode},
         },
     },
     format_tokens,
@@ -72,13 +78,14 @@ use crate::{
 use super::op_configuration::{
     argmax_config, avg_pool1d_config, avg_pool2d_config, batch_norm_config, clip_config,
     concat_config, conv_transpose1d_config, conv_transpose2d_config, conv_transpose3d_config,
-    conv1d_config, conv2d_config, conv3d_config, dropout_config, expand_config, flatten_config,
-    gather_config, gemm_config, hard_sigmoid_config, layer_norm_config, leaky_relu_config,
-    linear_config, log_softmax_config, max_pool1d_config, max_pool2d_config, one_hot_config,
-    pad_config, reduce_max_config, reduce_mean_config, reduce_min_config, reduce_prod_config,
-    reduce_sum_config, reshape_config, resize_config, shape_config, slice_config, softmax_config,
-    split_config, squeeze_config, tile_config, top_k_config, transpose_config, trilu_config,
-    unsqueeze_config,
+    conv1d_config, conv2d_config, conv3d_config, cumsum_config, dft_config, dropout_config,
+    einsum_config, expand_config, flatten_config, gather_config, gemm_config, hard_sigmoid_config,
+    layer_norm_config, leaky_relu_config, linear_config, log_softmax_config, max_pool1d_config,
+    max_pool2d_config, one_hot_config, pad_config, reduce_max_config, reduce_mean_config,
+    reduce_min_config, reduce_prod_config, reduce_sum_config, reshape_config, resize_config,
+    shape_config, slice_config, softmax_config, split_config, squeeze_config, stft_config,
+    stft_frame_length, tile_config, top_k_config, transpose_config, trilu_config, unsqueeze_config,
+    window_config,
 };
 use onnx_ir::{
     convert_constant_value,
@@ -335,7 +342,11 @@ impl ParsedOnnxGraph {
                 NodeType::Transpose => graph.register(Self::transpose_conversion(node)),
                 NodeType::Concat => graph.register(Self::concat_conversion(node)),
                 NodeType::Cast => graph.register(Self::cast_conversion(node)),
+                NodeType::CumSum => graph.register(Self::cumsum_conversion(node)),
+                NodeType::Det => graph.register(Self::det_conversion(node)),
+                NodeType::DFT => graph.register(Self::dft_conversion(node)),
                 NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
+                NodeType::Einsum => graph.register(Self::einsum_conversion(node)),
                 NodeType::GlobalAveragePool => {
                     graph.register(Self::global_avg_pool_conversion(node))
                 }
@@ -369,6 +380,16 @@ impl ParsedOnnxGraph {
                     graph.register(Self::constant_of_shape_conversion(node))
                 }
                 NodeType::Split => graph.register(Self::split_conversion(node)),
+                NodeType::STFT => graph.register(Self::stft_conversion(node)),
+                NodeType::HannWindow => {
+                    graph.register(Self::window_conversion(node, WindowKind::Hann))
+                }
+                NodeType::HammingWindow => {
+                    graph.register(Self::window_conversion(node, WindowKind::Hamming))
+                }
+                NodeType::BlackmanWindow => {
+                    graph.register(Self::window_conversion(node, WindowKind::Blackman))
+                }
                 NodeType::Gemm => graph.register(Self::gemm_conversion(node)),
                 node_type => unsupported_ops.push(node_type),
             }
@@ -1305,6 +1326,67 @@ impl ParsedOnnxGraph {
         TopKNode::new(input, outputs, config)
     }
 
+    fn cumsum_conversion(node: Node) -> CumSumNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = cumsum_config(&node);
+        CumSumNode::new(input, output, config)
+    }
+
+    fn det_conversion(node: Node) -> DetNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        DetNode::new(input, output)
+    }
+
+    fn dft_conversion(node: Node) -> DftNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = dft_config(&node);
+        DftNode::new(input, output, config)
+    }
+
+    fn stft_conversion(node: Node) -> StftNode {
+        let input = TensorType::from(node.inputs.first().unwrap());
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let config = stft_config(&node);
+
+        let window = match node.inputs.get(2).filter(|arg| !arg.name.is_empty()) {
+            Some(arg) => match &arg.value {
+                Some(data) => StftWindow::Constant(data.clone().into_f32s()),
+                None => StftWindow::Tensor(TensorType::from(arg)),
+            },
+            None => StftWindow::Rectangular(
+                stft_frame_length(&node)
+                    .expect("STFT: the 'frame_length' input is required without a window"),
+            ),
+        };
+
+        StftNode::new(input, window, output, config)
+    }
+
+    fn window_conversion(node: Node, kind: WindowKind) -> WindowNode {
+        let arg = node.inputs.first().unwrap();
+        let size = match &arg.ty {
+            ArgType::Scalar(scalar) => ScalarType::new(arg.name.clone(), ScalarKind::from(scalar)),
+            ArgType::Tensor(tensor) if tensor.rank == 0 => {
+                ScalarType::new(arg.name.clone(), ScalarKind::from(&tensor.elem_type))
+            }
+            _ => panic!("{}: the size input should be a scalar", node.name),
+        };
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let periodic = window_config(&node);
+
+        WindowNode::new(kind, size, output, periodic)
+    }
+
+    fn einsum_conversion(node: Node) -> EinsumNode {
+        let inputs = node.inputs.iter().map(TensorType::from).collect();
+        let output = TensorType::from(node.outputs.first().unwrap());
+        let equation = einsum_config(&node);
+        EinsumNode::new(inputs, output, equation)
+    }
+
     fn trilu_conversion(node: Node) -> TriluNode {
         let input = TensorType::from(node.inputs.first().unwrap());
         let output = TensorType::from(node.outputs.first().unwrap());
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{error::Error, recorder::AdapterType};
+use crate::common::adapter::PyTorchAdapter;
+
+use burn::{
+    module::ParamId,
+    record::serde::{
+        adapter::DefaultAdapter,
+        data::{NestedValue, Serializable, remap, unflatten},
+        de::Deserializer,
+        error,
+        ser::Serializer,
+    },
+    record::{MappedTensor, MappedTensorFile, PrecisionSettings},
+    tensor::{DType, backend::Backend},
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+/// Deserializes a Safetensors file.
+///
+/// The file is memory-mapped and only its header is read up front. The parameters of the record
+/// are lazy: every tensor is read from the file, and converted to the precision of the record,
+/// when its parameter is first used, such as when the record is loaded into a module. The whole
+/// file is thus never loaded in memory at once.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+/// * `adapter_type` - The adapter used to convert the modules stored in the file.
+/// * `debug` - Whether to print the keys, shapes and dtypes of the tensors.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    adapter_type: AdapterType,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    let file = MappedTensorFile::open(path)?;
+    let tensors = read_header(&file)?;
+
+    // Remap the keys (replace the keys in the map with the new keys)
+    let (tensors, remapped_keys) = remap(tensors, key_remap);
+
+    // Print the remapped keys if debug is enabled
+    if debug {
+        let mut remapped_keys = remapped_keys;
+        remapped_keys.sort();
+        println!("Debug information of keys and tensor shapes:\n---");
+        for (new_key, old_key) in remapped_keys {
+            if old_key != new_key {
+                println!("Original Key: {old_key}");
+                println!("Remapped Key: {new_key}");
+            } else {
+                println!("Key: {}", new_key);
+            }
+
+            let tensor = &tensors[&new_key].0;
+            println!("Shape: {:?}", tensor.shape());
+            println!("Dtype: {:?}", tensor.dtype());
+            println!("---");
+        }
+    }
+
+    // Convert the map of tensors to a nested value data structure
+    let nested_value = unflatten::<PS, _>(tensors)?;
+
+    // Deserialize the nested value into a record type with the selected adapter, the tensors
+    // being read from the file when their parameters are used.
+    let value = file.deserialize(|| match adapter_type {
+        AdapterType::PyTorch => D::deserialize(Deserializer::<PyTorchAdapter<PS, B>>::new(
+            nested_value,
+            true,
+        )),
+        AdapterType::NoAdapter => {
+            D::deserialize(Deserializer::<DefaultAdapter>::new(nested_value, true))
+        }
+    })?;
+
+    Ok(value)
+}
+
+/// The description of a tensor in the header of a Safetensors file.
+#[derive(Deserialize)]
+struct TensorInfo {
+    dtype: String,
+    shape: Vec<usize>,
+    data_offsets: (usize, usize),
+}
+
+/// Reads the header of the file, which describes where every tensor is stored.
+///
+/// The file starts with the size of the header as a little-endian `u64`, followed by the header as
+/// a JSON object mapping the tensor names to their description, then by the data of the tensors.
+fn read_header(file: &MappedTensorFile) -> Result<HashMap<String, LazyTensor>, Error> {
+    let bytes = file.bytes();
+    let size = bytes
+        .first_chunk::<8>()
+        .map(|size| u64::from_le_bytes(*size) as usize)
+        .ok_or_else(|| Error::Other("The Safetensors header is missing".into()))?;
+    let header = bytes[8..]
+        .get(..size)
+        .ok_or_else(|| Error::Other("The Safetensors header is truncated".into()))?;
+    let header: HashMap<String, serde_json::Value> = serde_json::from_slice(header)
+        .map_err(|err| Error::Other(format!("Invalid Safetensors header: {err}")))?;
+
+    let start = 8 + size;
+    header
+        .into_iter()
+        .filter(|(name, _)| name != "__metadata__")
+        .map(|(name, info)| {
+            let info = TensorInfo::deserialize(info)
+                .map_err(|err| Error::Other(format!("Invalid header of tensor {name}: {err}")))?;
+            let dtype = dtype(&info.dtype)?;
+            let (begin, end) = info.data_offsets;
+
+            // The shape and offsets come from the file, so they can't be trusted not to overflow.
+            let num_bytes = info
+                .shape
+                .iter()
+                .try_fold(dtype.size(), |num_bytes, dim| num_bytes.checked_mul(*dim))
+                .ok_or_else(|| Error::Other(format!("Invalid shape of tensor {name}")))?;
+            let range = match (start.checked_add(begin), start.checked_add(end)) {
+                (Some(begin), Some(end))
+                    if begin <= end && end <= bytes.len() && end - begin == num_bytes =>
+                {
+                    begin..end
+                }
+                _ => {
+                    return Err(Error::Other(format!(
+                        "Invalid data offsets of tensor {name}"
+                    )));
+                }
+            };
+
+            let tensor = file.tensor(range, info.shape, dtype);
+            Ok((name, LazyTensor(tensor)))
+        })
+        .collect()
+}
+
+/// The data type of a tensor from its name in a Safetensors header.
+fn dtype(name: &str) -> Result<DType, Error> {
+    let dtype = match name {
+        "F64" => DType::F64,
+        "F32" => DType::F32,
+        "F16" => DType::F16,
+        "BF16" => DType::BF16,
+        "I64" => DType::I64,
+        "I32" => DType::I32,
+        "I16" => DType::I16,
+        "I8" => DType::I8,
+        "U64" => DType::U64,
+        "U32" => DType::U32,
+        "U16" => DType::U16,
+        "U8" => DType::U8,
+        "BOOL" => DType::Bool,
+        _ => {
+            return Err(Error::Other(format!(
+                "Unsupported Safetensors dtype {name}"
+            )));
+        }
+    };
+
+    Ok(dtype)
+}
+
+/// A tensor of a memory-mapped Safetensors file, read when its parameter is used.
+struct LazyTensor(MappedTensor);
+
+impl Serializable for LazyTensor {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        // Serialize the tensor as a parameter whose data is a reference to the file.
+        let mut param: HashMap<String, NestedValue> = HashMap::new();
+        param.insert("id".into(), NestedValue::String(ParamId::new().serialize()));
+        param.insert("param".into(), self.0.serialize(serializer)?);
+
+        Ok(NestedValue::Map(param))
+    }
+}
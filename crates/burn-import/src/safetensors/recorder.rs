@@ -0,0 +1,189 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use regex::Regex;
+use serde::{Serialize, de::DeserializeOwned};
+
+use super::{reader::from_file, writer::to_file};
+
+/// A recorder that loads and saves Safetensors files (`.safetensors`).
+///
+/// LoadArgs can be used to remap keys, select the module adapter or file path.
+/// See [LoadArgs](struct.LoadArgs.html) for more information.
+///
+/// Records are saved with the parameter names and layouts of the Burn modules, so they should be
+/// loaded back with [AdapterType::NoAdapter].
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder<PS: PrecisionSettings> {
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn record<R: Record<B>>(
+        &self,
+        record: R,
+        file: Self::RecordArgs,
+    ) -> Result<Self::RecordOutput, RecorderError> {
+        // The metadata of the record can't be stored as tensors, only the item is saved.
+        self.save_item(record.into_item::<PS>(), file)
+    }
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        to_file(item, &file).map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(
+        &self,
+        args: &mut Self::LoadArgs,
+    ) -> Result<I, RecorderError> {
+        let item = from_file::<PS, I, B>(
+            &args.file,
+            args.key_remap.clone(),
+            args.adapter_type,
+            args.debug,
+        )?;
+        Ok(item)
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        mut args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item = Recorder::<B>::load_item::<R::Item<Self::Settings>>(self, &mut args)?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+/// The adapter used to convert the modules stored in a file to Burn modules.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AdapterType {
+    /// The modules follow the PyTorch conventions: the linear weights are transposed and the
+    /// normalization layers use `weight` and `bias` instead of `gamma` and `beta`.
+    #[default]
+    PyTorch,
+
+    /// The modules follow the Burn conventions, as in files saved with
+    /// [SafetensorsFileRecorder].
+    NoAdapter,
+}
+
+/// Arguments for loading a Safetensors file.
+///
+/// # Fields
+///
+/// * `file` - The path to the file to load.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+///   See [regex::Regex::replace](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace)
+///   for more information.
+/// * `adapter_type` - The adapter used to convert the modules, PyTorch by default.
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::safetensors::{LoadArgs, SafetensorsFileRecorder};
+/// use burn::record::FullPrecisionSettings;
+/// use burn::record::Recorder;
+///
+/// let args = LoadArgs::new("tests/key_remap/key_remap.safetensors".into())
+///    .with_key_remap("conv\\.(.*)", "$1"); // Remove "conv" prefix, e.g. "conv.conv1" -> "conv1"
+///
+/// let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+///   .load(args, &device)
+///   .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LoadArgs {
+    /// The path to the file to load.
+    pub file: PathBuf,
+
+    /// A list of key remappings.
+    pub key_remap: Vec<(Regex, String)>,
+
+    /// The adapter used to convert the modules.
+    pub adapter_type: AdapterType,
+
+    /// Whether to print debug information.
+    pub debug: bool,
+}
+
+impl LoadArgs {
+    /// Creates a new `LoadArgs` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path to the file to load.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            key_remap: Vec::new(),
+            adapter_type: AdapterType::default(),
+            debug: false,
+        }
+    }
+
+    /// Sets key remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern to be replaced.
+    /// * `replacement` - The pattern to replace with.
+    ///
+    /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+    /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace) for the
+    /// replacement syntax.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets the adapter used to convert the modules.
+    ///
+    /// # Arguments
+    ///
+    /// * `adapter_type` - The adapter type.
+    pub fn with_adapter_type(mut self, adapter_type: AdapterType) -> Self {
+        self.adapter_type = adapter_type;
+        self
+    }
+
+    /// Sets printing debug information on.
+    pub fn with_debug_print(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+}
+
+impl From<PathBuf> for LoadArgs {
+    fn from(val: PathBuf) -> Self {
+        LoadArgs::new(val)
+    }
+}
+
+impl From<String> for LoadArgs {
+    fn from(val: String) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+impl From<&str> for LoadArgs {
+    fn from(val: &str) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
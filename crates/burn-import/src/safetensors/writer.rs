@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::error::Error;
+
+use burn::{
+    record::serde::{
+        adapter::DefaultAdapter, data::NestedValue, de::Deserializer, ser::Serializer,
+    },
+    tensor::{DType, TensorData},
+};
+
+use candle_core::{Device, Tensor};
+use serde::{Deserialize, Serialize};
+
+/// Serializes a record item to a Safetensors file.
+///
+/// Every parameter is stored under its path in the record, such as `fc1.weight`, with the layout
+/// of the Burn module. Values that aren't tensors, such as constants, are not saved.
+///
+/// # Arguments
+///
+/// * `item` - The record item to save.
+/// * `path` - The path of the file to write.
+pub fn to_file<I: Serialize>(item: I, path: &Path) -> Result<(), Error> {
+    let value = item.serialize(Serializer::new())?;
+
+    let mut tensors = HashMap::new();
+    flatten(value, String::new(), &mut tensors)?;
+
+    candle_core::safetensors::save(&tensors, path)?;
+
+    Ok(())
+}
+
+/// Collects the parameters of a nested value with their dot-separated path as key.
+fn flatten(
+    value: NestedValue,
+    key: String,
+    tensors: &mut HashMap<String, Tensor>,
+) -> Result<(), Error> {
+    match value {
+        NestedValue::Map(mut map) => {
+            // Parameters are serialized as their id and tensor data
+            if map.contains_key("id") {
+                if let Some(param) = map.remove("param") {
+                    tensors.insert(key, to_candle(param)?);
+                    return Ok(());
+                }
+            }
+
+            for (name, value) in map {
+                flatten(value, join(&key, &name), tensors)?;
+            }
+        }
+        NestedValue::Vec(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                flatten(value, join(&key, &index.to_string()), tensors)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Appends a field name to a dot-separated path.
+fn join(key: &str, name: &str) -> String {
+    match key.is_empty() {
+        true => name.to_string(),
+        false => format!("{key}.{name}"),
+    }
+}
+
+/// Converts serialized tensor data to a candle tensor.
+fn to_candle(value: NestedValue) -> Result<Tensor, Error> {
+    let data = TensorData::deserialize(Deserializer::<DefaultAdapter>::new(value, false))?;
+    let shape = data.shape.clone();
+
+    // Safetensors files written by candle only hold the element types supported by candle
+    let (data, dtype) = match data.dtype {
+        DType::F64 => (data, candle_core::DType::F64),
+        DType::F32 => (data, candle_core::DType::F32),
+        DType::F16 => (data, candle_core::DType::F16),
+        DType::BF16 => (data, candle_core::DType::BF16),
+        DType::I64 => (data, candle_core::DType::I64),
+        DType::U32 => (data, candle_core::DType::U32),
+        DType::U8 => (data, candle_core::DType::U8),
+        DType::I32 | DType::I16 | DType::I8 | DType::U64 | DType::U16 => {
+            (data.convert::<i64>(), candle_core::DType::I64)
+        }
+        DType::Bool => {
+            let bytes = data.as_bytes().to_vec();
+            (
+                TensorData::from_bytes(bytes, shape.clone(), DType::U8),
+                candle_core::DType::U8,
+            )
+        }
+        DType::QFloat(_) => {
+            return Err(Error::Other(
+                "Quantized tensors can't be saved to Safetensors files".into(),
+            ));
+        }
+    };
+
+    Ok(Tensor::from_raw_buffer(
+        data.as_bytes(),
+        dtype,
+        &shape,
+        &Device::Cpu,
+    )?)
+}
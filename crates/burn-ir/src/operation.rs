@@ -512,6 +512,26 @@ pub enum NumericOperationIr<E> {
     /// Float => [powf](burn_tensor::ops::FloatTensorOps::float_powf).
     /// Int => [powf](burn_tensor::ops::IntTensorOps::int_powf).
     Powf(BinaryOpIr),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumsum](burn_tensor::ops::FloatTensorOps::float_cumsum).
+    /// Int => [cumsum](burn_tensor::ops::IntTensorOps::int_cumsum).
+    CumSum(ReduceDimOpIr),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cumprod](burn_tensor::ops::FloatTensorOps::float_cumprod).
+    /// Int => [cumprod](burn_tensor::ops::IntTensorOps::int_cumprod).
+    CumProd(ReduceDimOpIr),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cummax with indices](burn_tensor::ops::FloatTensorOps::float_cummax_with_indices).
+    /// Int => [cummax with indices](burn_tensor::ops::IntTensorOps::int_cummax_with_indices).
+    CumMaxWithIndices(ReduceDimWithIndicesOpIr),
+    /// Operation corresponding to:
+    ///
+    /// Float => [cummin with indices](burn_tensor::ops::FloatTensorOps::float_cummin_with_indices).
+    /// Int => [cummin with indices](burn_tensor::ops::IntTensorOps::int_cummin_with_indices).
+    CumMinWithIndices(ReduceDimWithIndicesOpIr),
 }
 
 /// Operation intermediate representation specific to an int tensor.
@@ -1554,6 +1574,18 @@ impl<E: Element> NumericOperationIr<E> {
             NumericOperationIr::Powf(repr) => {
                 vec![&repr.lhs, &repr.rhs, &repr.out]
             }
+            NumericOperationIr::CumSum(repr) => {
+                vec![&repr.input, &repr.out]
+            }
+            NumericOperationIr::CumProd(repr) => {
+                vec![&repr.input, &repr.out]
+            }
+            NumericOperationIr::CumMaxWithIndices(repr) => {
+                vec![&repr.tensor, &repr.out_indices, &repr.out]
+            }
+            NumericOperationIr::CumMinWithIndices(repr) => {
+                vec![&repr.tensor, &repr.out_indices, &repr.out]
+            }
         }
     }
 }
@@ -1857,6 +1889,10 @@ impl<E> core::hash::Hash for NumericOperationIr<E> {
             NumericOperationIr::Clamp(repr) => repr.hash(state),
             NumericOperationIr::IntRandom(repr) => repr.hash(state),
             NumericOperationIr::Powf(repr) => repr.hash(state),
+            NumericOperationIr::CumSum(repr) => repr.hash(state),
+            NumericOperationIr::CumProd(repr) => repr.hash(state),
+            NumericOperationIr::CumMaxWithIndices(repr) => repr.hash(state),
+            NumericOperationIr::CumMinWithIndices(repr) => repr.hash(state),
         }
     }
 }
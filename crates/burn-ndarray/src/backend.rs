@@ -1,14 +1,18 @@
 use crate::element::{FloatNdArrayElement, IntNdArrayElement, QuantElement};
 use crate::{NdArrayQTensor, NdArrayTensor, NdArrayTensorFloat};
 use alloc::string::String;
+use alloc::vec::Vec;
+use burn_common::rand::get_seeded_rng;
 use burn_common::stub::Mutex;
 use burn_ir::{BackendIr, HandleKind, TensorHandle};
 use burn_tensor::backend::{Backend, DeviceId, DeviceOps};
 use burn_tensor::ops::{BoolTensor, FloatTensor, IntTensor, QuantizedTensor};
 use core::marker::PhantomData;
-use rand::{SeedableRng, rngs::StdRng};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
 
-pub(crate) static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
+// The algorithm of `StdRng`, which exposes its state so it can be saved and restored.
+pub(crate) static SEED: Mutex<Option<ChaCha12Rng>> = Mutex::new(None);
 
 /// The device type for the ndarray backend.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -66,7 +70,27 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> Backend for
     }
 
     fn seed(seed: u64) {
-        let rng = StdRng::seed_from_u64(seed);
+        let rng = ChaCha12Rng::seed_from_u64(seed);
+        let mut seed = SEED.lock().unwrap();
+        *seed = Some(rng);
+    }
+
+    fn rng_state() -> Option<Vec<u8>> {
+        let mut seed = SEED.lock().unwrap();
+        let rng = seed.get_or_insert_with(|| ChaCha12Rng::from_rng(&mut get_seeded_rng()));
+        let mut state = Vec::with_capacity(56);
+        state.extend_from_slice(&rng.get_seed());
+        state.extend_from_slice(&rng.get_stream().to_le_bytes());
+        state.extend_from_slice(&rng.get_word_pos().to_le_bytes());
+
+        Some(state)
+    }
+
+    fn set_rng_state(state: &[u8]) {
+        assert_eq!(state.len(), 56, "Invalid random number generator state.");
+        let mut rng = ChaCha12Rng::from_seed(state[..32].try_into().unwrap());
+        rng.set_stream(u64::from_le_bytes(state[32..40].try_into().unwrap()));
+        rng.set_word_pos(u128::from_le_bytes(state[40..].try_into().unwrap()));
         let mut seed = SEED.lock().unwrap();
         *seed = Some(rng);
     }
@@ -696,6 +696,34 @@ where
         arg(tensor, dim, CmpType::Min)
     }
 
+    pub fn cumsum(tensor: NdArrayTensor<E>, dim: usize) -> NdArrayTensor<E> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr = *curr + prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cumprod(tensor: NdArrayTensor<E>, dim: usize) -> NdArrayTensor<E> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |&prev, curr| *curr = *curr * prev);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
+    pub fn cummax_with_indices<I: NdArrayElement>(
+        tensor: NdArrayTensor<E>,
+        dim: usize,
+    ) -> (NdArrayTensor<E>, NdArrayTensor<I>) {
+        cum_arg(tensor, dim, CmpType::Max)
+    }
+
+    pub fn cummin_with_indices<I: NdArrayElement>(
+        tensor: NdArrayTensor<E>,
+        dim: usize,
+    ) -> (NdArrayTensor<E>, NdArrayTensor<I>) {
+        cum_arg(tensor, dim, CmpType::Min)
+    }
+
     pub fn clamp_min(tensor: NdArrayTensor<E>, min: E) -> NdArrayTensor<E> {
         let mut tensor = dispatch_binary_scalar_simd!(
             E,
@@ -1226,6 +1254,45 @@ fn arg<E: NdArrayElement, I: NdArrayElement>(
     }
 }
 
+fn cum_arg<E: NdArrayElement, I: NdArrayElement>(
+    tensor: NdArrayTensor<E>,
+    dim: usize,
+    cmp: CmpType,
+) -> (NdArrayTensor<E>, NdArrayTensor<I>) {
+    let mut values = tensor.array.into_owned();
+    let mut indices = ndarray::Array::<I, IxDyn>::zeros(values.raw_dim());
+
+    Zip::from(values.lanes_mut(Axis(dim)))
+        .and(indices.lanes_mut(Axis(dim)))
+        .for_each(|mut values, mut indices| {
+            if values.is_empty() {
+                return;
+            }
+
+            // Keep track of the running min/max, ties select the latest index.
+            let mut acc = (values[0], 0usize);
+            for idx in 0..values.len() {
+                let e = values[idx];
+                let cmp = match cmp {
+                    CmpType::Min => e <= acc.0,
+                    CmpType::Max => e >= acc.0,
+                };
+
+                if cmp {
+                    acc = (e, idx);
+                }
+
+                values[idx] = acc.0;
+                indices[idx] = (acc.1 as i64).elem();
+            }
+        });
+
+    (
+        NdArrayTensor::new(values.into_shared()),
+        NdArrayTensor::new(indices.into_shared()),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
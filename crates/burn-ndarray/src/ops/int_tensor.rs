@@ -4,6 +4,8 @@ use burn_common::rand::get_seeded_rng;
 use burn_tensor::Distribution;
 use burn_tensor::ops::FloatTensor;
 use burn_tensor::ops::IntTensorOps;
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
 
 use burn_tensor::ElementConversion;
 use core::ops::Range;
@@ -201,6 +203,28 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps
         NdArrayMathOps::prod_dim(tensor, dim)
     }
 
+    fn int_cumsum(tensor: NdArrayTensor<I>, dim: usize) -> NdArrayTensor<I> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
+    fn int_cumprod(tensor: NdArrayTensor<I>, dim: usize) -> NdArrayTensor<I> {
+        NdArrayMathOps::cumprod(tensor, dim)
+    }
+
+    fn int_cummax_with_indices(
+        tensor: NdArrayTensor<I>,
+        dim: usize,
+    ) -> (NdArrayTensor<I>, NdArrayTensor<I>) {
+        NdArrayMathOps::cummax_with_indices(tensor, dim)
+    }
+
+    fn int_cummin_with_indices(
+        tensor: NdArrayTensor<I>,
+        dim: usize,
+    ) -> (NdArrayTensor<I>, NdArrayTensor<I>) {
+        NdArrayMathOps::cummin_with_indices(tensor, dim)
+    }
+
     fn int_mean(tensor: NdArrayTensor<I>) -> NdArrayTensor<I> {
         NdArrayMathOps::mean(tensor)
     }
@@ -285,7 +309,7 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> IntTensorOps
         let mut rng = if let Some(rng_seeded) = seed.as_ref() {
             rng_seeded.clone()
         } else {
-            get_seeded_rng()
+            ChaCha12Rng::from_rng(&mut get_seeded_rng())
         };
 
         let effective_distribution = if distribution == Distribution::Default {
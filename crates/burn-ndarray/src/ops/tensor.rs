@@ -14,6 +14,8 @@ use crate::{NdArrayDevice, NdArrayTensorFloat, SEED, execute_with_float_dtype};
 use burn_common::rand::get_seeded_rng;
 use burn_tensor::{DType, Distribution, FloatDType};
 use burn_tensor::{ElementConversion, Shape, TensorData, backend::Backend, ops::FloatTensorOps};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports)]
@@ -57,7 +59,7 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         let mut rng = if let Some(rng_seeded) = seed.as_ref() {
             rng_seeded.clone()
         } else {
-            get_seeded_rng()
+            ChaCha12Rng::from_rng(&mut get_seeded_rng())
         };
         let tensor = Self::float_from_data(
             TensorData::random::<E, _, _>(shape, distribution, &mut rng),
@@ -336,6 +338,46 @@ impl<E: FloatNdArrayElement, I: IntNdArrayElement, Q: QuantElement> FloatTensorO
         execute_with_float_dtype!(tensor, |tensor| NdArrayMathOps::prod_dim(tensor, dim))
     }
 
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| NdArrayMathOps::cumsum(tensor, dim))
+    }
+
+    fn float_cumprod(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        execute_with_float_dtype!(tensor, |tensor| NdArrayMathOps::cumprod(tensor, dim))
+    }
+
+    fn float_cummax_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+    ) -> (FloatTensor<Self>, NdArrayTensor<I>) {
+        match tensor {
+            NdArrayTensorFloat::F32(tensor) => {
+                let (values, indices) = NdArrayMathOps::cummax_with_indices(tensor, dim);
+                (NdArrayTensorFloat::F32(values), indices)
+            }
+            NdArrayTensorFloat::F64(tensor) => {
+                let (values, indices) = NdArrayMathOps::cummax_with_indices(tensor, dim);
+                (NdArrayTensorFloat::F64(values), indices)
+            }
+        }
+    }
+
+    fn float_cummin_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+    ) -> (FloatTensor<Self>, NdArrayTensor<I>) {
+        match tensor {
+            NdArrayTensorFloat::F32(tensor) => {
+                let (values, indices) = NdArrayMathOps::cummin_with_indices(tensor, dim);
+                (NdArrayTensorFloat::F32(values), indices)
+            }
+            NdArrayTensorFloat::F64(tensor) => {
+                let (values, indices) = NdArrayMathOps::cummin_with_indices(tensor, dim);
+                (NdArrayTensorFloat::F64(values), indices)
+            }
+        }
+    }
+
     fn float_log1p(tensor: FloatTensor<Self>) -> FloatTensor<Self> {
         execute_with_float_dtype!(tensor, E, |tensor: NdArrayTensor<E>| {
             let array = tensor.array.mapv_into(|a| a.log1p_elem()).into_shared();
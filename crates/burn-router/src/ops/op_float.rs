@@ -1436,4 +1436,92 @@ impl<R: RunnerChannel> FloatTensorOps<Self> for BackendRouter<R> {
 
         out
     }
+
+    fn float_cumsum(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let out = client.register_empty_tensor(tensor.shape.clone(), dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericFloat(
+            dtype,
+            NumericOperationIr::CumSum(desc),
+        ));
+
+        out
+    }
+
+    fn float_cumprod(tensor: FloatTensor<Self>, dim: usize) -> FloatTensor<Self> {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let out = client.register_empty_tensor(tensor.shape.clone(), dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericFloat(
+            dtype,
+            NumericOperationIr::CumProd(desc),
+        ));
+
+        out
+    }
+
+    fn float_cummax_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let shape = tensor.shape.clone();
+        let out = client.register_empty_tensor(shape.clone(), dtype);
+        let out_indices = client.register_empty_tensor(shape, IntElem::<Self>::dtype());
+
+        let desc = ReduceDimWithIndicesOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericFloat(
+            dtype,
+            NumericOperationIr::CumMaxWithIndices(desc),
+        ));
+
+        (out, out_indices)
+    }
+
+    fn float_cummin_with_indices(
+        tensor: FloatTensor<Self>,
+        dim: usize,
+    ) -> (FloatTensor<Self>, IntTensor<Self>) {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let shape = tensor.shape.clone();
+        let out = client.register_empty_tensor(shape.clone(), dtype);
+        let out_indices = client.register_empty_tensor(shape, IntElem::<Self>::dtype());
+
+        let desc = ReduceDimWithIndicesOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericFloat(
+            dtype,
+            NumericOperationIr::CumMinWithIndices(desc),
+        ));
+
+        (out, out_indices)
+    }
 }
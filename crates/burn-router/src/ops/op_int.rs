@@ -1342,4 +1342,92 @@ impl<R: RunnerChannel> IntTensorOps<Self> for BackendRouter<R> {
 
         out
     }
+
+    fn int_cumsum(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let out = client.register_empty_tensor(tensor.shape.clone(), dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericInt(
+            dtype,
+            NumericOperationIr::CumSum(desc),
+        ));
+
+        out
+    }
+
+    fn int_cumprod(tensor: IntTensor<Self>, dim: usize) -> IntTensor<Self> {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let out = client.register_empty_tensor(tensor.shape.clone(), dtype);
+
+        let desc = ReduceDimOpIr {
+            input: tensor.into_ir(),
+            axis: dim,
+            out: out.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericInt(
+            dtype,
+            NumericOperationIr::CumProd(desc),
+        ));
+
+        out
+    }
+
+    fn int_cummax_with_indices(
+        tensor: IntTensor<Self>,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let shape = tensor.shape.clone();
+        let out = client.register_empty_tensor(shape.clone(), dtype);
+        let out_indices = client.register_empty_tensor(shape, IntElem::<Self>::dtype());
+
+        let desc = ReduceDimWithIndicesOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericInt(
+            dtype,
+            NumericOperationIr::CumMaxWithIndices(desc),
+        ));
+
+        (out, out_indices)
+    }
+
+    fn int_cummin_with_indices(
+        tensor: IntTensor<Self>,
+        dim: usize,
+    ) -> (IntTensor<Self>, IntTensor<Self>) {
+        let client = tensor.client.clone();
+        let dtype = tensor.dtype;
+        let shape = tensor.shape.clone();
+        let out = client.register_empty_tensor(shape.clone(), dtype);
+        let out_indices = client.register_empty_tensor(shape, IntElem::<Self>::dtype());
+
+        let desc = ReduceDimWithIndicesOpIr {
+            tensor: tensor.into_ir(),
+            dim,
+            out: out.to_ir_out(),
+            out_indices: out_indices.to_ir_out(),
+        };
+
+        client.register(OperationIr::NumericInt(
+            dtype,
+            NumericOperationIr::CumMinWithIndices(desc),
+        ));
+
+        (out, out_indices)
+    }
 }
@@ -569,6 +569,26 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                 NumericOperationIr::Powf(desc) => {
                     binary_float_ops!(handles, desc, B::float_powf)
                 }
+                NumericOperationIr::CumSum(desc) => {
+                    reduce_float_dim_ops!(handles, desc, B::float_cumsum)
+                }
+                NumericOperationIr::CumProd(desc) => {
+                    reduce_float_dim_ops!(handles, desc, B::float_cumprod)
+                }
+                NumericOperationIr::CumMaxWithIndices(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.tensor);
+
+                    let (output, output_idx) = B::float_cummax_with_indices(tensor, desc.dim);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                    handles.register_int_tensor::<B>(&desc.out_indices.id, output_idx);
+                }
+                NumericOperationIr::CumMinWithIndices(desc) => {
+                    let tensor = handles.get_float_tensor::<B>(&desc.tensor);
+
+                    let (output, output_idx) = B::float_cummin_with_indices(tensor, desc.dim);
+                    handles.register_float_tensor::<B>(&desc.out.id, output);
+                    handles.register_int_tensor::<B>(&desc.out_indices.id, output_idx);
+                }
             },
             OperationIr::NumericInt(_dtype, op) => match op {
                 NumericOperationIr::Add(desc) => {
@@ -760,6 +780,26 @@ impl<B: BackendIr> RunnerClient for Runner<B> {
                     let output = B::int_powf(lhs, rhs);
                     handles.register_int_tensor::<B>(&desc.out.id, output);
                 }
+                NumericOperationIr::CumSum(desc) => {
+                    reduce_int_dim_ops!(handles, desc, B::int_cumsum)
+                }
+                NumericOperationIr::CumProd(desc) => {
+                    reduce_int_dim_ops!(handles, desc, B::int_cumprod)
+                }
+                NumericOperationIr::CumMaxWithIndices(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.tensor);
+
+                    let (output, output_idx) = B::int_cummax_with_indices(tensor, desc.dim);
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                    handles.register_int_tensor::<B>(&desc.out_indices.id, output_idx);
+                }
+                NumericOperationIr::CumMinWithIndices(desc) => {
+                    let tensor = handles.get_int_tensor::<B>(&desc.tensor);
+
+                    let (output, output_idx) = B::int_cummin_with_indices(tensor, desc.dim);
+                    handles.register_int_tensor::<B>(&desc.out.id, output);
+                    handles.register_int_tensor::<B>(&desc.out_indices.id, output_idx);
+                }
             },
             OperationIr::Bool(op) => match op {
                 BoolOperationIr::IntoFloat(desc) => {
@@ -1,6 +1,6 @@
 use crate::backend::Backend;
 use crate::check::TensorCheck;
-use crate::{Tensor, TensorPrimitive, check};
+use crate::{DType, FloatDType, Tensor, TensorPrimitive, check};
 
 /// Applies the rectified linear unit function as described in the paper [Deep Learning using
 /// Rectified Linear Units (ReLU)](https://arxiv.org/pdf/1803.08375).
@@ -71,11 +71,13 @@ pub fn prelu<const D: usize, B: Backend>(
 pub fn softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     check!(TensorCheck::dim_ops::<D>("softmax", dim));
 
-    let tensor = tensor.clone() - tensor.detach().max_dim(dim);
-    let tensor = tensor.exp();
-    let tensor_tmp = tensor.clone().sum_dim(dim);
+    full_precision(tensor, |tensor| {
+        let tensor = tensor.clone() - tensor.detach().max_dim(dim);
+        let tensor = tensor.exp();
+        let tensor_tmp = tensor.clone().sum_dim(dim);
 
-    tensor.div(tensor_tmp)
+        tensor.div(tensor_tmp)
+    })
 }
 
 /// Applies the softmin function on the input tensor along the given dimension.
@@ -112,11 +114,13 @@ pub fn softplus<const D: usize, B: Backend>(tensor: Tensor<B, D>, beta: f64) ->
 pub fn quiet_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     check!(TensorCheck::dim_ops::<D>("softmax", dim));
 
-    let tensor = tensor.clone() - tensor.detach().max_dim(dim);
-    let tensor = tensor.exp();
-    let tensor_tmp = tensor.clone().sum_dim(dim);
+    full_precision(tensor, |tensor| {
+        let tensor = tensor.clone() - tensor.detach().max_dim(dim);
+        let tensor = tensor.exp();
+        let tensor_tmp = tensor.clone().sum_dim(dim);
 
-    tensor.div(tensor_tmp + 1)
+        tensor.div(tensor_tmp + 1)
+    })
 }
 
 /// Applies the log softmax function on the input tensor along the given dimension.
@@ -130,10 +134,25 @@ pub fn quiet_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usiz
 pub fn log_softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     check!(TensorCheck::dim_ops::<D>("log softmax", dim));
 
-    let tensor = tensor.clone() - tensor.detach().max_dim(dim);
-    let tensor_tmp = tensor.clone().exp().sum_dim(dim).log();
+    full_precision(tensor, |tensor| {
+        let tensor = tensor.clone() - tensor.detach().max_dim(dim);
+        let tensor_tmp = tensor.clone().exp().sum_dim(dim).log();
 
-    tensor.sub(tensor_tmp)
+        tensor.sub(tensor_tmp)
+    })
+}
+
+/// Computes `func` in full precision when the tensor is in half precision, since the exponentials
+/// and the sums of the softmax overflow or lose too much precision otherwise, and casts the
+/// result back to the data type of the tensor.
+fn full_precision<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    func: impl FnOnce(Tensor<B, D>) -> Tensor<B, D>,
+) -> Tensor<B, D> {
+    match tensor.dtype() {
+        dtype @ (DType::F16 | DType::BF16) => func(tensor.cast(FloatDType::F32)).cast(dtype),
+        _ => func(tensor),
+    }
 }
 
 /// Applies the sigmoid function.
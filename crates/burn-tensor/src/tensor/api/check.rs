@@ -1,3 +1,4 @@
+use super::einsum::EinsumEquation;
 use crate::{BasicOps, Numeric, Shape, Tensor, backend::Backend, cast::ToElement};
 use alloc::format;
 use alloc::string::{String, ToString};
@@ -1017,6 +1018,136 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn einsum<const D: usize>(equation: &Result<EinsumEquation, String>) -> Self {
+        let mut check = Self::Ok;
+
+        match equation {
+            Ok(equation) => {
+                // A fully contracted output is returned as a tensor of rank 1.
+                let rank = usize::max(equation.output.len(), 1);
+
+                if rank != D {
+                    check = check.register(
+                        "Einsum",
+                        TensorError::new(
+                            "The number of output subscripts doesn't match the tensor rank.",
+                        )
+                        .details(format!("Tensor rank: '{D}', output rank: '{rank}'.")),
+                    );
+                }
+            }
+            Err(error) => {
+                check = check.register("Einsum", TensorError::new(error.clone()));
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn linalg_matrix<const D: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "Linear algebra operations require the matrices to be stored in the last \
+                     two dimensions of the tensor.",
+                )
+                .details(format!("Tensor rank: '{D}'.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn linalg_square<const D: usize>(ops: &str, shape: &Shape) -> Self {
+        let check = Self::linalg_matrix::<D>(ops);
+
+        if let Self::Failed(_) = check {
+            return check;
+        }
+
+        let rows = shape.dims[D - 2];
+        let cols = shape.dims[D - 1];
+
+        if rows != cols {
+            return check.register(
+                ops,
+                TensorError::new("The operation is only defined for square matrices.")
+                    .details(format!("Got matrices of shape ({rows}, {cols}).")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn linalg_solve<const D: usize>(lhs: &Shape, rhs: &Shape) -> Self {
+        let check = Self::linalg_square::<D>("Solve", lhs);
+
+        if let Self::Failed(_) = check {
+            return check;
+        }
+
+        if lhs.dims[..D - 1] != rhs.dims[..D - 1] {
+            return check.register(
+                "Solve",
+                TensorError::new(
+                    "The right-hand side should have the same batch dimensions and number of \
+                     rows as the coefficient matrices.",
+                )
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    lhs.dims, rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn fft<const D: usize>(ops: &str, dim: usize, re: &Shape, im: &Shape) -> Self {
+        let check = Self::dim_ops::<D>(ops, dim);
+
+        if re != im {
+            return check.register(
+                ops,
+                TensorError::new(
+                    "The real and imaginary parts of a complex tensor should have the same shape.",
+                )
+                .details(format!(
+                    "Real part shape {:?}, imaginary part shape {:?}.",
+                    re.dims, im.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn stft(length: usize, frame_length: usize, hop_length: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if hop_length == 0 {
+            check = check.register(
+                "STFT",
+                TensorError::new("The hop length should be greater than zero."),
+            );
+        }
+
+        if frame_length == 0 || frame_length > length {
+            check = check.register(
+                "STFT",
+                TensorError::new("The window should fit in the signal and can't be empty.")
+                    .details(format!(
+                        "Window length: '{frame_length}', signal length: '{length}'."
+                    )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn split<const D: usize>(
         tensor_dims: &[usize],
         split_size: usize,
@@ -1215,6 +1346,67 @@ impl TensorCheck {
         check
     }
 
+    /// Checks if the query, key, value and mask of an attention are compatible.
+    pub fn attention(
+        ops: &str,
+        query: [usize; 4],
+        key: [usize; 4],
+        value: [usize; 4],
+        mask: Option<[usize; 4]>,
+    ) -> Self {
+        let mut check = TensorCheck::Ok;
+
+        if query[0] != key[0] || key[0] != value[0] {
+            check = check.register(
+                ops,
+                TensorError::new("Query, key and value must have the same batch size.")
+                    .details(format!("got: {}, {} and {}", query[0], key[0], value[0])),
+            );
+        }
+        if key[1] != value[1] || key[1] == 0 || query[1] % key[1] != 0 {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "Key and value must have the same number of heads, dividing the number of query heads.",
+                )
+                .details(format!("got: {}, {} and {}", query[1], key[1], value[1])),
+            );
+        }
+        if key[2] != value[2] {
+            check = check.register(
+                ops,
+                TensorError::new("Key and value must have the same sequence length.")
+                    .details(format!("got: {} and {}", key[2], value[2])),
+            );
+        }
+        if query[3] != key[3] {
+            check = check.register(
+                ops,
+                TensorError::new("Query and key must have the same embedding size.")
+                    .details(format!("got: {} and {}", query[3], key[3])),
+            );
+        }
+        if let Some(mask) = mask {
+            let shape = [query[0], query[1], query[2], key[2]];
+            let broadcastable = mask
+                .iter()
+                .zip(shape)
+                .enumerate()
+                .all(|(i, (&dim, expected))| dim == expected || (dim == 1 && i < 3));
+            if !broadcastable {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "The mask must be broadcastable to [batch_size, num_heads, seq_length_q, seq_length_k].",
+                    )
+                    .details(format!("got: {mask:?}, expected: {shape:?}")),
+                );
+            }
+        }
+
+        check
+    }
+
     /// Checks if input is compatible with transposed convolution weights.
     pub fn conv_transpose<const D1: usize, const D2: usize>(
         ops: &str,
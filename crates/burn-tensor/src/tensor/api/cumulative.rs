@@ -0,0 +1,182 @@
+use core::ops::Range;
+
+use crate::{Numeric, Shape, TensorMetadata, backend::Backend, ops::IntTensor};
+use alloc::{vec, vec::Vec};
+
+/// Computes the cumulative sum of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the sum of all
+/// the previous elements (inclusive) along `dim`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cumsum<B: Backend, K: Numeric<B>>(tensor: K::Primitive, dim: usize) -> K::Primitive {
+    scan::<B, K, _>(tensor, dim, |previous, current| K::add(previous, current))
+}
+
+/// Computes the cumulative product of the elements of the input `tensor` along a given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, where each element is the product of all
+/// the previous elements (inclusive) along `dim`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cumprod<B: Backend, K: Numeric<B>>(tensor: K::Primitive, dim: usize) -> K::Primitive {
+    scan::<B, K, _>(tensor, dim, |previous, current| K::mul(previous, current))
+}
+
+/// Computes the cumulative maximum of the elements of the input `tensor` along a given dimension,
+/// along with the index of the maximum for each position.
+///
+/// When the same value appears several times, the index of the last occurrence is returned.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+///
+/// # Returns
+///
+/// A tuple with the running maximum values and their indices along `dim`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cummax_with_indices<B: Backend, K: Numeric<B>>(
+    tensor: K::Primitive,
+    dim: usize,
+) -> (K::Primitive, IntTensor<B>) {
+    scan_with_indices::<B, K, _>(tensor, dim, |previous, current| {
+        K::greater(previous, current)
+    })
+}
+
+/// Computes the cumulative minimum of the elements of the input `tensor` along a given dimension,
+/// along with the index of the minimum for each position.
+///
+/// When the same value appears several times, the index of the last occurrence is returned.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `dim` - The axis along which to accumulate.
+///
+/// # Returns
+///
+/// A tuple with the running minimum values and their indices along `dim`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+pub fn cummin_with_indices<B: Backend, K: Numeric<B>>(
+    tensor: K::Primitive,
+    dim: usize,
+) -> (K::Primitive, IntTensor<B>) {
+    scan_with_indices::<B, K, _>(tensor, dim, |previous, current| K::lower(previous, current))
+}
+
+/// Inclusive scan (Hillis-Steele) along `dim`.
+///
+/// Each step combines every element with the one `offset` positions before it, doubling the
+/// offset until it covers the whole dimension. This only needs `log2(n)` slice operations, which
+/// keeps the computation on the device for every backend.
+fn scan<B: Backend, K: Numeric<B>, F>(tensor: K::Primitive, dim: usize, op: F) -> K::Primitive
+where
+    F: Fn(K::Primitive, K::Primitive) -> K::Primitive,
+{
+    let shape = tensor.shape();
+    let size = shape.dims[dim];
+    let mut ranges = full_ranges(&shape);
+    let mut output = tensor;
+    let mut offset = 1;
+
+    while offset < size {
+        ranges[dim] = 0..size - offset;
+        let previous = K::slice(output.clone(), &ranges);
+        ranges[dim] = offset..size;
+        let current = K::slice(output.clone(), &ranges);
+
+        output = K::slice_assign(output, &ranges, op(previous, current));
+        offset *= 2;
+    }
+
+    output
+}
+
+/// Inclusive scan along `dim` that also tracks the index of the selected element.
+///
+/// The `keep_previous` function returns a mask where the earlier element should be kept over the
+/// current one; ties keep the current element.
+fn scan_with_indices<B: Backend, K: Numeric<B>, F>(
+    tensor: K::Primitive,
+    dim: usize,
+    keep_previous: F,
+) -> (K::Primitive, IntTensor<B>)
+where
+    F: Fn(K::Primitive, K::Primitive) -> B::BoolTensorPrimitive,
+{
+    let shape = tensor.shape();
+    let size = shape.dims[dim];
+    let device = K::device(&tensor);
+
+    let mut index_shape = vec![1; shape.num_dims()];
+    index_shape[dim] = size;
+    let indices = B::int_arange(0..size as i64, &device);
+    let indices = B::int_reshape(indices, Shape::from(index_shape));
+    let mut indices = B::int_expand(indices, shape.clone());
+
+    let mut ranges = full_ranges(&shape);
+    let mut values = tensor;
+    let mut offset = 1;
+
+    while offset < size {
+        ranges[dim] = 0..size - offset;
+        let previous = K::slice(values.clone(), &ranges);
+        let previous_indices = B::int_slice(indices.clone(), &ranges);
+        ranges[dim] = offset..size;
+        let current = K::slice(values.clone(), &ranges);
+        let current_indices = B::int_slice(indices.clone(), &ranges);
+
+        let mask = keep_previous(previous.clone(), current.clone());
+        let selected = K::mask_where(current, mask.clone(), previous);
+        let selected_indices = B::int_mask_where(current_indices, mask, previous_indices);
+
+        values = K::slice_assign(values, &ranges, selected);
+        indices = B::int_slice_assign(indices, &ranges, selected_indices);
+        offset *= 2;
+    }
+
+    (values, indices)
+}
+
+fn full_ranges(shape: &Shape) -> Vec<Range<usize>> {
+    shape.dims.iter().map(|&dim| 0..dim).collect()
+}
@@ -0,0 +1,465 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Shape, Tensor, TensorMetadata, backend::Backend, ops::FloatTensor};
+
+/// A tensor that can be used as an operand of [einsum](Tensor::einsum), whatever its rank.
+pub trait EinsumOperand<B: Backend> {
+    /// Returns the float tensor primitive of the operand.
+    fn float_primitive(&self) -> FloatTensor<B>;
+}
+
+impl<B: Backend, const D: usize> EinsumOperand<B> for Tensor<B, D> {
+    fn float_primitive(&self) -> FloatTensor<B> {
+        self.primitive.clone().tensor()
+    }
+}
+
+/// The labels standing for the dimensions covered by an ellipsis are taken from the unicode
+/// private use area, so they can't collide with the letters of the equation.
+const ELLIPSIS_LABEL_START: u32 = 0xE000;
+
+/// A parsed einsum equation, where every dimension of the operands and of the output is
+/// identified by a label.
+#[derive(Debug)]
+pub(crate) struct EinsumEquation {
+    inputs: Vec<Vec<char>>,
+    pub(crate) output: Vec<char>,
+    sizes: BTreeMap<char, usize>,
+}
+
+/// The subscripts of a single term of the equation.
+struct Subscript {
+    labels: Vec<char>,
+    /// The position of the ellipsis in the labels, if any.
+    ellipsis: Option<usize>,
+}
+
+impl Subscript {
+    fn parse(term: &str) -> Result<Self, String> {
+        let mut labels = Vec::new();
+        let mut ellipsis = None;
+        let mut chars = term.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '.' => {
+                    if chars.next() != Some('.') || chars.next() != Some('.') {
+                        return Err(format!("Invalid ellipsis in the subscripts '{term}'."));
+                    }
+                    if ellipsis.is_some() {
+                        return Err(format!("Multiple ellipses in the subscripts '{term}'."));
+                    }
+                    ellipsis = Some(labels.len());
+                }
+                c if c.is_ascii_alphabetic() => labels.push(c),
+                c => return Err(format!("Invalid subscript '{c}' in '{term}'.")),
+            }
+        }
+
+        Ok(Self { labels, ellipsis })
+    }
+
+    /// Returns the labels of every dimension, the ellipsis standing for the last `count`
+    /// ellipsis labels.
+    fn expand(&self, ellipsis_labels: &[char], count: usize) -> Vec<char> {
+        let mut labels = self.labels.clone();
+
+        if let Some(position) = self.ellipsis {
+            let start = ellipsis_labels.len() - count;
+            labels.splice(position..position, ellipsis_labels[start..].iter().copied());
+        }
+
+        labels
+    }
+}
+
+impl EinsumEquation {
+    /// Parses the `equation` for operands of the given `shapes`.
+    pub(crate) fn parse(equation: &str, shapes: &[Shape]) -> Result<Self, String> {
+        let equation: String = equation.chars().filter(|c| !c.is_whitespace()).collect();
+        let (lhs, rhs) = match equation.split_once("->") {
+            Some((lhs, rhs)) => (lhs, Some(rhs)),
+            None => (equation.as_str(), None),
+        };
+
+        let terms = lhs
+            .split(',')
+            .map(Subscript::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if terms.len() != shapes.len() {
+            return Err(format!(
+                "The equation has {} operands, but {} tensors were given.",
+                terms.len(),
+                shapes.len()
+            ));
+        }
+
+        // Every ellipsis stands for the same broadcast dimensions, aligned to the right.
+        let mut ellipsis_rank = 0;
+        for (i, (term, shape)) in terms.iter().zip(shapes).enumerate() {
+            let rank = shape.num_dims();
+            let explicit = term.labels.len();
+
+            match term.ellipsis {
+                Some(_) if explicit <= rank => {
+                    ellipsis_rank = usize::max(ellipsis_rank, rank - explicit)
+                }
+                None if explicit == rank => {}
+                _ => {
+                    return Err(format!(
+                        "Operand {i} has {rank} dimensions, which doesn't match its {explicit} \
+                         subscripts."
+                    ));
+                }
+            }
+        }
+
+        let ellipsis_labels: Vec<char> = (0..ellipsis_rank as u32)
+            .map(|i| char::from_u32(ELLIPSIS_LABEL_START + i).unwrap())
+            .collect();
+
+        let mut inputs = Vec::with_capacity(terms.len());
+        let mut sizes = BTreeMap::new();
+
+        for (i, (term, shape)) in terms.iter().zip(shapes).enumerate() {
+            let count = shape.num_dims() - term.labels.len();
+            let labels = term.expand(&ellipsis_labels, count);
+
+            for (label, &size) in labels.iter().zip(shape.dims.iter()) {
+                // Dimensions of size 1 are broadcast.
+                match sizes.get(label) {
+                    Some(&current) if current != size && current != 1 && size != 1 => {
+                        return Err(format!(
+                            "Operand {i} has a dimension of size {size} that doesn't match the \
+                             size {current} of the other dimensions with the same subscript."
+                        ));
+                    }
+                    Some(&current) if current >= size => {}
+                    _ => {
+                        sizes.insert(*label, size);
+                    }
+                }
+            }
+
+            inputs.push(labels);
+        }
+
+        let output = match rhs {
+            Some(rhs) => {
+                let term = Subscript::parse(rhs)?;
+                let output = term.expand(&ellipsis_labels, ellipsis_rank);
+
+                for (i, label) in output.iter().enumerate() {
+                    if output[..i].contains(label) {
+                        return Err(format!("The output subscript '{label}' is repeated."));
+                    }
+                    if !sizes.contains_key(label) {
+                        return Err(format!(
+                            "The output subscript '{label}' doesn't appear in the operands."
+                        ));
+                    }
+                }
+
+                output
+            }
+            None => {
+                // The implicit output has the broadcast dimensions followed by the subscripts
+                // appearing only once, in alphabetical order (the map keys are sorted).
+                let mut output = ellipsis_labels;
+                let letters: Vec<char> = sizes
+                    .keys()
+                    .copied()
+                    .filter(|label| label.is_ascii_alphabetic())
+                    .filter(|label| inputs.iter().flatten().filter(|l| *l == label).count() == 1)
+                    .collect();
+                output.extend(letters);
+                output
+            }
+        };
+
+        Ok(Self {
+            inputs,
+            output,
+            sizes,
+        })
+    }
+
+    /// Evaluates the equation with the given operands.
+    ///
+    /// Operands are contracted two at a time, always picking the pair producing the smallest
+    /// intermediate tensor. Each contraction is lowered to a batched matrix multiplication, while
+    /// the dimensions only found in a single operand are summed as soon as possible.
+    pub(crate) fn evaluate<B: Backend>(&self, tensors: Vec<FloatTensor<B>>) -> FloatTensor<B> {
+        let mut operands: Vec<Operand<B>> = tensors
+            .into_iter()
+            .zip(self.inputs.iter())
+            .map(|(tensor, labels)| {
+                Operand::new(tensor, labels.clone())
+                    .broadcast(&self.sizes)
+                    .diagonal()
+            })
+            .collect();
+
+        while operands.len() > 1 {
+            let (i, j) = self.next_pair(&operands);
+            let rhs = operands.remove(j);
+            let lhs = operands.remove(i);
+
+            let needed = self.needed_labels(operands.iter());
+            operands.push(lhs.contract(rhs, &needed));
+        }
+
+        operands
+            .pop()
+            .unwrap()
+            .sum_except(&self.output)
+            .permute(&self.output)
+            .tensor
+    }
+
+    /// The labels that must be kept by contractions: the ones in the output or in the
+    /// remaining operands.
+    fn needed_labels<'a, B: Backend + 'a>(
+        &self,
+        operands: impl Iterator<Item = &'a Operand<B>>,
+    ) -> Vec<char> {
+        let mut needed = self.output.clone();
+        for operand in operands {
+            needed.extend(operand.labels.iter());
+        }
+        needed
+    }
+
+    /// Greedily selects the pair of operands whose contraction has the smallest result.
+    fn next_pair<B: Backend>(&self, operands: &[Operand<B>]) -> (usize, usize) {
+        let mut best = (0, 1);
+        let mut best_size = usize::MAX;
+
+        for i in 0..operands.len() {
+            for j in i + 1..operands.len() {
+                let others = operands
+                    .iter()
+                    .enumerate()
+                    .filter(|(k, _)| *k != i && *k != j)
+                    .map(|(_, operand)| operand);
+                let needed = self.needed_labels(others);
+
+                let mut labels = operands[i].labels.clone();
+                for label in operands[j].labels.iter() {
+                    if !labels.contains(label) {
+                        labels.push(*label);
+                    }
+                }
+                let size = labels
+                    .iter()
+                    .filter(|label| needed.contains(label))
+                    .map(|label| self.sizes[label])
+                    .product();
+
+                if size < best_size {
+                    best = (i, j);
+                    best_size = size;
+                }
+            }
+        }
+
+        best
+    }
+}
+
+/// A tensor along with the labels of its dimensions.
+///
+/// Operands without labels hold a tensor of shape `[1]`, since Burn doesn't have 0-dim tensors.
+struct Operand<B: Backend> {
+    tensor: FloatTensor<B>,
+    labels: Vec<char>,
+}
+
+impl<B: Backend> Operand<B> {
+    fn new(tensor: FloatTensor<B>, labels: Vec<char>) -> Self {
+        Self { tensor, labels }
+    }
+
+    fn dims(&self) -> Vec<usize> {
+        self.tensor.shape().dims
+    }
+
+    /// Expands the dimensions of size 1 to the size of their label.
+    fn broadcast(self, sizes: &BTreeMap<char, usize>) -> Self {
+        let dims = self.dims();
+        let target: Vec<usize> = self.labels.iter().map(|label| sizes[label]).collect();
+
+        if dims == target {
+            return self;
+        }
+
+        Self::new(
+            B::float_expand(self.tensor, Shape::from(target)),
+            self.labels,
+        )
+    }
+
+    /// Takes the diagonal of the dimensions sharing the same label, e.g. `ii -> i`.
+    fn diagonal(mut self) -> Self {
+        while let Some((first, second)) = self.repeated_label() {
+            let dims = self.dims();
+            let size = dims[first];
+            let device = B::float_device(&self.tensor);
+
+            let mut mask_dims = vec![1; dims.len()];
+            mask_dims[first] = size;
+            mask_dims[second] = size;
+            let mask = Tensor::<B, 2>::eye(size, &device).into_primitive().tensor();
+            let mask = B::float_reshape(mask, Shape::from(mask_dims));
+
+            let tensor = B::float_sum_dim(B::float_mul(self.tensor, mask), second);
+            self.labels.remove(second);
+            self = Self::new(tensor, self.labels).squeeze(&[second]);
+        }
+
+        self
+    }
+
+    fn repeated_label(&self) -> Option<(usize, usize)> {
+        self.labels.iter().enumerate().find_map(|(i, label)| {
+            self.labels[i + 1..]
+                .iter()
+                .position(|other| other == label)
+                .map(|j| (i, i + 1 + j))
+        })
+    }
+
+    /// Removes the given `axes` of size 1 from the tensor, whose labels were already removed.
+    fn squeeze(self, axes: &[usize]) -> Self {
+        let dims: Vec<usize> = self
+            .dims()
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| !axes.contains(i))
+            .map(|(_, dim)| dim)
+            .collect();
+
+        Self::new(reshape::<B>(self.tensor, dims), self.labels)
+    }
+
+    /// Sums the dimensions whose label isn't in `keep`.
+    fn sum_except(self, keep: &[char]) -> Self {
+        let axes: Vec<usize> = self
+            .labels
+            .iter()
+            .enumerate()
+            .filter(|(_, label)| !keep.contains(label))
+            .map(|(i, _)| i)
+            .collect();
+
+        if axes.is_empty() {
+            return self;
+        }
+
+        let tensor = axes
+            .iter()
+            .fold(self.tensor, |tensor, &axis| B::float_sum_dim(tensor, axis));
+        let labels = self
+            .labels
+            .into_iter()
+            .filter(|label| keep.contains(label))
+            .collect();
+
+        Self::new(tensor, labels).squeeze(&axes)
+    }
+
+    /// Permutes the dimensions to follow the given `order`.
+    fn permute(self, order: &[char]) -> Self {
+        if self.labels == order {
+            return self;
+        }
+
+        let axes: Vec<usize> = order
+            .iter()
+            .map(|label| self.labels.iter().position(|l| l == label).unwrap())
+            .collect();
+
+        Self::new(B::float_permute(self.tensor, &axes), order.to_vec())
+    }
+
+    /// Contracts two operands with a batched matrix multiplication, keeping the `needed` labels.
+    fn contract(self, rhs: Self, needed: &[char]) -> Self {
+        // Dimensions only found in one operand and not needed afterward are summed first.
+        let mut keep = needed.to_vec();
+        keep.extend(rhs.labels.iter());
+        let lhs = self.sum_except(&keep);
+
+        let mut keep = needed.to_vec();
+        keep.extend(lhs.labels.iter());
+        let rhs = rhs.sum_except(&keep);
+
+        let (mut batch, mut contracted, mut left) = (Vec::new(), Vec::new(), Vec::new());
+        for label in lhs.labels.iter() {
+            match (rhs.labels.contains(label), needed.contains(label)) {
+                (true, true) => batch.push(*label),
+                (true, false) => contracted.push(*label),
+                (false, _) => left.push(*label),
+            }
+        }
+        let right: Vec<char> = rhs
+            .labels
+            .iter()
+            .filter(|label| !lhs.labels.contains(label))
+            .copied()
+            .collect();
+
+        let sizes = |labels: &[char], operand: &Operand<B>| -> Vec<usize> {
+            let dims = operand.dims();
+            labels
+                .iter()
+                .map(|label| dims[operand.labels.iter().position(|l| l == label).unwrap()])
+                .collect()
+        };
+        let batch_dims = sizes(&batch, &lhs);
+        let left_dims = sizes(&left, &lhs);
+        let contracted_dims = sizes(&contracted, &lhs);
+        let right_dims = sizes(&right, &rhs);
+        let product = |dims: &[usize]| dims.iter().product::<usize>();
+
+        let lhs_order = [batch.as_slice(), &left, &contracted].concat();
+        let lhs = lhs.permute(&lhs_order);
+        let lhs = B::float_reshape(
+            lhs.tensor,
+            Shape::new([
+                product(&batch_dims),
+                product(&left_dims),
+                product(&contracted_dims),
+            ]),
+        );
+
+        let rhs_order = [batch.as_slice(), &contracted, &right].concat();
+        let rhs = rhs.permute(&rhs_order);
+        let rhs = B::float_reshape(
+            rhs.tensor,
+            Shape::new([
+                product(&batch_dims),
+                product(&contracted_dims),
+                product(&right_dims),
+            ]),
+        );
+
+        let output = B::float_matmul(lhs, rhs);
+        let output_dims = [batch_dims, left_dims, right_dims].concat();
+        let output_labels = [batch, left, right].concat();
+
+        Self::new(reshape::<B>(output, output_dims), output_labels)
+    }
+}
+
+fn reshape<B: Backend>(tensor: FloatTensor<B>, dims: Vec<usize>) -> FloatTensor<B> {
+    if dims.is_empty() {
+        B::float_reshape(tensor, Shape::new([1]))
+    } else {
+        B::float_reshape(tensor, Shape::from(dims))
+    }
+}
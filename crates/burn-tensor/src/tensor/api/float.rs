@@ -1,3 +1,4 @@
+use super::einsum::{EinsumEquation, EinsumOperand};
 use crate::Tensor;
 use crate::check::TensorCheck;
 use crate::quantization::{QuantizationParameters, QuantizationScheme};
@@ -5,7 +6,8 @@ use crate::tensor::backend::Backend;
 use crate::tensor::stats;
 use crate::tensor::{Distribution, TensorData};
 use crate::{FloatDType, check};
-use crate::{Int, TensorPrimitive};
+use crate::{Int, TensorMetadata, TensorPrimitive};
+use alloc::vec::Vec;
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -210,6 +212,54 @@ where
         )))
     }
 
+    /// Sums the product of the elements of the input `tensors` along the dimensions specified
+    /// with the Einstein summation convention.
+    ///
+    /// The `equation` lists the subscripts of every operand, separated by commas, optionally
+    /// followed by `->` and the subscripts of the output, e.g. `"ij,jk->ik"` for a matrix
+    /// multiplication. Subscripts missing from the output are summed. When the output isn't
+    /// specified, it has the subscripts appearing only once, in alphabetical order. An ellipsis
+    /// (`...`) stands for the dimensions that aren't named, which are broadcast between operands.
+    ///
+    /// The operands are contracted pairwise, picking the pair producing the smallest
+    /// intermediate tensor first, and each contraction is lowered to a batched matrix
+    /// multiplication.
+    ///
+    /// # Panics
+    ///
+    /// If the equation is invalid, doesn't match the shapes of the tensors, or if its output
+    /// doesn't have `D` dimensions. A fully contracted output has a single dimension of size 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let matrix = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///     let vector = Tensor::<B, 1>::from_floats([1.0, -1.0], &device);
+    ///     let tensor = Tensor::<B, 1>::einsum("ij,j->i", &[&matrix, &vector]);
+    ///     println!("{tensor}");
+    ///     // [-1.0, -1.0]
+    /// }
+    /// ```
+    pub fn einsum(equation: &str, tensors: &[&dyn EinsumOperand<B>]) -> Self {
+        let tensors: Vec<_> = tensors
+            .iter()
+            .map(|tensor| tensor.float_primitive())
+            .collect();
+        let shapes: Vec<_> = tensors.iter().map(|tensor| tensor.shape()).collect();
+
+        let equation = EinsumEquation::parse(equation, &shapes);
+        check!(TensorCheck::einsum::<D>(&equation));
+
+        Self::new(TensorPrimitive::Float(
+            equation.unwrap().evaluate::<B>(tensors),
+        ))
+    }
+
     /// Calculate the variance along the given dimension.
     pub fn var(self, dim: usize) -> Self {
         stats::var(self, dim)
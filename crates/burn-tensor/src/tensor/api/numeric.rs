@@ -599,6 +599,170 @@ where
         Self::new(K::prod_dim(self.primitive, dim))
     }
 
+    /// Computes the cumulative sum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cumsum(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, -1.0, 2.0], [5.0, 14.0, 20.0]]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("CumSum", dim));
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
+    /// Computes the cumulative product of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cumprod(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, -2.0, -6.0], [5.0, 45.0, 270.0]]
+    /// }
+    /// ```
+    pub fn cumprod(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("CumProd", dim));
+        Self::new(K::cumprod(self.primitive, dim))
+    }
+
+    /// Computes the cumulative maximum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cummax(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, 1.0, 3.0], [5.0, 9.0, 9.0]]
+    /// }
+    /// ```
+    pub fn cummax(self, dim: usize) -> Self {
+        self.cummax_with_indices(dim).0
+    }
+
+    /// Computes the cumulative maximum of the elements along the given *dimension* or *axis*,
+    /// along with the index of the maximum for each position.
+    ///
+    /// When the same value appears several times, the index of the last occurrence is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let (tensor, index) = tensor.cummax_with_indices(1);
+    ///    // [[1.0, 1.0, 3.0], [5.0, 9.0, 9.0]]
+    ///    println!("{tensor}");
+    ///    // [[0, 0, 2], [0, 1, 1]]
+    ///    println!("{index}");
+    /// }
+    /// ```
+    pub fn cummax_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::aggregate_dim::<D>("CumMax", dim));
+
+        let (tensor, index) = K::cummax_with_indices(self.primitive, dim);
+
+        (Tensor::new(tensor), Tensor::new(index))
+    }
+
+    /// Computes the cumulative minimum of the elements along the given *dimension* or *axis*.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let tensor = tensor.cummin(1);
+    ///    println!("{tensor}");
+    ///    // [[1.0, -2.0, -2.0], [5.0, 5.0, 5.0]]
+    /// }
+    /// ```
+    pub fn cummin(self, dim: usize) -> Self {
+        self.cummin_with_indices(dim).0
+    }
+
+    /// Computes the cumulative minimum of the elements along the given *dimension* or *axis*,
+    /// along with the index of the minimum for each position.
+    ///
+    /// When the same value appears several times, the index of the last occurrence is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension or axis along which to accumulate the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///    let device = B::Device::default();
+    ///    let tensor = Tensor::<B, 2>::from_data([[1.0, -2.0, 3.0], [5.0, 9.0, 6.0]], &device);
+    ///    let (tensor, index) = tensor.cummin_with_indices(1);
+    ///    // [[1.0, -2.0, -2.0], [5.0, 5.0, 5.0]]
+    ///    println!("{tensor}");
+    ///    // [[0, 1, 1], [0, 0, 0]]
+    ///    println!("{index}");
+    /// }
+    /// ```
+    pub fn cummin_with_indices(self, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::aggregate_dim::<D>("CumMin", dim));
+
+        let (tensor, index) = K::cummin_with_indices(self.primitive, dim);
+
+        (Tensor::new(tensor), Tensor::new(index))
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     ///
     /// # Arguments
@@ -2657,6 +2821,98 @@ where
     ///
     fn prod_dim(tensor: Self::Primitive, dim: usize) -> Self::Primitive;
 
+    /// Computes the cumulative sum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// The cumulative sum of the elements of the tensor along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative sum of a tensor along a dimension, users should prefer the
+    /// [Tensor::cumsum](Tensor::cumsum) function, which is more high-level and designed for public use.
+    fn cumsum(tensor: Self::Primitive, dim: usize) -> Self::Primitive;
+
+    /// Computes the cumulative product of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// The cumulative product of the elements of the tensor along the specified dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative product of a tensor along a dimension, users should prefer the
+    /// [Tensor::cumprod](Tensor::cumprod) function, which is more high-level and designed for public use.
+    fn cumprod(tensor: Self::Primitive, dim: usize) -> Self::Primitive;
+
+    /// Computes the cumulative maximum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running maximum values along the specified dimension and their indices.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative maximum of a tensor along a dimension, users should prefer the
+    /// [Tensor::cummax_with_indices](Tensor::cummax_with_indices) function, which is more high-level
+    /// and designed for public use.
+    fn cummax_with_indices(
+        tensor: Self::Primitive,
+        dim: usize,
+    ) -> (Self::Primitive, B::IntTensorPrimitive);
+
+    /// Computes the cumulative minimum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running minimum values along the specified dimension and their indices.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For computing the cumulative minimum of a tensor along a dimension, users should prefer the
+    /// [Tensor::cummin_with_indices](Tensor::cummin_with_indices) function, which is more high-level
+    /// and designed for public use.
+    fn cummin_with_indices(
+        tensor: Self::Primitive,
+        dim: usize,
+    ) -> (Self::Primitive, B::IntTensorPrimitive);
+
     /// Computes the mean of all the elements of the tensor.
     ///
     /// # Arguments
@@ -3548,6 +3804,22 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_prod_dim(tensor, dim)
     }
 
+    fn cumsum(tensor: Self::Primitive, dim: usize) -> Self::Primitive {
+        B::int_cumsum(tensor, dim)
+    }
+
+    fn cumprod(tensor: Self::Primitive, dim: usize) -> Self::Primitive {
+        B::int_cumprod(tensor, dim)
+    }
+
+    fn cummax_with_indices(tensor: Self::Primitive, dim: usize) -> (Self::Primitive, IntTensor<B>) {
+        B::int_cummax_with_indices(tensor, dim)
+    }
+
+    fn cummin_with_indices(tensor: Self::Primitive, dim: usize) -> (Self::Primitive, IntTensor<B>) {
+        B::int_cummin_with_indices(tensor, dim)
+    }
+
     fn mean(tensor: Self::Primitive) -> Self::Primitive {
         B::int_mean(tensor)
     }
@@ -3900,6 +4172,46 @@ impl<B: Backend> Numeric<B> for Float {
         }
     }
 
+    fn cumsum(tensor: Self::Primitive, dim: usize) -> Self::Primitive {
+        match tensor {
+            TensorPrimitive::Float(tensor) => TensorPrimitive::Float(B::float_cumsum(tensor, dim)),
+            TensorPrimitive::QFloat(tensor) => TensorPrimitive::QFloat(B::q_cumsum(tensor, dim)),
+        }
+    }
+
+    fn cumprod(tensor: Self::Primitive, dim: usize) -> Self::Primitive {
+        match tensor {
+            TensorPrimitive::Float(tensor) => TensorPrimitive::Float(B::float_cumprod(tensor, dim)),
+            TensorPrimitive::QFloat(tensor) => TensorPrimitive::QFloat(B::q_cumprod(tensor, dim)),
+        }
+    }
+
+    fn cummax_with_indices(tensor: Self::Primitive, dim: usize) -> (Self::Primitive, IntTensor<B>) {
+        match tensor {
+            TensorPrimitive::Float(tensor) => {
+                let (values, indices) = B::float_cummax_with_indices(tensor, dim);
+                (TensorPrimitive::Float(values), indices)
+            }
+            TensorPrimitive::QFloat(tensor) => {
+                let (values, indices) = B::q_cummax_with_indices(tensor, dim);
+                (TensorPrimitive::QFloat(values), indices)
+            }
+        }
+    }
+
+    fn cummin_with_indices(tensor: Self::Primitive, dim: usize) -> (Self::Primitive, IntTensor<B>) {
+        match tensor {
+            TensorPrimitive::Float(tensor) => {
+                let (values, indices) = B::float_cummin_with_indices(tensor, dim);
+                (TensorPrimitive::Float(values), indices)
+            }
+            TensorPrimitive::QFloat(tensor) => {
+                let (values, indices) = B::q_cummin_with_indices(tensor, dim);
+                (TensorPrimitive::QFloat(values), indices)
+            }
+        }
+    }
+
     fn mean(tensor: Self::Primitive) -> Self::Primitive {
         match tensor {
             TensorPrimitive::Float(tensor) => TensorPrimitive::Float(B::float_mean(tensor)),
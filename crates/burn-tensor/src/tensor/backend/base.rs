@@ -1,4 +1,5 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 
 use crate::TensorMetadata;
 use crate::tensor::Element;
@@ -102,6 +103,19 @@ pub trait Backend:
     /// Seed the backend.
     fn seed(seed: u64);
 
+    /// The state of the random number generator of the backend, or `None` if the backend doesn't
+    /// expose it.
+    ///
+    /// Restoring it with [set_rng_state](Backend::set_rng_state) resumes the random operations
+    /// exactly where they were, which seeding the backend again can't do.
+    fn rng_state() -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Restore the state of the random number generator returned by
+    /// [rng_state](Backend::rng_state).
+    fn set_rng_state(_state: &[u8]) {}
+
     /// Sync the backend, ensure that all computation are finished.
     fn sync(_device: &Self::Device) {}
 }
@@ -0,0 +1,49 @@
+use core::ops::Range;
+
+use crate::{Shape, Tensor, backend::Backend};
+
+/// Ranges selecting the given `rows` and `cols` of every matrix, the matrices being stored in the
+/// last two dimensions of a tensor with the given `shape`.
+pub(crate) fn matrix_ranges<const D: usize>(
+    shape: &Shape,
+    rows: Range<usize>,
+    cols: Range<usize>,
+) -> [Range<usize>; D] {
+    let mut ranges: [Range<usize>; D] = core::array::from_fn(|i| 0..shape.dims[i]);
+    ranges[D - 2] = rows;
+    ranges[D - 1] = cols;
+    ranges
+}
+
+/// The shape of a tensor with the batch dimensions of `shape` holding `rows` x `cols` matrices.
+pub(crate) fn matrix_shape<const D: usize>(shape: &Shape, rows: usize, cols: usize) -> [usize; D] {
+    let mut dims: [usize; D] = core::array::from_fn(|i| shape.dims[i]);
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+    dims
+}
+
+/// Identity matrices of the given `size` with the batch dimensions of `shape`.
+pub(crate) fn identity<B: Backend, const D: usize>(
+    shape: &Shape,
+    size: usize,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    let mut dims = [1; D];
+    dims[D - 2] = size;
+    dims[D - 1] = size;
+
+    let eye = Tensor::<B, 2>::eye(size, device).reshape(dims);
+
+    // Add to zeros instead of expanding so that the result owns its memory.
+    Tensor::zeros(matrix_shape::<D>(shape, size, size), device).add(eye)
+}
+
+/// Extracts the diagonal of every square matrix as a row vector of shape `[..., 1, n]`.
+pub(crate) fn diagonal<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let shape = tensor.shape();
+    let size = shape.dims[D - 1];
+    let eye = identity::<B, D>(&shape, size, &tensor.device());
+
+    tensor.mul(eye).sum_dim(D - 2)
+}
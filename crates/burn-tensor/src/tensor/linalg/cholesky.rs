@@ -0,0 +1,70 @@
+use super::base::matrix_ranges;
+use crate::{Tensor, backend::Backend, check, check::TensorCheck};
+
+/// Computes the Cholesky decomposition of every symmetric positive-definite matrix stored in the
+/// last two dimensions of `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices to decompose, with shape `[..., n, n]`. Only the lower triangular
+///   part of each matrix is read.
+///
+/// # Returns
+///
+/// The lower triangular matrices `L` such that `L @ L^T` is equal to the input.
+///
+/// # Notes
+///
+/// Matrices that are not positive-definite produce non-finite values.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, linalg};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let tensor = Tensor::<B, 2>::from_floats([[4.0, 2.0], [2.0, 5.0]], &device);
+///     let lower = linalg::cholesky(tensor);
+///     println!("{lower}");
+///     // [[2.0, 0.0], [1.0, 2.0]]
+/// }
+/// ```
+pub fn cholesky<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let shape = tensor.shape();
+    check!(TensorCheck::linalg_square::<D>("Cholesky", &shape));
+
+    let size = shape.dims[D - 1];
+    let mut lower = tensor.zeros_like();
+
+    // Cholesky-Crout: compute one column of the factor at a time.
+    for j in 0..size {
+        let mut column = tensor
+            .clone()
+            .slice(matrix_ranges::<D>(&shape, j..size, j..j + 1));
+
+        if j > 0 {
+            let previous = lower
+                .clone()
+                .slice(matrix_ranges::<D>(&shape, j..size, 0..j));
+            let row = previous
+                .clone()
+                .slice(matrix_ranges::<D>(&shape, 0..1, 0..j));
+            column = column.sub(previous.matmul(row.transpose()));
+        }
+
+        // Dividing by the square root of the first element leaves that square root on the
+        // diagonal and scales the remaining elements of the column.
+        let diagonal = column
+            .clone()
+            .slice(matrix_ranges::<D>(&shape, 0..1, 0..1))
+            .sqrt();
+        lower = lower.slice_assign(
+            matrix_ranges::<D>(&shape, j..size, j..j + 1),
+            column.div(diagonal),
+        );
+    }
+
+    lower
+}
@@ -0,0 +1,179 @@
+use super::base::{diagonal, identity, matrix_ranges, matrix_shape};
+use crate::{ElementConversion, Tensor, backend::Backend, check, check::TensorCheck};
+
+/// The maximum number of Jacobi sweeps used by [eigh](eigh), each one rotating every off-diagonal
+/// pair.
+///
+/// Jacobi's method converges quadratically, so the sweeps normally stop way before this bound
+/// once the off-diagonal elements are negligible.
+const MAX_JACOBI_SWEEPS: usize = 30;
+
+/// The relative tolerance on the norm of the off-diagonal elements under which the matrices are
+/// considered diagonal.
+const JACOBI_TOLERANCE: f64 = 1e-6;
+
+/// Computes the eigenvalues and eigenvectors of every symmetric matrix stored in the last two
+/// dimensions of `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The symmetric matrices, with shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// A tuple `(eigenvalues, eigenvectors)` where the eigenvalues are sorted in ascending order with
+/// shape `[..., 1, n]`, and the eigenvectors are the columns of a tensor with shape `[..., n, n]`.
+/// Keeping the eigenvalues as row vectors means that `eigenvectors * eigenvalues` scales each
+/// eigenvector by its eigenvalue.
+///
+/// # Notes
+///
+/// The decomposition uses the cyclic Jacobi eigenvalue algorithm, which performs `O(n^3)`
+/// operations per sweep and is better suited for small matrices. The sweeps stop once the norm
+/// of the off-diagonal elements of every matrix is negligible relative to its norm, which reads
+/// a scalar back from the device after each sweep. The input is symmetrized before the
+/// decomposition.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, linalg};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let tensor = Tensor::<B, 2>::from_floats([[2.0, 1.0], [1.0, 2.0]], &device);
+///     let (eigenvalues, eigenvectors) = linalg::eigh(tensor);
+///     println!("{eigenvalues}");
+///     // [[1.0, 3.0]]
+///     println!("{eigenvectors}");
+///     // [[0.7071, 0.7071], [-0.7071, 0.7071]]
+/// }
+/// ```
+pub fn eigh<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    let shape = tensor.shape();
+    check!(TensorCheck::linalg_square::<D>("Eigh", &shape));
+
+    let device = tensor.device();
+    let size = shape.dims[D - 1];
+
+    let mut matrix = tensor.clone().add(tensor.transpose()).div_scalar(2);
+    let mut eigenvectors = identity::<B, D>(&shape, size, &device);
+
+    let off_diagonal = identity::<B, D>(&shape, size, &device).neg().add_scalar(1);
+    let tolerance = JACOBI_TOLERANCE * JACOBI_TOLERANCE;
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let squared = matrix.clone().powi_scalar(2);
+        let norm = squared.clone().sum_dim(D - 1).sum_dim(D - 2);
+        let off_norm = squared
+            .mul(off_diagonal.clone())
+            .sum_dim(D - 1)
+            .sum_dim(D - 2);
+
+        if off_norm
+            .lower_equal(norm.mul_scalar(tolerance))
+            .all()
+            .into_scalar()
+            .elem::<bool>()
+        {
+            break;
+        }
+
+        for p in 0..size {
+            for q in p + 1..size {
+                let (cos, sin) = jacobi_rotation(matrix.clone(), p, q);
+
+                // A' = R^T A R only changes the rows and columns p and q of A.
+                matrix = rotate(matrix, p, q, cos.clone(), sin.clone(), false);
+                matrix = rotate(matrix, p, q, cos.clone(), sin.clone(), true);
+                eigenvectors = rotate(eigenvectors, p, q, cos, sin, false);
+            }
+        }
+    }
+
+    let (eigenvalues, indices) = diagonal(matrix).sort_with_indices(D - 1);
+    let eigenvectors =
+        eigenvectors.gather(D - 1, indices.expand(matrix_shape::<D>(&shape, size, size)));
+
+    (eigenvalues, eigenvectors)
+}
+
+/// Computes the cosines and sines, with shape `[..., 1, 1]`, of the rotations zeroing the
+/// `(p, q)` element of the symmetric matrices.
+fn jacobi_rotation<B: Backend, const D: usize>(
+    matrix: Tensor<B, D>,
+    p: usize,
+    q: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let shape = matrix.shape();
+    let element = |i: usize, j: usize| {
+        matrix
+            .clone()
+            .slice(matrix_ranges::<D>(&shape, i..i + 1, j..j + 1))
+    };
+
+    let app = element(p, p);
+    let aqq = element(q, q);
+    let apq = element(p, q);
+
+    // Negligible elements are left untouched, which also keeps the gradients finite once the
+    // matrix has converged.
+    let threshold = app.clone().abs().add(aqq.clone().abs()).mul_scalar(1e-12);
+    let is_zero = apq.clone().abs().lower_equal(threshold);
+    let theta = aqq
+        .sub(app)
+        .div(apq.mask_fill(is_zero.clone(), 1.0).mul_scalar(2));
+
+    // The smallest rotation angle, with tan = sign(theta) / (|theta| + sqrt(theta^2 + 1)).
+    let sign = theta
+        .clone()
+        .lower_elem(0.0)
+        .float()
+        .mul_scalar(-2)
+        .add_scalar(1);
+    let tan = sign
+        .div(
+            theta
+                .clone()
+                .abs()
+                .add(theta.powi_scalar(2).add_scalar(1).sqrt()),
+        )
+        .mask_fill(is_zero, 0.0);
+    let cos = tan.clone().powi_scalar(2).add_scalar(1).sqrt().recip();
+    let sin = tan.mul(cos.clone());
+
+    (cos, sin)
+}
+
+/// Applies the rotations of the `(p, q)` plane to the columns `p` and `q` of the matrices, which
+/// computes `tensor @ R`, or to their rows `p` and `q` when `rows` is set, which computes
+/// `R^T @ tensor`.
+fn rotate<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    p: usize,
+    q: usize,
+    cos: Tensor<B, D>,
+    sin: Tensor<B, D>,
+    rows: bool,
+) -> Tensor<B, D> {
+    let shape = tensor.shape();
+    let size = shape.dims[D - 1];
+    let ranges = |i: usize| match rows {
+        true => matrix_ranges::<D>(&shape, i..i + 1, 0..size),
+        false => matrix_ranges::<D>(&shape, 0..size, i..i + 1),
+    };
+
+    let vector_p = tensor.clone().slice(ranges(p));
+    let vector_q = tensor.clone().slice(ranges(q));
+
+    let rotated_p = vector_p
+        .clone()
+        .mul(cos.clone())
+        .sub(vector_q.clone().mul(sin.clone()));
+    let rotated_q = vector_p.mul(sin).add(vector_q.mul(cos));
+
+    tensor
+        .slice_assign(ranges(p), rotated_p)
+        .slice_assign(ranges(q), rotated_q)
+}
@@ -0,0 +1,205 @@
+use super::base::{identity, matrix_ranges, matrix_shape};
+use crate::{Bool, Int, Tensor, backend::Backend, check, check::TensorCheck};
+use alloc::vec;
+
+/// Computes the inverse of every square matrix stored in the last two dimensions of `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices to invert, with shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input holding the inverse matrices.
+///
+/// # Notes
+///
+/// The inverse is computed with a Gauss-Jordan elimination with partial pivoting. The inverse of
+/// a singular matrix, whose elimination finds a zero pivot, is filled with NaN.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, linalg};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let tensor = Tensor::<B, 2>::from_floats([[4.0, 7.0], [2.0, 6.0]], &device);
+///     let inverse = linalg::inverse(tensor);
+///     println!("{inverse}");
+///     // [[0.6, -0.7], [-0.2, 0.4]]
+/// }
+/// ```
+pub fn inverse<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let shape = tensor.shape();
+    check!(TensorCheck::linalg_square::<D>("Inverse", &shape));
+
+    let eye = identity::<B, D>(&shape, shape.dims[D - 1], &tensor.device());
+
+    solve(tensor, eye)
+}
+
+/// Computes the determinant of every square matrix stored in the last two dimensions of `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices, with shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// The determinants, with shape `[..., 1, 1]`.
+///
+/// # Notes
+///
+/// The determinant is computed with a Gauss-Jordan elimination with partial pivoting, and is 0
+/// when the elimination finds a zero pivot.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, linalg};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let tensor = Tensor::<B, 2>::from_floats([[4.0, 7.0], [2.0, 6.0]], &device);
+///     let det = linalg::det(tensor);
+///     println!("{det}");
+///     // [[10.0]]
+/// }
+/// ```
+pub fn det<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    check!(TensorCheck::linalg_square::<D>("Det", &tensor.shape()));
+
+    let (_, det, _) = gauss_jordan(tensor);
+
+    det
+}
+
+/// Solves the linear systems `lhs @ x = rhs` for `x`.
+///
+/// # Arguments
+///
+/// * `lhs` - The square coefficient matrices, with shape `[..., n, n]`.
+/// * `rhs` - The right-hand sides, with shape `[..., n, k]`.
+///
+/// # Returns
+///
+/// The solutions, with shape `[..., n, k]`.
+///
+/// # Notes
+///
+/// The systems are solved with a Gauss-Jordan elimination with partial pivoting. The solutions
+/// of the systems with a singular matrix, whose elimination finds a zero pivot, are filled with
+/// NaN.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, linalg};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let lhs = Tensor::<B, 2>::from_floats([[3.0, 1.0], [1.0, 2.0]], &device);
+///     let rhs = Tensor::<B, 2>::from_floats([[9.0], [8.0]], &device);
+///     let x = linalg::solve(lhs, rhs);
+///     println!("{x}");
+///     // [[2.0], [3.0]]
+/// }
+/// ```
+pub fn solve<B: Backend, const D: usize>(lhs: Tensor<B, D>, rhs: Tensor<B, D>) -> Tensor<B, D> {
+    let shape_lhs = lhs.shape();
+    let shape_rhs = rhs.shape();
+    check!(TensorCheck::linalg_solve::<D>(&shape_lhs, &shape_rhs));
+
+    let size = shape_lhs.dims[D - 1];
+    let columns = shape_rhs.dims[D - 1];
+
+    let augmented = Tensor::cat(vec![lhs, rhs], D - 1);
+    let (reduced, _, singular) = gauss_jordan(augmented);
+    let shape = reduced.shape();
+
+    let solution = reduced.slice(matrix_ranges::<D>(&shape, 0..size, size..size + columns));
+    let singular = singular.expand(matrix_shape::<D>(&shape, size, columns));
+
+    solution.mask_fill(singular, f32::NAN)
+}
+
+/// Reduces the `[..., n, m]` matrices to reduced row echelon form, with `m >= n`.
+///
+/// Rows are swapped so that the pivot with the largest magnitude is used at each step. Every
+/// operation is expressed with tensor primitives, which keeps the computation batched on the
+/// device and differentiable.
+///
+/// Returns the reduced matrices along with the determinants of their leading `n` x `n` blocks,
+/// which are 0 when a zero pivot is found, and a `[..., 1, 1]` mask of the matrices whose
+/// elimination found a zero pivot. The mask is tracked separately since the determinant of a
+/// regular matrix can still underflow to 0, e.g. with a scaled identity.
+fn gauss_jordan<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D, Bool>) {
+    let shape = tensor.shape();
+    let device = tensor.device();
+    let size = shape.dims[D - 2];
+    let width = shape.dims[D - 1];
+
+    let column_shape = matrix_shape::<D>(&shape, size, 1);
+    let mut row_dims = [1; D];
+    row_dims[D - 2] = size;
+    let rows = Tensor::<B, 1, Int>::arange(0..size as i64, &device)
+        .reshape(row_dims)
+        .expand(column_shape);
+
+    let mut matrix = tensor;
+    let mut det = Tensor::<B, D>::ones(matrix_shape::<D>(&shape, 1, 1), &device);
+    let mut singular = Tensor::<B, D, Int>::zeros(matrix_shape::<D>(&shape, 1, 1), &device).bool();
+
+    for k in 0..size {
+        // Partial pivoting: swap the current row with the one holding the largest value of the
+        // current column.
+        let column = matrix
+            .clone()
+            .slice(matrix_ranges::<D>(&shape, k..size, k..k + 1));
+        let pivot_index = column.abs().argmax(D - 2).add_scalar(k as i64);
+
+        let is_pivot = rows.clone().equal(pivot_index.clone().expand(column_shape));
+        let permutation = rows.clone().mask_fill(is_pivot, k as i64).mask_where(
+            rows.clone().equal_elem(k as i64),
+            pivot_index.clone().expand(column_shape),
+        );
+        matrix = matrix.gather(D - 2, permutation.expand(shape.clone()));
+
+        // Each row swap flips the sign of the determinant.
+        let swapped = pivot_index.not_equal_elem(k as i64);
+        det = det.clone().mask_where(swapped, det.neg());
+
+        let pivot_row = matrix
+            .clone()
+            .slice(matrix_ranges::<D>(&shape, k..k + 1, 0..width));
+        let pivot = pivot_row
+            .clone()
+            .slice(matrix_ranges::<D>(&shape, 0..1, k..k + 1));
+        det = det.mul(pivot.clone());
+        // A zero pivot means the matrix is singular: the determinant is now 0, and the row is
+        // kept as is so the elimination doesn't produce NaN.
+        let is_zero = pivot.clone().equal_elem(0.0);
+        singular = singular.bool_or(is_zero.clone());
+        let pivot_row = pivot_row.div(pivot.mask_fill(is_zero, 1.0));
+
+        // Eliminate the current column from every other row.
+        let factors = matrix
+            .clone()
+            .slice(matrix_ranges::<D>(&shape, 0..size, k..k + 1))
+            .slice_assign(
+                matrix_ranges::<D>(&shape, k..k + 1, 0..1),
+                Tensor::zeros(matrix_shape::<D>(&shape, 1, 1), &device),
+            );
+        matrix = matrix
+            .sub(factors.matmul(pivot_row.clone()))
+            .slice_assign(matrix_ranges::<D>(&shape, k..k + 1, 0..width), pivot_row);
+    }
+
+    (matrix, det, singular)
+}
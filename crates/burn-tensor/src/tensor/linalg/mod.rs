@@ -0,0 +1,12 @@
+mod base;
+mod cholesky;
+mod eigh;
+mod lu;
+mod qr;
+mod svd;
+
+pub use cholesky::*;
+pub use eigh::*;
+pub use lu::*;
+pub use qr::*;
+pub use svd::*;
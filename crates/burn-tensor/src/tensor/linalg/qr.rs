@@ -0,0 +1,88 @@
+use super::base::{matrix_ranges, matrix_shape};
+use crate::{Tensor, backend::Backend, check, check::TensorCheck};
+
+/// Computes the reduced QR decomposition of every matrix stored in the last two dimensions of
+/// `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices to decompose, with shape `[..., m, n]`.
+///
+/// # Returns
+///
+/// A tuple `(q, r)` where `q` has orthonormal columns with shape `[..., m, k]` and `r` is upper
+/// triangular with shape `[..., k, n]`, with `k = min(m, n)`. The diagonal of `r` is
+/// non-negative.
+///
+/// # Notes
+///
+/// The decomposition uses the Gram-Schmidt process with re-orthogonalization. Matrices that don't
+/// have full rank produce non-finite values.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, linalg};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 1.0], [4.0, 2.0]], &device);
+///     let (q, r) = linalg::qr(tensor);
+///     println!("{q}");
+///     // [[0.6, -0.8], [0.8, 0.6]]
+///     println!("{r}");
+///     // [[5.0, 2.2], [0.0, 0.4]]
+/// }
+/// ```
+pub fn qr<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>("QR"));
+
+    let shape = tensor.shape();
+    let device = tensor.device();
+    let rows = shape.dims[D - 2];
+    let cols = shape.dims[D - 1];
+    let rank = usize::min(rows, cols);
+
+    let mut q = Tensor::<B, D>::zeros(matrix_shape::<D>(&shape, rows, rank), &device);
+    let mut r = Tensor::<B, D>::zeros(matrix_shape::<D>(&shape, rank, cols), &device);
+
+    for j in 0..cols {
+        let mut column = tensor
+            .clone()
+            .slice(matrix_ranges::<D>(&shape, 0..rows, j..j + 1));
+        let previous = usize::min(j, rank);
+
+        if previous > 0 {
+            let basis = q
+                .clone()
+                .slice(matrix_ranges::<D>(&shape, 0..rows, 0..previous));
+            let mut coefficients =
+                Tensor::<B, D>::zeros(matrix_shape::<D>(&shape, previous, 1), &device);
+
+            // Projecting twice keeps the basis orthogonal in finite precision.
+            for _ in 0..2 {
+                let projection = basis.clone().transpose().matmul(column.clone());
+                column = column.sub(basis.clone().matmul(projection.clone()));
+                coefficients = coefficients.add(projection);
+            }
+
+            r = r.slice_assign(
+                matrix_ranges::<D>(&shape, 0..previous, j..j + 1),
+                coefficients,
+            );
+        }
+
+        if j < rank {
+            let norm = column.clone().powi_scalar(2).sum_dim(D - 2).sqrt();
+
+            q = q.slice_assign(
+                matrix_ranges::<D>(&shape, 0..rows, j..j + 1),
+                column.div(norm.clone()),
+            );
+            r = r.slice_assign(matrix_ranges::<D>(&shape, j..j + 1, j..j + 1), norm);
+        }
+    }
+
+    (q, r)
+}
@@ -0,0 +1,67 @@
+use super::eigh::eigh;
+use crate::{Tensor, backend::Backend, check, check::TensorCheck};
+
+/// Computes the reduced singular value decomposition of every matrix stored in the last two
+/// dimensions of `tensor`.
+///
+/// # Arguments
+///
+/// * `tensor` - The matrices to decompose, with shape `[..., m, n]`.
+///
+/// # Returns
+///
+/// A tuple `(u, s, vh)` where `u` has shape `[..., m, k]`, the singular values `s` are sorted in
+/// descending order with shape `[..., 1, k]` and `vh` has shape `[..., k, n]`, with
+/// `k = min(m, n)`. The input is reconstructed with `(u * s).matmul(vh)`.
+///
+/// # Notes
+///
+/// The decomposition is computed from the eigendecomposition of the Gram matrix with
+/// [eigh](crate::linalg::eigh), which squares the condition number of the input: small singular
+/// values are less accurate than the largest ones, and singular values below the square root of
+/// the precision of the float type relative to the largest one are not resolved.
+///
+/// The left singular vectors are obtained by normalizing `tensor @ v`, so for rank-deficient
+/// inputs the columns of `u` matching zero singular values are zero instead of completing an
+/// orthonormal basis. The product `(u * s).matmul(vh)` still reconstructs the input.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, linalg};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let tensor = Tensor::<B, 2>::from_floats([[3.0, 0.0], [4.0, 5.0]], &device);
+///     let (_u, s, _vh) = linalg::svd(tensor);
+///     println!("{s}");
+///     // [[6.7082, 2.2361]]
+/// }
+/// ```
+pub fn svd<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+) -> (Tensor<B, D>, Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::linalg_matrix::<D>("SVD"));
+
+    let [rows, cols] = [tensor.dims()[D - 2], tensor.dims()[D - 1]];
+
+    if rows < cols {
+        let (u, s, vh) = svd(tensor.transpose());
+        return (vh.transpose(), s, u.transpose());
+    }
+
+    let gram = tensor.clone().transpose().matmul(tensor.clone());
+    let (eigenvalues, v) = eigh(gram);
+
+    // The eigenvalues are sorted in ascending order.
+    let axis = D as isize - 1;
+    let s = eigenvalues.flip([axis]).clamp_min(0.0).sqrt();
+    let v = v.flip([axis]);
+
+    // Avoid dividing by zero for the left singular vectors of rank-deficient matrices.
+    let divisor = s.clone().mask_fill(s.clone().equal_elem(0.0), 1.0);
+    let u = tensor.matmul(v.clone()).div(divisor);
+
+    (u, s, v.transpose())
+}
@@ -23,6 +23,9 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// The linear algebra module.
+pub mod linalg;
+
 /// The loss module.
 pub mod loss;
 
@@ -35,6 +38,9 @@ pub mod ops;
 /// Tensor quantization module.
 pub mod quantization;
 
+/// The signal processing module.
+pub mod signal;
+
 #[cfg(feature = "std")]
 pub use report::*;
 
@@ -1,9 +1,9 @@
 use crate::{
-    Int, Tensor, TensorPrimitive,
+    Bool, Int, Tensor, TensorPrimitive,
     backend::Backend,
     check,
     check::TensorCheck,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
+    ops::{AttentionOptions, ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
 };
 
 use super::ops::DeformConvOptions;
@@ -357,3 +357,36 @@ where
         options,
     )))
 }
+
+/// Applies a [scaled dot-product attention](crate::ops::ModuleOps::attention).
+///
+/// The mask, where `true` masks the key, is broadcast to
+/// `[batch_size, num_heads, seq_length_q, seq_length_k]` one block of keys at a time, so a padding
+/// mask can be given as `[batch_size, 1, 1, seq_length_k]` without being expanded. The attention
+/// weights are only dropped when autodiff is enabled, as with the dropout module.
+pub fn scaled_dot_product_attention<B>(
+    query: Tensor<B, 4>,
+    key: Tensor<B, 4>,
+    value: Tensor<B, 4>,
+    mask: Option<Tensor<B, 4, Bool>>,
+    options: AttentionOptions,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::attention(
+        "scaled_dot_product_attention",
+        query.dims(),
+        key.dims(),
+        value.dims(),
+        mask.as_ref().map(Tensor::dims),
+    ));
+
+    Tensor::new(TensorPrimitive::Float(B::attention(
+        query.primitive.tensor(),
+        key.primitive.tensor(),
+        value.primitive.tensor(),
+        mask.map(|mask| mask.primitive),
+        options,
+    )))
+}
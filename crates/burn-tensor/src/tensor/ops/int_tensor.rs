@@ -8,7 +8,10 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{TensorMetadata, argsort, sort, sort_with_indices};
+use crate::{
+    TensorMetadata, argsort, cummax_with_indices, cummin_with_indices, cumprod, cumsum, sort,
+    sort_with_indices,
+};
 
 /// Int Tensor API for basic and numeric operations, see [tensor](crate::Tensor)
 /// for documentation on each function.
@@ -1186,6 +1189,68 @@ pub trait IntTensorOps<B: Backend> {
         argsort::<B, Int>(tensor, dim, descending)
     }
 
+    /// Computes the cumulative sum of the elements of `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of all
+    /// the previous elements (inclusive) along `dim`.
+    fn int_cumsum(tensor: IntTensor<B>, dim: usize) -> IntTensor<B> {
+        cumsum::<B, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative product of the elements of `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of all
+    /// the previous elements (inclusive) along `dim`.
+    fn int_cumprod(tensor: IntTensor<B>, dim: usize) -> IntTensor<B> {
+        cumprod::<B, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative maximum of the elements of `tensor` along a given dimension.
+    ///
+    /// When the same value appears several times, the index of the last occurrence is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running maximum values of `tensor` along `dim` and their indices.
+    fn int_cummax_with_indices(tensor: IntTensor<B>, dim: usize) -> (IntTensor<B>, IntTensor<B>) {
+        cummax_with_indices::<B, Int>(tensor, dim)
+    }
+
+    /// Computes the cumulative minimum of the elements of `tensor` along a given dimension.
+    ///
+    /// When the same value appears several times, the index of the last occurrence is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running minimum values of `tensor` along `dim` and their indices.
+    fn int_cummin_with_indices(tensor: IntTensor<B>, dim: usize) -> (IntTensor<B>, IntTensor<B>) {
+        cummin_with_indices::<B, Int>(tensor, dim)
+    }
+
     /// Bitwise AND operation for Int Tensors
     fn bitwise_and(lhs: IntTensor<B>, rhs: IntTensor<B>) -> IntTensor<B>;
 
@@ -0,0 +1,341 @@
+use super::{AttentionBackward, AttentionOptions};
+use crate::{
+    Bool, Distribution, Int, Tensor, TensorMetadata, TensorPrimitive,
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor},
+};
+use alloc::vec::Vec;
+
+/// The number of keys processed at once by the tiled attention, which bounds the attention
+/// weights kept in memory to `[batch_size, num_heads, seq_length_q, BLOCK_SIZE]`.
+const BLOCK_SIZE: usize = 64;
+
+/// Calculate the [attention](crate::ops::ModuleOps::attention) one block of keys at a time,
+/// using an online softmax so the attention weights of all the keys are never materialized.
+///
+/// The attention weights are dropped with the probability of the options, whether the backend is
+/// used for training or not.
+pub fn attention_tiled<B: Backend>(
+    query: FloatTensor<B>,
+    key: FloatTensor<B>,
+    value: FloatTensor<B>,
+    mask: Option<BoolTensor<B>>,
+    options: &AttentionOptions,
+) -> FloatTensor<B> {
+    let shape = output_shape::<B>(&query, &value);
+    let inputs = AttentionInputs::<B>::new(query, key, value, mask, options);
+    let [batch_size, num_kv_heads, rows, _] = inputs.query.dims();
+    let [_, _, seq_length_k, d_v] = inputs.value.dims();
+    let device = inputs.query.device();
+
+    let mut max = Tensor::<B, 4>::full(
+        [batch_size, num_kv_heads, rows, 1],
+        f32::NEG_INFINITY,
+        &device,
+    );
+    let mut sum = Tensor::<B, 4>::zeros([batch_size, num_kv_heads, rows, 1], &device);
+    let mut output = Tensor::<B, 4>::zeros([batch_size, num_kv_heads, rows, d_v], &device);
+
+    for start in (0..seq_length_k).step_by(BLOCK_SIZE) {
+        let end = usize::min(start + BLOCK_SIZE, seq_length_k);
+        let scores = inputs.scores(start, end);
+
+        let max_block = max.clone().max_pair(scores.clone().max_dim(3));
+        let max_finite = finite_or_zero(max_block.clone());
+        // Rescale the previous blocks to the new maximum.
+        let correction = (max - max_finite.clone()).exp();
+        let weights = (scores - max_finite).exp();
+
+        sum = sum * correction.clone() + weights.clone().sum_dim(3);
+        let weights = dropout(weights, options.dropout);
+        output = output * correction + weights.matmul(inputs.value_block(start, end));
+        max = max_block;
+    }
+
+    // The rows with all their keys masked output zeros.
+    let sum = sum.clone().mask_fill(sum.equal_elem(0.0), 1.0);
+    (output / sum).reshape(shape).into_primitive().tensor()
+}
+
+/// Calculate the [attention](crate::ops::ModuleOps::attention) backward pass one block of keys
+/// at a time, recomputing the attention weights from the log-sum-exp of the scores.
+pub(crate) fn attention_tiled_backward<B: Backend>(
+    query: FloatTensor<B>,
+    key: FloatTensor<B>,
+    value: FloatTensor<B>,
+    mask: Option<BoolTensor<B>>,
+    output: FloatTensor<B>,
+    output_grad: FloatTensor<B>,
+    options: &AttentionOptions,
+) -> AttentionBackward<B> {
+    let shape_q: [usize; 4] = query.shape().dims();
+    let shape_k: [usize; 4] = key.shape().dims();
+    let shape_v: [usize; 4] = value.shape().dims();
+    let inputs = AttentionInputs::<B>::new(query, key, value, mask, options);
+    let [batch_size, num_kv_heads, rows, _] = inputs.query.dims();
+    let [_, _, seq_length_k, d_v] = inputs.value.dims();
+    let shape_grouped = [batch_size, num_kv_heads, rows, d_v];
+
+    let output =
+        Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(output)).reshape(shape_grouped);
+    let output_grad =
+        Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(output_grad)).reshape(shape_grouped);
+
+    let log_sum_exp = inputs.log_sum_exp();
+    let delta = (output_grad.clone() * output).sum_dim(3);
+
+    let mut query_grad = inputs.query.zeros_like();
+    let mut key_grads = Vec::new();
+    let mut value_grads = Vec::new();
+
+    for start in (0..seq_length_k).step_by(BLOCK_SIZE) {
+        let end = usize::min(start + BLOCK_SIZE, seq_length_k);
+        let weights = (inputs.scores(start, end) - log_sum_exp.clone()).exp();
+
+        value_grads.push(weights.clone().transpose().matmul(output_grad.clone()));
+
+        let weights_grad = output_grad
+            .clone()
+            .matmul(inputs.value_block(start, end).transpose());
+        let scores_grad = (weights * (weights_grad - delta.clone())).mul_scalar(inputs.scale);
+
+        query_grad = query_grad + scores_grad.clone().matmul(inputs.key_block(start, end));
+        key_grads.push(scores_grad.transpose().matmul(inputs.query.clone()));
+    }
+
+    AttentionBackward::new(
+        query_grad.reshape(shape_q).into_primitive().tensor(),
+        Tensor::cat(key_grads, 2)
+            .reshape(shape_k)
+            .into_primitive()
+            .tensor(),
+        Tensor::cat(value_grads, 2)
+            .reshape(shape_v)
+            .into_primitive()
+            .tensor(),
+    )
+}
+
+/// Calculate the [attention](crate::ops::ModuleOps::attention) by materializing the attention
+/// weights of all the keys at once.
+///
+/// It only relies on differentiable operations, so it can be used to differentiate through the
+/// dropout of the attention weights, which the tiled backward pass can't replay. The memory usage
+/// is thus proportional to `batch_size * num_heads * seq_length_q * seq_length_k`.
+pub fn attention_decomposed<B: Backend>(
+    query: FloatTensor<B>,
+    key: FloatTensor<B>,
+    value: FloatTensor<B>,
+    mask: Option<BoolTensor<B>>,
+    options: &AttentionOptions,
+) -> FloatTensor<B> {
+    let shape = output_shape::<B>(&query, &value);
+    let inputs = AttentionInputs::<B>::new(query, key, value, mask, options);
+    let [_, _, seq_length_k, _] = inputs.key.dims();
+
+    let scores = inputs.scores(0, seq_length_k);
+    let max = finite_or_zero(scores.clone().max_dim(3).detach());
+    let weights = (scores - max).exp();
+    let sum = weights.clone().sum_dim(3);
+    let weights = weights / sum.clone().mask_fill(sum.equal_elem(0.0), 1.0);
+    let weights = dropout(weights, options.dropout);
+
+    weights
+        .matmul(inputs.value)
+        .reshape(shape)
+        .into_primitive()
+        .tensor()
+}
+
+/// The inputs of the attention, with the query heads grouped by key-value head so grouped-query
+/// attention doesn't require repeating the keys and values.
+struct AttentionInputs<B: Backend> {
+    /// `[batch_size, num_kv_heads, num_groups * seq_length_q, d_k]`
+    query: Tensor<B, 4>,
+    /// `[batch_size, num_kv_heads, seq_length_k, d_k]`
+    key: Tensor<B, 4>,
+    /// `[batch_size, num_kv_heads, seq_length_k, d_v]`
+    value: Tensor<B, 4>,
+    /// Broadcastable to `[batch_size, num_heads, seq_length_q, seq_length_k]`, and only expanded
+    /// one block of keys at a time.
+    mask: Option<Tensor<B, 4, Bool>>,
+    num_heads: usize,
+    seq_length_q: usize,
+    /// The position of each query among the keys, `[num_groups * seq_length_q, 1]`, when causal.
+    positions: Option<Tensor<B, 2, Int>>,
+    scale: f64,
+}
+
+impl<B: Backend> AttentionInputs<B> {
+    fn new(
+        query: FloatTensor<B>,
+        key: FloatTensor<B>,
+        value: FloatTensor<B>,
+        mask: Option<BoolTensor<B>>,
+        options: &AttentionOptions,
+    ) -> Self {
+        let query = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(query));
+        let key = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(key));
+        let value = Tensor::<B, 4>::from_primitive(TensorPrimitive::Float(value));
+
+        let [batch_size, num_heads, seq_length_q, d_k] = query.dims();
+        let [_, num_kv_heads, seq_length_k, _] = key.dims();
+        let num_groups = num_heads / num_kv_heads;
+        let rows = num_groups * seq_length_q;
+
+        // The heads sharing a key-value head are contiguous, so grouping them is a reshape.
+        let query = query.reshape([batch_size, num_kv_heads, rows, d_k]);
+        let mask = mask.map(Tensor::<B, 4, Bool>::from_primitive);
+
+        // The queries are aligned with the last keys, so a query can attend to the keys cached
+        // before it when decoding.
+        let positions = options.causal.then(|| {
+            let offset = seq_length_k as i64 - seq_length_q as i64;
+            Tensor::<B, 1, Int>::arange(offset..offset + seq_length_q as i64, &query.device())
+                .reshape([1, seq_length_q])
+                .expand([num_groups, seq_length_q])
+                .reshape([rows, 1])
+        });
+
+        let scale = options
+            .scale
+            .unwrap_or_else(|| 1.0 / num_traits::Float::sqrt(d_k as f64));
+
+        Self {
+            query,
+            key,
+            value,
+            mask,
+            num_heads,
+            seq_length_q,
+            positions,
+            scale,
+        }
+    }
+
+    /// The scaled scores of the keys in `start..end`, set to negative infinity when masked.
+    fn scores(&self, start: usize, end: usize) -> Tensor<B, 4> {
+        let [batch_size, num_kv_heads, rows, _] = self.query.dims();
+        let shape = [batch_size, num_kv_heads, rows, end - start];
+
+        let scores = self
+            .query
+            .clone()
+            .matmul(self.key_block(start, end).transpose())
+            .mul_scalar(self.scale);
+
+        let mut mask = self
+            .mask
+            .as_ref()
+            .map(|mask| self.mask_block(mask, start, end));
+
+        if let Some(positions) = &self.positions {
+            let keys = Tensor::<B, 1, Int>::arange(start as i64..end as i64, &positions.device())
+                .reshape([1, end - start])
+                .expand([rows, end - start]);
+            let causal = keys
+                .greater(positions.clone().expand([rows, end - start]))
+                .unsqueeze::<4>()
+                .expand(shape);
+
+            mask = Some(match mask {
+                Some(mask) => mask.bool_or(causal),
+                None => causal,
+            });
+        }
+
+        match mask {
+            Some(mask) => scores.mask_fill(mask, f32::NEG_INFINITY),
+            None => scores,
+        }
+    }
+
+    /// The log-sum-exp of the scores of every query, set to infinity when all the keys are
+    /// masked so the recomputed attention weights are zeros.
+    fn log_sum_exp(&self) -> Tensor<B, 4> {
+        let [batch_size, num_kv_heads, rows, _] = self.query.dims();
+        let [_, _, seq_length_k, _] = self.key.dims();
+        let device = self.query.device();
+
+        let mut max = Tensor::<B, 4>::full(
+            [batch_size, num_kv_heads, rows, 1],
+            f32::NEG_INFINITY,
+            &device,
+        );
+        let mut sum = Tensor::<B, 4>::zeros([batch_size, num_kv_heads, rows, 1], &device);
+
+        for start in (0..seq_length_k).step_by(BLOCK_SIZE) {
+            let end = usize::min(start + BLOCK_SIZE, seq_length_k);
+            let scores = self.scores(start, end);
+
+            let max_block = max.clone().max_pair(scores.clone().max_dim(3));
+            let max_finite = finite_or_zero(max_block.clone());
+            let correction = (max - max_finite.clone()).exp();
+
+            sum = sum * correction + (scores - max_finite).exp().sum_dim(3);
+            max = max_block;
+        }
+
+        let empty = sum.clone().equal_elem(0.0);
+        (finite_or_zero(max) + sum.log()).mask_fill(empty, f32::INFINITY)
+    }
+
+    /// The mask of the keys in `start..end`, with the query heads grouped by key-value head.
+    fn mask_block(
+        &self,
+        mask: &Tensor<B, 4, Bool>,
+        start: usize,
+        end: usize,
+    ) -> Tensor<B, 4, Bool> {
+        let [batch_size, num_kv_heads, rows, _] = self.query.dims();
+        let [mask_batch_size, mask_num_heads, mask_seq_length_q, _] = mask.dims();
+
+        mask.clone()
+            .slice([
+                0..mask_batch_size,
+                0..mask_num_heads,
+                0..mask_seq_length_q,
+                start..end,
+            ])
+            .expand([batch_size, self.num_heads, self.seq_length_q, end - start])
+            .reshape([batch_size, num_kv_heads, rows, end - start])
+    }
+
+    fn key_block(&self, start: usize, end: usize) -> Tensor<B, 4> {
+        let [batch_size, num_kv_heads, _, d_k] = self.key.dims();
+        self.key
+            .clone()
+            .slice([0..batch_size, 0..num_kv_heads, start..end, 0..d_k])
+    }
+
+    fn value_block(&self, start: usize, end: usize) -> Tensor<B, 4> {
+        let [batch_size, num_kv_heads, _, d_v] = self.value.dims();
+        self.value
+            .clone()
+            .slice([0..batch_size, 0..num_kv_heads, start..end, 0..d_v])
+    }
+}
+
+fn output_shape<B: Backend>(query: &FloatTensor<B>, value: &FloatTensor<B>) -> [usize; 4] {
+    let [batch_size, num_heads, seq_length_q, _] = query.shape().dims();
+    let [_, _, _, d_v] = value.shape().dims();
+    [batch_size, num_heads, seq_length_q, d_v]
+}
+
+/// Replace the negative infinity of the rows with all their keys masked, which would otherwise
+/// produce NaNs when subtracted.
+fn finite_or_zero<B: Backend>(tensor: Tensor<B, 4>) -> Tensor<B, 4> {
+    tensor
+        .clone()
+        .mask_fill(tensor.equal_elem(f32::NEG_INFINITY), 0.0)
+}
+
+fn dropout<B: Backend>(weights: Tensor<B, 4>, prob: f64) -> Tensor<B, 4> {
+    if prob == 0.0 {
+        return weights;
+    }
+
+    let prob_keep = 1.0 - prob;
+    let keep = weights.random_like(Distribution::Bernoulli(prob_keep));
+    (weights * keep).div_scalar(prob_keep)
+}
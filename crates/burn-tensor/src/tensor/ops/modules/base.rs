@@ -1,10 +1,10 @@
 use core::num::NonZeroUsize;
 
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{attention, conv, pool, unfold::unfold4d_using_conv2d};
 use crate::{
     Shape, TensorMetadata,
     backend::Backend,
-    ops::{FloatTensor, IntTensor},
+    ops::{BoolTensor, FloatTensor, IntTensor},
 };
 
 /// Gradient computed during the backward pass for each tensor used by [conv2d](ModuleOps::conv2d).
@@ -86,6 +86,19 @@ pub struct MaxPool2dWithIndices<B: Backend> {
     pub indices: IntTensor<B>,
 }
 
+/// Gradient computed during the backward pass for each tensor used by [attention](ModuleOps::attention).
+#[derive(new)]
+pub struct AttentionBackward<B: Backend> {
+    /// Query gradient.
+    pub query_grad: FloatTensor<B>,
+
+    /// Key gradient.
+    pub key_grad: FloatTensor<B>,
+
+    /// Value gradient.
+    pub value_grad: FloatTensor<B>,
+}
+
 /// Check that the parameter value is non-zero.
 // NOTE: for now we keep usize but we could refactor the parameters to hold `NonZeroUsize`.
 pub(crate) fn check_nonzero(value: usize, msg: &str) -> usize {
@@ -93,6 +106,42 @@ pub(crate) fn check_nonzero(value: usize, msg: &str) -> usize {
     value
 }
 
+/// Attention options.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttentionOptions {
+    /// Scale of the attention scores, `1 / sqrt(d_k)` when `None`.
+    pub scale: Option<f64>,
+
+    /// Whether each query only attends to the keys up to its position. The queries are aligned
+    /// with the last keys, so a single query attends to all the keys.
+    pub causal: bool,
+
+    /// Dropout probability of the attention weights, in `[0, 1)`.
+    pub dropout: f64,
+}
+
+impl AttentionOptions {
+    /// Constructs a new `AttentionOptions`.
+    pub fn new(scale: Option<f64>, causal: bool, dropout: f64) -> Self {
+        assert!(
+            (0.0..1.0).contains(&dropout),
+            "dropout must be at least 0 and less than 1"
+        );
+
+        Self {
+            scale,
+            causal,
+            dropout,
+        }
+    }
+}
+
+impl Default for AttentionOptions {
+    fn default() -> Self {
+        Self::new(None, false, 0.0)
+    }
+}
+
 /// Convolution options.
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ConvOptions<const N: usize> {
@@ -746,6 +795,67 @@ pub trait ModuleOps<B: Backend> {
         indices: IntTensor<B>,
     ) -> MaxPool2dBackward<B>;
 
+    /// Scaled dot-product attention, `softmax(query @ key^T * scale) @ value`.
+    ///
+    /// # Shapes
+    ///
+    /// query: `[batch_size, num_heads, seq_length_q, d_k]`,
+    /// key: `[batch_size, num_kv_heads, seq_length_k, d_k]`,
+    /// value: `[batch_size, num_kv_heads, seq_length_k, d_v]`,
+    /// mask: broadcastable to `[batch_size, num_heads, seq_length_q, seq_length_k]`, where `true`
+    /// masks the key,
+    ///
+    /// The number of heads must be a multiple of the number of key-value heads, each key-value
+    /// head being shared by consecutive query heads (grouped-query attention).
+    ///
+    /// The default implementation processes the keys in blocks with an online softmax, so neither
+    /// the attention weights nor the mask of all the keys are ever materialized. The rows with all
+    /// their keys masked are filled with zeros. As with the dropout module, the attention weights
+    /// are only dropped when the backend is used for training, i.e. when autodiff is enabled.
+    fn attention(
+        query: FloatTensor<B>,
+        key: FloatTensor<B>,
+        value: FloatTensor<B>,
+        mask: Option<BoolTensor<B>>,
+        options: AttentionOptions,
+    ) -> FloatTensor<B> {
+        let options = match B::ad_enabled() {
+            true => options,
+            false => AttentionOptions {
+                dropout: 0.0,
+                ..options
+            },
+        };
+
+        attention::attention_tiled::<B>(query, key, value, mask, &options)
+    }
+
+    /// Backward pass for the [attention](ModuleOps::attention) operation.
+    ///
+    /// The dropout of the options is ignored, since the dropped weights can't be replayed. The
+    /// autodiff backend thus differentiates the attention with dropout through the
+    /// [decomposed attention](attention::attention_decomposed) instead, which materializes the
+    /// attention weights of all the keys.
+    fn attention_backward(
+        query: FloatTensor<B>,
+        key: FloatTensor<B>,
+        value: FloatTensor<B>,
+        mask: Option<BoolTensor<B>>,
+        output: FloatTensor<B>,
+        output_grad: FloatTensor<B>,
+        options: AttentionOptions,
+    ) -> AttentionBackward<B> {
+        attention::attention_tiled_backward::<B>(
+            query,
+            key,
+            value,
+            mask,
+            output,
+            output_grad,
+            &options,
+        )
+    }
+
     /// Down/up samples the input.
     ///
     /// # Shapes
@@ -1446,4 +1446,88 @@ pub trait QTensorOps<B: Backend> {
         let tensor_f = Self::dequantize(tensor);
         B::float_argsort(tensor_f, dim, descending)
     }
+
+    /// Computes the cumulative sum of the elements of `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of all
+    /// the previous elements (inclusive) along `dim`.
+    fn q_cumsum(tensor: QuantizedTensor<B>, dim: usize) -> QuantizedTensor<B> {
+        dequant_op_quant!(
+            ty Self,
+            float_op |tensor| B::float_cumsum(tensor, dim),
+            tensor
+        )
+    }
+
+    /// Computes the cumulative product of the elements of `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of all
+    /// the previous elements (inclusive) along `dim`.
+    fn q_cumprod(tensor: QuantizedTensor<B>, dim: usize) -> QuantizedTensor<B> {
+        dequant_op_quant!(
+            ty Self,
+            float_op |tensor| B::float_cumprod(tensor, dim),
+            tensor
+        )
+    }
+
+    /// Computes the cumulative maximum of the elements of `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running maximum values of `tensor` along `dim` and their indices.
+    fn q_cummax_with_indices(
+        tensor: QuantizedTensor<B>,
+        dim: usize,
+    ) -> (QuantizedTensor<B>, IntTensor<B>) {
+        // Default implementation. Backends can scan the int values since qparams remain the same.
+        let scheme = *tensor.scheme();
+
+        let tensor_f = Self::dequantize(tensor);
+        let (out_f, indices) = B::float_cummax_with_indices(tensor_f, dim);
+
+        (Self::quantize_dynamic(out_f, &scheme), indices)
+    }
+
+    /// Computes the cumulative minimum of the elements of `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running minimum values of `tensor` along `dim` and their indices.
+    fn q_cummin_with_indices(
+        tensor: QuantizedTensor<B>,
+        dim: usize,
+    ) -> (QuantizedTensor<B>, IntTensor<B>) {
+        // Default implementation. Backends can scan the int values since qparams remain the same.
+        let scheme = *tensor.scheme();
+
+        let tensor_f = Self::dequantize(tensor);
+        let (out_f, indices) = B::float_cummin_with_indices(tensor_f, dim);
+
+        (Self::quantize_dynamic(out_f, &scheme), indices)
+    }
 }
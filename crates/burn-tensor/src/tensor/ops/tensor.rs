@@ -11,7 +11,9 @@ use alloc::vec::Vec;
 use core::future::Future;
 use core::ops::Range;
 
-use crate::{argsort, sort, sort_with_indices};
+use crate::{
+    argsort, cummax_with_indices, cummin_with_indices, cumprod, cumsum, sort, sort_with_indices,
+};
 
 /// Operations on float tensors.
 pub trait FloatTensorOps<B: Backend> {
@@ -1401,4 +1403,76 @@ pub trait FloatTensorOps<B: Backend> {
     fn float_argsort(tensor: FloatTensor<B>, dim: usize, descending: bool) -> IntTensor<B> {
         argsort::<B, Float>(TensorPrimitive::Float(tensor), dim, descending)
     }
+
+    /// Computes the cumulative sum of the elements of `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the sum of all
+    /// the previous elements (inclusive) along `dim`.
+    fn float_cumsum(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        cumsum::<B, Float>(TensorPrimitive::Float(tensor), dim).tensor()
+    }
+
+    /// Computes the cumulative product of the elements of `tensor` along a given dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor, where each element is the product of all
+    /// the previous elements (inclusive) along `dim`.
+    fn float_cumprod(tensor: FloatTensor<B>, dim: usize) -> FloatTensor<B> {
+        cumprod::<B, Float>(TensorPrimitive::Float(tensor), dim).tensor()
+    }
+
+    /// Computes the cumulative maximum of the elements of `tensor` along a given dimension.
+    ///
+    /// When the same value appears several times, the index of the last occurrence is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running maximum values of `tensor` along `dim` and their indices.
+    fn float_cummax_with_indices(
+        tensor: FloatTensor<B>,
+        dim: usize,
+    ) -> (FloatTensor<B>, IntTensor<B>) {
+        let (values, indices) =
+            cummax_with_indices::<B, Float>(TensorPrimitive::Float(tensor), dim);
+        (values.tensor(), indices)
+    }
+
+    /// Computes the cumulative minimum of the elements of `tensor` along a given dimension.
+    ///
+    /// When the same value appears several times, the index of the last occurrence is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The axis along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the running minimum values of `tensor` along `dim` and their indices.
+    fn float_cummin_with_indices(
+        tensor: FloatTensor<B>,
+        dim: usize,
+    ) -> (FloatTensor<B>, IntTensor<B>) {
+        let (values, indices) =
+            cummin_with_indices::<B, Float>(TensorPrimitive::Float(tensor), dim);
+        (values.tensor(), indices)
+    }
 }
@@ -0,0 +1,180 @@
+use core::f64::consts::PI;
+
+use crate::{Int, Tensor, TensorData, backend::Backend};
+use alloc::{vec, vec::Vec};
+use num_traits::Float;
+
+/// Computes the discrete Fourier transform of the complex signals stored along `dim`, or its
+/// unnormalized inverse when `inverse` is true.
+///
+/// Lengths that are powers of two use a radix-2 FFT, and the other lengths use Bluestein's
+/// algorithm, which turns the transform into a convolution computed with radix-2 FFTs. Both cost
+/// `O(n log n)` operations and are expressed with tensor primitives, which keeps them
+/// differentiable and running on every backend.
+pub(crate) fn fft_dim<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+    inverse: bool,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    let dims = re.dims();
+    let n = dims[dim];
+    if n <= 1 {
+        return (re, im);
+    }
+
+    let mut swapped = dims;
+    swapped.swap(dim, D - 1);
+    let rows = swapped[..D - 1].iter().product::<usize>();
+    let [re, im] = [re, im].map(|tensor| tensor.swap_dims(dim, D - 1).reshape([rows, n]));
+
+    // The inverse transform is the conjugate of the transform of the conjugate.
+    let im = if inverse { im.neg() } else { im };
+    let (re, im) = match n.is_power_of_two() {
+        true => radix2(re, im),
+        false => bluestein(re, im),
+    };
+    let im = if inverse { im.neg() } else { im };
+
+    let [re, im] = [re, im].map(|tensor| tensor.reshape(swapped).swap_dims(dim, D - 1));
+    (re, im)
+}
+
+/// Iterative radix-2 FFT of the rows of `[rows, n]` tensors, with `n` a power of two.
+fn radix2<B: Backend>(re: Tensor<B, 2>, im: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let [rows, n] = re.dims();
+    let device = re.device();
+
+    // The butterflies combine the transforms of the even and odd samples, which end up next to
+    // each other once the samples are in bit-reversed order.
+    let bits = n.trailing_zeros();
+    let order = (0..n)
+        .map(|i| (i.reverse_bits() >> (usize::BITS - bits)) as i64)
+        .collect::<Vec<_>>();
+    let order = Tensor::<B, 1, Int>::from_data(TensorData::new(order, [n]), &device);
+    let mut re = re.select(1, order.clone());
+    let mut im = im.select(1, order);
+
+    let mut half = 1;
+    while half < n {
+        let size = 2 * half;
+        let (w_re, w_im) = unit_roots(size, half);
+        let w_re = Tensor::<B, 4>::from_data(TensorData::new(w_re, [1, 1, 1, half]), &device);
+        let w_im = Tensor::<B, 4>::from_data(TensorData::new(w_im, [1, 1, 1, half]), &device);
+
+        let shape = [rows, n / size, 2, half];
+        let [re_4, im_4] = [re, im].map(|tensor| tensor.reshape(shape));
+        let (a_re, b_re) = (re_4.clone().narrow(2, 0, 1), re_4.narrow(2, 1, 1));
+        let (a_im, b_im) = (im_4.clone().narrow(2, 0, 1), im_4.narrow(2, 1, 1));
+
+        let t_re = b_re
+            .clone()
+            .mul(w_re.clone())
+            .sub(b_im.clone().mul(w_im.clone()));
+        let t_im = b_re.mul(w_im).add(b_im.mul(w_re));
+
+        re =
+            Tensor::cat(vec![a_re.clone().add(t_re.clone()), a_re.sub(t_re)], 2).reshape([rows, n]);
+        im =
+            Tensor::cat(vec![a_im.clone().add(t_im.clone()), a_im.sub(t_im)], 2).reshape([rows, n]);
+        half = size;
+    }
+
+    (re, im)
+}
+
+/// Bluestein's FFT of the rows of `[rows, n]` tensors, for any `n`.
+///
+/// With `mk = (m² + k² - (k - m)²) / 2`, the transform becomes `X_k = c_k Σ_m (x_m c_m)
+/// conj(c_{k - m})` with the chirp `c_m = exp(-iπm²/n)`, a convolution computed with radix-2
+/// FFTs of a padded length.
+fn bluestein<B: Backend>(re: Tensor<B, 2>, im: Tensor<B, 2>) -> (Tensor<B, 2>, Tensor<B, 2>) {
+    let [rows, n] = re.dims();
+    let device = re.device();
+    let size = (2 * n - 1).next_power_of_two();
+
+    // Reduce `m²` modulo `2n` before the conversion so that large transforms keep accurate
+    // angles.
+    let angles = (0..n)
+        .map(|m| PI * ((m as u128 * m as u128) % (2 * n as u128)) as f64 / n as f64)
+        .collect::<Vec<_>>();
+    let c_re = angles
+        .iter()
+        .map(|&a| Float::cos(a) as f32)
+        .collect::<Vec<_>>();
+    let c_im = angles
+        .iter()
+        .map(|&a| -Float::sin(a) as f32)
+        .collect::<Vec<_>>();
+
+    // The conjugate chirp for the offsets `-(n - 1)..n`, wrapped around the padded length.
+    let mut b_re = vec![0.0; size];
+    let mut b_im = vec![0.0; size];
+    for m in 0..n {
+        for index in [m, (size - m) % size] {
+            b_re[index] = c_re[m];
+            b_im[index] = -c_im[m];
+        }
+    }
+    let b_re = Tensor::<B, 2>::from_data(TensorData::new(b_re, [1, size]), &device);
+    let b_im = Tensor::<B, 2>::from_data(TensorData::new(b_im, [1, size]), &device);
+    let (b_re, b_im) = radix2(b_re, b_im);
+
+    let c_re = Tensor::<B, 2>::from_data(TensorData::new(c_re, [1, n]), &device);
+    let c_im = Tensor::<B, 2>::from_data(TensorData::new(c_im, [1, n]), &device);
+
+    let padding = Tensor::<B, 2>::zeros([rows, size - n], &device);
+    let a_re = re
+        .clone()
+        .mul(c_re.clone())
+        .sub(im.clone().mul(c_im.clone()));
+    let a_im = re.mul(c_im.clone()).add(im.mul(c_re.clone()));
+    let a_re = Tensor::cat(vec![a_re, padding.clone()], 1);
+    let a_im = Tensor::cat(vec![a_im, padding], 1);
+    let (a_re, a_im) = radix2(a_re, a_im);
+
+    // Inverse transform of the product, as the conjugate of the transform of the conjugate.
+    let p_re = a_re
+        .clone()
+        .mul(b_re.clone())
+        .sub(a_im.clone().mul(b_im.clone()));
+    let p_im = a_re.mul(b_im).add(a_im.mul(b_re));
+    let (conv_re, conv_im) = radix2(p_re, p_im.neg());
+    let scale = 1.0 / size as f64;
+    let conv_re = conv_re.narrow(1, 0, n).mul_scalar(scale);
+    let conv_im = conv_im.narrow(1, 0, n).mul_scalar(-scale);
+
+    let re = conv_re
+        .clone()
+        .mul(c_re.clone())
+        .sub(conv_im.clone().mul(c_im.clone()));
+    let im = conv_re.mul(c_im).add(conv_im.mul(c_re));
+
+    (re, im)
+}
+
+/// The cosine and sine of the angles `-2πk / n` for the `count` first `k`.
+fn unit_roots(n: usize, count: usize) -> (Vec<f32>, Vec<f32>) {
+    (0..count)
+        .map(|k| {
+            let angle = -2.0 * PI * k as f64 / n as f64;
+            (Float::cos(angle) as f32, Float::sin(angle) as f32)
+        })
+        .unzip()
+}
+
+/// Indices of the samples of every frame, frame after frame, as a flat tensor.
+pub(crate) fn frame_indices<B: Backend>(
+    num_frames: usize,
+    frame_length: usize,
+    hop_length: usize,
+    device: &B::Device,
+) -> Tensor<B, 1, Int> {
+    let starts =
+        Tensor::<B, 1, Int>::arange_step(0..(num_frames * hop_length) as i64, hop_length, device)
+            .reshape([num_frames, 1]);
+    let offsets =
+        Tensor::<B, 1, Int>::arange(0..frame_length as i64, device).reshape([1, frame_length]);
+
+    starts.add(offsets).reshape([num_frames * frame_length])
+}
@@ -0,0 +1,176 @@
+use super::base::fft_dim;
+use crate::{Tensor, backend::Backend, check, check::TensorCheck};
+use alloc::vec;
+
+/// Computes the discrete Fourier transform of a complex signal along the given dimension.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the signal.
+/// * `im` - The imaginary part of the signal, with the same shape as `re`.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the spectrum, with the same shape as the signal.
+///
+/// # Notes
+///
+/// The transform is computed with a radix-2 FFT, or with Bluestein's algorithm when the length
+/// isn't a power of two, so it costs `O(n log n)` operations per signal of length `n`. It is
+/// differentiable and runs on every backend.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, signal};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let re = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+///     let im = re.zeros_like();
+///     let (re, im) = signal::fft(re, im, 0);
+///     println!("{re}\n{im}");
+///     // [10.0, -2.0, -2.0, -2.0]
+///     // [0.0, 2.0, 0.0, -2.0]
+/// }
+/// ```
+pub fn fft<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft::<D>("FFT", dim, &re.shape(), &im.shape()));
+
+    fft_dim(re, im, dim, false)
+}
+
+/// Computes the inverse discrete Fourier transform of a complex spectrum along the given
+/// dimension.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the spectrum.
+/// * `im` - The imaginary part of the spectrum, with the same shape as `re`.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the signal, normalized by `1 / n` so that
+/// `ifft(fft(x)) == x`.
+pub fn ifft<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::fft::<D>("IFFT", dim, &re.shape(), &im.shape()));
+
+    let scale = 1.0 / re.dims()[dim] as f64;
+    let (re, im) = fft_dim(re, im, dim, true);
+
+    (re.mul_scalar(scale), im.mul_scalar(scale))
+}
+
+/// Computes the discrete Fourier transform of a real signal along the given dimension.
+///
+/// Since the spectrum of a real signal is Hermitian-symmetric, only the `n / 2 + 1`
+/// non-negative frequency bins are returned.
+///
+/// # Arguments
+///
+/// * `signal` - The real signal.
+/// * `dim` - The dimension along which the transform is computed.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the spectrum, with `n / 2 + 1` elements along `dim`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, signal};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let signal = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+///     let (re, im) = signal::rfft(signal, 0);
+///     println!("{re}\n{im}");
+///     // [10.0, -2.0, -2.0]
+///     // [0.0, 2.0, 0.0]
+/// }
+/// ```
+pub fn rfft<B: Backend, const D: usize>(
+    signal: Tensor<B, D>,
+    dim: usize,
+) -> (Tensor<B, D>, Tensor<B, D>) {
+    check!(TensorCheck::dim_ops::<D>("RFFT", dim));
+
+    let bins = signal.dims()[dim] / 2 + 1;
+    let im = signal.zeros_like();
+    let (re, im) = fft_dim(signal, im, dim, false);
+
+    (re.narrow(dim, 0, bins), im.narrow(dim, 0, bins))
+}
+
+/// Computes the inverse of [rfft](rfft), turning the non-negative frequency bins of a
+/// Hermitian-symmetric spectrum back into a real signal.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the spectrum.
+/// * `im` - The imaginary part of the spectrum, with the same shape as `re`.
+/// * `dim` - The dimension along which the transform is computed.
+/// * `length` - The length of the output signal, `2 * (bins - 1)` by default. The spectrum is
+///   truncated or zero-padded to `length / 2 + 1` bins.
+///
+/// # Returns
+///
+/// The real signal, normalized by `1 / length` so that `irfft(rfft(x)) == x`.
+pub fn irfft<B: Backend, const D: usize>(
+    re: Tensor<B, D>,
+    im: Tensor<B, D>,
+    dim: usize,
+    length: Option<usize>,
+) -> Tensor<B, D> {
+    check!(TensorCheck::fft::<D>(
+        "IRFFT",
+        dim,
+        &re.shape(),
+        &im.shape()
+    ));
+
+    let device = re.device();
+    let input_bins = re.dims()[dim];
+    let n = length.unwrap_or(2 * input_bins.saturating_sub(1)).max(1);
+    let bins = n / 2 + 1;
+
+    let [re, im] = [re, im].map(|tensor| match input_bins >= bins {
+        true => tensor.narrow(dim, 0, bins),
+        false => {
+            let mut dims = tensor.dims();
+            dims[dim] = bins - input_bins;
+            Tensor::cat(vec![tensor, Tensor::zeros(dims, &device)], dim)
+        }
+    });
+
+    // The negative frequencies are the conjugates of the mirrored positive ones, except the zero
+    // and Nyquist frequencies.
+    let mirrored = n - bins;
+    let (re, im) = match mirrored > 0 {
+        true => {
+            let re_mirror = re.clone().narrow(dim, 1, mirrored).flip([dim as isize]);
+            let im_mirror = im.clone().narrow(dim, 1, mirrored).flip([dim as isize]);
+            (
+                Tensor::cat(vec![re, re_mirror], dim),
+                Tensor::cat(vec![im, im_mirror.neg()], dim),
+            )
+        }
+        false => (re, im),
+    };
+
+    let (re, _) = fft_dim(re, im, dim, true);
+
+    re.mul_scalar(1.0 / n as f64)
+}
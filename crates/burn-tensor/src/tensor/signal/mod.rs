@@ -0,0 +1,8 @@
+mod base;
+mod fft;
+mod stft;
+mod window;
+
+pub use fft::*;
+pub use stft::*;
+pub use window::*;
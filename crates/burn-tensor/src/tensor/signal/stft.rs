@@ -0,0 +1,120 @@
+use super::base::frame_indices;
+use super::fft::{fft, ifft, irfft, rfft};
+use crate::{Tensor, backend::Backend, check, check::TensorCheck};
+
+/// Computes the short-time Fourier transform of a batch of real signals.
+///
+/// The signals are split into frames of `window.dims()[0]` samples, `hop_length` samples apart,
+/// and every frame is multiplied by the window before its transform is computed. The signals are
+/// not padded, so the number of frames is `(length - frame_length) / hop_length + 1`.
+///
+/// # Arguments
+///
+/// * `signal` - The signals, with shape `[batch, length]`.
+/// * `window` - The window applied to every frame, which also sets the frame length.
+/// * `hop_length` - The number of samples between the starts of two consecutive frames.
+/// * `onesided` - Whether only the `frame_length / 2 + 1` non-negative frequency bins are kept.
+///
+/// # Returns
+///
+/// The real and imaginary parts of the spectrogram, with shape `[batch, frames, bins]`.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{Tensor, signal};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let audio = Tensor::<B, 2>::random([1, 16000], Default::default(), &device);
+///     let window = signal::hann_window::<B>(400, true, &device);
+///     let (re, im) = signal::stft(audio, window, 160, true);
+///     let power = re.powi_scalar(2) + im.powi_scalar(2);
+///     println!("{:?}", power.dims());
+///     // [1, 98, 201]
+/// }
+/// ```
+pub fn stft<B: Backend>(
+    signal: Tensor<B, 2>,
+    window: Tensor<B, 1>,
+    hop_length: usize,
+    onesided: bool,
+) -> (Tensor<B, 3>, Tensor<B, 3>) {
+    let [batch_size, length] = signal.dims();
+    let [frame_length] = window.dims();
+    check!(TensorCheck::stft(length, frame_length, hop_length));
+
+    let num_frames = (length - frame_length) / hop_length + 1;
+    let indices = frame_indices::<B>(num_frames, frame_length, hop_length, &signal.device());
+
+    let frames = signal
+        .select(1, indices)
+        .reshape([batch_size, num_frames, frame_length])
+        .mul(window.reshape([1, 1, frame_length]));
+
+    match onesided {
+        true => rfft(frames, 2),
+        false => {
+            let im = frames.zeros_like();
+            fft(frames, im, 2)
+        }
+    }
+}
+
+/// Computes the inverse of [stft](stft) with the overlap-add method.
+///
+/// # Arguments
+///
+/// * `re` - The real part of the spectrogram, with shape `[batch, frames, bins]`.
+/// * `im` - The imaginary part of the spectrogram, with the same shape as `re`.
+/// * `window` - The window used by the forward transform.
+/// * `hop_length` - The number of samples between the starts of two consecutive frames.
+/// * `onesided` - Whether the spectrogram only holds the non-negative frequency bins.
+///
+/// # Returns
+///
+/// The signals, with shape `[batch, (frames - 1) * hop_length + frame_length]`. Samples covered
+/// by no frame, or only by zeros of the window, are set to zero.
+pub fn istft<B: Backend>(
+    re: Tensor<B, 3>,
+    im: Tensor<B, 3>,
+    window: Tensor<B, 1>,
+    hop_length: usize,
+    onesided: bool,
+) -> Tensor<B, 2> {
+    let [batch_size, num_frames, _] = re.dims();
+    let [frame_length] = window.dims();
+    let length = (num_frames.max(1) - 1) * hop_length + frame_length;
+    check!(TensorCheck::stft(length, frame_length, hop_length));
+
+    let device = re.device();
+    let frames = match onesided {
+        true => irfft(re, im, 2, Some(frame_length)),
+        false => ifft(re, im, 2).0,
+    };
+    let frames = frames.mul(window.clone().reshape([1, 1, frame_length]));
+
+    let indices = frame_indices::<B>(num_frames, frame_length, hop_length, &device);
+    let signal = Tensor::zeros([batch_size, length], &device).select_assign(
+        1,
+        indices.clone(),
+        frames.reshape([batch_size, num_frames * frame_length]),
+    );
+
+    // Normalize by the overlapping squared windows, leaving the samples they don't cover as is.
+    let envelope = Tensor::<B, 1>::zeros([length], &device)
+        .select_assign(
+            0,
+            indices,
+            window
+                .powi_scalar(2)
+                .reshape([1, frame_length])
+                .repeat_dim(0, num_frames)
+                .reshape([num_frames * frame_length]),
+        )
+        .reshape([1, length]);
+    let envelope = envelope.clone().mask_fill(envelope.lower_elem(1e-11), 1.0);
+
+    signal.div(envelope)
+}
@@ -0,0 +1,97 @@
+use core::f64::consts::PI;
+
+use crate::{Int, Tensor, backend::Backend};
+
+/// Creates a Hann window, `0.5 - 0.5 cos(2πn / N)`.
+///
+/// # Arguments
+///
+/// * `size` - The number of samples of the window.
+/// * `periodic` - Whether the window is periodic, with `N = size`, as used for spectral analysis,
+///   or symmetric, with `N = size - 1`, as used for filter design.
+/// * `device` - The device on which the window is created.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::signal;
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let window = signal::hann_window::<B>(4, true, &device);
+///     println!("{window}");
+///     // [0.0, 0.5, 1.0, 0.5]
+/// }
+/// ```
+pub fn hann_window<B: Backend>(size: usize, periodic: bool, device: &B::Device) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.5, 0.5], device)
+}
+
+/// Creates a Hamming window, `α - β cos(2πn / N)`.
+///
+/// The coefficients `α = 25 / 46` and `β = 21 / 46` follow the ONNX `HammingWindow` operator,
+/// which slightly differs from the rounded `0.54` and `0.46` used by some other libraries.
+///
+/// # Arguments
+///
+/// * `size` - The number of samples of the window.
+/// * `periodic` - Whether the window is periodic, with `N = size`, or symmetric, with
+///   `N = size - 1`.
+/// * `device` - The device on which the window is created.
+pub fn hamming_window<B: Backend>(size: usize, periodic: bool, device: &B::Device) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[25.0 / 46.0, 21.0 / 46.0], device)
+}
+
+/// Creates a Blackman window, `0.42 - 0.5 cos(2πn / N) + 0.08 cos(4πn / N)`.
+///
+/// # Arguments
+///
+/// * `size` - The number of samples of the window.
+/// * `periodic` - Whether the window is periodic, with `N = size`, or symmetric, with
+///   `N = size - 1`.
+/// * `device` - The device on which the window is created.
+pub fn blackman_window<B: Backend>(
+    size: usize,
+    periodic: bool,
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    cosine_window(size, periodic, &[0.42, 0.5, 0.08], device)
+}
+
+/// Creates the window `Σ (-1)^j a_j cos(2πjn / N)` from its coefficients `a_j`.
+fn cosine_window<B: Backend>(
+    size: usize,
+    periodic: bool,
+    coefficients: &[f64],
+    device: &B::Device,
+) -> Tensor<B, 1> {
+    let period = match periodic {
+        true => size,
+        false => size.saturating_sub(1),
+    };
+
+    // A single sample window can't be normalized by its period.
+    if period == 0 {
+        return Tensor::ones([size], device);
+    }
+
+    let angles = Tensor::<B, 1, Int>::arange(0..size as i64, device)
+        .float()
+        .mul_scalar(2.0 * PI / period as f64);
+
+    coefficients.iter().enumerate().skip(1).fold(
+        Tensor::full([size], coefficients[0], device),
+        |window, (j, coefficient)| {
+            let term = angles
+                .clone()
+                .mul_scalar(j as f64)
+                .cos()
+                .mul_scalar(*coefficient);
+            match j % 2 {
+                0 => window.add(term),
+                _ => window.sub(term),
+            }
+        },
+    )
+}
@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(cholesky)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, linalg};
+
+    #[test]
+    fn should_compute_cholesky() {
+        let tensor = TestTensor::<2>::from([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let output = linalg::cholesky(tensor);
+
+        let expected = TensorData::from([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_compute_batched_cholesky() {
+        let tensor = TestTensor::<3>::from([[[4.0, 2.0], [2.0, 5.0]], [[9.0, 0.0], [0.0, 1.0]]]);
+
+        let output = linalg::cholesky(tensor);
+
+        let expected = TensorData::from([[[2.0, 0.0], [1.0, 2.0]], [[3.0, 0.0], [0.0, 1.0]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_only_read_lower_triangle() {
+        let tensor = TestTensor::<2>::from([[4.0, 100.0], [2.0, 5.0]]);
+
+        let output = linalg::cholesky(tensor);
+
+        let expected = TensorData::from([[2.0, 0.0], [1.0, 2.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
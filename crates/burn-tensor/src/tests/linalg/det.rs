@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(det)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, linalg};
+
+    #[test]
+    fn should_compute_det() {
+        let tensor = TestTensor::<2>::from([[4.0, 7.0], [2.0, 6.0]]);
+
+        let output = linalg::det(tensor);
+
+        let expected = TensorData::from([[10.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_compute_batched_det_with_row_swaps() {
+        let tensor = TestTensor::<3>::from([
+            [[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]],
+            [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+            [[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]],
+        ]);
+
+        let output = linalg::det(tensor);
+
+        let expected = TensorData::from([[[-5.0]], [[-1.0]], [[1.0]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_compute_det_of_singular_matrix() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [2.0, 4.0]]);
+
+        let output = linalg::det(tensor);
+
+        let expected = TensorData::from([[0.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_compute_det_of_singular_matrix_with_zero_pivot() {
+        let tensor = TestTensor::<3>::from([
+            [[1.0, 1.0, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            [[0.0, 0.0, 0.0], [0.0, 1.0, 2.0], [0.0, 3.0, 4.0]],
+        ]);
+
+        let output = linalg::det(tensor);
+
+        let expected = TensorData::from([[[0.0]], [[0.0]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
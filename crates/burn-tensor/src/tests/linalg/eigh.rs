@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(eigh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, linalg};
+
+    #[test]
+    fn should_compute_eigh() {
+        let tensor = TestTensor::<2>::from([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+
+        let (eigenvalues, eigenvectors) = linalg::eigh(tensor.clone());
+
+        let expected = TensorData::from([[0.5858, 2.0, 3.4142]]);
+        eigenvalues
+            .clone()
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+
+        // A = V diag(w) V^T
+        (eigenvectors.clone() * eigenvalues)
+            .matmul(eigenvectors.transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_batched_eigh_in_ascending_order() {
+        let tensor = TestTensor::<3>::from([[[3.0, 0.0], [0.0, 1.0]], [[2.0, 1.0], [1.0, 2.0]]]);
+
+        let (eigenvalues, eigenvectors) = linalg::eigh(tensor);
+
+        let expected = TensorData::from([[[1.0, 3.0]], [[1.0, 3.0]]]);
+        eigenvalues.into_data().assert_approx_eq(&expected, 3);
+
+        let expected = TensorData::from([
+            [[0.0, 1.0], [1.0, 0.0]],
+            [[0.7071, 0.7071], [0.7071, 0.7071]],
+        ]);
+        // Eigenvectors are only defined up to their sign.
+        eigenvectors
+            .abs()
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_converge_for_larger_matrices() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..64, &device)
+            .float()
+            .reshape([8, 8])
+            .sin();
+        let tensor = tensor.clone().add(tensor.transpose());
+
+        let (eigenvalues, eigenvectors) = linalg::eigh(tensor.clone());
+
+        (eigenvectors.clone() * eigenvalues)
+            .matmul(eigenvectors.clone().transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+        eigenvectors
+            .clone()
+            .transpose()
+            .matmul(eigenvectors)
+            .into_data()
+            .assert_approx_eq(&TestTensor::<2>::eye(8, &device).into_data(), 3);
+    }
+}
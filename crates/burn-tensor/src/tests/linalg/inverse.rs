@@ -0,0 +1,95 @@
+#[burn_tensor_testgen::testgen(inverse)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, linalg};
+
+    #[test]
+    fn should_compute_inverse() {
+        let tensor = TestTensor::<2>::from([[4.0, 7.0], [2.0, 6.0]]);
+
+        let output = linalg::inverse(tensor);
+
+        let expected = TensorData::from([[0.6, -0.7], [-0.2, 0.4]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_compute_inverse_with_row_swaps() {
+        let tensor = TestTensor::<2>::from([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]]);
+
+        let output = linalg::inverse(tensor);
+
+        let expected = TensorData::from([[-0.2, 0.4, 0.2], [0.2, 0.6, -0.2], [0.6, -1.2, 0.4]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_compute_batched_inverse() {
+        let tensor = TestTensor::<3>::from([
+            [[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]],
+            [[2.0, 0.0, 0.0], [0.0, 0.0, 4.0], [0.0, 1.0, 0.0]],
+        ]);
+
+        let output = linalg::inverse(tensor);
+
+        let expected = TensorData::from([
+            [[-24.0, 18.0, 5.0], [20.0, -15.0, -4.0], [-5.0, 4.0, 1.0]],
+            [[0.5, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.25, 0.0]],
+        ]);
+        output.into_data().assert_approx_eq(&expected, 2);
+    }
+
+    #[test]
+    fn should_fill_inverse_of_singular_matrix_with_nan() {
+        let tensor = TestTensor::<3>::from([
+            [[1.0, 1.0, 0.0], [1.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            [[2.0, 0.0, 0.0], [0.0, 0.0, 4.0], [0.0, 1.0, 0.0]],
+        ]);
+
+        let output = linalg::inverse(tensor);
+
+        output
+            .clone()
+            .slice([0..1])
+            .is_nan()
+            .all()
+            .into_data()
+            .assert_eq(&TensorData::from([true]), false);
+        let expected = TensorData::from([[[0.5, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.25, 0.0]]]);
+        output
+            .slice([1..2])
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_not_fill_inverse_of_regular_matrix_whose_determinant_underflows() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::eye(40, &device).mul_scalar(0.01);
+
+        let output = linalg::inverse(tensor);
+
+        let expected = TestTensor::<2>::eye(40, &device).mul_scalar(100.0);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn inverse_should_be_the_identity_when_multiplied() {
+        let tensor = TestTensor::<2>::from([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+
+        let output = tensor.clone().matmul(linalg::inverse(tensor));
+
+        let expected = TensorData::from([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_matrix_is_not_square() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _output = linalg::inverse(tensor);
+    }
+}
@@ -0,0 +1,7 @@
+mod cholesky;
+mod det;
+mod eigh;
+mod inverse;
+mod qr;
+mod solve;
+mod svd;
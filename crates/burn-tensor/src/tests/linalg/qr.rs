@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(qr)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, linalg};
+
+    #[test]
+    fn should_compute_qr() {
+        let tensor = TestTensor::<2>::from([[3.0, 1.0], [4.0, 2.0]]);
+
+        let (q, r) = linalg::qr(tensor);
+
+        let expected_q = TensorData::from([[0.6, -0.8], [0.8, 0.6]]);
+        let expected_r = TensorData::from([[5.0, 2.2], [0.0, 0.4]]);
+        q.into_data().assert_approx_eq(&expected_q, 3);
+        r.into_data().assert_approx_eq(&expected_r, 3);
+    }
+
+    #[test]
+    fn should_compute_reduced_qr_of_tall_matrices() {
+        let tensor = TestTensor::<3>::from([
+            [[1.0, 2.0], [2.0, 1.0], [2.0, 2.0]],
+            [[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]],
+        ]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        assert_eq!(q.dims(), [2, 3, 2]);
+        assert_eq!(r.dims(), [2, 2, 2]);
+        let expected_identity =
+            TensorData::from([[[1.0, 0.0], [0.0, 1.0]], [[1.0, 0.0], [0.0, 1.0]]]);
+        q.clone()
+            .transpose()
+            .matmul(q.clone())
+            .into_data()
+            .assert_approx_eq(&expected_identity, 3);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_reduced_qr_of_wide_matrices() {
+        let tensor = TestTensor::<2>::from([[3.0, 1.0, 2.0], [4.0, 2.0, -1.0]]);
+
+        let (q, r) = linalg::qr(tensor.clone());
+
+        let expected_r = TensorData::from([[5.0, 2.2, 0.4], [0.0, 0.4, -2.2]]);
+        r.clone().into_data().assert_approx_eq(&expected_r, 3);
+        q.matmul(r)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
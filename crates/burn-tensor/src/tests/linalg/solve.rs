@@ -0,0 +1,58 @@
+#[burn_tensor_testgen::testgen(solve)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, linalg};
+
+    #[test]
+    fn should_solve_linear_system() {
+        let lhs = TestTensor::<2>::from([[3.0, 1.0], [1.0, 2.0]]);
+        let rhs = TestTensor::<2>::from([[9.0], [8.0]]);
+
+        let output = linalg::solve(lhs, rhs);
+
+        let expected = TensorData::from([[2.0], [3.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_solve_batched_systems_with_multiple_right_hand_sides() {
+        let lhs = TestTensor::<3>::from([
+            [[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]],
+            [[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 8.0]],
+        ]);
+        let rhs = TestTensor::<3>::from([
+            [[3.0, 1.0], [2.0, 1.0], [4.0, 0.0]],
+            [[2.0, 4.0], [4.0, 8.0], [8.0, 16.0]],
+        ]);
+
+        let output = linalg::solve(lhs, rhs);
+
+        let expected = TensorData::from([
+            [[1.0, 0.2], [1.0, 0.8], [1.0, -0.6]],
+            [[1.0, 2.0], [1.0, 2.0], [1.0, 2.0]],
+        ]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_solve_system_whose_determinant_underflows() {
+        // The determinant, 0.01^40, underflows to 0 in f32 although the matrix is regular.
+        let device = Default::default();
+        let lhs = TestTensor::<2>::eye(40, &device).mul_scalar(0.01);
+        let rhs = TestTensor::<2>::ones([40, 1], &device).mul_scalar(0.01);
+
+        let output = linalg::solve(lhs, rhs);
+
+        let expected = TensorData::ones::<f32, _>([40, 1]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_rows_do_not_match() {
+        let lhs = TestTensor::<2>::from([[3.0, 1.0], [1.0, 2.0]]);
+        let rhs = TestTensor::<2>::from([[9.0], [8.0], [7.0]]);
+
+        let _output = linalg::solve(lhs, rhs);
+    }
+}
@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(svd)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, linalg};
+
+    #[test]
+    fn should_compute_svd() {
+        let tensor = TestTensor::<2>::from([[3.0, 0.0], [4.0, 5.0]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        let expected = TensorData::from([[6.7082, 2.2361]]);
+        s.clone().into_data().assert_approx_eq(&expected, 3);
+        (u * s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_reduced_svd_of_wide_matrices() {
+        let tensor = TestTensor::<3>::from([[[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]]);
+
+        let (u, s, vh) = linalg::svd(tensor.clone());
+
+        assert_eq!(u.dims(), [1, 2, 2]);
+        assert_eq!(vh.dims(), [1, 2, 3]);
+        let expected = TensorData::from([[[1.7321, 1.0]]]);
+        s.clone().into_data().assert_approx_eq(&expected, 3);
+        (u * s)
+            .matmul(vh)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+}
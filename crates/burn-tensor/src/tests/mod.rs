@@ -1,9 +1,11 @@
 mod activation;
 mod clone_invariance;
+mod linalg;
 mod module;
 mod ops;
 mod primitive;
 mod quantization;
+mod signal;
 mod stats;
 
 pub use cubecl::prelude::{Float, Int, Numeric};
@@ -213,6 +215,7 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_attention!();
 
         // test ops
         burn_tensor::testgen_gather_scatter!();
@@ -230,7 +233,9 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_cos!();
         burn_tensor::testgen_cosh!();
         burn_tensor::testgen_create_like!();
+        burn_tensor::testgen_cumulative!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_einsum!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
@@ -292,6 +297,20 @@ macro_rules! testgen_with_float_param {
         burn_tensor::testgen_cov!();
         burn_tensor::testgen_eye!();
 
+        // test linalg
+        burn_tensor::testgen_cholesky!();
+        burn_tensor::testgen_det!();
+        burn_tensor::testgen_eigh!();
+        burn_tensor::testgen_inverse!();
+        burn_tensor::testgen_qr!();
+        burn_tensor::testgen_solve!();
+        burn_tensor::testgen_svd!();
+
+        // test signal
+        burn_tensor::testgen_fft!();
+        burn_tensor::testgen_stft!();
+        burn_tensor::testgen_window!();
+
         // test padding
         burn_tensor::testgen_padding!();
     };
@@ -308,6 +327,7 @@ macro_rules! testgen_with_int_param {
         burn_tensor::testgen_cast!();
         burn_tensor::testgen_bool!();
         burn_tensor::testgen_cat!();
+        burn_tensor::testgen_cumulative!();
         burn_tensor::testgen_div!();
         burn_tensor::testgen_expand!();
         burn_tensor::testgen_flip!();
@@ -0,0 +1,242 @@
+#[burn_tensor_testgen::testgen(module_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::activation::softmax;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::ops::AttentionOptions;
+
+    #[test]
+    fn test_attention_simple() {
+        let device = Default::default();
+        let query = TestTensor::<4>::from_floats([[[[1.0, 0.0]]]], &device);
+        let key = TestTensor::<4>::from_floats([[[[1.0, 0.0], [0.0, 1.0]]]], &device);
+        let value = TestTensor::<4>::from_floats([[[[1.0, 2.0], [3.0, 4.0]]]], &device);
+
+        let output =
+            scaled_dot_product_attention(query, key, value, None, AttentionOptions::default());
+
+        output.into_data().assert_approx_eq(
+            &TestTensor::<4>::from_floats([[[[1.6604769, 2.6604769]]]], &device).into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_attention_multiple_blocks_of_keys() {
+        let test = AttentionTestCase {
+            batch_size: 2,
+            num_heads: 2,
+            num_kv_heads: 2,
+            seq_length_q: 5,
+            seq_length_k: 70,
+            causal: false,
+        };
+
+        test.assert_matches_reference(None);
+    }
+
+    #[test]
+    fn test_attention_causal_grouped_query() {
+        let test = AttentionTestCase {
+            batch_size: 1,
+            num_heads: 4,
+            num_kv_heads: 2,
+            seq_length_q: 70,
+            seq_length_k: 70,
+            causal: true,
+        };
+
+        test.assert_matches_reference(None);
+    }
+
+    #[test]
+    fn test_attention_causal_with_cached_keys() {
+        let test = AttentionTestCase {
+            batch_size: 1,
+            num_heads: 3,
+            num_kv_heads: 1,
+            seq_length_q: 3,
+            seq_length_k: 8,
+            causal: true,
+        };
+
+        test.assert_matches_reference(None);
+    }
+
+    #[test]
+    fn test_attention_padding_mask() {
+        let test = AttentionTestCase {
+            batch_size: 2,
+            num_heads: 2,
+            num_kv_heads: 1,
+            seq_length_q: 4,
+            seq_length_k: 6,
+            causal: false,
+        };
+        let mask = TestTensorBool::<4>::from([
+            [[[false, false, false, false, true, true]]],
+            [[[false, false, true, true, true, true]]],
+        ]);
+
+        test.assert_matches_reference(Some(mask));
+    }
+
+    #[test]
+    fn test_attention_mask_broadcast_over_batch_and_heads() {
+        let test = AttentionTestCase {
+            batch_size: 2,
+            num_heads: 4,
+            num_kv_heads: 2,
+            seq_length_q: 3,
+            seq_length_k: 5,
+            causal: false,
+        };
+        let mask = TestTensorBool::<4>::from([[[
+            [false, true, false, true, false],
+            [true, false, false, false, true],
+            [false, false, true, true, true],
+        ]]]);
+
+        test.assert_matches_reference(Some(mask));
+    }
+
+    #[test]
+    fn test_attention_dropout_is_ignored_without_autodiff() {
+        let query = sequence([2, 2, 3, 4], 0);
+        let key = sequence([2, 2, 5, 4], 1);
+        let value = sequence([2, 2, 5, 3], 2);
+
+        let output = scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            AttentionOptions::new(None, false, 0.5),
+        );
+        let expected =
+            scaled_dot_product_attention(query, key, value, None, AttentionOptions::default());
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_attention_fully_masked_rows_are_zeros() {
+        let device = Default::default();
+        let query = sequence([1, 1, 2, 3], 0);
+        let key = sequence([1, 1, 2, 3], 1);
+        let value = TestTensor::<4>::from_floats([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]], &device);
+        let mask = TestTensorBool::<4>::from([[[[true, true], [false, true]]]]);
+
+        let output = scaled_dot_product_attention(
+            query,
+            key,
+            value,
+            Some(mask),
+            AttentionOptions::default(),
+        );
+
+        output.into_data().assert_approx_eq(
+            &TestTensor::<4>::from_floats([[[[0.0, 0.0, 0.0], [1.0, 2.0, 3.0]]]], &device)
+                .into_data(),
+            3,
+        );
+    }
+
+    struct AttentionTestCase {
+        batch_size: usize,
+        num_heads: usize,
+        num_kv_heads: usize,
+        seq_length_q: usize,
+        seq_length_k: usize,
+        causal: bool,
+    }
+
+    impl AttentionTestCase {
+        fn assert_matches_reference(self, mask: Option<TestTensorBool<4>>) {
+            let d_k = 4;
+            let d_v = 3;
+            let query = sequence([self.batch_size, self.num_heads, self.seq_length_q, d_k], 0);
+            let key = sequence(
+                [self.batch_size, self.num_kv_heads, self.seq_length_k, d_k],
+                1,
+            );
+            let value = sequence(
+                [self.batch_size, self.num_kv_heads, self.seq_length_k, d_v],
+                2,
+            );
+
+            let output = scaled_dot_product_attention(
+                query.clone(),
+                key.clone(),
+                value.clone(),
+                mask.clone(),
+                AttentionOptions::new(None, self.causal, 0.0),
+            );
+
+            let expected = self.reference(query, key, value, mask);
+            output
+                .into_data()
+                .assert_approx_eq(&expected.into_data(), 3);
+        }
+
+        /// Attention with the key-value heads repeated and the attention weights materialized.
+        fn reference(
+            &self,
+            query: TestTensor<4>,
+            key: TestTensor<4>,
+            value: TestTensor<4>,
+            mask: Option<TestTensorBool<4>>,
+        ) -> TestTensor<4> {
+            let key = self.repeat_heads(key);
+            let value = self.repeat_heads(value);
+            let [_, _, _, d_k] = query.dims();
+            let shape = [
+                self.batch_size,
+                self.num_heads,
+                self.seq_length_q,
+                self.seq_length_k,
+            ];
+
+            let mut scores = query
+                .matmul(key.transpose())
+                .div_scalar((d_k as f32).sqrt());
+            if let Some(mask) = mask {
+                scores = scores.mask_fill(mask.expand(shape), f32::NEG_INFINITY);
+            }
+            if self.causal {
+                let offset = (self.seq_length_k - self.seq_length_q) as i64;
+                let causal = TestTensorBool::<2>::tril_mask(
+                    [self.seq_length_q, self.seq_length_k],
+                    offset,
+                    &scores.device(),
+                );
+                let causal = causal.unsqueeze::<4>().expand(shape);
+                scores = scores.mask_fill(causal, f32::NEG_INFINITY);
+            }
+
+            softmax(scores, 3).matmul(value)
+        }
+
+        fn repeat_heads(&self, tensor: TestTensor<4>) -> TestTensor<4> {
+            let [batch_size, num_kv_heads, seq_length, d] = tensor.dims();
+            let num_groups = self.num_heads / num_kv_heads;
+
+            tensor
+                .unsqueeze_dim::<5>(2)
+                .expand([batch_size, num_kv_heads, num_groups, seq_length, d])
+                .reshape([batch_size, self.num_heads, seq_length, d])
+        }
+    }
+
+    /// A deterministic tensor with values in `[-1, 1]`.
+    fn sequence(shape: [usize; 4], seed: i64) -> TestTensor<4> {
+        let num_elements = shape.iter().product::<usize>() as i64;
+        TestTensorInt::<1>::arange(seed..num_elements + seed, &Default::default())
+            .float()
+            .mul_scalar(0.37 * (seed + 1) as f32)
+            .sin()
+            .reshape(shape)
+    }
+}
@@ -0,0 +1,117 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_cumsum_float_dim_0() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.cumsum(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 2.0, 3.0], [5.0, 7.0, 9.0]]), false);
+    }
+
+    #[test]
+    fn test_cumsum_float_dim_1() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.cumsum(1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_cumsum_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+
+        let output = tensor.cumsum(1);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3, 6], [4, 9, 15]]), false);
+    }
+
+    #[test]
+    fn test_cumprod_float() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.cumprod(1);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, 2.0, 6.0], [4.0, 20.0, 120.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_cumprod_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+
+        let output = tensor.cumprod(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 3], [4, 10, 18]]), false);
+    }
+
+    #[test]
+    fn test_cummax_with_indices_float() {
+        let tensor = TestTensor::<2>::from([[1.0, 3.0, 2.0, 5.0], [4.0, 1.0, 4.0, 0.0]]);
+
+        let (values, indices) = tensor.cummax_with_indices(1);
+
+        values.into_data().assert_eq(
+            &TensorData::from([[1.0, 3.0, 3.0, 5.0], [4.0, 4.0, 4.0, 4.0]]),
+            false,
+        );
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 1, 3], [0, 0, 2, 2]]), false);
+    }
+
+    #[test]
+    fn test_cummin_with_indices_float() {
+        let tensor = TestTensor::<2>::from([[3.0, 1.0, 2.0, 0.0], [2.0, 2.0, 5.0, 1.0]]);
+
+        let (values, indices) = tensor.cummin_with_indices(1);
+
+        values.into_data().assert_eq(
+            &TensorData::from([[3.0, 1.0, 1.0, 0.0], [2.0, 2.0, 2.0, 1.0]]),
+            false,
+        );
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 1, 3], [0, 1, 1, 3]]), false);
+    }
+
+    #[test]
+    fn test_cummax_int_dim_0() {
+        let tensor = TestTensorInt::<2>::from([[1, 5], [3, 2], [2, 7]]);
+
+        let (values, indices) = tensor.cummax_with_indices(0);
+
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 5], [3, 5], [3, 7]]), false);
+        indices
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 0], [1, 0], [1, 2]]), false);
+    }
+
+    #[test]
+    fn test_cummin_int() {
+        let tensor = TestTensorInt::<1>::from([4, 2, 3, 1, 1]);
+
+        let output = tensor.cummin(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([4, 2, 2, 1, 1]), false);
+    }
+}
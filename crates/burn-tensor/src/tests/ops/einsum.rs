@@ -0,0 +1,138 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_matmul() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 0.0], [2.0, 1.0], [0.0, -1.0]]);
+
+        let output = TestTensor::<2>::einsum("ij,jk->ik", &[&lhs, &rhs]);
+
+        let expected = TensorData::from([[5.0, -1.0], [14.0, -1.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_implicit_output() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 0.0], [2.0, 1.0], [0.0, -1.0]]);
+
+        let output = TestTensor::<2>::einsum("ij,jk", &[&lhs, &rhs]);
+
+        let expected = TensorData::from([[5.0, -1.0], [14.0, -1.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_operands_with_different_ranks() {
+        let matrix = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let vector = TestTensor::<1>::from([1.0, -1.0]);
+
+        let output = TestTensor::<1>::einsum("ij,j->i", &[&matrix, &vector]);
+
+        let expected = TensorData::from([-1.0, -1.0]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_batched_matmul() {
+        let lhs = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[0.0, 1.0], [1.0, 0.0]]]);
+        let rhs = TestTensor::<3>::from([[[1.0, 1.0], [0.0, 1.0]], [[2.0, 0.0], [0.0, 3.0]]]);
+
+        let output = TestTensor::<3>::einsum("bij,bjk->bik", &[&lhs, &rhs]);
+
+        let expected = TensorData::from([[[1.0, 3.0], [3.0, 7.0]], [[0.0, 3.0], [2.0, 0.0]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_ellipsis_with_broadcast() {
+        let lhs = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]], [[0.0, 1.0], [1.0, 0.0]]]);
+        let rhs = TestTensor::<2>::from([[1.0, 1.0], [0.0, 1.0]]);
+
+        let output = TestTensor::<3>::einsum("...ij,...jk->...ik", &[&lhs, &rhs]);
+
+        let expected = TensorData::from([[[1.0, 3.0], [3.0, 7.0]], [[0.0, 1.0], [1.0, 1.0]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_chain_of_three_operands() {
+        let a = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = TestTensor::<2>::from([[1.0, 0.0], [2.0, 1.0], [0.0, -1.0]]);
+        let c = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = TestTensor::<2>::einsum("ij,jk,kl->il", &[&a, &b, &c]);
+
+        let expected = TensorData::from([[2.0, 6.0], [11.0, 24.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_outer_product() {
+        let lhs = TestTensor::<1>::from([1.0, 2.0]);
+        let rhs = TestTensor::<1>::from([3.0, 4.0, 5.0]);
+
+        let output = TestTensor::<2>::einsum("i,j->ij", &[&lhs, &rhs]);
+
+        let expected = TensorData::from([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_transpose_and_sum() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let transposed = TestTensor::<2>::einsum("ij->ji", &[&tensor]);
+        let summed = TestTensor::<1>::einsum("ij->j", &[&tensor]);
+
+        let expected = TensorData::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        transposed.into_data().assert_approx_eq(&expected, 3);
+        let expected = TensorData::from([5.0, 7.0, 9.0]);
+        summed.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_diagonal_and_trace() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let diagonal = TestTensor::<1>::einsum("ii->i", &[&tensor]);
+        let trace = TestTensor::<1>::einsum("ii->", &[&tensor]);
+
+        let expected = TensorData::from([1.0, 5.0, 9.0]);
+        diagonal.into_data().assert_approx_eq(&expected, 3);
+        let expected = TensorData::from([15.0]);
+        trace.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_dot_product() {
+        let lhs = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+        let rhs = TestTensor::<1>::from([4.0, 5.0, 6.0]);
+
+        let output = TestTensor::<1>::einsum("i,i->", &[&lhs, &rhs]);
+
+        let expected = TensorData::from([32.0]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_sizes_do_not_match() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let _output = TestTensor::<2>::einsum("ij,jk->ik", &[&lhs, &rhs]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_output_rank_does_not_match() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output = TestTensor::<3>::einsum("ij,jk->ik", &[&lhs, &rhs]);
+    }
+}
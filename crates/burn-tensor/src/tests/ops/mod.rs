@@ -19,7 +19,9 @@ mod close;
 mod cos;
 mod cosh;
 mod create_like;
+mod cumulative;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
 mod expand;
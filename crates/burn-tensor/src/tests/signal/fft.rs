@@ -0,0 +1,130 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, signal};
+
+    #[test]
+    fn should_compute_fft() {
+        let re = TestTensor::<1>::from([1.0, 2.0, 3.0, 4.0]);
+        let im = TestTensor::<1>::from([0.0, 1.0, 0.0, -1.0]);
+
+        let (re, im) = signal::fft(re, im, 0);
+
+        re.into_data()
+            .assert_approx_eq(&TensorData::from([10.0, 0.0, -2.0, -4.0]), 3);
+        im.into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 2.0, 0.0, -2.0]), 3);
+    }
+
+    #[test]
+    fn should_compute_fft_along_dim() {
+        let re = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let im = re.zeros_like();
+
+        let (re_rows, im_rows) = signal::fft(re.clone(), im.clone(), 1);
+        let (re_cols, im_cols) = signal::fft(re, im, 0);
+
+        re_rows.into_data().assert_approx_eq(
+            &TensorData::from([[6.0, -1.5, -1.5], [15.0, -1.5, -1.5]]),
+            3,
+        );
+        im_rows.into_data().assert_approx_eq(
+            &TensorData::from([[0.0, 0.866, -0.866], [0.0, 0.866, -0.866]]),
+            3,
+        );
+        re_cols
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[5.0, 7.0, 9.0], [-3.0, -3.0, -3.0]]), 3);
+        im_cols
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]), 3);
+    }
+
+    #[test]
+    fn should_compute_ifft() {
+        let re = TestTensor::<1>::from([2.0, -1.0, 0.5]);
+        let im = TestTensor::<1>::from([1.0, 0.0, -2.0]);
+
+        let (re, im) = signal::ifft(re, im, 0);
+
+        re.into_data()
+            .assert_approx_eq(&TensorData::from([0.5, 0.1726, 1.3274]), 3);
+        im.into_data()
+            .assert_approx_eq(&TensorData::from([-0.3333, 0.2337, 1.0997]), 3);
+    }
+
+    #[test]
+    fn ifft_should_invert_fft() {
+        let re = TestTensor::<2>::from([[1.0, -2.0, 0.5, 3.0, 1.5], [0.0, 1.0, 2.0, -1.0, 4.0]]);
+        let im = TestTensor::<2>::from([[0.5, 0.0, -1.0, 2.0, 1.0], [1.0, 1.0, 0.0, 0.0, -3.0]]);
+
+        let (re_spectrum, im_spectrum) = signal::fft(re.clone(), im.clone(), 1);
+        let (re_output, im_output) = signal::ifft(re_spectrum, im_spectrum, 1);
+
+        re_output.into_data().assert_approx_eq(&re.into_data(), 3);
+        im_output.into_data().assert_approx_eq(&im.into_data(), 3);
+    }
+
+    #[test]
+    fn should_compute_rfft_of_odd_length() {
+        let signal = TestTensor::<1>::from([1.0, -1.0, 2.0, 0.5, 3.0]);
+
+        let (re, im) = signal::rfft(signal, 0);
+
+        re.into_data()
+            .assert_approx_eq(&TensorData::from([5.5, -0.4045, 0.1545]), 3);
+        im.into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 2.9225, 3.7777]), 3);
+    }
+
+    #[test]
+    fn should_compute_irfft() {
+        let re = TestTensor::<1>::from([10.0, -2.0, -2.0]);
+        let im = TestTensor::<1>::from([0.0, 2.0, 0.0]);
+
+        let output = signal::irfft(re, im, 0, None);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 2.0, 3.0, 4.0]), 3);
+    }
+
+    #[test]
+    fn irfft_should_invert_rfft_with_length() {
+        let signal = TestTensor::<2>::from([[1.0, -1.0, 2.0, 0.5, 3.0], [0.0, 2.0, 4.0, 2.0, 0.0]]);
+
+        let (re, im) = signal::rfft(signal.clone(), 1);
+        let output = signal::irfft(re, im, 1, Some(5));
+
+        output.into_data().assert_approx_eq(&signal.into_data(), 3);
+    }
+
+    #[test]
+    fn fft_should_match_the_dft_for_powers_of_two_and_other_lengths() {
+        for n in [16, 12, 37] {
+            let values = (0..n)
+                .map(|m| ((m * 7 % 11) as f32 - 5.0) / 3.0)
+                .collect::<Vec<_>>();
+
+            let (mut re_expected, mut im_expected) = (Vec::new(), Vec::new());
+            for k in 0..n {
+                let (mut re, mut im) = (0.0f64, 0.0f64);
+                for (m, x) in values.iter().enumerate() {
+                    let angle = -2.0 * core::f64::consts::PI * ((m * k) % n) as f64 / n as f64;
+                    re += *x as f64 * angle.cos();
+                    im += *x as f64 * angle.sin();
+                }
+                re_expected.push(re as f32);
+                im_expected.push(im as f32);
+            }
+
+            let re = TestTensor::<1>::from_data(TensorData::new(values, [n]), &Default::default());
+            let (re, im) = signal::fft(re.clone(), re.zeros_like(), 0);
+
+            re.into_data()
+                .assert_approx_eq(&TensorData::new(re_expected, [n]), 2);
+            im.into_data()
+                .assert_approx_eq(&TensorData::new(im_expected, [n]), 2);
+        }
+    }
+}
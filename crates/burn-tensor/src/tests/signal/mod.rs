@@ -0,0 +1,3 @@
+mod fft;
+mod stft;
+mod window;
@@ -0,0 +1,67 @@
+#[burn_tensor_testgen::testgen(stft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, signal};
+
+    #[test]
+    fn should_compute_onesided_stft() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]]);
+        let window = signal::hann_window::<TestBackend>(4, true, &Default::default());
+
+        let (re, im) = signal::stft(tensor, window, 2, true);
+
+        re.into_data().assert_approx_eq(
+            &TensorData::from([[[6.0, -3.0, 0.0], [10.0, -5.0, 0.0]]]),
+            3,
+        );
+        im.into_data()
+            .assert_approx_eq(&TensorData::from([[[0.0, 1.0, 0.0], [0.0, 1.0, 0.0]]]), 3);
+    }
+
+    #[test]
+    fn should_compute_twosided_stft() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]]);
+        let window = signal::hann_window::<TestBackend>(4, true, &Default::default());
+
+        let (re, im) = signal::stft(tensor, window, 2, false);
+
+        re.into_data().assert_approx_eq(
+            &TensorData::from([[[6.0, -3.0, 0.0, -3.0], [10.0, -5.0, 0.0, -5.0]]]),
+            3,
+        );
+        im.into_data().assert_approx_eq(
+            &TensorData::from([[[0.0, 1.0, 0.0, -1.0], [0.0, 1.0, 0.0, -1.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn istft_should_invert_stft() {
+        let tensor = TestTensor::<2>::from([
+            [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            [0.5, -1.0, 2.0, 0.0, 1.0, 3.0, -2.0, 1.0],
+        ]);
+        let window = signal::hann_window::<TestBackend>(4, true, &Default::default());
+
+        let (re, im) = signal::stft(tensor, window.clone(), 2, true);
+        let output = signal::istft(re, im, window, 2, true);
+
+        // The first sample is only covered by a zero of the window.
+        output.into_data().assert_approx_eq(
+            &TensorData::from([
+                [0.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+                [0.0, -1.0, 2.0, 0.0, 1.0, 3.0, -2.0, 1.0],
+            ]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_window_is_longer_than_signal() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0]]);
+        let window = signal::hann_window::<TestBackend>(4, true, &Default::default());
+
+        let _ = signal::stft(tensor, window, 1, true);
+    }
+}
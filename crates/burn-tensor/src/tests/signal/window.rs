@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(window)]
+mod tests {
+    use super::*;
+    use burn_tensor::{TensorData, signal};
+
+    #[test]
+    fn should_create_hann_window() {
+        let device = Default::default();
+
+        let periodic = signal::hann_window::<TestBackend>(4, true, &device);
+        let symmetric = signal::hann_window::<TestBackend>(5, false, &device);
+
+        periodic
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.5, 1.0, 0.5]), 3);
+        symmetric
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.5, 1.0, 0.5, 0.0]), 3);
+    }
+
+    #[test]
+    fn should_create_hamming_window() {
+        let window = signal::hamming_window::<TestBackend>(4, true, &Default::default());
+
+        window
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0870, 0.5435, 1.0, 0.5435]), 3);
+    }
+
+    #[test]
+    fn should_create_blackman_window() {
+        let window = signal::blackman_window::<TestBackend>(5, false, &Default::default());
+
+        window
+            .into_data()
+            .assert_approx_eq(&TensorData::from([0.0, 0.34, 1.0, 0.34, 0.0]), 3);
+    }
+
+    #[test]
+    fn should_create_window_of_one_sample() {
+        let window = signal::hann_window::<TestBackend>(1, false, &Default::default());
+
+        window
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.0]), 3);
+    }
+}
@@ -4,15 +4,22 @@ use std::sync::mpsc;
 
 enum Message<R, B: Backend> {
     Restore(
-        usize,
+        Key,
         B::Device,
         mpsc::SyncSender<Result<R, CheckpointerError>>,
     ),
-    Save(usize, R),
-    Delete(usize),
+    Save(Key, R),
+    Delete(Key),
     End,
 }
 
+/// Identifies a checkpoint saved at the end of an epoch, or at an iteration of an epoch.
+#[derive(Clone, Copy)]
+enum Key {
+    Epoch(usize),
+    Iteration(usize, usize),
+}
+
 #[derive(new)]
 struct CheckpointerThread<C, R, B: Backend> {
     checkpointer: C,
@@ -28,20 +35,31 @@ where
     fn run(self) {
         for item in self.receiver.iter() {
             match item {
-                Message::Restore(epoch, device, callback) => {
-                    let record = self.checkpointer.restore(epoch, &device);
+                Message::Restore(key, device, callback) => {
+                    let record = match key {
+                        Key::Epoch(epoch) => self.checkpointer.restore(epoch, &device),
+                        Key::Iteration(epoch, iteration) => self
+                            .checkpointer
+                            .restore_iteration(epoch, iteration, &device),
+                    };
                     callback
                         .send(record)
                         .expect("Can send response through callback channel.");
                 }
-                Message::Save(epoch, state) => self
-                    .checkpointer
-                    .save(epoch, state)
-                    .expect("Can save the state."),
-                Message::Delete(epoch) => self
-                    .checkpointer
-                    .delete(epoch)
-                    .expect("Can delete the state."),
+                Message::Save(key, state) => match key {
+                    Key::Epoch(epoch) => self.checkpointer.save(epoch, state),
+                    Key::Iteration(epoch, iteration) => {
+                        self.checkpointer.save_iteration(epoch, iteration, state)
+                    }
+                }
+                .expect("Can save the state."),
+                Message::Delete(key) => match key {
+                    Key::Epoch(epoch) => self.checkpointer.delete(epoch),
+                    Key::Iteration(epoch, iteration) => {
+                        self.checkpointer.delete_iteration(epoch, iteration)
+                    }
+                }
+                .expect("Can delete the state."),
                 Message::End => {
                     return;
                 }
@@ -54,6 +72,7 @@ where
 pub struct AsyncCheckpointer<Record, B: Backend> {
     sender: mpsc::SyncSender<Message<Record, B>>,
     handler: Option<std::thread::JoinHandle<()>>,
+    supports_iteration: bool,
 }
 
 impl<R, B> AsyncCheckpointer<R, B>
@@ -76,30 +95,35 @@ where
     {
         // Only on checkpoint can be done in advance.
         let (sender, receiver) = mpsc::sync_channel(0);
+        let supports_iteration = checkpointer.supports_iteration();
         let thread = CheckpointerThread::new(checkpointer, receiver);
         let handler = Some(std::thread::spawn(move || thread.run()));
 
-        Self { sender, handler }
+        Self {
+            sender,
+            handler,
+            supports_iteration,
+        }
     }
 }
 
-impl<R, B> Checkpointer<R, B> for AsyncCheckpointer<R, B>
+impl<R, B> AsyncCheckpointer<R, B>
 where
     R: Record<B> + 'static,
     B: Backend,
 {
-    fn save(&self, epoch: usize, record: R) -> Result<(), CheckpointerError> {
+    fn save_key(&self, key: Key, record: R) -> Result<(), CheckpointerError> {
         self.sender
-            .send(Message::Save(epoch, record))
+            .send(Message::Save(key, record))
             .expect("Can send message to checkpointer thread.");
 
         Ok(())
     }
 
-    fn restore(&self, epoch: usize, device: &B::Device) -> Result<R, CheckpointerError> {
+    fn restore_key(&self, key: Key, device: &B::Device) -> Result<R, CheckpointerError> {
         let (sender, receiver) = mpsc::sync_channel(1);
         self.sender
-            .send(Message::Restore(epoch, device.clone(), sender))
+            .send(Message::Restore(key, device.clone(), sender))
             .map_err(|e| CheckpointerError::Unknown(e.to_string()))?;
 
         if let Ok(record) = receiver.recv() {
@@ -109,15 +133,59 @@ where
         Err(CheckpointerError::Unknown("Channel error.".to_string()))
     }
 
-    fn delete(&self, epoch: usize) -> Result<(), CheckpointerError> {
+    fn delete_key(&self, key: Key) -> Result<(), CheckpointerError> {
         self.sender
-            .send(Message::Delete(epoch))
+            .send(Message::Delete(key))
             .map_err(|e| CheckpointerError::Unknown(e.to_string()))?;
 
         Ok(())
     }
 }
 
+impl<R, B> Checkpointer<R, B> for AsyncCheckpointer<R, B>
+where
+    R: Record<B> + 'static,
+    B: Backend,
+{
+    fn save(&self, epoch: usize, record: R) -> Result<(), CheckpointerError> {
+        self.save_key(Key::Epoch(epoch), record)
+    }
+
+    fn restore(&self, epoch: usize, device: &B::Device) -> Result<R, CheckpointerError> {
+        self.restore_key(Key::Epoch(epoch), device)
+    }
+
+    fn delete(&self, epoch: usize) -> Result<(), CheckpointerError> {
+        self.delete_key(Key::Epoch(epoch))
+    }
+
+    fn supports_iteration(&self) -> bool {
+        self.supports_iteration
+    }
+
+    fn save_iteration(
+        &self,
+        epoch: usize,
+        iteration: usize,
+        record: R,
+    ) -> Result<(), CheckpointerError> {
+        self.save_key(Key::Iteration(epoch, iteration), record)
+    }
+
+    fn restore_iteration(
+        &self,
+        epoch: usize,
+        iteration: usize,
+        device: &B::Device,
+    ) -> Result<R, CheckpointerError> {
+        self.restore_key(Key::Iteration(epoch, iteration), device)
+    }
+
+    fn delete_iteration(&self, epoch: usize, iteration: usize) -> Result<(), CheckpointerError> {
+        self.delete_key(Key::Iteration(epoch, iteration))
+    }
+}
+
 impl<E, B> Drop for AsyncCheckpointer<E, B>
 where
     B: Backend,
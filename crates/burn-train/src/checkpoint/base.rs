@@ -44,4 +44,58 @@ where
     ///
     /// The record.
     fn restore(&self, epoch: usize, device: &B::Device) -> Result<R, CheckpointerError>;
+
+    /// Whether the checkpointer can save the records of [iterations](Self::save_iteration), which
+    /// is required to save checkpoints in the middle of the epochs.
+    fn supports_iteration(&self) -> bool {
+        false
+    }
+
+    /// Save the record of an iteration in the middle of an epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The epoch.
+    /// * `iteration` - The iteration in the epoch.
+    /// * `record` - The record.
+    fn save_iteration(
+        &self,
+        epoch: usize,
+        iteration: usize,
+        record: R,
+    ) -> Result<(), CheckpointerError> {
+        let _ = (epoch, iteration, record);
+        Err(iteration_unsupported())
+    }
+
+    /// Delete the record at the given iteration of an epoch if present.
+    fn delete_iteration(&self, epoch: usize, iteration: usize) -> Result<(), CheckpointerError> {
+        let _ = (epoch, iteration);
+        Err(iteration_unsupported())
+    }
+
+    /// Restore the record of an iteration in the middle of an epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `epoch` - The epoch.
+    /// * `iteration` - The iteration in the epoch.
+    /// * `device` - The device used to restore the record.
+    ///
+    /// # Returns
+    ///
+    /// The record.
+    fn restore_iteration(
+        &self,
+        epoch: usize,
+        iteration: usize,
+        device: &B::Device,
+    ) -> Result<R, CheckpointerError> {
+        let _ = (epoch, iteration, device);
+        Err(iteration_unsupported())
+    }
+}
+
+fn iteration_unsupported() -> CheckpointerError {
+    CheckpointerError::Unknown("The checkpointer doesn't support iteration checkpoints.".into())
 }
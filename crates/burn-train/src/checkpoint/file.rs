@@ -35,6 +35,57 @@ impl<FR> FileCheckpointer<FR> {
     fn path_for_epoch(&self, epoch: usize) -> PathBuf {
         self.directory.join(format!("{}-{}", self.name, epoch))
     }
+
+    fn path_for_iteration(&self, epoch: usize, iteration: usize) -> PathBuf {
+        self.directory
+            .join(format!("{}-{}-{}", self.name, epoch, iteration))
+    }
+
+    fn save_path<R, B>(&self, file_path: PathBuf, record: R) -> Result<(), CheckpointerError>
+    where
+        R: Record<B>,
+        FR: FileRecorder<B>,
+        B: Backend,
+    {
+        self.recorder
+            .record(record, file_path)
+            .map_err(CheckpointerError::RecorderError)?;
+
+        Ok(())
+    }
+
+    fn restore_path<R, B>(
+        &self,
+        file_path: PathBuf,
+        device: &B::Device,
+    ) -> Result<R, CheckpointerError>
+    where
+        R: Record<B>,
+        FR: FileRecorder<B>,
+        B: Backend,
+    {
+        let record = self
+            .recorder
+            .load(file_path, device)
+            .map_err(CheckpointerError::RecorderError)?;
+
+        Ok(record)
+    }
+
+    fn delete_path<B>(&self, file_path: PathBuf) -> Result<(), CheckpointerError>
+    where
+        FR: FileRecorder<B>,
+        B: Backend,
+    {
+        let file_to_remove = format!("{}.{}", file_path.display(), FR::file_extension());
+
+        if std::path::Path::new(&file_to_remove).exists() {
+            log::info!("Removing checkpoint {}", file_to_remove);
+            std::fs::remove_file(file_to_remove).map_err(CheckpointerError::IOError)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<FR, R, B> Checkpointer<R, B> for FileCheckpointer<FR>
@@ -47,11 +98,7 @@ where
         let file_path = self.path_for_epoch(epoch);
         log::info!("Saving checkpoint {} to {}", epoch, file_path.display());
 
-        self.recorder
-            .record(record, file_path)
-            .map_err(CheckpointerError::RecorderError)?;
-
-        Ok(())
+        self.save_path::<R, B>(file_path, record)
     }
 
     fn restore(&self, epoch: usize, device: &B::Device) -> Result<R, CheckpointerError> {
@@ -61,26 +108,52 @@ where
             epoch,
             file_path.display()
         );
-        let record = self
-            .recorder
-            .load(file_path, device)
-            .map_err(CheckpointerError::RecorderError)?;
-
-        Ok(record)
+        self.restore_path::<R, B>(file_path, device)
     }
 
     fn delete(&self, epoch: usize) -> Result<(), CheckpointerError> {
-        let file_to_remove = format!(
-            "{}.{}",
-            self.path_for_epoch(epoch).display(),
-            FR::file_extension(),
+        self.delete_path::<B>(self.path_for_epoch(epoch))
+    }
+
+    fn supports_iteration(&self) -> bool {
+        true
+    }
+
+    fn save_iteration(
+        &self,
+        epoch: usize,
+        iteration: usize,
+        record: R,
+    ) -> Result<(), CheckpointerError> {
+        let file_path = self.path_for_iteration(epoch, iteration);
+        log::info!(
+            "Saving checkpoint {} of epoch {} to {}",
+            iteration,
+            epoch,
+            file_path.display()
         );
 
-        if std::path::Path::new(&file_to_remove).exists() {
-            log::info!("Removing checkpoint {}", file_to_remove);
-            std::fs::remove_file(file_to_remove).map_err(CheckpointerError::IOError)?;
-        }
+        self.save_path::<R, B>(file_path, record)
+    }
 
-        Ok(())
+    fn restore_iteration(
+        &self,
+        epoch: usize,
+        iteration: usize,
+        device: &B::Device,
+    ) -> Result<R, CheckpointerError> {
+        let file_path = self.path_for_iteration(epoch, iteration);
+        log::info!(
+            "Restoring checkpoint {} of epoch {} from {}",
+            iteration,
+            epoch,
+            file_path.display()
+        );
+
+        self.restore_path::<R, B>(file_path, device)
+    }
+
+    fn delete_iteration(&self, epoch: usize, iteration: usize) -> Result<(), CheckpointerError> {
+        self.delete_path::<B>(self.path_for_iteration(epoch, iteration))
     }
 }
@@ -0,0 +1,190 @@
+use super::{ClassificationCollector, ClassificationReportInput, EvaluationReport, MetricReport};
+use crate::metric::processor::{Event, EventProcessor, FullEventProcessor, ItemLazy, LearnerItem};
+use crate::metric::store::{Aggregate, EventStoreClient, Split};
+use crate::{TrainingInterrupter, ValidStep};
+use burn_core::data::dataloader::DataLoader;
+use burn_core::tensor::backend::Backend;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Evaluates a trained model on a test dataset, with the metrics of the validation.
+///
+/// To create an evaluator, use the [builder](crate::EvaluatorBuilder) struct.
+pub struct Evaluator<B: Backend, M, O: ItemLazy + 'static> {
+    pub(crate) model: M,
+    pub(crate) directory: PathBuf,
+    pub(crate) processor: FullEventProcessor<O, O>,
+    pub(crate) event_store: Arc<EventStoreClient>,
+    pub(crate) metrics: Vec<String>,
+    pub(crate) classification: Option<(ClassificationCollector<B>, ClassificationAdaptor<B, O>)>,
+    pub(crate) interrupter: TrainingInterrupter,
+}
+
+/// Adapts the output of the model to the input of the classification report.
+pub(crate) type ClassificationAdaptor<B, O> = fn(&O) -> ClassificationReportInput<B>;
+
+impl<B: Backend, M, O: ItemLazy + 'static> Evaluator<B, M, O> {
+    /// Evaluates the model on every item of the data loader.
+    ///
+    /// The metrics are rendered during the evaluation, and the report is displayed and saved as
+    /// `evaluation.json` in the directory of the evaluator at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloader` - The test dataloader.
+    ///
+    /// # Returns
+    ///
+    /// The evaluation report.
+    pub fn eval<I>(mut self, dataloader: Arc<dyn DataLoader<B, I>>) -> EvaluationReport
+    where
+        M: ValidStep<I, O>,
+    {
+        log::info!("Evaluating the model");
+
+        let mut iterator = dataloader.iter();
+        let mut iteration = 0;
+        let mut num_items = 0;
+
+        while let Some(item) = iterator.next() {
+            let progress = iterator.progress();
+            iteration += 1;
+            num_items = progress.items_processed;
+
+            let item = self.model.step(item);
+            if let Some((collector, adaptor)) = &mut self.classification {
+                collector.update(adaptor(&item));
+            }
+
+            let item = LearnerItem::new(item, progress, 1, 1, iteration, None, None);
+            self.processor.process_valid(Event::ProcessedItem(item));
+
+            if self.interrupter.should_stop() {
+                log::info!("Evaluation interrupted.");
+                break;
+            }
+        }
+        self.processor.process_valid(Event::EndEpoch(1));
+        // Signal the end and drop the renderer, so the TUI renderer has returned to the main
+        // screen when the report is printed.
+        self.processor.process_train(Event::End);
+        core::mem::drop(self.processor);
+
+        let metrics = self
+            .metrics
+            .iter()
+            .filter_map(|name| {
+                self.event_store
+                    .find_metric(name, 1, Aggregate::Mean, Split::Valid)
+                    .map(|value| MetricReport {
+                        name: name.clone(),
+                        value,
+                    })
+            })
+            .collect();
+
+        let report = EvaluationReport {
+            num_items,
+            metrics,
+            classification: self
+                .classification
+                .and_then(|(collector, _)| collector.report()),
+        };
+
+        println!("{report}");
+        if let Err(err) = report.save(self.directory.join("evaluation.json")) {
+            log::error!("Could not save the evaluation report: {err}");
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::InMemoryMetricLogger;
+    use crate::metric::LossMetric;
+    use crate::renderer::{MetricState, MetricsRenderer, TrainingProgress};
+    use crate::{ClassificationOutput, EvaluatorBuilder, TestBackend};
+    use burn_core::data::dataloader::{DataLoaderBuilder, batcher::Batcher};
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::tensor::{Int, Tensor};
+
+    struct TestModel;
+
+    impl ValidStep<Tensor<TestBackend, 2>, ClassificationOutput<TestBackend>> for TestModel {
+        fn step(&self, item: Tensor<TestBackend, 2>) -> ClassificationOutput<TestBackend> {
+            let [batch_size, _] = item.dims();
+            let targets = Tensor::<TestBackend, 1, Int>::zeros([batch_size], &item.device());
+            // The loss of each item is its first feature.
+            let loss = item.clone().narrow(1, 0, 1).squeeze(1);
+
+            ClassificationOutput::new(loss, item, targets)
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestBatcher;
+
+    impl Batcher<TestBackend, [f32; 2], Tensor<TestBackend, 2>> for TestBatcher {
+        fn batch(
+            &self,
+            items: Vec<[f32; 2]>,
+            device: &<TestBackend as Backend>::Device,
+        ) -> Tensor<TestBackend, 2> {
+            let items = items
+                .into_iter()
+                .map(|item| Tensor::<TestBackend, 1>::from_floats(item, device).unsqueeze())
+                .collect();
+
+            Tensor::cat(items, 0)
+        }
+    }
+
+    struct TestRenderer;
+
+    impl MetricsRenderer for TestRenderer {
+        fn update_train(&mut self, _state: MetricState) {}
+        fn update_valid(&mut self, _state: MetricState) {}
+        fn render_train(&mut self, _item: TrainingProgress) {}
+        fn render_valid(&mut self, _item: TrainingProgress) {}
+    }
+
+    #[test]
+    fn report_should_include_the_mean_of_the_metrics_over_the_items() {
+        let directory = std::env::temp_dir().join(format!("burn-evaluator-{}", std::process::id()));
+        std::fs::remove_dir_all(&directory).ok();
+        let items = [1.0, 3.0, 5.0, 7.0, 9.0].map(|loss| [loss, 0.0]).to_vec();
+        let dataloader = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(items));
+
+        let evaluator = EvaluatorBuilder::<TestBackend, _>::new(&directory)
+            .metric_numeric(LossMetric::new())
+            .metric_logger(InMemoryMetricLogger::new())
+            .renderer(TestRenderer)
+            .build(TestModel);
+        let report = evaluator.eval(dataloader);
+
+        // The batches of 2, 2 and 1 items are weighted by their size.
+        assert_eq!(report.num_items, 5);
+        assert_eq!(report.metrics.len(), 1);
+        assert_eq!(report.metrics[0].name, "Loss");
+        assert_eq!(report.metrics[0].value, 5.0);
+        assert!(report.classification.is_none());
+
+        let content = std::fs::read_to_string(directory.join("evaluation.json")).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "num_items": 5,
+                "metrics": [{ "name": "Loss", "value": 5.0 }],
+                "classification": null,
+            })
+        );
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+}
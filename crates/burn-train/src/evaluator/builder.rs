@@ -0,0 +1,133 @@
+use super::{ClassificationAdaptor, ClassificationCollector, ClassificationReportInput, Evaluator};
+use crate::TrainingInterrupter;
+use crate::logger::{FileMetricLogger, MetricLogger};
+use crate::metric::processor::{FullEventProcessor, ItemLazy, Metrics};
+use crate::metric::store::{EventStoreClient, LogEventStore};
+use crate::metric::{Adaptor, Metric, Numeric};
+use crate::renderer::{MetricsRenderer, default_renderer};
+use burn_core::tensor::backend::Backend;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Struct to configure and create an [evaluator](Evaluator).
+pub struct EvaluatorBuilder<B: Backend, O: ItemLazy + 'static> {
+    directory: PathBuf,
+    metrics: Metrics<O, O>,
+    metric_names: Vec<String>,
+    event_store: LogEventStore,
+    num_loggers: usize,
+    renderer: Option<Box<dyn MetricsRenderer + 'static>>,
+    interrupter: TrainingInterrupter,
+    classification: Option<ClassificationAdaptor<B, O>>,
+    class_names: Vec<String>,
+}
+
+impl<B: Backend, O: ItemLazy + 'static> EvaluatorBuilder<B, O> {
+    /// Creates a new evaluator builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory to save the metrics and the report.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            directory: directory.as_ref().to_path_buf(),
+            metrics: Metrics::default(),
+            metric_names: Vec::new(),
+            event_store: LogEventStore::default(),
+            num_loggers: 0,
+            renderer: None,
+            interrupter: TrainingInterrupter::new(),
+            classification: None,
+            class_names: Vec::new(),
+        }
+    }
+
+    /// Register a test metric.
+    pub fn metric<Me: Metric + 'static>(mut self, metric: Me) -> Self
+    where
+        O::ItemSync: Adaptor<Me::Input>,
+    {
+        self.metrics.register_valid_metric(metric);
+        self
+    }
+
+    /// Register a [numeric](Numeric) test [metric](Metric), whose mean is included in the
+    /// [report](super::EvaluationReport).
+    pub fn metric_numeric<Me: Metric + Numeric + 'static>(mut self, metric: Me) -> Self
+    where
+        O::ItemSync: Adaptor<Me::Input>,
+    {
+        self.metric_names.push(metric.name());
+        self.metrics.register_valid_metric_numeric(metric);
+        self
+    }
+
+    /// Replace the default metric logger, which saves the metrics to files in the `test`
+    /// directory, with the provided one.
+    pub fn metric_logger<ML: MetricLogger + 'static>(mut self, logger: ML) -> Self {
+        self.event_store.register_logger_valid(logger);
+        self.num_loggers += 1;
+        self
+    }
+
+    /// Replace the default CLI renderer with a custom one.
+    pub fn renderer<MR: MetricsRenderer + 'static>(mut self, renderer: MR) -> Self {
+        self.renderer = Some(Box::new(renderer));
+        self
+    }
+
+    /// Provides a handle that can be used to interrupt the evaluation.
+    pub fn interrupter(&self) -> TrainingInterrupter {
+        self.interrupter.clone()
+    }
+
+    /// Include a [classification report](super::ClassificationReport) in the report, with the
+    /// confusion matrix and the precision, the recall and the F1-score of each class.
+    ///
+    /// The predicted class is the one with the highest score, or the positive class of a binary
+    /// classification when its probability is above 0.5.
+    pub fn classification_report(mut self) -> Self
+    where
+        O: Adaptor<ClassificationReportInput<B>>,
+    {
+        self.classification = Some(|output: &O| output.adapt());
+        self
+    }
+
+    /// The names of the classes in the [classification report](Self::classification_report),
+    /// which are otherwise named after their index.
+    pub fn class_names<S: Into<String>>(mut self, names: impl IntoIterator<Item = S>) -> Self {
+        self.class_names = names.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Create the [evaluator](Evaluator) of a model.
+    pub fn build<M>(mut self, model: M) -> Evaluator<B, M, O> {
+        let renderer = self
+            .renderer
+            .unwrap_or_else(|| default_renderer(self.interrupter.clone(), None));
+
+        if self.num_loggers == 0 {
+            self.event_store
+                .register_logger_valid(FileMetricLogger::new(self.directory.join("test")));
+        }
+
+        let event_store = Arc::new(EventStoreClient::new(self.event_store));
+        let processor = FullEventProcessor::new(self.metrics, renderer, event_store.clone());
+
+        let class_names = self.class_names;
+        let classification = self
+            .classification
+            .map(|adaptor| (ClassificationCollector::new(class_names), adaptor));
+
+        Evaluator {
+            model,
+            directory: self.directory,
+            processor,
+            event_store,
+            metrics: self.metric_names,
+            classification,
+            interrupter: self.interrupter,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod base;
+mod builder;
+mod report;
+
+pub use base::*;
+pub use builder::*;
+pub use report::*;
@@ -0,0 +1,377 @@
+use crate::metric::classification::{ClassReduction, ClassificationMetricConfig, DecisionRule};
+use crate::metric::{ConfusionStats, ConfusionStatsInput};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor};
+use core::fmt::Display;
+use serde::Serialize;
+use std::num::NonZeroUsize;
+use std::path::Path;
+
+/// The result of an [evaluation](crate::Evaluator::eval).
+#[derive(Clone, Debug, Serialize)]
+pub struct EvaluationReport {
+    /// The number of evaluated items.
+    pub num_items: usize,
+    /// The mean of each numeric metric over the items.
+    pub metrics: Vec<MetricReport>,
+    /// The classification report, when [enabled](crate::EvaluatorBuilder::classification_report).
+    pub classification: Option<ClassificationReport>,
+}
+
+/// The value of a numeric metric in an [evaluation report](EvaluationReport).
+#[derive(Clone, Debug, Serialize)]
+pub struct MetricReport {
+    /// The metric name.
+    pub name: String,
+    /// The mean of the metric over the items.
+    pub value: f64,
+}
+
+/// The per-class results of a single-label classification.
+#[derive(Clone, Debug, Serialize)]
+pub struct ClassificationReport {
+    /// The results of each class.
+    pub classes: Vec<ClassReport>,
+    /// The unweighted mean of the results of the classes, with the total support.
+    pub macro_average: ClassReport,
+    /// The ratio of the items whose predicted class is the target class.
+    pub accuracy: f64,
+    /// The number of items of each target class (rows) for each predicted class (columns).
+    pub confusion_matrix: Vec<Vec<usize>>,
+}
+
+/// The results of a class in a [classification report](ClassificationReport).
+#[derive(Clone, Debug, Serialize)]
+pub struct ClassReport {
+    /// The class name.
+    pub name: String,
+    /// The ratio of the items predicted as the class that belong to it.
+    pub precision: f64,
+    /// The ratio of the items of the class that are predicted as it.
+    pub recall: f64,
+    /// The harmonic mean of the precision and the recall.
+    pub f1_score: f64,
+    /// The number of items of the class.
+    pub support: usize,
+}
+
+impl EvaluationReport {
+    /// Save the report as JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+}
+
+impl Display for EvaluationReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(
+            f,
+            "{:=>width_symbol$} Evaluation Report {:=>width_symbol$}",
+            "",
+            "",
+            width_symbol = 24,
+        )?;
+        writeln!(f, "Items: {}", self.num_items)?;
+
+        if !self.metrics.is_empty() {
+            let width = self
+                .metrics
+                .iter()
+                .map(|metric| metric.name.len())
+                .fold("Metric".len(), usize::max);
+
+            writeln!(f, "\n| {:<width$} | Value      |", "Metric")?;
+            writeln!(f, "|{:->width$}--|------------|", "")?;
+            for metric in self.metrics.iter() {
+                writeln!(f, "| {:<width$} | {:<10.5} |", metric.name, metric.value)?;
+            }
+        }
+
+        if let Some(classification) = &self.classification {
+            writeln!(f, "\n{classification}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for ClassificationReport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let width = self
+            .classes
+            .iter()
+            .chain([&self.macro_average])
+            .map(|class| class.name.len())
+            .fold("Class".len(), usize::max);
+
+        writeln!(
+            f,
+            "| {:<width$} | Precision | Recall    | F1-Score  | Support   |",
+            "Class"
+        )?;
+        writeln!(
+            f,
+            "|{:->width$}--|-----------|-----------|-----------|-----------|",
+            ""
+        )?;
+        for class in self.classes.iter().chain([&self.macro_average]) {
+            writeln!(
+                f,
+                "| {:<width$} | {:<9.4} | {:<9.4} | {:<9.4} | {:<9} |",
+                class.name, class.precision, class.recall, class.f1_score, class.support
+            )?;
+        }
+        writeln!(f, "\nAccuracy: {:.4}", self.accuracy)?;
+
+        // The columns fit the class names and the counts.
+        writeln!(
+            f,
+            "\nConfusion matrix (rows are targets, columns are predictions):"
+        )?;
+        let width_count = self
+            .confusion_matrix
+            .iter()
+            .flatten()
+            .map(|count| count.to_string().len())
+            .chain(self.classes.iter().map(|class| class.name.len()))
+            .fold(1, usize::max);
+
+        write!(f, "{:<width$}", "")?;
+        for class in self.classes.iter() {
+            write!(f, " {:>width_count$}", class.name)?;
+        }
+        writeln!(f)?;
+        for (class, counts) in self.classes.iter().zip(self.confusion_matrix.iter()) {
+            write!(f, "{:<width$}", class.name)?;
+            for count in counts {
+                write!(f, " {count:>width_count$}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The input of a [classification report](ClassificationReport).
+#[derive(new, Debug, Clone)]
+pub struct ClassificationReportInput<B: Backend> {
+    /// Sample x Class scores, or the probability of the positive class for a binary
+    /// classification with a single column.
+    pub output: Tensor<B, 2>,
+    /// The target class of each sample.
+    pub targets: Tensor<B, 1, Int>,
+}
+
+/// Collects the counts of a [classification report](ClassificationReport) over the batches.
+pub(crate) struct ClassificationCollector<B: Backend> {
+    class_names: Vec<String>,
+    counts: Option<ClassificationCounts<B>>,
+}
+
+struct ClassificationCounts<B: Backend> {
+    true_positive: Tensor<B, 1>,
+    false_positive: Tensor<B, 1>,
+    false_negative: Tensor<B, 1>,
+    confusion_matrix: Tensor<B, 2>,
+}
+
+impl<B: Backend> ClassificationCollector<B> {
+    pub(crate) fn new(class_names: Vec<String>) -> Self {
+        Self {
+            class_names,
+            counts: None,
+        }
+    }
+
+    pub(crate) fn update(&mut self, input: ClassificationReportInput<B>) {
+        let ClassificationReportInput { output, targets } = input;
+
+        // A binary classification is reported with a negative and a positive class.
+        let output = match output.dims()[1] {
+            1 => Tensor::cat(vec![output.clone().neg().add_scalar(1.0), output], 1),
+            _ => output,
+        };
+        let [_, num_classes] = output.dims();
+        let targets = targets.one_hot::<2>(num_classes);
+
+        let config = ClassificationMetricConfig {
+            decision_rule: DecisionRule::TopK(NonZeroUsize::new(1).unwrap()),
+            class_reduction: ClassReduction::Macro,
+        };
+        let stats = ConfusionStats::new(
+            &ConfusionStatsInput::new(output.clone(), targets.clone().bool()),
+            &config,
+        );
+        let predictions = output
+            .argmax(1)
+            .squeeze::<1>(1)
+            .one_hot::<2>(num_classes)
+            .float();
+
+        let counts = ClassificationCounts {
+            true_positive: stats.clone().true_positive(),
+            false_positive: stats.clone().false_positive(),
+            false_negative: stats.false_negative(),
+            confusion_matrix: targets.float().transpose().matmul(predictions),
+        };
+
+        self.counts = Some(match self.counts.take() {
+            Some(previous) => ClassificationCounts {
+                true_positive: previous.true_positive + counts.true_positive,
+                false_positive: previous.false_positive + counts.false_positive,
+                false_negative: previous.false_negative + counts.false_negative,
+                confusion_matrix: previous.confusion_matrix + counts.confusion_matrix,
+            },
+            None => counts,
+        });
+    }
+
+    /// The report of the collected batches, if any.
+    pub(crate) fn report(self) -> Option<ClassificationReport> {
+        let counts = self.counts?;
+        let to_vec = |tensor: Tensor<B, 1>| tensor.into_data().iter::<f64>().collect::<Vec<_>>();
+
+        let true_positive = to_vec(counts.true_positive);
+        let false_positive = to_vec(counts.false_positive);
+        let false_negative = to_vec(counts.false_negative);
+        let num_classes = true_positive.len();
+        let confusion_matrix = counts
+            .confusion_matrix
+            .into_data()
+            .iter::<f64>()
+            .map(|count| count as usize)
+            .collect::<Vec<_>>()
+            .chunks(num_classes)
+            .map(|row| row.to_vec())
+            .collect::<Vec<_>>();
+
+        let classes = (0..num_classes)
+            .map(|class| {
+                let (tp, fp, fn_) = (
+                    true_positive[class],
+                    false_positive[class],
+                    false_negative[class],
+                );
+                let precision = ratio(tp, tp + fp);
+                let recall = ratio(tp, tp + fn_);
+
+                ClassReport {
+                    name: self
+                        .class_names
+                        .get(class)
+                        .cloned()
+                        .unwrap_or_else(|| class.to_string()),
+                    precision,
+                    recall,
+                    f1_score: ratio(2.0 * precision * recall, precision + recall),
+                    support: (tp + fn_) as usize,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mean = |value: fn(&ClassReport) -> f64| {
+            classes.iter().map(value).sum::<f64>() / num_classes as f64
+        };
+        let support = classes.iter().map(|class| class.support).sum::<usize>();
+        let macro_average = ClassReport {
+            name: "Macro Average".to_string(),
+            precision: mean(|class| class.precision),
+            recall: mean(|class| class.recall),
+            f1_score: mean(|class| class.f1_score),
+            support,
+        };
+        let correct = (0..num_classes)
+            .map(|class| confusion_matrix[class][class])
+            .sum::<usize>();
+
+        Some(ClassificationReport {
+            accuracy: ratio(correct as f64, support as f64),
+            classes,
+            macro_average,
+            confusion_matrix,
+        })
+    }
+}
+
+/// The ratio of two counts, which is 0 when the denominator is 0.
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    match denominator > 0.0 {
+        true => numerator / denominator,
+        false => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn multiclass_report_should_count_each_class() {
+        let device = Default::default();
+        let mut collector =
+            ClassificationCollector::<TestBackend>::new(vec!["cat".to_string(), "dog".to_string()]);
+
+        // The predictions are [1, 1, 0] then [2, 2].
+        collector.update(ClassificationReportInput::new(
+            Tensor::from_data([[0.2, 0.7, 0.1], [0.1, 0.8, 0.1], [0.6, 0.3, 0.1]], &device),
+            Tensor::from_data([0, 1, 0], &device),
+        ));
+        collector.update(ClassificationReportInput::new(
+            Tensor::from_data([[0.1, 0.2, 0.7], [0.3, 0.1, 0.6]], &device),
+            Tensor::from_data([2, 1], &device),
+        ));
+        let report = collector.report().unwrap();
+
+        assert_eq!(
+            report.confusion_matrix,
+            vec![vec![1, 1, 0], vec![0, 1, 1], vec![0, 0, 1]]
+        );
+        assert_eq!(
+            report
+                .classes
+                .iter()
+                .map(|class| (class.name.as_str(), class.support))
+                .collect::<Vec<_>>(),
+            vec![("cat", 2), ("dog", 2), ("2", 1)]
+        );
+        let dog = &report.classes[1];
+        assert_eq!((dog.precision, dog.recall, dog.f1_score), (0.5, 0.5, 0.5));
+        let class_2 = &report.classes[2];
+        assert_eq!(class_2.precision, 0.5);
+        assert_eq!(class_2.recall, 1.0);
+        assert_eq!(report.accuracy, 0.6);
+        assert_eq!(report.macro_average.support, 5);
+        assert!(report.to_string().contains("Macro Average"));
+    }
+
+    #[test]
+    fn binary_report_should_have_a_negative_and_a_positive_class() {
+        let device = Default::default();
+        let mut collector = ClassificationCollector::<TestBackend>::new(Vec::new());
+
+        collector.update(ClassificationReportInput::new(
+            Tensor::from_data([[0.3], [0.2], [0.7], [0.1], [0.55]], &device),
+            Tensor::from_data([0, 1, 0, 0, 1], &device),
+        ));
+        let report = collector.report().unwrap();
+
+        assert_eq!(report.confusion_matrix, vec![vec![2, 1], vec![1, 1]]);
+        assert_eq!(report.classes[1].precision, 0.5);
+        assert_eq!(report.classes[0].recall, 2.0 / 3.0);
+    }
+
+    #[test]
+    fn empty_report_should_be_none() {
+        let collector = ClassificationCollector::<TestBackend>::new(Vec::new());
+
+        assert!(collector.report().is_none());
+    }
+}
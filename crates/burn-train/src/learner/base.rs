@@ -1,13 +1,20 @@
 use crate::LearnerSummaryConfig;
-use crate::checkpoint::{Checkpointer, CheckpointingAction, CheckpointingStrategy};
+use crate::checkpoint::{
+    AsyncCheckpointer, Checkpointer, CheckpointerError, CheckpointingAction, CheckpointingStrategy,
+};
 use crate::components::LearnerComponents;
-use crate::learner::EarlyStoppingStrategy;
-use crate::metric::store::EventStoreClient;
+use crate::learner::{EarlyStoppingStrategy, LearnerCallbacks};
+use crate::metric::processor::EventProcessor;
+use crate::metric::store::{Aggregate, EventStoreClient, Split};
+use burn_core as burn;
+use burn_core::data::dataloader::DataLoaderState;
 use burn_core::lr_scheduler::LrScheduler;
-use burn_core::module::Module;
-use burn_core::optim::Optimizer;
+use burn_core::module::{Module, ModuleEma, ModuleEmaConfig, ModuleEmaRecord};
+use burn_core::optim::{GradScalerConfig, GradScalerRecord, HalfPrecision, Optimizer};
+use burn_core::record::Record;
 use burn_core::tensor::Device;
 use burn_core::tensor::backend::Backend;
+use std::any::Any;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -20,30 +27,167 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) lr_scheduler: LC::LrScheduler,
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
+    pub(crate) checkpoint_iteration: Option<(usize, usize)>,
+    pub(crate) checkpoint_interval: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
     pub(crate) early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    pub(crate) lr_scheduler_metric: Option<LrSchedulerMetric>,
+    pub(crate) ema: Option<ModuleEmaConfig>,
+    pub(crate) ema_validation: bool,
+    pub(crate) mixed_precision: Option<MixedPrecision>,
     pub(crate) event_processor: LC::EventProcessor,
     pub(crate) event_store: Arc<EventStoreClient>,
+    pub(crate) callbacks: LearnerCallbacks<<LC::EventProcessor as EventProcessor>::ItemTrain>,
     pub(crate) summary: Option<LearnerSummaryConfig>,
 }
 
+/// The settings of [mixed precision training](crate::LearnerBuilder::mixed_precision).
+pub(crate) struct MixedPrecision {
+    pub(crate) precision: HalfPrecision,
+    pub(crate) scaler: GradScalerConfig,
+    /// The [step](crate::MixedPrecisionStepFn) of the model, whose input type is only known when
+    /// the learner is fitted.
+    pub(crate) step: Box<dyn Any + Send + Sync>,
+}
+
+/// A metric reported to the [learning rate scheduler](LrScheduler) at the end of every epoch.
+#[derive(new)]
+pub(crate) struct LrSchedulerMetric {
+    name: String,
+    aggregate: Aggregate,
+    split: Split,
+}
+
+impl LrSchedulerMetric {
+    pub(crate) fn report<S: LrScheduler>(
+        &self,
+        scheduler: &mut S,
+        epoch: usize,
+        store: &EventStoreClient,
+    ) {
+        match store.find_metric(&self.name, epoch, self.aggregate, self.split) {
+            Some(value) => scheduler.report_metric(value),
+            None => log::warn!("Can't find metric for the learning rate scheduler."),
+        }
+    }
+}
+
+/// The checkpointer used for the [moving average](ModuleEma) of the model weights.
+pub(crate) type EmaCheckpointer<LC> = AsyncCheckpointer<
+    ModuleEmaRecord<<LC as LearnerComponents>::Backend, <LC as LearnerComponents>::Model>,
+    <LC as LearnerComponents>::Backend,
+>;
+
+/// The checkpointer used for the [training state](TrainingStateRecord) of the checkpoints.
+pub(crate) type StateCheckpointer<LC> =
+    AsyncCheckpointer<TrainingStateRecord, <LC as LearnerComponents>::Backend>;
+
+/// The state of the training saved with the checkpoints, in addition to the model, the optimizer
+/// and the scheduler, to resume the training exactly where it was interrupted.
+#[derive(Record, Clone, Debug)]
+pub(crate) struct TrainingStateRecord {
+    /// The state of the random number generator of the backend, if the backend exposes it.
+    pub(crate) rng: Option<Vec<u8>>,
+    /// The state of the data loader of each device, empty for epoch checkpoints.
+    pub(crate) dataloaders: Vec<DataLoaderStateRecord>,
+    /// The state of the gradient scaler of mixed precision training.
+    pub(crate) grad_scaler: Option<GradScalerRecord>,
+}
+
+#[derive(Record, Clone, Debug)]
+pub(crate) struct DataLoaderStateRecord {
+    iteration: usize,
+    items_processed: Vec<usize>,
+}
+
+impl From<DataLoaderState> for DataLoaderStateRecord {
+    fn from(state: DataLoaderState) -> Self {
+        Self {
+            iteration: state.iteration,
+            items_processed: state.items_processed,
+        }
+    }
+}
+
+impl From<DataLoaderStateRecord> for DataLoaderState {
+    fn from(record: DataLoaderStateRecord) -> Self {
+        Self {
+            iteration: record.iteration,
+            items_processed: record.items_processed,
+        }
+    }
+}
+
+/// Identifies a checkpoint saved at the end of an epoch, or at an iteration of an epoch.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum CheckpointKey {
+    Epoch(usize),
+    Iteration(usize, usize),
+}
+
+impl CheckpointKey {
+    fn save<R: Record<B>, B: Backend>(
+        &self,
+        checkpointer: &impl Checkpointer<R, B>,
+        record: R,
+    ) -> Result<(), CheckpointerError> {
+        match *self {
+            Self::Epoch(epoch) => checkpointer.save(epoch, record),
+            Self::Iteration(epoch, iteration) => {
+                checkpointer.save_iteration(epoch, iteration, record)
+            }
+        }
+    }
+
+    fn restore<R: Record<B>, B: Backend>(
+        &self,
+        checkpointer: &impl Checkpointer<R, B>,
+        device: &B::Device,
+    ) -> Result<R, CheckpointerError> {
+        match *self {
+            Self::Epoch(epoch) => checkpointer.restore(epoch, device),
+            Self::Iteration(epoch, iteration) => {
+                checkpointer.restore_iteration(epoch, iteration, device)
+            }
+        }
+    }
+
+    fn delete<R: Record<B>, B: Backend>(
+        &self,
+        checkpointer: &impl Checkpointer<R, B>,
+    ) -> Result<(), CheckpointerError> {
+        match *self {
+            Self::Epoch(epoch) => checkpointer.delete(epoch),
+            Self::Iteration(epoch, iteration) => checkpointer.delete_iteration(epoch, iteration),
+        }
+    }
+}
+
 #[derive(new)]
 pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     model: LC::CheckpointerModel,
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
+    ema: Option<EmaCheckpointer<LC>>,
+    state: StateCheckpointer<LC>,
     strategy: LC::CheckpointerStrategy,
+    /// Only the last iteration checkpoint is kept.
+    #[new(default)]
+    last_iteration: Option<CheckpointKey>,
 }
 
 impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn checkpoint(
         &mut self,
         model: &LC::Model,
         optim: &LC::Optimizer,
         scheduler: &LC::LrScheduler,
+        ema: Option<&ModuleEma<LC::Backend, LC::Model>>,
+        grad_scaler: Option<GradScalerRecord>,
         epoch: usize,
         store: &EventStoreClient,
     ) {
@@ -51,30 +195,92 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
 
         for action in actions {
             match action {
-                CheckpointingAction::Delete(epoch) => {
-                    self.model
-                        .delete(epoch)
-                        .expect("Can delete model checkpoint.");
-                    self.optim
-                        .delete(epoch)
-                        .expect("Can delete optimizer checkpoint.");
-                    self.lr_scheduler
-                        .delete(epoch)
-                        .expect("Can delete learning rate scheduler checkpoint.");
-                }
+                CheckpointingAction::Delete(epoch) => self.delete(CheckpointKey::Epoch(epoch)),
                 CheckpointingAction::Save => {
-                    self.model
-                        .save(epoch, model.clone().into_record())
-                        .expect("Can save model checkpoint.");
-                    self.optim
-                        .save(epoch, optim.to_record())
-                        .expect("Can save optimizer checkpoint.");
-                    self.lr_scheduler
-                        .save(epoch, scheduler.to_record())
-                        .expect("Can save learning rate scheduler checkpoint.");
+                    let key = CheckpointKey::Epoch(epoch);
+                    self.save(key, model, optim, scheduler, ema);
+                    self.save_state(key, grad_scaler, Vec::new());
                 }
             }
         }
+
+        // The epoch is complete, so it won't be resumed from one of its iterations.
+        if let Some(key) = self.last_iteration.take() {
+            self.delete(key);
+        }
+    }
+
+    /// Save a checkpoint in the middle of an epoch, from which the training can be resumed at
+    /// the next iteration, replacing the previous one.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn checkpoint_iteration(
+        &mut self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        ema: Option<&ModuleEma<LC::Backend, LC::Model>>,
+        grad_scaler: Option<GradScalerRecord>,
+        dataloaders: Vec<DataLoaderState>,
+        epoch: usize,
+        iteration: usize,
+    ) {
+        let key = CheckpointKey::Iteration(epoch, iteration);
+        self.save(key, model, optim, scheduler, ema);
+        self.save_state(key, grad_scaler, dataloaders);
+
+        if let Some(key) = self.last_iteration.replace(key) {
+            self.delete(key);
+        }
+    }
+
+    fn save_state(
+        &self,
+        key: CheckpointKey,
+        grad_scaler: Option<GradScalerRecord>,
+        dataloaders: Vec<DataLoaderState>,
+    ) {
+        let state = TrainingStateRecord {
+            rng: LC::Backend::rng_state(),
+            dataloaders: dataloaders.into_iter().map(Into::into).collect(),
+            grad_scaler,
+        };
+        key.save(&self.state, state)
+            .expect("Can save training state checkpoint.");
+    }
+
+    fn save(
+        &self,
+        key: CheckpointKey,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        ema: Option<&ModuleEma<LC::Backend, LC::Model>>,
+    ) {
+        key.save(&self.model, model.clone().into_record())
+            .expect("Can save model checkpoint.");
+        key.save(&self.optim, optim.to_record())
+            .expect("Can save optimizer checkpoint.");
+        key.save(&self.lr_scheduler, scheduler.to_record())
+            .expect("Can save learning rate scheduler checkpoint.");
+        if let (Some(checkpointer), Some(ema)) = (&self.ema, ema) {
+            key.save(checkpointer, ema.to_record())
+                .expect("Can save EMA checkpoint.");
+        }
+    }
+
+    fn delete(&self, key: CheckpointKey) {
+        key.delete(&self.model)
+            .expect("Can delete model checkpoint.");
+        key.delete(&self.optim)
+            .expect("Can delete optimizer checkpoint.");
+        key.delete(&self.lr_scheduler)
+            .expect("Can delete learning rate scheduler checkpoint.");
+        if let Some(checkpointer) = &self.ema {
+            key.delete(checkpointer)
+                .expect("Can delete EMA checkpoint.");
+        }
+        key.delete(&self.state)
+            .expect("Can delete training state checkpoint.");
     }
 
     pub(crate) fn load_checkpoint(
@@ -83,28 +289,73 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
         optim: LC::Optimizer,
         scheduler: LC::LrScheduler,
         device: &Device<LC::Backend>,
-        epoch: usize,
+        key: CheckpointKey,
     ) -> (LC::Model, LC::Optimizer, LC::LrScheduler) {
-        let record = self
-            .model
-            .restore(epoch, device)
+        let record = key
+            .restore(&self.model, device)
             .expect("Can load model checkpoint.");
         let model = model.load_record(record);
 
-        let record = self
-            .optim
-            .restore(epoch, device)
+        let record = key
+            .restore(&self.optim, device)
             .expect("Can load optimizer checkpoint.");
         let optim = optim.load_record(record);
 
-        let record = self
-            .lr_scheduler
-            .restore(epoch, device)
+        let record = key
+            .restore(&self.lr_scheduler, device)
             .expect("Can load learning rate scheduler checkpoint.");
         let scheduler = scheduler.load_record(record);
 
         (model, optim, scheduler)
     }
+
+    pub(crate) fn load_ema_checkpoint(
+        &self,
+        ema: ModuleEma<LC::Backend, LC::Model>,
+        device: &Device<LC::Backend>,
+        key: CheckpointKey,
+    ) -> ModuleEma<LC::Backend, LC::Model> {
+        let Some(checkpointer) = &self.ema else {
+            return ema;
+        };
+
+        // The EMA may have been enabled after the checkpoint was saved, in which case it starts
+        // again from the restored model.
+        match key.restore(checkpointer, device) {
+            Ok(record) => ema.load_record(record),
+            Err(err) => {
+                log::warn!("Can't load EMA checkpoint, starting from the model: {err:?}");
+                ema
+            }
+        }
+    }
+
+    /// Load the training state of a checkpoint. An iteration checkpoint is then replaced by the
+    /// next one.
+    ///
+    /// Epoch checkpoints saved without a training state resume without it.
+    pub(crate) fn load_state_checkpoint(
+        &mut self,
+        device: &Device<LC::Backend>,
+        key: CheckpointKey,
+    ) -> Option<TrainingStateRecord> {
+        match key {
+            CheckpointKey::Epoch(_) => match key.restore(&self.state, device) {
+                Ok(state) => Some(state),
+                Err(err) => {
+                    log::warn!("Can't load training state checkpoint, resuming without: {err:?}");
+                    None
+                }
+            },
+            CheckpointKey::Iteration(..) => {
+                let state = key
+                    .restore(&self.state, device)
+                    .expect("Can load training state checkpoint.");
+                self.last_iteration = Some(key);
+                Some(state)
+            }
+        }
+    }
 }
 
 #[derive(Clone, Default)]
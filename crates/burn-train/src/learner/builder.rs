@@ -4,24 +4,26 @@ use std::sync::Arc;
 
 use super::Learner;
 use crate::checkpoint::{
-    AsyncCheckpointer, CheckpointingStrategy, ComposedCheckpointingStrategy, FileCheckpointer,
-    KeepLastNCheckpoints, MetricCheckpointingStrategy,
+    AsyncCheckpointer, Checkpointer, CheckpointingStrategy, ComposedCheckpointingStrategy,
+    FileCheckpointer, KeepLastNCheckpoints, MetricCheckpointingStrategy,
 };
 use crate::components::LearnerComponentsMarker;
 use crate::learner::EarlyStoppingStrategy;
 use crate::learner::base::TrainingInterrupter;
-use crate::logger::{FileMetricLogger, MetricLogger};
+use crate::logger::{FileMetricLogger, Hyperparameters, MetricLogger};
 use crate::metric::processor::{AsyncProcessor, FullEventProcessor, ItemLazy, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
 use crate::metric::{Adaptor, LossMetric, Metric};
 use crate::renderer::{MetricsRenderer, default_renderer};
 use crate::{
-    ApplicationLoggerInstaller, FileApplicationLoggerInstaller, LearnerCheckpointer,
-    LearnerSummaryConfig,
+    ApplicationLoggerInstaller, FileApplicationLoggerInstaller, LearnerCallback, LearnerCallbacks,
+    LearnerCheckpointer, LearnerSummaryConfig, LrSchedulerMetric, MixedPrecision,
+    MixedPrecisionStepFn, MixedPrecisionTrainStep, TrainingStateRecord,
 };
+use burn_core::config::Config;
 use burn_core::lr_scheduler::LrScheduler;
-use burn_core::module::AutodiffModule;
-use burn_core::optim::Optimizer;
+use burn_core::module::{AutodiffModule, ModuleEmaConfig, ModuleEmaRecord};
+use burn_core::optim::{GradScalerConfig, HalfPrecision, Optimizer};
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::AutodiffBackend;
 
@@ -43,9 +45,13 @@ where
         AsyncCheckpointer<M::Record, B>,
         AsyncCheckpointer<O::Record, B>,
         AsyncCheckpointer<S::Record<B>, B>,
+        AsyncCheckpointer<ModuleEmaRecord<B, M>, B>,
+        AsyncCheckpointer<TrainingStateRecord, B>,
     )>,
     num_epochs: usize,
     checkpoint: Option<usize>,
+    checkpoint_iteration: Option<(usize, usize)>,
+    checkpoint_interval: Option<usize>,
     directory: PathBuf,
     grad_accumulation: Option<usize>,
     devices: Vec<B::Device>,
@@ -57,8 +63,14 @@ where
     num_loggers: usize,
     checkpointer_strategy: Box<dyn CheckpointingStrategy>,
     early_stopping: Option<Box<dyn EarlyStoppingStrategy>>,
+    lr_scheduler_metric: Option<LrSchedulerMetric>,
+    ema: Option<ModuleEmaConfig>,
+    ema_validation: bool,
+    mixed_precision: Option<MixedPrecision>,
     summary_metrics: HashSet<String>,
     summary: bool,
+    hyperparameters: Hyperparameters,
+    callbacks: Vec<Box<dyn LearnerCallback<T>>>,
 }
 
 impl<B, T, V, M, O, S> LearnerBuilder<B, T, V, M, O, S>
@@ -81,6 +93,8 @@ where
         Self {
             num_epochs: 1,
             checkpoint: None,
+            checkpoint_iteration: None,
+            checkpoint_interval: None,
             checkpointers: None,
             directory,
             grad_accumulation: None,
@@ -105,8 +119,14 @@ where
                     .build(),
             ),
             early_stopping: None,
+            lr_scheduler_metric: None,
+            ema: None,
+            ema_validation: false,
+            mixed_precision: None,
             summary_metrics: HashSet::new(),
             summary: false,
+            hyperparameters: Hyperparameters::new(),
+            callbacks: Vec::new(),
         }
     }
 
@@ -127,6 +147,17 @@ where
         self
     }
 
+    /// Log the fields of a [config](Config) as the hyperparameters of the experiment, with the
+    /// [metric loggers](MetricLogger) that support it.
+    ///
+    /// Nested configs are flattened, with the names of their fields joined by dots. Several
+    /// configs can be logged, such as the configs of the model and of the optimizer, in which
+    /// case their fields should have different names.
+    pub fn hyperparameters<C: Config>(mut self, config: &C) -> Self {
+        self.hyperparameters = self.hyperparameters.with_config("", config);
+        self
+    }
+
     /// Update the checkpointing_strategy.
     pub fn with_checkpointing_strategy<CS>(mut self, strategy: CS) -> Self
     where
@@ -136,6 +167,16 @@ where
         self
     }
 
+    /// Register a [callback](LearnerCallback) whose hooks are called during the training, after
+    /// the callbacks registered before it.
+    pub fn callback<C>(mut self, callback: C) -> Self
+    where
+        C: LearnerCallback<T> + 'static,
+    {
+        self.callbacks.push(Box::new(callback));
+        self
+    }
+
     /// Replace the default CLI renderer with a custom one.
     ///
     /// # Arguments
@@ -224,6 +265,40 @@ where
         self
     }
 
+    /// The iteration of an epoch from which the training must resume, saved with
+    /// [checkpoint_interval](Self::checkpoint_interval).
+    ///
+    /// The training resumes at the next batch, with the same state as if it had never been
+    /// interrupted, overriding the [epoch checkpoint](Self::checkpoint).
+    pub fn checkpoint_iteration(mut self, epoch: usize, iteration: usize) -> Self {
+        self.checkpoint_iteration = Some((epoch, iteration));
+        self
+    }
+
+    /// Save a checkpoint every `interval` iterations in the middle of the epochs, in addition to
+    /// the checkpoints at the end of the epochs.
+    ///
+    /// Along with the model, the optimizer and the scheduler, the checkpoint saves the random
+    /// state of the backend and the position of the data loaders, so the training can be
+    /// [resumed](Self::checkpoint_iteration) at the same batch. Only the last one is kept, and it
+    /// is deleted at the end of the epoch.
+    ///
+    /// # Notes
+    ///
+    /// A [file checkpointer](Self::with_file_checkpointer) should be registered, otherwise
+    /// [build](Self::build) panics, and the checkpoints are only saved after an optimizer step
+    /// when gradients are accumulated. The training only resumes bit-for-bit with data loaders
+    /// using at most one worker thread (`num_workers <= 1`), since the order of the batches of
+    /// multiple threads isn't deterministic.
+    ///
+    /// The state of the metrics isn't saved: when resuming, the metrics of the interrupted epoch
+    /// only aggregate the iterations following the checkpoint.
+    pub fn checkpoint_interval(mut self, interval: usize) -> Self {
+        assert!(interval > 0, "The checkpoint interval should be positive.");
+        self.checkpoint_interval = Some(interval);
+        self
+    }
+
     /// Provides a handle that can be used to interrupt training.
     pub fn interrupter(&self) -> TrainingInterrupter {
         self.interrupter.clone()
@@ -239,6 +314,68 @@ where
         self
     }
 
+    /// Report a metric to the [learning rate scheduler](LrScheduler) at the end of every epoch,
+    /// for schedulers that adapt the learning rate to it, such as
+    /// [ReduceLrOnPlateauScheduler](burn_core::lr_scheduler::reduce_on_plateau::ReduceLrOnPlateauScheduler).
+    ///
+    /// # Notes
+    ///
+    /// The metric should be registered, otherwise no data is collected.
+    pub fn lr_scheduler_metric<Me: Metric>(
+        mut self,
+        metric: &Me,
+        aggregate: Aggregate,
+        split: Split,
+    ) -> Self {
+        self.lr_scheduler_metric = Some(LrSchedulerMetric::new(metric.name(), aggregate, split));
+        self
+    }
+
+    /// Keep an [exponential moving average](burn_core::module::ModuleEma) of the model weights,
+    /// updated after every optimizer step and saved with the checkpoints.
+    pub fn ema(mut self, config: ModuleEmaConfig) -> Self {
+        self.ema = Some(config);
+        self
+    }
+
+    /// Validate the moving average of the model weights instead of the trained model, which
+    /// is then also the model returned by [fit](Learner::fit).
+    ///
+    /// # Notes
+    ///
+    /// The moving average should be enabled with [ema](Self::ema), otherwise the trained model
+    /// is validated.
+    pub fn validate_with_ema(mut self) -> Self {
+        self.ema_validation = true;
+        self
+    }
+
+    /// Train with mixed precision: the forward and backward passes run on a
+    /// [half precision copy](HalfPrecision::cast) of the model, while the optimizer updates its
+    /// full precision weights. The loss is scaled by a
+    /// [gradient scaler](burn_core::optim::GradScaler), and the batches whose gradients overflow
+    /// are skipped: they don't step the optimizer, the learning rate scheduler nor the gradient
+    /// accumulation, and the callbacks aren't called.
+    ///
+    /// # Notes
+    ///
+    /// The model should implement [MixedPrecisionTrainStep] for the items of the training data
+    /// loader, and only single device training is supported. The state of the gradient scaler
+    /// is saved with the checkpoints.
+    pub fn mixed_precision<TI>(mut self, precision: HalfPrecision, scaler: GradScalerConfig) -> Self
+    where
+        M: MixedPrecisionTrainStep<TI, T>,
+        TI: 'static,
+    {
+        let step: MixedPrecisionStepFn<M, TI, T> = M::step_mixed_precision;
+        self.mixed_precision = Some(MixedPrecision {
+            precision,
+            scaler,
+            step: Box::new(step),
+        });
+        self
+    }
+
     /// By default, Rust logs are captured and written into
     /// `experiment.log`. If disabled, standard Rust log handling
     /// will apply.
@@ -265,12 +402,16 @@ where
         let checkpointer_optimizer =
             FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "optim");
         let checkpointer_scheduler: FileCheckpointer<FR> =
-            FileCheckpointer::new(recorder, &checkpoint_dir, "scheduler");
+            FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "scheduler");
+        let checkpointer_ema = FileCheckpointer::new(recorder.clone(), &checkpoint_dir, "ema");
+        let checkpointer_state = FileCheckpointer::new(recorder, &checkpoint_dir, "state");
 
         self.checkpointers = Some((
             AsyncCheckpointer::new(checkpointer_model),
             AsyncCheckpointer::new(checkpointer_optimizer),
             AsyncCheckpointer::new(checkpointer_scheduler),
+            AsyncCheckpointer::new(checkpointer_ema),
+            AsyncCheckpointer::new(checkpointer_state),
         ));
 
         self
@@ -312,14 +453,35 @@ where
         O::Record: 'static,
         S::Record<B>: 'static,
     {
+        if self.checkpoint_interval.is_some() || self.checkpoint_iteration.is_some() {
+            let supports_iteration =
+                self.checkpointers
+                    .as_ref()
+                    .is_some_and(|(model, optim, scheduler, ema, state)| {
+                        model.supports_iteration()
+                            && optim.supports_iteration()
+                            && scheduler.supports_iteration()
+                            && ema.supports_iteration()
+                            && state.supports_iteration()
+                    });
+            assert!(
+                supports_iteration,
+                "Iteration checkpoints require a checkpointer supporting them."
+            );
+        }
         if self.tracing_logger.is_some() {
             if let Err(e) = self.tracing_logger.as_ref().unwrap().install() {
                 log::warn!("Failed to install the experiment logger: {}", e);
             }
         }
+        // An interrupted epoch is rendered from its start.
+        let checkpoint = self
+            .checkpoint_iteration
+            .map(|(epoch, _)| epoch - 1)
+            .or(self.checkpoint);
         let renderer = self
             .renderer
-            .unwrap_or_else(|| default_renderer(self.interrupter.clone(), self.checkpoint));
+            .unwrap_or_else(|| default_renderer(self.interrupter.clone(), checkpoint));
 
         if self.num_loggers == 0 {
             self.event_store
@@ -327,6 +489,9 @@ where
             self.event_store
                 .register_logger_valid(FileMetricLogger::new(self.directory.join("valid")));
         }
+        if !self.hyperparameters.is_empty() {
+            self.event_store.log_hyperparameters(&self.hyperparameters);
+        }
 
         let event_store = Arc::new(EventStoreClient::new(self.event_store));
         let event_processor = AsyncProcessor::new(FullEventProcessor::new(
@@ -335,9 +500,25 @@ where
             event_store.clone(),
         ));
 
-        let checkpointer = self.checkpointers.map(|(model, optim, scheduler)| {
-            LearnerCheckpointer::new(model, optim, scheduler, self.checkpointer_strategy)
-        });
+        let callbacks = LearnerCallbacks::new(
+            self.callbacks,
+            self.interrupter.clone(),
+            event_store.clone(),
+        );
+
+        let ema_enabled = self.ema.is_some();
+        let checkpointer = self
+            .checkpointers
+            .map(|(model, optim, scheduler, ema, state)| {
+                LearnerCheckpointer::new(
+                    model,
+                    optim,
+                    scheduler,
+                    ema_enabled.then_some(ema),
+                    state,
+                    self.checkpointer_strategy,
+                )
+            });
 
         let summary = if self.summary {
             Some(LearnerSummaryConfig {
@@ -356,11 +537,18 @@ where
             num_epochs: self.num_epochs,
             event_processor,
             event_store,
+            callbacks,
             checkpoint: self.checkpoint,
+            checkpoint_iteration: self.checkpoint_iteration,
+            checkpoint_interval: self.checkpoint_interval,
             grad_accumulation: self.grad_accumulation,
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
+            lr_scheduler_metric: self.lr_scheduler_metric,
+            ema: self.ema,
+            ema_validation: self.ema_validation,
+            mixed_precision: self.mixed_precision,
             summary,
         }
     }
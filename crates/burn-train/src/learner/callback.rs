@@ -0,0 +1,347 @@
+use crate::TrainingInterrupter;
+use crate::metric::processor::{Event, EventProcessor};
+use crate::metric::store::{Aggregate, EventStoreClient, MetricsUpdate, Split};
+use crate::metric::{MetricEntry, format_float};
+use burn_core::LearningRate;
+use burn_core::optim::GradientsParams;
+use std::sync::Arc;
+
+/// Hooks called by the [learner](crate::Learner) during the training, to extend the training loop
+/// without rewriting it, such as to track the norm of the gradients or to follow a custom
+/// learning rate schedule.
+///
+/// All the hooks do nothing by default. They receive a [context](CallbackContext) that can change
+/// the learning rate of the optimizer step, stop the training and log values alongside the
+/// metrics. The type `T` is the output of the [training step](crate::TrainStep).
+pub trait LearnerCallback<T>: Send {
+    /// Called before the first training epoch.
+    fn on_train_begin(&mut self, _context: &mut CallbackContext<'_>) {}
+
+    /// Called after the backward pass of every training iteration, before the optimizer step.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context of the iteration, whose learning rate can be changed.
+    /// * `outputs` - The output of the training step on each device.
+    /// * `grads` - The gradients of the optimizer step, or `None` when they are accumulated.
+    fn on_batch_end(
+        &mut self,
+        _context: &mut CallbackContext<'_>,
+        _outputs: &[T],
+        _grads: Option<&GradientsParams>,
+    ) {
+    }
+
+    /// Called at the end of every training epoch, before the validation.
+    fn on_epoch_end(&mut self, _context: &mut CallbackContext<'_>) {}
+
+    /// Called at the end of every validation epoch.
+    fn on_validation_end(&mut self, _context: &mut CallbackContext<'_>) {}
+}
+
+/// The context given to the hooks of a [learner callback](LearnerCallback).
+pub struct CallbackContext<'a> {
+    split: Split,
+    epoch: usize,
+    epoch_total: usize,
+    iteration: usize,
+    lr: Option<LearningRate>,
+    interrupter: &'a TrainingInterrupter,
+    store: &'a EventStoreClient,
+    update: MetricsUpdate,
+}
+
+impl<'a> CallbackContext<'a> {
+    fn new(
+        split: Split,
+        epoch: usize,
+        epoch_total: usize,
+        interrupter: &'a TrainingInterrupter,
+        store: &'a EventStoreClient,
+    ) -> Self {
+        Self {
+            split,
+            epoch,
+            epoch_total,
+            iteration: 0,
+            lr: None,
+            interrupter,
+            store,
+            update: MetricsUpdate::new(Vec::new(), Vec::new()),
+        }
+    }
+
+    /// The current epoch.
+    pub fn epoch(&self) -> usize {
+        self.epoch
+    }
+
+    /// The total number of epochs.
+    pub fn epoch_total(&self) -> usize {
+        self.epoch_total
+    }
+
+    /// The current iteration of the training epoch, which is 0 outside of
+    /// [on_batch_end](LearnerCallback::on_batch_end).
+    pub fn iteration(&self) -> usize {
+        self.iteration
+    }
+
+    /// The learning rate of the optimizer step following
+    /// [on_batch_end](LearnerCallback::on_batch_end), which is `None` in the other hooks.
+    pub fn lr(&self) -> Option<LearningRate> {
+        self.lr
+    }
+
+    /// Replace the learning rate given by the scheduler for the optimizer step following
+    /// [on_batch_end](LearnerCallback::on_batch_end).
+    ///
+    /// # Panics
+    ///
+    /// If called from another hook, since there is no optimizer step to update.
+    pub fn set_lr(&mut self, lr: LearningRate) {
+        assert!(
+            self.lr.is_some(),
+            "The learning rate can only be changed in `on_batch_end`."
+        );
+        self.lr = Some(lr);
+    }
+
+    /// Stop the training, as with the [interrupter](crate::LearnerBuilder::interrupter).
+    pub fn stop(&self) {
+        self.interrupter.stop();
+    }
+
+    /// Log a numeric value with the metric loggers and the renderer, in the training split or,
+    /// in [on_validation_end](LearnerCallback::on_validation_end), the validation split.
+    ///
+    /// The values can then be aggregated with [find_metric](Self::find_metric), like the
+    /// numeric metrics.
+    pub fn log_numeric(&mut self, name: impl Into<String>, value: f64) {
+        let entry = MetricEntry::new(name.into(), format_float(value, 4), value.to_string());
+        self.update.entries_numeric.push((entry, value));
+    }
+
+    /// Log a text value with the metric loggers and the renderer, in the same split as
+    /// [log_numeric](Self::log_numeric).
+    pub fn log_text(&mut self, name: impl Into<String>, text: impl Into<String>) {
+        let text = text.into();
+        self.update
+            .entries
+            .push(MetricEntry::new(name.into(), text.clone(), text));
+    }
+
+    /// Find the value of a metric for the given epoch following the given criteria.
+    ///
+    /// # Notes
+    ///
+    /// The metrics are computed in the background, so the values of the current epoch may not
+    /// all be collected yet.
+    pub fn find_metric(
+        &self,
+        name: &str,
+        epoch: usize,
+        aggregate: Aggregate,
+        split: Split,
+    ) -> Option<f64> {
+        self.store.find_metric(name, epoch, aggregate, split)
+    }
+}
+
+/// The [callbacks](LearnerCallback) of a [learner](crate::Learner), registered with
+/// [callback](crate::LearnerBuilder::callback).
+pub struct LearnerCallbacks<T> {
+    callbacks: Vec<Box<dyn LearnerCallback<T>>>,
+    interrupter: TrainingInterrupter,
+    store: Arc<EventStoreClient>,
+}
+
+impl<T> LearnerCallbacks<T> {
+    pub(crate) fn new(
+        callbacks: Vec<Box<dyn LearnerCallback<T>>>,
+        interrupter: TrainingInterrupter,
+        store: Arc<EventStoreClient>,
+    ) -> Self {
+        Self {
+            callbacks,
+            interrupter,
+            store,
+        }
+    }
+
+    pub(crate) fn on_train_begin<P>(&mut self, processor: &mut P, epoch: usize, epoch_total: usize)
+    where
+        P: EventProcessor<ItemTrain = T>,
+    {
+        self.call(
+            processor,
+            Split::Train,
+            epoch,
+            epoch_total,
+            |callback, context| callback.on_train_begin(context),
+        );
+    }
+
+    /// Call [on_batch_end](LearnerCallback::on_batch_end) and return the learning rate of the
+    /// optimizer step.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn on_batch_end<P>(
+        &mut self,
+        processor: &mut P,
+        epoch: usize,
+        epoch_total: usize,
+        iteration: usize,
+        lr: LearningRate,
+        outputs: &[T],
+        grads: Option<&GradientsParams>,
+    ) -> LearningRate
+    where
+        P: EventProcessor<ItemTrain = T>,
+    {
+        let mut lr = Some(lr);
+        self.call(
+            processor,
+            Split::Train,
+            epoch,
+            epoch_total,
+            |callback, context| {
+                context.iteration = iteration;
+                context.lr = lr;
+                callback.on_batch_end(context, outputs, grads);
+                lr = context.lr;
+            },
+        );
+        lr.expect("The learning rate is set")
+    }
+
+    pub(crate) fn on_epoch_end<P>(&mut self, processor: &mut P, epoch: usize, epoch_total: usize)
+    where
+        P: EventProcessor<ItemTrain = T>,
+    {
+        self.call(
+            processor,
+            Split::Train,
+            epoch,
+            epoch_total,
+            |callback, context| callback.on_epoch_end(context),
+        );
+    }
+
+    pub(crate) fn on_validation_end<P>(
+        &mut self,
+        processor: &mut P,
+        epoch: usize,
+        epoch_total: usize,
+    ) where
+        P: EventProcessor<ItemTrain = T>,
+    {
+        self.call(
+            processor,
+            Split::Valid,
+            epoch,
+            epoch_total,
+            |callback, context| callback.on_validation_end(context),
+        );
+    }
+
+    /// Call a hook of every callback with the same context, then send the logged values to the
+    /// processor, so they are ordered with the metrics of the split.
+    fn call<P, F>(
+        &mut self,
+        processor: &mut P,
+        split: Split,
+        epoch: usize,
+        epoch_total: usize,
+        mut hook: F,
+    ) where
+        P: EventProcessor<ItemTrain = T>,
+        F: FnMut(&mut dyn LearnerCallback<T>, &mut CallbackContext<'_>),
+    {
+        if self.callbacks.is_empty() {
+            return;
+        }
+
+        let mut context =
+            CallbackContext::new(split, epoch, epoch_total, &self.interrupter, &self.store);
+        for callback in self.callbacks.iter_mut() {
+            hook(callback.as_mut(), &mut context);
+        }
+
+        let update = context.update;
+        if update.entries.is_empty() && update.entries_numeric.is_empty() {
+            return;
+        }
+        match context.split {
+            Split::Train => processor.process_train(Event::Log(update)),
+            Split::Valid => processor.process_valid(Event::Log(update)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::InMemoryMetricLogger;
+    use crate::metric::processor::test_utils::end_epoch;
+    use crate::metric::processor::{Metrics, MinimalEventProcessor};
+    use crate::metric::store::LogEventStore;
+
+    /// Logs the outputs, halves the learning rate and stops after the second iteration.
+    struct TestCallback;
+
+    impl LearnerCallback<f64> for TestCallback {
+        fn on_batch_end(
+            &mut self,
+            context: &mut CallbackContext<'_>,
+            outputs: &[f64],
+            _grads: Option<&GradientsParams>,
+        ) {
+            context.log_numeric("Output", outputs.iter().sum());
+            context.set_lr(context.lr().unwrap() / 2.0);
+
+            if context.iteration() == 2 {
+                context.stop();
+            }
+        }
+
+        fn on_validation_end(&mut self, context: &mut CallbackContext<'_>) {
+            context.log_text("Note", format!("epoch {}", context.epoch()));
+        }
+    }
+
+    #[test]
+    fn callbacks_should_update_the_lr_log_and_stop() {
+        let mut store = LogEventStore::default();
+        store.register_logger_train(InMemoryMetricLogger::default());
+        store.register_logger_valid(InMemoryMetricLogger::default());
+        let store = Arc::new(EventStoreClient::new(store));
+        let mut processor =
+            MinimalEventProcessor::new(Metrics::<f64, f64>::default(), store.clone());
+
+        let interrupter = TrainingInterrupter::new();
+        let mut callbacks = LearnerCallbacks::new(
+            vec![Box::new(TestCallback)],
+            interrupter.clone(),
+            store.clone(),
+        );
+
+        let lr = callbacks.on_batch_end(&mut processor, 1, 1, 1, 0.1, &[1.0, 2.0], None);
+        assert_eq!(lr, 0.05);
+        assert!(!interrupter.should_stop());
+
+        callbacks.on_batch_end(&mut processor, 1, 1, 2, 0.1, &[5.0], None);
+        assert!(interrupter.should_stop());
+
+        callbacks.on_validation_end(&mut processor, 1, 1);
+        end_epoch(&mut processor, 1);
+
+        assert_eq!(
+            store.find_metric("Output", 1, Aggregate::Mean, Split::Train),
+            Some(4.0)
+        );
+        assert_eq!(
+            store.find_metric("Output", 1, Aggregate::Mean, Split::Valid),
+            None
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::ClassificationReportInput;
 use crate::metric::TopKAccuracyInput;
 use crate::metric::{
     AccuracyInput, Adaptor, ConfusionStatsInput, HammingScoreInput, LossInput, processor::ItemLazy,
@@ -76,6 +77,12 @@ impl<B: Backend> Adaptor<ConfusionStatsInput<B>> for ClassificationOutput<B> {
     }
 }
 
+impl<B: Backend> Adaptor<ClassificationReportInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> ClassificationReportInput<B> {
+        ClassificationReportInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
 /// Multi-label classification output adapted for multiple metrics.
 #[derive(new)]
 pub struct MultiLabelClassificationOutput<B: Backend> {
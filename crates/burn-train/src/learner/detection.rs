@@ -0,0 +1,75 @@
+use crate::metric::processor::ItemLazy;
+use crate::metric::{Adaptor, DetectionInput, LossInput};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor, Transaction};
+use burn_ndarray::NdArray;
+
+/// Simple object detection output adapted for multiple metrics.
+///
+/// The boxes are `[x_min, y_min, x_max, y_max]` and the labels that are negative are padding.
+#[derive(new)]
+pub struct DetectionOutput<B: Backend> {
+    /// The loss.
+    pub loss: Tensor<B, 1>,
+
+    /// The predicted boxes (batch_size, num_predictions, 4).
+    pub boxes: Tensor<B, 3>,
+
+    /// The confidence scores of the predicted boxes (batch_size, num_predictions).
+    pub scores: Tensor<B, 2>,
+
+    /// The classes of the predicted boxes (batch_size, num_predictions).
+    pub labels: Tensor<B, 2, Int>,
+
+    /// The ground truth boxes (batch_size, num_targets, 4).
+    pub target_boxes: Tensor<B, 3>,
+
+    /// The classes of the ground truth boxes (batch_size, num_targets).
+    pub target_labels: Tensor<B, 2, Int>,
+}
+
+impl<B: Backend> Adaptor<LossInput<B>> for DetectionOutput<B> {
+    fn adapt(&self) -> LossInput<B> {
+        LossInput::new(self.loss.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<DetectionInput<B>> for DetectionOutput<B> {
+    fn adapt(&self) -> DetectionInput<B> {
+        DetectionInput::new(
+            self.boxes.clone(),
+            self.scores.clone(),
+            self.labels.clone(),
+            self.target_boxes.clone(),
+            self.target_labels.clone(),
+        )
+    }
+}
+
+impl<B: Backend> ItemLazy for DetectionOutput<B> {
+    type ItemSync = DetectionOutput<NdArray>;
+
+    fn sync(self) -> Self::ItemSync {
+        let [loss, boxes, scores, labels, target_boxes, target_labels] = Transaction::default()
+            .register(self.loss)
+            .register(self.boxes)
+            .register(self.scores)
+            .register(self.labels)
+            .register(self.target_boxes)
+            .register(self.target_labels)
+            .execute()
+            .try_into()
+            .expect("Correct amount of tensor data");
+
+        let device = &Default::default();
+
+        DetectionOutput {
+            loss: Tensor::from_data(loss, device),
+            boxes: Tensor::from_data(boxes, device),
+            scores: Tensor::from_data(scores, device),
+            labels: Tensor::from_data(labels, device),
+            target_boxes: Tensor::from_data(target_boxes, device),
+            target_labels: Tensor::from_data(target_labels, device),
+        }
+    }
+}
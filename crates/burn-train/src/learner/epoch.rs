@@ -1,13 +1,18 @@
-use burn_core::data::dataloader::DataLoader;
+use burn_core::data::dataloader::{DataLoader, DataLoaderIterator, DataLoaderState};
 use burn_core::tensor::backend::AutodiffBackend;
 use burn_core::{
-    lr_scheduler::LrScheduler, module::AutodiffModule, optim::GradientsAccumulator,
+    lr_scheduler::LrScheduler,
+    module::{AutodiffModule, ModuleEma},
+    optim::{GradScaler, GradientsAccumulator, HalfPrecision, Optimizer},
     tensor::backend::Backend,
 };
 use std::sync::Arc;
 
 use crate::metric::processor::{Event, EventProcessor, LearnerItem};
-use crate::{MultiDevicesTrainStep, TrainStep, ValidStep};
+use crate::{
+    DEFAULT_BUCKET_SIZE, DataParallelTrainStep, LearnerCallbacks, LearnerCheckpointer,
+    MixedPrecisionStepFn, TrainStep, ValidStep,
+};
 use crate::{components::LearnerComponents, learner::base::TrainingInterrupter};
 
 /// A validation epoch.
@@ -25,6 +30,77 @@ pub struct TrainEpoch<B: AutodiffBackend, TI> {
     epoch: usize,
     epoch_total: usize,
     grad_accumulation: Option<usize>,
+    #[new(default)]
+    resume: Option<(usize, Vec<DataLoaderState>)>,
+}
+
+/// Saves [iteration checkpoints](crate::LearnerBuilder::checkpoint_interval) during a
+/// [training epoch](TrainEpoch).
+pub struct IterationCheckpointing<'a, LC: LearnerComponents> {
+    checkpointer: &'a mut LearnerCheckpointer<LC>,
+    interval: usize,
+    warned: bool,
+}
+
+impl<'a, LC: LearnerComponents> IterationCheckpointing<'a, LC> {
+    pub(crate) fn new(checkpointer: &'a mut LearnerCheckpointer<LC>, interval: usize) -> Self {
+        Self {
+            checkpointer,
+            interval,
+            warned: false,
+        }
+    }
+
+    /// Save a checkpoint after the optimizer step of the given iteration, if it is a multiple of
+    /// the interval.
+    #[allow(clippy::too_many_arguments)]
+    fn checkpoint<O>(
+        &mut self,
+        model: &LC::Model,
+        optim: &LC::Optimizer,
+        scheduler: &LC::LrScheduler,
+        ema: Option<&ModuleEma<LC::Backend, LC::Model>>,
+        grad_scaler: Option<&GradScaler>,
+        iterators: &[Box<dyn DataLoaderIterator<O> + '_>],
+        epoch: usize,
+        iteration: usize,
+    ) {
+        if iteration % self.interval != 0 {
+            return;
+        }
+
+        let dataloaders = iterators
+            .iter()
+            .map(|iterator| {
+                // Iterators without a state are resumed by skipping the processed items.
+                iterator.state().unwrap_or_else(|| {
+                    if !self.warned {
+                        log::warn!(
+                            "The data loader doesn't track the state of its iterations, the \
+                             training resumed from an iteration checkpoint only loads the same \
+                             batches when the data loader isn't shuffled."
+                        );
+                        self.warned = true;
+                    }
+                    DataLoaderState {
+                        iteration: 0,
+                        items_processed: vec![iterator.progress().items_processed],
+                    }
+                })
+            })
+            .collect();
+
+        self.checkpointer.checkpoint_iteration(
+            model,
+            optim,
+            scheduler,
+            ema,
+            grad_scaler.map(GradScaler::to_record),
+            dataloaders,
+            epoch,
+            iteration,
+        );
+    }
 }
 
 impl<B: Backend, VI> ValidEpoch<B, VI> {
@@ -34,10 +110,12 @@ impl<B: Backend, VI> ValidEpoch<B, VI> {
     ///
     /// * `model` - The model to validate.
     /// * `processor` - The event processor to use.
+    /// * `callbacks` - The callbacks of the learner.
     pub fn run<LC: LearnerComponents, VO>(
         &self,
         model: &LC::Model,
         processor: &mut LC::EventProcessor,
+        callbacks: &mut LearnerCallbacks<<LC::EventProcessor as EventProcessor>::ItemTrain>,
         interrupter: &TrainingInterrupter,
     ) where
         LC::EventProcessor: EventProcessor<ItemValid = VO>,
@@ -62,6 +140,7 @@ impl<B: Backend, VI> ValidEpoch<B, VI> {
                 self.epoch_total,
                 iteration,
                 None,
+                None,
             );
 
             processor.process_valid(Event::ProcessedItem(item));
@@ -71,11 +150,45 @@ impl<B: Backend, VI> ValidEpoch<B, VI> {
                 break;
             }
         }
+        callbacks.on_validation_end(processor, self.epoch, self.epoch_total);
         processor.process_valid(Event::EndEpoch(self.epoch));
     }
 }
 
 impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
+    /// Resume the next epoch after the given iteration, with the data loaders of each device
+    /// resumed at the given state.
+    pub(crate) fn resume(&mut self, iteration: usize, dataloaders: Vec<DataLoaderState>) {
+        assert_eq!(
+            dataloaders.len(),
+            self.dataloader.len(),
+            "The training state should have a data loader per device."
+        );
+        self.resume = Some((iteration, dataloaders));
+    }
+
+    /// Create the iterators of the data loaders, resuming them if needed, and the index of the
+    /// last iteration.
+    fn iterators(&mut self) -> (Vec<Box<dyn DataLoaderIterator<TI> + '_>>, usize) {
+        match self.resume.take() {
+            Some((iteration, states)) => {
+                log::info!(
+                    "Resuming epoch {} after iteration {}",
+                    self.epoch,
+                    iteration
+                );
+                let iterators = self
+                    .dataloader
+                    .iter()
+                    .zip(states.iter())
+                    .map(|(dataloader, state)| dataloader.iter_resume(state))
+                    .collect();
+                (iterators, iteration)
+            }
+            None => (self.dataloader.iter().map(|d| d.iter()).collect(), 0),
+        }
+    }
+
     /// Runs the training epoch.
     ///
     /// # Arguments
@@ -83,17 +196,31 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
     /// * `model` - The model to train.
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
+    /// * `ema` - The moving average of the model weights to update, if any.
+    /// * `mixed_precision` - The half precision, the gradient scaler and the training step of
+    ///   mixed precision training, if any.
+    /// * `checkpointing` - The checkpointing of the iterations, if any.
     /// * `processor` - The event processor to use.
+    /// * `callbacks` - The callbacks of the learner.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run<LC: LearnerComponents<Backend = B>, TO>(
         &mut self,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
         scheduler: &mut LC::LrScheduler,
+        mut ema: Option<&mut ModuleEma<B, LC::Model>>,
+        mut mixed_precision: Option<(
+            &HalfPrecision,
+            &mut GradScaler,
+            MixedPrecisionStepFn<LC::Model, TI, TO>,
+        )>,
+        mut checkpointing: Option<IterationCheckpointing<'_, LC>>,
         processor: &mut LC::EventProcessor,
+        callbacks: &mut LearnerCallbacks<TO>,
         interrupter: &TrainingInterrupter,
     ) -> (LC::Model, LC::Optimizer)
     where
@@ -102,41 +229,103 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
     {
         log::info!("Executing training step for epoch {}", self.epoch,);
 
+        let epoch = self.epoch;
+        let epoch_total = self.epoch_total;
+        let grad_accumulation = self.grad_accumulation;
+
         // Single device / dataloader
-        let mut iterator = self.dataloader[0].iter();
-        let mut iteration = 0;
+        let (mut iterators, mut iteration) = self.iterators();
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
 
-        while let Some(item) = iterator.next() {
+        while let Some(item) = iterators[0].next() {
             iteration += 1;
-            let lr = scheduler.step();
             log::info!("Iteration {}", iteration);
 
-            let progress = iterator.progress();
-            let item = model.step(item);
+            let progress = iterators[0].progress();
+            let (item, grads) = match &mut mixed_precision {
+                Some((precision, scaler, step)) => {
+                    let model_half = precision.cast(&model);
+                    let output = step(&model_half, item, scaler.scale());
+                    (output.item, scaler.unscale(&model, output.grads))
+                }
+                None => {
+                    let output = model.step(item);
+                    (output.item, Some(output.grads))
+                }
+            };
+
+            // The gradients overflowed: the batch is skipped by the learning rate scheduler, the
+            // accumulation and the optimizer.
+            let Some(grads) = grads else {
+                log::warn!("Skipping iteration {iteration}, whose gradients overflowed.");
+                let item =
+                    LearnerItem::new(item, progress, epoch, epoch_total, iteration, None, None);
+                processor.process_train(Event::ProcessedItem(item));
+
+                if interrupter.should_stop() {
+                    log::info!("Training interrupted.");
+                    break;
+                }
+                continue;
+            };
 
-            match self.grad_accumulation {
+            let lr = scheduler.step();
+            let grads = match grad_accumulation {
                 Some(accumulation) => {
-                    accumulator.accumulate(&model, item.grads);
+                    accumulator.accumulate(&model, grads);
                     accumulation_current += 1;
 
                     if accumulation <= accumulation_current {
-                        let grads = accumulator.grads();
-                        model = model.optimize(&mut optim, lr, grads);
                         accumulation_current = 0;
+                        Some(accumulator.grads())
+                    } else {
+                        None
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+                None => Some(grads),
+            };
+            let lr = callbacks.on_batch_end(
+                processor,
+                epoch,
+                epoch_total,
+                iteration,
+                lr,
+                core::slice::from_ref(&item),
+                grads.as_ref(),
+            );
+
+            let mut grad_norm = None;
+            if let Some(grads) = grads {
+                model = model.optimize(&mut optim, lr, grads);
+                grad_norm = optim.grad_norm();
+
+                if let Some(ema) = &mut ema {
+                    ema.update(&model);
+                }
+
+                if let Some(checkpointing) = &mut checkpointing {
+                    checkpointing.checkpoint(
+                        &model,
+                        &optim,
+                        scheduler,
+                        ema.as_deref(),
+                        mixed_precision.as_ref().map(|(_, scaler, _)| &**scaler),
+                        iterators.as_slice(),
+                        epoch,
+                        iteration,
+                    );
+                }
             }
 
             let item = LearnerItem::new(
-                item.item,
+                item,
                 progress,
-                self.epoch,
-                self.epoch_total,
+                epoch,
+                epoch_total,
                 iteration,
                 Some(lr),
+                grad_norm,
             );
 
             processor.process_train(Event::ProcessedItem(item));
@@ -146,6 +335,8 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
                 break;
             }
         }
+        drop(iterators);
+        callbacks.on_epoch_end(processor, epoch, epoch_total);
         processor.process_train(Event::EndEpoch(self.epoch));
 
         self.epoch += 1;
@@ -162,18 +353,25 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
     /// * `model` - The model to train.
     /// * `optim` - The optimizer to use.
     /// * `lr_scheduler` - The learning rate scheduler to use.
+    /// * `ema` - The moving average of the model weights to update, if any.
+    /// * `checkpointing` - The checkpointing of the iterations, if any.
     /// * `processor` - The event processor to use.
+    /// * `callbacks` - The callbacks of the learner.
     /// * `devices` - The devices to use.
     ///
     /// # Returns
     ///
     /// The trained model and the optimizer.
+    #[allow(clippy::too_many_arguments)]
     pub fn run_multi_device<LC: LearnerComponents<Backend = B>, TO>(
         &mut self,
         mut model: LC::Model,
         mut optim: LC::Optimizer,
         lr_scheduler: &mut LC::LrScheduler,
+        mut ema: Option<&mut ModuleEma<B, LC::Model>>,
+        mut checkpointing: Option<IterationCheckpointing<'_, LC>>,
         processor: &mut LC::EventProcessor,
+        callbacks: &mut LearnerCallbacks<TO>,
         devices: Vec<<LC::Backend as Backend>::Device>,
         interrupter: &TrainingInterrupter,
     ) -> (LC::Model, LC::Optimizer)
@@ -189,62 +387,103 @@ impl<B: AutodiffBackend, TI> TrainEpoch<B, TI> {
             devices
         );
 
-        let mut iterators = self.dataloader.iter().map(|d| d.iter()).collect::<Vec<_>>();
-        let mut iteration = 0;
+        let epoch = self.epoch;
+        let epoch_total = self.epoch_total;
+        let accumulation = self.grad_accumulation.unwrap_or(1);
+
+        let (mut iterators, mut iteration) = self.iterators();
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
 
-        let accumulation = self.grad_accumulation.unwrap_or(1) * devices.len();
-        let step = MultiDevicesTrainStep::new(&devices);
-
-        // The main device is always the first in the list.
-        let device_main = devices.first().expect("A minimum of one device.").clone();
-        let mut interrupted = false;
+        let step = DataParallelTrainStep::new(&devices, DEFAULT_BUCKET_SIZE);
+        let mut submitted = step.submit(iterators.as_mut_slice(), &model);
 
-        loop {
-            let (items, progress) = step.step(iterators.as_mut_slice(), &model);
-            if items.is_empty() {
-                break;
+        while let Some(progress) = submitted.take() {
+            // The model only changes with the optimizer step: until then, the next step is
+            // submitted before receiving the current one, so its forward and backward passes
+            // overlap the all-reduce of the current gradients.
+            let submitted_ahead = accumulation_current + 1 < accumulation;
+            if submitted_ahead {
+                submitted = step.submit(iterators.as_mut_slice(), &model);
             }
+            let output = step.receive();
 
-            for item in items {
-                iteration += 1;
-                let lr = lr_scheduler.step();
-
-                // TODO: aggregate multi device (all-reduce)
-                let grads = item.grads.to_device(&device_main, &model);
+            iteration += 1;
+            let lr = lr_scheduler.step();
 
-                accumulator.accumulate(&model, grads);
-                accumulation_current += 1;
+            // The gradients are already averaged over the devices, on the first device.
+            accumulator.accumulate(&model, output.grads);
+            accumulation_current += 1;
 
-                if accumulation <= accumulation_current {
-                    let grads = accumulator.grads();
-                    model = model.optimize(&mut optim, lr, grads);
+            let grads = match accumulation <= accumulation_current {
+                true => {
                     accumulation_current = 0;
+                    Some(accumulator.grads())
                 }
+                false => None,
+            };
+            let lr = callbacks.on_batch_end(
+                processor,
+                epoch,
+                epoch_total,
+                iteration,
+                lr,
+                &output.item,
+                grads.as_ref(),
+            );
+
+            let mut grad_norm = None;
+            if let Some(grads) = grads {
+                model = model.optimize(&mut optim, lr, grads);
+                grad_norm = optim.grad_norm();
+
+                if let Some(ema) = &mut ema {
+                    ema.update(&model);
+                }
+
+                if let Some(checkpointing) = &mut checkpointing {
+                    checkpointing.checkpoint(
+                        &model,
+                        &optim,
+                        lr_scheduler,
+                        ema.as_deref(),
+                        None,
+                        iterators.as_slice(),
+                        epoch,
+                        iteration,
+                    );
+                }
+            }
 
+            for item in output.item {
                 let item = LearnerItem::new(
-                    item.item,
+                    item,
                     progress.clone(),
-                    self.epoch,
-                    self.epoch_total,
+                    epoch,
+                    epoch_total,
                     iteration,
                     Some(lr),
+                    grad_norm,
                 );
 
                 processor.process_train(Event::ProcessedItem(item));
+            }
 
-                if interrupter.should_stop() {
-                    log::info!("Training interrupted.");
-                    interrupted = true;
-                    break;
+            if interrupter.should_stop() {
+                log::info!("Training interrupted.");
+                // The workers complete the step submitted ahead, whose output is dropped.
+                if submitted.is_some() {
+                    step.receive();
                 }
+                break;
             }
 
-            if interrupted {
-                break;
+            if !submitted_ahead {
+                submitted = step.submit(iterators.as_mut_slice(), &model);
             }
         }
+        drop(iterators);
+        callbacks.on_epoch_end(processor, epoch, epoch_total);
 
         processor.process_train(Event::EndEpoch(self.epoch));
 
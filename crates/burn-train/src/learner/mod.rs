@@ -1,10 +1,14 @@
 mod application_logger;
 mod base;
 mod builder;
+mod callback;
 mod classification;
+mod detection;
 mod early_stopping;
 mod epoch;
 mod regression;
+mod segmentation;
+mod sequence;
 mod step;
 mod summary;
 mod train_val;
@@ -12,10 +16,14 @@ mod train_val;
 pub use application_logger::*;
 pub use base::*;
 pub use builder::*;
+pub use callback::*;
 pub use classification::*;
+pub use detection::*;
 pub use early_stopping::*;
 pub use epoch::*;
 pub use regression::*;
+pub use segmentation::*;
+pub use sequence::*;
 pub use step::*;
 pub use summary::*;
 pub use train::*;
@@ -0,0 +1,52 @@
+use crate::metric::processor::ItemLazy;
+use crate::metric::{Adaptor, LossInput, SequenceInput};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor, Transaction};
+use burn_ndarray::NdArray;
+
+/// Simple sequence output, such as of a language model, adapted for multiple metrics.
+#[derive(new)]
+pub struct SequenceOutput<B: Backend> {
+    /// The loss.
+    pub loss: Tensor<B, 1>,
+
+    /// The logits of each token (batch_size, seq_length, vocab_size).
+    pub output: Tensor<B, 3>,
+
+    /// The target tokens (batch_size, seq_length).
+    pub targets: Tensor<B, 2, Int>,
+}
+
+impl<B: Backend> Adaptor<LossInput<B>> for SequenceOutput<B> {
+    fn adapt(&self) -> LossInput<B> {
+        LossInput::new(self.loss.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<SequenceInput<B>> for SequenceOutput<B> {
+    fn adapt(&self) -> SequenceInput<B> {
+        SequenceInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> ItemLazy for SequenceOutput<B> {
+    type ItemSync = SequenceOutput<NdArray>;
+
+    fn sync(self) -> Self::ItemSync {
+        let [output, loss, targets] = Transaction::default()
+            .register(self.output)
+            .register(self.loss)
+            .register(self.targets)
+            .execute()
+            .try_into()
+            .expect("Correct amount of tensor data");
+
+        let device = &Default::default();
+
+        SequenceOutput {
+            output: Tensor::from_data(output, device),
+            loss: Tensor::from_data(loss, device),
+            targets: Tensor::from_data(targets, device),
+        }
+    }
+}
@@ -1,34 +1,520 @@
 use crate::{TrainOutput, TrainStep};
 use burn_core::data::dataloader::Progress;
 use burn_core::{
-    data::dataloader::DataLoaderIterator, module::AutodiffModule, tensor::backend::AutodiffBackend,
+    collective::{Communicator, ReduceOp},
+    data::dataloader::DataLoaderIterator,
+    module::{AutodiffModule, ModuleVisitor, ParamId},
+    optim::GradientsParams,
+    tensor::{Tensor, backend::AutodiffBackend},
 };
-use std::sync::mpsc::{Receiver, Sender};
+use std::any::Any;
+use std::marker::PhantomData;
+use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
+use std::sync::mpsc::{Receiver, Sender, channel};
 use std::thread::spawn;
 
+/// The default number of gradient elements all-reduced at once by the
+/// [data-parallel train step](DataParallelTrainStep).
+pub const DEFAULT_BUCKET_SIZE: usize = 1 << 20;
+
+type InnerTensor<B> = Tensor<<B as AutodiffBackend>::InnerBackend, 1>;
+
+/// Data-parallel train step.
+///
+/// Each device has a worker keeping a replica of the model, which runs the train step on its
+/// own item. The gradients of the replicas are then averaged with a ring all-reduce, so the
+/// model is optimized once per step with the gradients of all the items.
+///
+/// The gradients are flattened into buckets of about `bucket_size` elements, which are
+/// all-reduced on a communication thread of the worker in the order they are flattened. Since
+/// the gradients are always summed in the same order, the result doesn't depend on the timing of
+/// the workers.
+///
+/// The communication thread also splits the reduced buckets back into gradients, so the worker
+/// is free to run the next step as soon as its buckets are flattened: when the model doesn't
+/// change between two steps, as when accumulating gradients, the next step can be
+/// [submitted](Self::submit) before [receiving](Self::receive) the current one, and its forward
+/// and backward passes overlap the all-reduce of the current gradients.
+///
+/// # Notes
+///
+/// The gradients are only flattened once the backward pass of the train step has completed, so
+/// the all-reduce of a step doesn't overlap its own backward pass.
+///
+/// # Panics
+///
+/// When the train step of a worker panics, the ring is aborted so the other workers stop
+/// waiting for its gradients, and the panic is resumed by [receive](Self::receive).
+pub struct DataParallelTrainStep<B: AutodiffBackend, M, TI, TO> {
+    workers: Vec<WorkerHandle<M, TI, TO>>,
+    phantom: PhantomData<B>,
+}
+
+struct WorkerHandle<M, TI, TO> {
+    sender: Sender<Message<M, TI>>,
+    receiver: Receiver<WorkerResult<TO>>,
+}
+
+struct Message<M, TI> {
+    item: Option<TI>,
+    model: M,
+    num_items: usize,
+}
+
+struct WorkerOutput<TO> {
+    grads: Option<GradientsParams>,
+    item: Option<TO>,
+}
+
+/// The reason a worker couldn't complete a step.
+enum WorkerError {
+    /// The train step panicked, with the payload of the panic.
+    Panic(Box<dyn Any + Send>),
+    /// The ring was aborted by the failure of another worker.
+    Aborted,
+}
+
+type WorkerResult<TO> = Result<WorkerOutput<TO>, WorkerError>;
+
+/// The work sent to the communication thread of a worker, in the order of the steps.
+enum Reduction<M, TO, T> {
+    /// A bucket of flattened gradients to all-reduce.
+    Bucket(T),
+    /// All the buckets of the step have been sent.
+    End {
+        model: M,
+        num_items: usize,
+        item: Option<TO>,
+    },
+    /// The train step panicked.
+    Failed(Box<dyn Any + Send>),
+}
+
+struct Worker<B: AutodiffBackend> {
+    rank: usize,
+    device: B::Device,
+    communicator: Communicator,
+    bucket_size: usize,
+}
+
+impl<B: AutodiffBackend> Worker<B> {
+    fn start<M, TI, TO>(
+        self,
+        sender_output: Sender<WorkerResult<TO>>,
+        receiver_input: Receiver<Message<M, TI>>,
+    ) where
+        TI: Send + 'static,
+        TO: Send + 'static,
+        M: AutodiffModule<B> + TrainStep<TI, TO> + Send + 'static,
+    {
+        let Worker {
+            rank,
+            device,
+            communicator,
+            bucket_size,
+        } = self;
+
+        let (sender_reduction, receiver_reduction) = channel();
+        Self::start_communication(rank, communicator, receiver_reduction, sender_output);
+
+        spawn(move || {
+            for message in receiver_input {
+                let result = catch_unwind(AssertUnwindSafe(|| {
+                    let model = message.model.fork(&device);
+                    let (grads, item) = match message.item {
+                        Some(item) => {
+                            let output = model.step(item);
+                            (output.grads, Some(output.item))
+                        }
+                        // Workers without items still take part in the all-reduce.
+                        None => (GradientsParams::new(), None),
+                    };
+
+                    let mut bucketer = GradientsBucketer::<B, M, TO> {
+                        grads,
+                        bucket: Vec::new(),
+                        bucket_len: 0,
+                        bucket_size,
+                        sender: &sender_reduction,
+                    };
+                    model.visit(&mut bucketer);
+                    bucketer.flush();
+
+                    (model, item)
+                }));
+
+                let reduction = match result {
+                    Ok((model, item)) => Reduction::End {
+                        model,
+                        num_items: message.num_items,
+                        item,
+                    },
+                    Err(payload) => Reduction::Failed(payload),
+                };
+                let failed = matches!(reduction, Reduction::Failed(_));
+
+                // The communication thread stops when the ring is aborted.
+                if sender_reduction.send(reduction).is_err() || failed {
+                    break;
+                }
+            }
+
+            log::info!("Closing thread on device {:?}", device);
+        });
+    }
+
+    /// The communication thread all-reduces the buckets in the order they are sent, and splits
+    /// them back into gradients at the end of each step.
+    ///
+    /// The communicator is dropped when a step fails, which aborts the ring: the other members
+    /// fail to exchange data with this one, and abort in turn.
+    fn start_communication<M, TO>(
+        rank: usize,
+        mut communicator: Communicator,
+        receiver_reduction: Receiver<Reduction<M, TO, InnerTensor<B>>>,
+        sender_output: Sender<WorkerResult<TO>>,
+    ) where
+        TO: Send + 'static,
+        M: AutodiffModule<B> + Send + 'static,
+    {
+        spawn(move || {
+            let mut buckets = Vec::new();
+
+            for reduction in receiver_reduction {
+                let result = match reduction {
+                    Reduction::Bucket(bucket) => {
+                        let reduced = catch_unwind(AssertUnwindSafe(|| {
+                            communicator.all_reduce(bucket, ReduceOp::Sum)
+                        }));
+                        match reduced {
+                            Ok(bucket) => {
+                                buckets.push(bucket);
+                                continue;
+                            }
+                            Err(_) => Err(WorkerError::Aborted),
+                        }
+                    }
+                    Reduction::End {
+                        model,
+                        num_items,
+                        item,
+                    } => {
+                        let mut unbucketer = GradientsUnbucketer::<B, M> {
+                            grads: GradientsParams::new(),
+                            buckets: core::mem::take(&mut buckets).into_iter(),
+                            bucket: None,
+                            offset: 0,
+                            num_items,
+                            phantom: PhantomData,
+                        };
+                        model.visit(&mut unbucketer);
+
+                        // All the replicas have the same gradients, only the first one is
+                        // returned.
+                        let grads = (rank == 0).then_some(unbucketer.grads);
+                        Ok(WorkerOutput { grads, item })
+                    }
+                    Reduction::Failed(payload) => Err(WorkerError::Panic(payload)),
+                };
+
+                let failed = result.is_err();
+                if sender_output.send(result).is_err() || failed {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+/// Flatten the gradients of the parameters requiring gradients into buckets, in visiting
+/// order. Missing gradients are filled with zeros, so every replica sends the same buckets.
+struct GradientsBucketer<'a, B: AutodiffBackend, M, TO> {
+    grads: GradientsParams,
+    bucket: Vec<InnerTensor<B>>,
+    bucket_len: usize,
+    bucket_size: usize,
+    sender: &'a Sender<Reduction<M, TO, InnerTensor<B>>>,
+}
+
+impl<B: AutodiffBackend, M, TO> GradientsBucketer<'_, B, M, TO> {
+    fn flush(&mut self) {
+        if self.bucket.is_empty() {
+            return;
+        }
+
+        let bucket = Tensor::cat(core::mem::take(&mut self.bucket), 0);
+        self.sender
+            .send(Reduction::Bucket(bucket))
+            .expect("The ring of the workers shouldn't be aborted.");
+        self.bucket_len = 0;
+    }
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>, TO> ModuleVisitor<B>
+    for GradientsBucketer<'_, B, M, TO>
+{
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        let num_elements = tensor.shape().num_elements();
+        if !tensor.is_require_grad() || num_elements == 0 {
+            return;
+        }
+
+        let grad = self
+            .grads
+            .remove::<B::InnerBackend, D>(id)
+            .unwrap_or_else(|| Tensor::zeros(tensor.shape(), &tensor.device()));
+
+        self.bucket.push(grad.reshape([num_elements]));
+        self.bucket_len += num_elements;
+
+        if self.bucket_len >= self.bucket_size {
+            self.flush();
+        }
+    }
+}
+
+/// Split the all-reduced buckets back into the gradients of the parameters, averaged over the
+/// items of the step.
+struct GradientsUnbucketer<B: AutodiffBackend, M> {
+    grads: GradientsParams,
+    buckets: std::vec::IntoIter<InnerTensor<B>>,
+    bucket: Option<InnerTensor<B>>,
+    offset: usize,
+    num_items: usize,
+    phantom: PhantomData<M>,
+}
+
+impl<B: AutodiffBackend, M: AutodiffModule<B>> ModuleVisitor<B> for GradientsUnbucketer<B, M> {
+    fn visit_float<const D: usize>(&mut self, id: ParamId, tensor: &Tensor<B, D>) {
+        let num_elements = tensor.shape().num_elements();
+        if !tensor.is_require_grad() || num_elements == 0 {
+            return;
+        }
+
+        let bucket = match self.bucket.take() {
+            Some(bucket) if self.offset < bucket.dims()[0] => bucket,
+            _ => {
+                self.offset = 0;
+                self.buckets
+                    .next()
+                    .expect("All the buckets of the step should be reduced.")
+            }
+        };
+
+        let grad = bucket
+            .clone()
+            .slice([self.offset..self.offset + num_elements])
+            .reshape(tensor.shape())
+            .div_scalar(self.num_items as f64);
+
+        self.offset += num_elements;
+        self.bucket = Some(bucket);
+        self.grads.register::<B::InnerBackend, D>(id, grad);
+    }
+}
+
+impl<B, M, TI, TO> DataParallelTrainStep<B, M, TI, TO>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B> + TrainStep<TI, TO> + Send + Clone + 'static,
+    TI: Send + 'static,
+    TO: Send + 'static,
+{
+    /// Create a new data-parallel train step.
+    ///
+    /// # Arguments
+    ///
+    /// * `devices` - Devices, the first one receiving the averaged gradients.
+    /// * `bucket_size` - The number of gradient elements all-reduced at once.
+    ///
+    /// # Returns
+    ///
+    /// DataParallelTrainStep instance.
+    pub fn new(devices: &[B::Device], bucket_size: usize) -> Self {
+        assert!(bucket_size > 0, "The bucket size must be greater than 0.");
+
+        let workers = devices
+            .iter()
+            .zip(Communicator::in_process_group(devices.len()))
+            .enumerate()
+            .map(|(rank, (device, communicator))| {
+                let (sender_input, receiver_input) = channel();
+                let (sender_output, receiver_output) = channel();
+                let worker = Worker::<B> {
+                    rank,
+                    device: device.clone(),
+                    communicator,
+                    bucket_size,
+                };
+
+                worker.start(sender_output, receiver_input);
+                WorkerHandle {
+                    sender: sender_input,
+                    receiver: receiver_output,
+                }
+            })
+            .collect();
+
+        Self {
+            workers,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Run one step on all the devices.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloaders` - The data loader for each worker.
+    /// * `model` - Model.
+    ///
+    /// # Returns
+    ///
+    /// The gradients averaged over the devices, on the first device, with the items of the
+    /// workers in device order, or `None` when all the data loaders are exhausted.
+    pub fn step<'a>(
+        &self,
+        dataloaders: &mut [Box<dyn DataLoaderIterator<TI> + 'a>],
+        model: &M,
+    ) -> (Option<TrainOutput<Vec<TO>>>, Progress) {
+        let (submitted, progress) = self.send(dataloaders, model);
+
+        (submitted.then(|| self.receive()), progress)
+    }
+
+    /// Start a step on all the devices, without waiting for its output.
+    ///
+    /// The outputs of the submitted steps should be [received](Self::receive) in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `dataloaders` - The data loader for each worker.
+    /// * `model` - Model.
+    ///
+    /// # Returns
+    ///
+    /// The progress of the data loaders, or `None` when they are all exhausted, in which case
+    /// no step is started.
+    pub fn submit<'a>(
+        &self,
+        dataloaders: &mut [Box<dyn DataLoaderIterator<TI> + 'a>],
+        model: &M,
+    ) -> Option<Progress> {
+        let (submitted, progress) = self.send(dataloaders, model);
+
+        submitted.then_some(progress)
+    }
+
+    /// Wait for the output of the oldest [submitted](Self::submit) step.
+    ///
+    /// # Returns
+    ///
+    /// The gradients averaged over the devices, on the first device, with the items of the
+    /// workers in device order.
+    ///
+    /// # Panics
+    ///
+    /// When the train step of a worker panicked, with the same payload.
+    pub fn receive(&self) -> TrainOutput<Vec<TO>> {
+        let mut grads = None;
+        let mut item = Vec::new();
+        let mut panic = None;
+        let mut aborted = false;
+
+        // Every worker is received from, so no output is left behind for the next step.
+        for worker in self.workers.iter() {
+            match worker.receiver.recv() {
+                Ok(Ok(output)) => {
+                    grads = grads.or(output.grads);
+                    item.extend(output.item);
+                }
+                Ok(Err(WorkerError::Panic(payload))) => panic = panic.or(Some(payload)),
+                Ok(Err(WorkerError::Aborted)) | Err(_) => aborted = true,
+            }
+        }
+
+        if let Some(payload) = panic {
+            resume_unwind(payload);
+        }
+        assert!(
+            !aborted,
+            "The ring of the data-parallel workers was aborted."
+        );
+
+        TrainOutput {
+            grads: grads.expect("The first worker should return the gradients."),
+            item,
+        }
+    }
+
+    fn send<'a>(
+        &self,
+        dataloaders: &mut [Box<dyn DataLoaderIterator<TI> + 'a>],
+        model: &M,
+    ) -> (bool, Progress) {
+        let mut items_total = 0;
+        let mut items_processed = 0;
+
+        let items = dataloaders
+            .iter_mut()
+            .map(|dataloader| {
+                let item = dataloader.next();
+                let progress = dataloader.progress();
+                items_total += progress.items_total;
+                items_processed += progress.items_processed;
+                item
+            })
+            .collect::<Vec<_>>();
+        let progress = Progress::new(items_processed, items_total);
+
+        let num_items = items.iter().flatten().count();
+        if num_items == 0 {
+            return (false, progress);
+        }
+
+        for (worker, item) in self.workers.iter().zip(items) {
+            let message = Message {
+                item,
+                model: model.clone(),
+                num_items,
+            };
+            worker
+                .sender
+                .send(message)
+                .expect("The worker should be alive.");
+        }
+
+        (true, progress)
+    }
+}
+
 /// Multi devices train step.
+#[deprecated(
+    since = "0.18.0",
+    note = "Use `DataParallelTrainStep`, which averages the gradients of the devices."
+)]
 pub struct MultiDevicesTrainStep<B: AutodiffBackend, M, TI, TO> {
-    workers: Vec<Worker<B, M, TI>>,
+    workers: Vec<MultiDevicesWorker<B, M, TI>>,
     receiver: Receiver<TrainOutput<TO>>,
 }
 
-struct Message<M, TI> {
+struct MultiDevicesMessage<M, TI> {
     item: TI,
     model: M,
 }
 
-struct Worker<B: AutodiffBackend, M, TI> {
-    sender_input: Sender<Message<M, TI>>,
+struct MultiDevicesWorker<B: AutodiffBackend, M, TI> {
+    sender_input: Sender<MultiDevicesMessage<M, TI>>,
     device: B::Device,
 }
 
-impl<B, M, TI> Worker<B, M, TI>
+impl<B, M, TI> MultiDevicesWorker<B, M, TI>
 where
     B: AutodiffBackend,
     M: AutodiffModule<B>,
 {
     fn register(&self, item: TI, model: &M) {
-        let message = Message {
+        let message = MultiDevicesMessage {
             item,
             model: model.clone(),
         };
@@ -38,7 +524,7 @@ where
     fn start<TO>(
         &self,
         sender_output: Sender<TrainOutput<TO>>,
-        receiver_input: Receiver<Message<M, TI>>,
+        receiver_input: Receiver<MultiDevicesMessage<M, TI>>,
     ) where
         TI: Send + 'static,
         TO: Send + 'static,
@@ -65,6 +551,7 @@ where
     }
 }
 
+#[allow(deprecated)]
 impl<B, M, TI, TO> MultiDevicesTrainStep<B, M, TI, TO>
 where
     B: AutodiffBackend,
@@ -85,12 +572,12 @@ where
     where
         TI: Send + 'static,
     {
-        let (sender_output, receiver_output) = std::sync::mpsc::channel();
+        let (sender_output, receiver_output) = channel();
         let workers = devices
             .iter()
             .map(|device| {
-                let (sender_input, receiver_input) = std::sync::mpsc::channel();
-                let worker = Worker {
+                let (sender_input, receiver_input) = channel();
+                let worker = MultiDevicesWorker {
                     sender_input,
                     device: device.clone(),
                 };
@@ -147,3 +634,185 @@ where
         (outputs, Progress::new(items_processed, items_total))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::data::dataloader::{DataLoader, DataLoaderBuilder, batcher::Batcher};
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::tensor::{Tensor, backend::Backend};
+    use std::sync::Arc;
+
+    type TestAutodiffBackend = burn_autodiff::Autodiff<TestBackend>;
+    type InnerBackend = <TestAutodiffBackend as AutodiffBackend>::InnerBackend;
+
+    impl TrainStep<Tensor<TestAutodiffBackend, 2>, ()> for Linear<TestAutodiffBackend> {
+        fn step(&self, item: Tensor<TestAutodiffBackend, 2>) -> TrainOutput<()> {
+            let loss = self.forward(item).powf_scalar(2.0).mean();
+
+            TrainOutput::new(self, loss.backward(), ())
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestBatcher;
+
+    impl<B: Backend, const N: usize> Batcher<B, [f32; N], Tensor<B, 2>> for TestBatcher {
+        fn batch(&self, items: Vec<[f32; N]>, device: &B::Device) -> Tensor<B, 2> {
+            let items = items
+                .into_iter()
+                .map(|item| Tensor::<B, 1>::from_floats(item, device).unsqueeze())
+                .collect();
+
+            Tensor::cat(items, 0)
+        }
+    }
+
+    fn dataloaders(
+        num_devices: usize,
+        num_items: usize,
+    ) -> Vec<Arc<dyn DataLoader<TestAutodiffBackend, Tensor<TestAutodiffBackend, 2>>>> {
+        (0..num_devices)
+            .map(|rank| {
+                let items = (rank..num_items)
+                    .step_by(num_devices)
+                    .map(|i| core::array::from_fn(|j| (i * 4 + j) as f32 / 10.0))
+                    .collect::<Vec<_>>();
+                DataLoaderBuilder::new(TestBatcher)
+                    .batch_size(2)
+                    .build(InMemDataset::new(items))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn gradients_should_be_averaged_over_the_devices() {
+        let device = Default::default();
+        let model = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        // Two batches for the first two devices and a single one for the last device.
+        let dataloaders = dataloaders(3, 8);
+        let step = DataParallelTrainStep::<TestAutodiffBackend, _, _, _>::new(&vec![device; 3], 5);
+
+        let mut iterators = dataloaders.iter().map(|d| d.iter()).collect::<Vec<_>>();
+        let mut expected = dataloaders
+            .iter()
+            .map(|dataloader| dataloader.iter().map(|item| model.step(item).grads))
+            .collect::<Vec<_>>();
+
+        for num_items in [3, 2] {
+            let (output, _) = step.step(iterators.as_mut_slice(), &model);
+            let output = output.unwrap();
+            assert_eq!(output.item.len(), num_items);
+
+            let grads = expected
+                .iter_mut()
+                .filter_map(|grads| grads.next())
+                .collect::<Vec<_>>();
+            assert_mean::<2>(&output.grads, &grads, model.weight.id);
+            assert_mean::<1>(&output.grads, &grads, model.bias.as_ref().unwrap().id);
+        }
+
+        let (output, _) = step.step(iterators.as_mut_slice(), &model);
+        assert!(output.is_none());
+    }
+
+    fn assert_mean<const D: usize>(
+        actual: &GradientsParams,
+        grads: &[GradientsParams],
+        id: ParamId,
+    ) {
+        let expected = grads
+            .iter()
+            .map(|grads| grads.get::<InnerBackend, D>(id).unwrap())
+            .reduce(|a, b| a + b)
+            .unwrap()
+            .div_scalar(grads.len() as f64);
+
+        actual
+            .get::<InnerBackend, D>(id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 5);
+    }
+
+    #[test]
+    fn gradients_should_be_deterministic() {
+        let device = Default::default();
+        let model = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let dataloaders = dataloaders(4, 8);
+
+        let grads = (0..3)
+            .map(|_| {
+                let step =
+                    DataParallelTrainStep::<TestAutodiffBackend, _, _, _>::new(&vec![device; 4], 2);
+                let mut iterators = dataloaders.iter().map(|d| d.iter()).collect::<Vec<_>>();
+                let (output, _) = step.step(iterators.as_mut_slice(), &model);
+                output
+                    .unwrap()
+                    .grads
+                    .get::<InnerBackend, 2>(model.weight.id)
+                    .unwrap()
+                    .into_data()
+            })
+            .collect::<Vec<_>>();
+
+        grads[1].assert_eq(&grads[0], true);
+        grads[2].assert_eq(&grads[0], true);
+    }
+
+    #[test]
+    fn submitted_steps_should_match_sequential_steps() {
+        let device = Default::default();
+        let model = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let dataloaders = dataloaders(3, 12);
+        let devices = vec![device; 3];
+
+        let step = DataParallelTrainStep::<TestAutodiffBackend, _, _, _>::new(&devices, 5);
+        let mut iterators = dataloaders.iter().map(|d| d.iter()).collect::<Vec<_>>();
+        let expected = (0..2)
+            .map(|_| step.step(iterators.as_mut_slice(), &model).0.unwrap())
+            .collect::<Vec<_>>();
+
+        // The second step runs while the gradients of the first one are all-reduced.
+        let step = DataParallelTrainStep::<TestAutodiffBackend, _, _, _>::new(&devices, 5);
+        let mut iterators = dataloaders.iter().map(|d| d.iter()).collect::<Vec<_>>();
+        assert!(step.submit(iterators.as_mut_slice(), &model).is_some());
+        assert!(step.submit(iterators.as_mut_slice(), &model).is_some());
+
+        for expected in expected {
+            let output = step.receive();
+            output
+                .grads
+                .get::<InnerBackend, 2>(model.weight.id)
+                .unwrap()
+                .into_data()
+                .assert_eq(
+                    &expected
+                        .grads
+                        .get::<InnerBackend, 2>(model.weight.id)
+                        .unwrap()
+                        .into_data(),
+                    true,
+                );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Matmul")]
+    fn worker_panic_should_be_propagated() {
+        let device = Default::default();
+        let model = LinearConfig::new(4, 3).init::<TestAutodiffBackend>(&device);
+        let mut dataloaders = dataloaders(3, 6);
+        // The items of the second device don't fit the model.
+        dataloaders[1] = DataLoaderBuilder::new(TestBatcher)
+            .batch_size(2)
+            .build(InMemDataset::new(vec![[0.0f32; 5]; 2]));
+        let step = DataParallelTrainStep::<TestAutodiffBackend, _, _, _>::new(&vec![device; 3], 5);
+
+        // The other workers don't wait for the gradients of the failed one.
+        let mut iterators = dataloaders.iter().map(|d| d.iter()).collect::<Vec<_>>();
+        step.step(iterators.as_mut_slice(), &model);
+    }
+}
@@ -67,6 +67,11 @@ pub struct LearnerSummary {
     pub metrics: SummaryMetrics,
     /// The model name (only recorded within the learner).
     pub(crate) model: Option<String>,
+    /// The number of parameters of the model (only recorded within the learner).
+    pub num_params: Option<usize>,
+    /// The number of trainable parameters of the model, which excludes the
+    /// [frozen](burn_core::module::Module::freeze) parameters (only recorded within the learner).
+    pub num_trainable_params: Option<usize>,
 }
 
 impl LearnerSummary {
@@ -125,6 +130,8 @@ impl LearnerSummary {
                 valid: valid_summary,
             },
             model: None,
+            num_params: None,
+            num_trainable_params: None,
         })
     }
 
@@ -132,6 +139,12 @@ impl LearnerSummary {
         self.model = Some(name);
         self
     }
+
+    pub(crate) fn with_params(mut self, num_params: usize, num_trainable_params: usize) -> Self {
+        self.num_params = Some(num_params);
+        self.num_trainable_params = Some(num_trainable_params);
+        self
+    }
 }
 
 impl Display for LearnerSummary {
@@ -160,6 +173,12 @@ impl Display for LearnerSummary {
         if let Some(model) = &self.model {
             writeln!(f, "Model:\n{model}")?;
         }
+        if let Some(num_params) = self.num_params {
+            writeln!(f, "Total Parameters: {num_params}")?;
+        }
+        if let Some(num_trainable_params) = self.num_trainable_params {
+            writeln!(f, "Trainable Parameters: {num_trainable_params}")?;
+        }
         writeln!(f, "Total Epochs: {epochs}\n\n", epochs = self.epochs)?;
 
         // Metrics table header
@@ -322,4 +341,19 @@ mod tests {
 
         std::fs::remove_dir_all(dir).unwrap();
     }
+
+    #[test]
+    fn test_summary_should_display_params() {
+        let dir = Path::new("/tmp/test-learner-summary-params");
+        std::fs::create_dir_all(dir.join("train/epoch-1")).unwrap();
+
+        let summary = LearnerSummary::new(dir.to_str().unwrap(), &["Loss"])
+            .expect("Summary artifacts should exist")
+            .with_params(120, 20);
+        let display = summary.to_string();
+
+        assert!(display.contains("Total Parameters: 120\nTrainable Parameters: 20\n"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
 }
@@ -1,11 +1,11 @@
 use crate::components::{LearnerComponents, TrainBackend, ValidBackend};
 use crate::metric::processor::{Event, EventProcessor};
-use crate::{Learner, TrainEpoch, ValidEpoch};
+use crate::{CheckpointKey, IterationCheckpointing, Learner, TrainEpoch, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
 use burn_core::data::dataloader::split::split_dataloader;
 use burn_core::module::{AutodiffModule, Module};
 use burn_core::optim::{GradientsParams, Optimizer};
-use burn_core::tensor::backend::AutodiffBackend;
+use burn_core::tensor::backend::{AutodiffBackend, Backend};
 use std::sync::Arc;
 
 /// A training output.
@@ -84,6 +84,34 @@ pub trait TrainStep<TI, TO> {
     }
 }
 
+/// Trait to be implemented for training models with
+/// [mixed precision](crate::LearnerBuilder::mixed_precision).
+///
+/// Mixed precision training needs to know where the inputs enter the model and where the loss is
+/// computed, so the step can't be derived from [TrainStep::step].
+pub trait MixedPrecisionTrainStep<TI, TO>: TrainStep<TI, TO> {
+    /// Runs the training step on a [half precision copy](burn_core::optim::HalfPrecision::cast)
+    /// of the trained model.
+    ///
+    /// The float inputs should be cast to the data type of the parameters of the copy. The
+    /// outputs of the model should be cast back to full precision before computing the loss, so
+    /// that the reductions of the loss don't run in half precision, and the loss multiplied by
+    /// `loss_scale` before the backward pass, to keep the small gradients from underflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The training input for the model.
+    /// * `loss_scale` - The current scale of the loss.
+    ///
+    /// # Returns
+    ///
+    /// The training output containing the model output and the scaled gradients.
+    fn step_mixed_precision(&self, item: TI, loss_scale: f64) -> TrainOutput<TO>;
+}
+
+/// The [mixed precision step](MixedPrecisionTrainStep::step_mixed_precision) of a model.
+pub(crate) type MixedPrecisionStepFn<M, TI, TO> = fn(&M, TI, f64) -> TrainOutput<TO>;
+
 /// Trait to be implemented for validating models.
 pub trait ValidStep<VI, VO> {
     /// Runs a validation step.
@@ -108,7 +136,8 @@ impl<LC: LearnerComponents> Learner<LC> {
     ///
     /// # Returns
     ///
-    /// The fitted model.
+    /// The fitted model, or its [moving average](burn_core::module::ModuleEma) when it is used for
+    /// validation.
     pub fn fit<InputTrain, InputValid, OutputTrain, OutputValid>(
         mut self,
         dataloader_train: Arc<dyn DataLoader<TrainBackend<LC>, InputTrain>>,
@@ -129,23 +158,65 @@ impl<LC: LearnerComponents> Learner<LC> {
             self.model = self.model.fork(device);
         }
 
-        let starting_epoch = match self.checkpoint {
-            Some(checkpoint) => {
-                if let Some(checkpointer) = &mut self.checkpointer {
-                    (self.model, self.optim, self.lr_scheduler) = checkpointer.load_checkpoint(
-                        self.model,
-                        self.optim,
-                        self.lr_scheduler,
-                        &Default::default(), // Load the checkpoint on the default device.
-                        checkpoint,
-                    );
-                }
-                checkpoint + 1
-            }
+        // An iteration checkpoint resumes its epoch, while an epoch checkpoint resumes the next
+        // one.
+        let checkpoint = match self.checkpoint_iteration {
+            Some((epoch, iteration)) => Some(CheckpointKey::Iteration(epoch, iteration)),
+            None => self.checkpoint.map(CheckpointKey::Epoch),
+        };
+        let starting_epoch = match checkpoint {
+            Some(CheckpointKey::Epoch(epoch)) => epoch + 1,
+            Some(CheckpointKey::Iteration(epoch, _)) => epoch,
             None => 1,
         };
 
-        // `MultiDevicesTrainStep` has one worker per device, so we use a fixed device strategy
+        let mut state = None;
+        if let (Some(checkpoint), Some(checkpointer)) = (checkpoint, &mut self.checkpointer) {
+            // Load the checkpoint on the default device.
+            let device = Default::default();
+            (self.model, self.optim, self.lr_scheduler) = checkpointer.load_checkpoint(
+                self.model,
+                self.optim,
+                self.lr_scheduler,
+                &device,
+                checkpoint,
+            );
+
+            state = checkpointer.load_state_checkpoint(&device, checkpoint);
+        }
+
+        assert!(
+            self.mixed_precision.is_none() || self.devices.len() <= 1,
+            "Mixed precision training isn't supported on multiple devices."
+        );
+        let mut mixed_precision = self.mixed_precision.take().map(|mixed_precision| {
+            let step = mixed_precision
+                .step
+                .downcast::<MixedPrecisionStepFn<LC::Model, InputTrain, OutputTrain>>()
+                .expect("The mixed precision step should take the training items.");
+            (
+                mixed_precision.precision,
+                mixed_precision.scaler.init(),
+                *step,
+            )
+        });
+        if let (Some(state), Some((_, scaler, _))) = (&mut state, &mut mixed_precision) {
+            if let Some(record) = state.grad_scaler.take() {
+                *scaler = scaler.clone().load_record(record);
+            }
+        }
+
+        let mut ema = self.ema.as_ref().map(|config| {
+            let ema = config.init(&self.model);
+            match (checkpoint, &self.checkpointer) {
+                (Some(checkpoint), Some(checkpointer)) => {
+                    checkpointer.load_ema_checkpoint(ema, &Default::default(), checkpoint)
+                }
+                _ => ema,
+            }
+        });
+
+        // `DataParallelTrainStep` has one worker per device, so we use a fixed device strategy
         // for each (worker) data loader. This matches the expected device on the worker, so we
         // don't have to move the data between devices.
         let dataloaders_train = split_dataloader(dataloader_train, &self.devices);
@@ -158,13 +229,42 @@ impl<LC: LearnerComponents> Learner<LC> {
             self.grad_accumulation,
         );
 
+        if let Some(state) = state {
+            match &state.rng {
+                Some(rng) => LC::Backend::set_rng_state(rng),
+                None => log::warn!(
+                    "The backend doesn't expose the state of its random number generator, the \
+                     resumed training doesn't restore it."
+                ),
+            }
+            if let Some(CheckpointKey::Iteration(_, iteration)) = checkpoint {
+                epoch_train.resume(
+                    iteration,
+                    state.dataloaders.into_iter().map(Into::into).collect(),
+                );
+            }
+        }
+
+        self.callbacks
+            .on_train_begin(&mut self.event_processor, starting_epoch, self.num_epochs);
+
         for epoch in starting_epoch..self.num_epochs + 1 {
+            let checkpointing = match (&mut self.checkpointer, self.checkpoint_interval) {
+                (Some(checkpointer), Some(interval)) => {
+                    Some(IterationCheckpointing::new(checkpointer, interval))
+                }
+                _ => None,
+            };
+
             if self.devices.len() > 1 {
                 (self.model, self.optim) = epoch_train.run_multi_device::<LC, OutputTrain>(
                     self.model,
                     self.optim,
                     &mut self.lr_scheduler,
+                    ema.as_mut(),
+                    checkpointing,
                     &mut self.event_processor,
+                    &mut self.callbacks,
                     self.devices.clone(),
                     &self.interrupter,
                 )
@@ -173,7 +273,13 @@ impl<LC: LearnerComponents> Learner<LC> {
                     self.model,
                     self.optim,
                     &mut self.lr_scheduler,
+                    ema.as_mut(),
+                    mixed_precision
+                        .as_mut()
+                        .map(|(precision, scaler, step)| (&*precision, scaler, *step)),
+                    checkpointing,
                     &mut self.event_processor,
+                    &mut self.callbacks,
                     &self.interrupter,
                 );
             }
@@ -184,17 +290,30 @@ impl<LC: LearnerComponents> Learner<LC> {
 
             // TODO: multi-device validation?
             let epoch_valid = ValidEpoch::new(dataloader_valid.clone(), epoch, self.num_epochs);
+            let model_valid = match &ema {
+                Some(ema) if self.ema_validation => ema.module(),
+                _ => &self.model,
+            };
             epoch_valid.run::<LC, OutputValid>(
-                &self.model,
+                model_valid,
                 &mut self.event_processor,
+                &mut self.callbacks,
                 &self.interrupter,
             );
 
+            if let Some(metric) = &self.lr_scheduler_metric {
+                metric.report(&mut self.lr_scheduler, epoch, &self.event_store);
+            }
+
             if let Some(checkpointer) = &mut self.checkpointer {
                 checkpointer.checkpoint(
                     &self.model,
                     &self.optim,
                     &self.lr_scheduler,
+                    ema.as_ref(),
+                    mixed_precision
+                        .as_ref()
+                        .map(|(_, scaler, _)| scaler.to_record()),
                     epoch,
                     &self.event_store,
                 );
@@ -205,6 +324,11 @@ impl<LC: LearnerComponents> Learner<LC> {
                     break;
                 }
             }
+
+            // A callback can stop the training at the end of the validation.
+            if self.interrupter.should_stop() {
+                break;
+            }
         }
 
         // Signal training end. For the TUI renderer, this handles the exit & return to main screen.
@@ -214,12 +338,18 @@ impl<LC: LearnerComponents> Learner<LC> {
         if let Some(summary) = self.summary {
             match summary.init() {
                 Ok(summary) => {
-                    println!("{}", summary.with_model(self.model.to_string()))
+                    let summary = summary
+                        .with_model(self.model.to_string())
+                        .with_params(self.model.num_params(), self.model.num_trainable_params());
+                    println!("{summary}")
                 }
                 Err(err) => log::error!("Could not retrieve learner summary:\n{err}"),
             }
         }
 
-        self.model
+        match ema {
+            Some(ema) if self.ema_validation => ema.into_module(),
+            _ => self.model,
+        }
     }
 }
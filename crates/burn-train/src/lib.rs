@@ -20,8 +20,10 @@ pub mod logger;
 /// The metric module.
 pub mod metric;
 
+mod evaluator;
 mod learner;
 
+pub use evaluator::*;
 pub use learner::*;
 
 #[cfg(test)]
@@ -0,0 +1,264 @@
+use super::{AsyncLogger, FileLogger, Hyperparameters, Logger, MetricLogger, MetricValue};
+use crate::metric::{MetricEntry, NumericEntry};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+const HEADER: &str = "epoch,step,metric,value,count,text";
+
+/// A metric logger writing the entries of all the epochs to a single CSV file, `metrics.csv`,
+/// with the columns `epoch,step,metric,value,count,text`.
+///
+/// Numeric entries fill the `value` column, with the number of elements they were aggregated
+/// over in the `count` column, or `running` for the values over all the elements of the epoch so
+/// far, while other entries fill the `text` column. The step counts the
+/// entries of each metric across the epochs. The hyperparameters are written to
+/// `hyperparameters.csv`, with the columns `name,value`.
+///
+/// The file is appended to when it already exists, so a resumed training keeps the entries of
+/// the previous epochs. The entries then continue at the last epoch of the file, and the steps
+/// after the last step of each metric.
+pub struct CsvMetricLogger {
+    logger: AsyncLogger<String>,
+    directory: PathBuf,
+    steps: HashMap<String, usize>,
+    epoch: usize,
+}
+
+impl CsvMetricLogger {
+    /// Create a new CSV metric logger.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory.
+    ///
+    /// # Returns
+    ///
+    /// The CSV metric logger.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        let directory = directory.as_ref().to_path_buf();
+        std::fs::create_dir_all(&directory).ok();
+
+        let file_path = directory.join("metrics.csv");
+        let content = std::fs::read_to_string(&file_path).unwrap_or_default();
+        let mut steps = HashMap::new();
+        let mut epoch = 1;
+
+        for row in parse(&content)
+            .into_iter()
+            .skip(1)
+            .filter(|row| row.len() == 6)
+        {
+            if let (Ok(row_epoch), Ok(step)) = (row[0].parse(), row[1].parse()) {
+                epoch = usize::max(epoch, row_epoch);
+                let last_step = steps.entry(row[2].clone()).or_default();
+                *last_step = usize::max(*last_step, step);
+            }
+        }
+
+        let mut logger = AsyncLogger::new(FileLogger::append(file_path));
+        if content.is_empty() {
+            logger.log(HEADER.to_string());
+        }
+
+        Self {
+            logger,
+            directory,
+            steps,
+            epoch,
+        }
+    }
+}
+
+impl MetricLogger for CsvMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        let step = self.steps.entry(item.name.clone()).or_default();
+        *step += 1;
+
+        let (value, count, text) = match MetricValue::new(item) {
+            MetricValue::Scalar(value, count) => (
+                value.to_string(),
+                count.map(|count| count.to_string()).unwrap_or_default(),
+                String::new(),
+            ),
+            MetricValue::Running(value) => {
+                (value.to_string(), "running".to_string(), String::new())
+            }
+            MetricValue::Text(text) => (String::new(), String::new(), escape(text)),
+        };
+
+        self.logger.log(format!(
+            "{},{},{},{value},{count},{text}",
+            self.epoch,
+            step,
+            escape(&item.name)
+        ));
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        self.epoch = epoch + 1;
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<NumericEntry>, String> {
+        self.logger.sync();
+
+        let content = std::fs::read_to_string(self.directory.join("metrics.csv"))
+            .map_err(|err| err.to_string())?;
+        let epoch = epoch.to_string();
+
+        parse(&content)
+            .into_iter()
+            .skip(1)
+            .filter(|row| row.len() == 6 && row[0] == epoch && row[2] == name)
+            .filter(|row| !row[3].is_empty())
+            .map(|row| {
+                let value = row[3].parse::<f64>().map_err(|err| err.to_string())?;
+                match row[4].as_str() {
+                    "" => Ok(NumericEntry::Value(value)),
+                    "running" => Ok(NumericEntry::Running(value)),
+                    count => count
+                        .parse::<usize>()
+                        .map(|count| NumericEntry::Aggregated(value, count))
+                        .map_err(|err| err.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    fn log_hyperparameters(&mut self, hyperparameters: &Hyperparameters) {
+        let mut logger = FileLogger::new(self.directory.join("hyperparameters.csv"));
+        logger.log("name,value");
+
+        for (name, value) in hyperparameters.iter() {
+            logger.log(format!("{},{}", escape(name), escape(&value.to_string())));
+        }
+    }
+}
+
+/// Quote a field containing separators, quotes or line breaks.
+fn escape(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+/// Parse the rows of a CSV file, where quoted fields can contain line breaks.
+fn parse(content: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match (quoted, char) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, char) => field.push(char),
+            (false, '"') => quoted = true,
+            (false, ',') => row.push(core::mem::take(&mut field)),
+            (false, '\n') => {
+                row.push(core::mem::take(&mut field));
+                rows.push(core::mem::take(&mut row));
+            }
+            (false, '\r') => {}
+            (false, char) => field.push(char),
+        }
+    }
+
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logger::HyperparameterValue;
+
+    #[test]
+    fn entries_should_be_read_back_by_epoch() {
+        let directory =
+            std::env::temp_dir().join(format!("burn-csv-logger-{}", std::process::id()));
+        std::fs::remove_dir_all(&directory).ok();
+        let mut logger = CsvMetricLogger::new(&directory);
+
+        logger.log_hyperparameters(
+            &Hyperparameters::new().with("optimizer", HyperparameterValue::Text("Adam, W".into())),
+        );
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.5,32".into()));
+        logger.log(&MetricEntry::new(
+            "Note".into(),
+            "".into(),
+            "a \"quoted\",\ntext".into(),
+        ));
+        logger.end_epoch(1);
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.25".into()));
+
+        let epoch_1 = logger.read_numeric("Loss", 1).unwrap();
+        let epoch_2 = logger.read_numeric("Loss", 2).unwrap();
+        assert!(matches!(epoch_1[..], [NumericEntry::Aggregated(0.5, 32)]));
+        assert!(matches!(epoch_2[..], [NumericEntry::Value(0.25)]));
+
+        let content = std::fs::read_to_string(directory.join("metrics.csv")).unwrap();
+        assert_eq!(
+            parse(&content),
+            vec![
+                vec!["epoch", "step", "metric", "value", "count", "text"],
+                vec!["1", "1", "Loss", "0.5", "32", ""],
+                vec!["1", "1", "Note", "", "", "a \"quoted\",\ntext"],
+                vec!["2", "2", "Loss", "0.25", "", ""],
+            ]
+        );
+        let content = std::fs::read_to_string(directory.join("hyperparameters.csv")).unwrap();
+        assert_eq!(content, "name,value\noptimizer,\"Adam, W\"\n");
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+
+    #[test]
+    fn resumed_logger_should_continue_the_epochs_and_steps() {
+        let directory =
+            std::env::temp_dir().join(format!("burn-csv-logger-resume-{}", std::process::id()));
+        std::fs::remove_dir_all(&directory).ok();
+
+        let mut logger = CsvMetricLogger::new(&directory);
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.5".into()));
+        logger.end_epoch(1);
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.4".into()));
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.3".into()));
+        core::mem::drop(logger);
+
+        let mut logger = CsvMetricLogger::new(&directory);
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.2".into()));
+        logger.log(&MetricEntry::new("Accuracy".into(), "".into(), "90".into()));
+        logger.end_epoch(2);
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.1".into()));
+
+        let epoch_2 = logger.read_numeric("Loss", 2).unwrap();
+        assert_eq!(epoch_2.len(), 3);
+        let content = std::fs::read_to_string(directory.join("metrics.csv")).unwrap();
+        assert_eq!(
+            parse(&content),
+            vec![
+                vec!["epoch", "step", "metric", "value", "count", "text"],
+                vec!["1", "1", "Loss", "0.5", "", ""],
+                vec!["2", "2", "Loss", "0.4", "", ""],
+                vec!["2", "3", "Loss", "0.3", "", ""],
+                vec!["2", "4", "Loss", "0.2", "", ""],
+                vec!["2", "1", "Accuracy", "90", "", ""],
+                vec!["3", "5", "Loss", "0.1", "", ""],
+            ]
+        );
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+}
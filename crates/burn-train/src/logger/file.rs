@@ -17,20 +17,36 @@ impl FileLogger {
     ///
     /// The file logger.
     pub fn new(path: impl AsRef<Path>) -> Self {
-        let path = path.as_ref();
         let mut options = std::fs::File::options();
-        let file = options
-            .write(true)
-            .truncate(true)
-            .create(true)
-            .open(path)
-            .unwrap_or_else(|err| {
-                panic!(
-                    "Should be able to create the new file '{}': {}",
-                    path.display(),
-                    err
-                )
-            });
+        options.write(true).truncate(true).create(true);
+
+        Self::open(path.as_ref(), options)
+    }
+
+    /// Create a file logger appending to the file if it already exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path.
+    ///
+    /// # Returns
+    ///
+    /// The file logger.
+    pub fn append(path: impl AsRef<Path>) -> Self {
+        let mut options = std::fs::File::options();
+        options.append(true).create(true);
+
+        Self::open(path.as_ref(), options)
+    }
+
+    fn open(path: &Path, options: std::fs::OpenOptions) -> Self {
+        let file = options.open(path).unwrap_or_else(|err| {
+            panic!(
+                "Should be able to create the new file '{}': {}",
+                path.display(),
+                err
+            )
+        });
 
         Self { file }
     }
@@ -0,0 +1,144 @@
+use burn_core::config::Config;
+use std::collections::BTreeMap;
+
+/// The value of a hyperparameter.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HyperparameterValue {
+    /// A numeric value.
+    Number(f64),
+    /// A boolean value.
+    Bool(bool),
+    /// Any other value, such as a name or a list, as text.
+    Text(String),
+}
+
+impl core::fmt::Display for HyperparameterValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Text(value) => f.write_str(value),
+        }
+    }
+}
+
+/// The hyperparameters of an experiment, logged by the [metric loggers](super::MetricLogger)
+/// before the training starts.
+///
+/// The hyperparameters are usually collected from the [configs](Config) used to build the
+/// learner, whose nested fields are flattened with their names joined by dots, such as
+/// `optimizer.weight_decay`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Hyperparameters {
+    values: BTreeMap<String, HyperparameterValue>,
+}
+
+impl Hyperparameters {
+    /// Create an empty set of hyperparameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the fields of a config as hyperparameters, with their names prefixed by `prefix`
+    /// unless it is empty.
+    pub fn with_config<C: Config>(mut self, prefix: &str, config: &C) -> Self {
+        let value = serde_json::to_value(config).expect("Can serialize the config.");
+        self.flatten(prefix.to_string(), value);
+        self
+    }
+
+    /// Add a hyperparameter, replacing the previous value with the same name.
+    pub fn with(mut self, name: impl Into<String>, value: HyperparameterValue) -> Self {
+        self.values.insert(name.into(), value);
+        self
+    }
+
+    /// Get the value of a hyperparameter.
+    pub fn get(&self, name: &str) -> Option<&HyperparameterValue> {
+        self.values.get(name)
+    }
+
+    /// Iterate over the hyperparameters, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &HyperparameterValue)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// If there are no hyperparameters.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn flatten(&mut self, name: String, value: serde_json::Value) {
+        let value = match value {
+            serde_json::Value::Object(fields) => {
+                for (field, value) in fields {
+                    let name = match name.is_empty() {
+                        true => field,
+                        false => format!("{name}.{field}"),
+                    };
+                    self.flatten(name, value);
+                }
+                return;
+            }
+            serde_json::Value::Number(number) => match number.as_f64() {
+                Some(number) => HyperparameterValue::Number(number),
+                None => HyperparameterValue::Text(number.to_string()),
+            },
+            serde_json::Value::Bool(value) => HyperparameterValue::Bool(value),
+            serde_json::Value::String(value) => HyperparameterValue::Text(value),
+            value => HyperparameterValue::Text(value.to_string()),
+        };
+
+        self.values.insert(name, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_core as burn;
+
+    #[derive(Config)]
+    struct OptimizerConfig {
+        #[config(default = 1e-4)]
+        weight_decay: f64,
+        kernel_size: [usize; 2],
+    }
+
+    #[derive(Config)]
+    struct TrainingConfig {
+        optimizer: OptimizerConfig,
+        #[config(default = 32)]
+        batch_size: usize,
+        #[config(default = true)]
+        shuffle: bool,
+        name: String,
+        seed: Option<u64>,
+    }
+
+    #[test]
+    fn config_should_be_flattened() {
+        let config = TrainingConfig::new(OptimizerConfig::new([3, 3]), "mnist".into());
+        let hyperparameters = Hyperparameters::new().with_config("train", &config);
+
+        assert_eq!(
+            hyperparameters.iter().collect::<Vec<_>>(),
+            vec![
+                ("train.batch_size", &HyperparameterValue::Number(32.0)),
+                ("train.name", &HyperparameterValue::Text("mnist".into())),
+                (
+                    "train.optimizer.kernel_size",
+                    &HyperparameterValue::Text("[3,3]".into())
+                ),
+                (
+                    "train.optimizer.weight_decay",
+                    &HyperparameterValue::Number(1e-4)
+                ),
+                ("train.seed", &HyperparameterValue::Text("null".into())),
+                ("train.shuffle", &HyperparameterValue::Bool(true)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use super::{
+    AsyncLogger, FileLogger, HyperparameterValue, Hyperparameters, Logger, MetricLogger,
+    MetricValue,
+};
+use crate::metric::{MetricEntry, NumericEntry};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+
+/// A record of a [JSON Lines metric logger](JsonlMetricLogger), written on its own line.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Record {
+    Scalar {
+        epoch: usize,
+        step: usize,
+        metric: String,
+        // Non-finite values are written as null.
+        value: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        count: Option<usize>,
+        // If the value is over all the elements of the epoch so far.
+        #[serde(default, skip_serializing_if = "core::ops::Not::not")]
+        running: bool,
+    },
+    Text {
+        epoch: usize,
+        step: usize,
+        metric: String,
+        text: String,
+    },
+    Hyperparameters {
+        hyperparameters: BTreeMap<String, serde_json::Value>,
+    },
+}
+
+/// A metric logger writing the entries of all the epochs to a single
+/// [JSON Lines](https://jsonlines.org) file, `metrics.jsonl`.
+///
+/// Every line is an object whose `type` is either:
+///
+/// - `scalar`, for numeric entries, with the `epoch`, the `step`, the `metric` name, its `value`
+///   and the number of elements it was aggregated over as `count`, if any, or `running` when the
+///   value is over all the elements of the epoch so far.
+/// - `text`, for other entries, with the `epoch`, the `step`, the `metric` name and its `text`.
+/// - `hyperparameters`, with the `hyperparameters` object, written before the entries.
+///
+/// The step counts the entries of each metric across the epochs. The file is appended to when it
+/// already exists, so a resumed training keeps the entries of the previous epochs. The entries
+/// then continue at the last epoch of the file, and the steps after the last step of each metric.
+pub struct JsonlMetricLogger {
+    logger: AsyncLogger<String>,
+    file_path: PathBuf,
+    steps: HashMap<String, usize>,
+    epoch: usize,
+}
+
+impl JsonlMetricLogger {
+    /// Create a new JSON Lines metric logger.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory.
+    ///
+    /// # Returns
+    ///
+    /// The JSON Lines metric logger.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory).ok();
+
+        let file_path = directory.join("metrics.jsonl");
+        let content = std::fs::read_to_string(&file_path).unwrap_or_default();
+        let mut steps = HashMap::new();
+        let mut epoch = 1;
+
+        for line in content.lines() {
+            if let Ok(
+                Record::Scalar {
+                    epoch: record_epoch,
+                    step,
+                    metric,
+                    ..
+                }
+                | Record::Text {
+                    epoch: record_epoch,
+                    step,
+                    metric,
+                    ..
+                },
+            ) = serde_json::from_str(line)
+            {
+                epoch = usize::max(epoch, record_epoch);
+                let last_step = steps.entry(metric).or_default();
+                *last_step = usize::max(*last_step, step);
+            }
+        }
+
+        let logger = AsyncLogger::new(FileLogger::append(&file_path));
+
+        Self {
+            logger,
+            file_path,
+            steps,
+            epoch,
+        }
+    }
+
+    fn write(&mut self, record: &Record) {
+        let line = serde_json::to_string(record).expect("Can serialize the record.");
+        self.logger.log(line);
+    }
+}
+
+impl MetricLogger for JsonlMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        let step = self.steps.entry(item.name.clone()).or_default();
+        *step += 1;
+
+        let (epoch, step, metric) = (self.epoch, *step, item.name.clone());
+        let record = match MetricValue::new(item) {
+            MetricValue::Scalar(value, count) => Record::Scalar {
+                epoch,
+                step,
+                metric,
+                value: Some(value),
+                count,
+                running: false,
+            },
+            MetricValue::Running(value) => Record::Scalar {
+                epoch,
+                step,
+                metric,
+                value: Some(value),
+                count: None,
+                running: true,
+            },
+            MetricValue::Text(text) => Record::Text {
+                epoch,
+                step,
+                metric,
+                text: text.to_string(),
+            },
+        };
+
+        self.write(&record);
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        self.epoch = epoch + 1;
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<NumericEntry>, String> {
+        self.logger.sync();
+
+        let content = std::fs::read_to_string(&self.file_path).map_err(|err| err.to_string())?;
+        let mut entries = Vec::new();
+
+        for line in content.lines().filter(|line| !line.is_empty()) {
+            let record = serde_json::from_str(line).map_err(|err| err.to_string())?;
+            if let Record::Scalar {
+                epoch: record_epoch,
+                metric,
+                value,
+                count,
+                running,
+                ..
+            } = record
+            {
+                if record_epoch != epoch || metric != name {
+                    continue;
+                }
+
+                let value = value.unwrap_or(f64::NAN);
+                entries.push(match (count, running) {
+                    (_, true) => NumericEntry::Running(value),
+                    (Some(count), false) => NumericEntry::Aggregated(value, count),
+                    (None, false) => NumericEntry::Value(value),
+                });
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn log_hyperparameters(&mut self, hyperparameters: &Hyperparameters) {
+        let hyperparameters = hyperparameters
+            .iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    HyperparameterValue::Number(value) => serde_json::Value::from(*value),
+                    HyperparameterValue::Bool(value) => serde_json::Value::Bool(*value),
+                    HyperparameterValue::Text(value) => serde_json::Value::from(value.as_str()),
+                };
+                (name.to_string(), value)
+            })
+            .collect();
+
+        self.write(&Record::Hyperparameters { hyperparameters });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_should_be_read_back_by_epoch() {
+        let directory =
+            std::env::temp_dir().join(format!("burn-jsonl-logger-{}", std::process::id()));
+        std::fs::remove_dir_all(&directory).ok();
+        let mut logger = JsonlMetricLogger::new(&directory);
+
+        logger.log_hyperparameters(
+            &Hyperparameters::new().with("batch_size", HyperparameterValue::Number(32.0)),
+        );
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "NaN,4".into()));
+        logger.log(&MetricEntry::new("Note".into(), "".into(), "text".into()));
+        logger.end_epoch(1);
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.25".into()));
+
+        let epoch_1 = logger.read_numeric("Loss", 1).unwrap();
+        let epoch_2 = logger.read_numeric("Loss", 2).unwrap();
+        assert!(matches!(epoch_1[..], [NumericEntry::Aggregated(value, 4)] if value.is_nan()));
+        assert!(matches!(epoch_2[..], [NumericEntry::Value(0.25)]));
+
+        let content = std::fs::read_to_string(directory.join("metrics.jsonl")).unwrap();
+        assert_eq!(
+            content.lines().collect::<Vec<_>>(),
+            vec![
+                r#"{"type":"hyperparameters","hyperparameters":{"batch_size":32.0}}"#,
+                r#"{"type":"scalar","epoch":1,"step":1,"metric":"Loss","value":null,"count":4}"#,
+                r#"{"type":"text","epoch":1,"step":1,"metric":"Note","text":"text"}"#,
+                r#"{"type":"scalar","epoch":2,"step":2,"metric":"Loss","value":0.25}"#,
+            ]
+        );
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+
+    #[test]
+    fn resumed_logger_should_continue_the_epochs_and_steps() {
+        let directory =
+            std::env::temp_dir().join(format!("burn-jsonl-logger-resume-{}", std::process::id()));
+        std::fs::remove_dir_all(&directory).ok();
+
+        let mut logger = JsonlMetricLogger::new(&directory);
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.5".into()));
+        logger.end_epoch(1);
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.25".into()));
+        logger.log(&MetricEntry::new("Note".into(), "".into(), "text".into()));
+        core::mem::drop(logger);
+
+        let mut logger = JsonlMetricLogger::new(&directory);
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.125".into()));
+        logger.log(&MetricEntry::new("Note".into(), "".into(), "text".into()));
+
+        let epoch_2 = logger.read_numeric("Loss", 2).unwrap();
+        assert!(matches!(
+            epoch_2[..],
+            [NumericEntry::Value(0.25), NumericEntry::Value(0.125)]
+        ));
+        let content = std::fs::read_to_string(directory.join("metrics.jsonl")).unwrap();
+        assert_eq!(
+            content.lines().skip(3).collect::<Vec<_>>(),
+            vec![
+                r#"{"type":"scalar","epoch":2,"step":3,"metric":"Loss","value":0.125}"#,
+                r#"{"type":"text","epoch":2,"step":2,"metric":"Note","text":"text"}"#,
+            ]
+        );
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+}
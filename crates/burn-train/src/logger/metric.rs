@@ -1,4 +1,4 @@
-use super::{AsyncLogger, FileLogger, InMemoryLogger, Logger};
+use super::{AsyncLogger, FileLogger, Hyperparameters, InMemoryLogger, Logger};
 use crate::metric::{MetricEntry, NumericEntry};
 use std::{
     collections::HashMap,
@@ -26,6 +26,32 @@ pub trait MetricLogger: Send {
 
     /// Read the logs for an epoch.
     fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<NumericEntry>, String>;
+
+    /// Logs the hyperparameters of the experiment, before the training starts.
+    ///
+    /// By default, the hyperparameters are ignored.
+    fn log_hyperparameters(&mut self, _hyperparameters: &Hyperparameters) {}
+}
+
+/// The value of a [metric entry](MetricEntry), mapped to the record type it is logged as.
+pub(crate) enum MetricValue<'a> {
+    /// A numeric value, with the number of elements it was aggregated over, if any.
+    Scalar(f64, Option<usize>),
+    /// A numeric value over all the elements of the epoch so far.
+    Running(f64),
+    /// Any other value, logged as text.
+    Text(&'a str),
+}
+
+impl<'a> MetricValue<'a> {
+    pub(crate) fn new(entry: &'a MetricEntry) -> Self {
+        match NumericEntry::deserialize(&entry.serialize) {
+            Ok(NumericEntry::Value(value)) => Self::Scalar(value, None),
+            Ok(NumericEntry::Aggregated(value, count)) => Self::Scalar(value, Some(count)),
+            Ok(NumericEntry::Running(value)) => Self::Running(value),
+            Err(_) => Self::Text(&entry.serialize),
+        }
+    }
 }
 
 /// The file metric logger.
@@ -1,11 +1,19 @@
 mod async_logger;
 mod base;
+mod csv;
 mod file;
+mod hyperparameters;
 mod in_memory;
+mod jsonl;
 mod metric;
+mod tensorboard;
 
 pub use async_logger::*;
 pub use base::*;
+pub use csv::*;
 pub use file::*;
+pub use hyperparameters::*;
 pub use in_memory::*;
+pub use jsonl::*;
 pub use metric::*;
+pub use tensorboard::*;
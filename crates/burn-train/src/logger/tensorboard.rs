@@ -0,0 +1,337 @@
+use super::{
+    HyperparameterValue, Hyperparameters, InMemoryMetricLogger, MetricLogger, MetricValue,
+};
+use crate::metric::{MetricEntry, NumericEntry};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A metric logger writing [TensorBoard](https://www.tensorflow.org/tensorboard) event files,
+/// which can be visualized by pointing TensorBoard to the parent directory of the training and
+/// validation loggers.
+///
+/// Numeric entries are written as scalar summaries, and other entries as text summaries, with
+/// the number of entries of each metric across the epochs as the step. The hyperparameters are
+/// written as a session of the HParams plugin. A new event file is created each time the logger
+/// is created, so a resumed training adds to the previous files.
+pub struct TensorBoardMetricLogger {
+    writer: EventWriter,
+    steps: HashMap<String, usize>,
+    // Event files are only written, so the numeric entries are also kept in memory to be read.
+    values: InMemoryMetricLogger,
+}
+
+impl TensorBoardMetricLogger {
+    /// Create a new TensorBoard metric logger.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory of the event files, which is the name of the run in
+    ///   TensorBoard.
+    ///
+    /// # Returns
+    ///
+    /// The TensorBoard metric logger.
+    pub fn new(directory: impl AsRef<Path>) -> Self {
+        Self {
+            writer: EventWriter::new(directory.as_ref()),
+            steps: HashMap::new(),
+            values: InMemoryMetricLogger::new(),
+        }
+    }
+}
+
+impl MetricLogger for TensorBoardMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        let step = self.steps.entry(item.name.clone()).or_default();
+        *step += 1;
+
+        let step = *step as i64;
+        match MetricValue::new(item) {
+            MetricValue::Scalar(value, _) | MetricValue::Running(value) => {
+                self.writer.write_summary(step, |value_proto| {
+                    value_proto.string(1, &item.name);
+                    value_proto.float(2, value as f32);
+                });
+                self.values.log(item);
+            }
+            MetricValue::Text(text) => self.writer.write_summary(step, |value_proto| {
+                value_proto.string(1, &item.name);
+                value_proto.message(8, |tensor| {
+                    tensor.int64(1, DT_STRING);
+                    tensor.message(2, |shape| shape.message(2, |dim| dim.int64(1, 1)));
+                    tensor.bytes(8, text.as_bytes());
+                });
+                value_proto.message(9, |metadata| {
+                    metadata.message(1, |plugin| plugin.string(1, "text"));
+                });
+            }),
+        }
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        self.values.end_epoch(epoch);
+        self.writer.flush();
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<NumericEntry>, String> {
+        self.values.read_numeric(name, epoch)
+    }
+
+    fn log_hyperparameters(&mut self, hyperparameters: &Hyperparameters) {
+        let mut session = ProtoWriter::default();
+        session.int64(1, 0);
+        session.message(3, |start| {
+            for (name, value) in hyperparameters.iter() {
+                // The hyperparameters are a map of `google.protobuf.Value`.
+                start.message(1, |entry| {
+                    entry.string(1, name);
+                    entry.message(2, |value_proto| match value {
+                        HyperparameterValue::Number(value) => value_proto.double(2, *value),
+                        HyperparameterValue::Text(value) => value_proto.string(3, value),
+                        HyperparameterValue::Bool(value) => value_proto.int64(4, *value as i64),
+                    });
+                });
+            }
+            start.double(5, wall_time());
+        });
+
+        self.writer.write_summary(0, |value_proto| {
+            value_proto.string(1, "_hparams_/session_start_info");
+            value_proto.message(9, |metadata| {
+                metadata.message(1, |plugin| {
+                    plugin.string(1, "hparams");
+                    plugin.bytes(2, &session.buffer);
+                });
+            });
+        });
+        self.writer.flush();
+    }
+}
+
+/// The `DT_STRING` tensor data type.
+const DT_STRING: i64 = 7;
+
+/// Writes `Event` protos to an event file, each in a TFRecord.
+struct EventWriter {
+    file: BufWriter<File>,
+}
+
+impl EventWriter {
+    fn new(directory: &Path) -> Self {
+        std::fs::create_dir_all(directory).ok();
+
+        let time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let path = directory.join(format!(
+            "events.out.tfevents.{}.{}.burn",
+            time.as_secs(),
+            time.subsec_nanos()
+        ));
+        let file = File::create(&path).unwrap_or_else(|err| {
+            panic!(
+                "Should be able to create the new file '{}': {}",
+                path.display(),
+                err
+            )
+        });
+
+        let mut writer = Self {
+            file: BufWriter::new(file),
+        };
+        writer.write_event(0, |event| event.string(3, "brain.Event:2"));
+        writer
+    }
+
+    /// Write an event with a summary of a single value.
+    fn write_summary(&mut self, step: i64, value: impl FnOnce(&mut ProtoWriter)) {
+        self.write_event(step, |event| {
+            event.message(5, |summary| summary.message(1, value));
+        });
+    }
+
+    fn write_event(&mut self, step: i64, what: impl FnOnce(&mut ProtoWriter)) {
+        let mut event = ProtoWriter::default();
+        event.double(1, wall_time());
+        event.int64(2, step);
+        what(&mut event);
+
+        let data = event.buffer;
+        let len = (data.len() as u64).to_le_bytes();
+        let result = self
+            .file
+            .write_all(&len)
+            .and_then(|_| self.file.write_all(&masked_crc32c(&len).to_le_bytes()))
+            .and_then(|_| self.file.write_all(&data))
+            .and_then(|_| self.file.write_all(&masked_crc32c(&data).to_le_bytes()));
+
+        if let Err(err) = result {
+            log::error!("Can't write the TensorBoard event: {err}");
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Err(err) = self.file.flush() {
+            log::error!("Can't flush the TensorBoard event file: {err}");
+        }
+    }
+}
+
+fn wall_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
+}
+
+/// Encodes the fields of a protobuf message.
+#[derive(Default)]
+struct ProtoWriter {
+    buffer: Vec<u8>,
+}
+
+impl ProtoWriter {
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buffer.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buffer.push(value as u8);
+    }
+
+    fn key(&mut self, field: u32, wire_type: u8) {
+        self.varint(((field as u64) << 3) | wire_type as u64);
+    }
+
+    fn int64(&mut self, field: u32, value: i64) {
+        self.key(field, 0);
+        self.varint(value as u64);
+    }
+
+    fn double(&mut self, field: u32, value: f64) {
+        self.key(field, 1);
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn float(&mut self, field: u32, value: f32) {
+        self.key(field, 5);
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u32, value: &[u8]) {
+        self.key(field, 2);
+        self.varint(value.len() as u64);
+        self.buffer.extend_from_slice(value);
+    }
+
+    fn string(&mut self, field: u32, value: &str) {
+        self.bytes(field, value.as_bytes());
+    }
+
+    fn message(&mut self, field: u32, message: impl FnOnce(&mut ProtoWriter)) {
+        let mut writer = ProtoWriter::default();
+        message(&mut writer);
+        self.bytes(field, &writer.buffer);
+    }
+}
+
+/// The CRC-32C (Castagnoli) lookup table.
+const CRC32C_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0x82F6_3B78,
+                _ => crc >> 1,
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+fn crc32c(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, byte| {
+        CRC32C_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// The checksum of the TFRecord format.
+fn masked_crc32c(data: &[u8]) -> u32 {
+    let crc = crc32c(data);
+    ((crc >> 15) | (crc << 17)).wrapping_add(0xA282_EAD8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32c_should_match_the_check_value() {
+        assert_eq!(crc32c(b"123456789"), 0xE306_9283);
+    }
+
+    #[test]
+    fn events_should_be_written_as_tf_records() {
+        let directory =
+            std::env::temp_dir().join(format!("burn-tensorboard-logger-{}", std::process::id()));
+        std::fs::remove_dir_all(&directory).ok();
+
+        let mut logger = TensorBoardMetricLogger::new(&directory);
+        logger.log_hyperparameters(
+            &Hyperparameters::new().with("lr", HyperparameterValue::Number(1e-3)),
+        );
+        logger.log(&MetricEntry::new("Loss".into(), "".into(), "0.5,2".into()));
+        logger.log(&MetricEntry::new("Note".into(), "".into(), "text".into()));
+        logger.end_epoch(1);
+
+        let file = std::fs::read_dir(&directory)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let data = std::fs::read(file).unwrap();
+
+        let mut records = Vec::new();
+        let mut data = data.as_slice();
+        while !data.is_empty() {
+            let (len, rest) = data.split_at(8);
+            let (len_crc, rest) = rest.split_at(4);
+            assert_eq!(masked_crc32c(len).to_le_bytes(), len_crc);
+
+            let (record, rest) =
+                rest.split_at(u64::from_le_bytes(len.try_into().unwrap()) as usize);
+            let (record_crc, rest) = rest.split_at(4);
+            assert_eq!(masked_crc32c(record).to_le_bytes(), record_crc);
+
+            records.push(record.to_vec());
+            data = rest;
+        }
+
+        // The file version, the hyperparameters, the scalar and the text.
+        assert_eq!(records.len(), 4);
+        let contains =
+            |record: &[u8], value: &[u8]| record.windows(value.len()).any(|w| w == value);
+        assert!(contains(&records[0], b"brain.Event:2"));
+        assert!(contains(&records[1], b"_hparams_/session_start_info"));
+        assert!(contains(&records[2], &0.5f32.to_le_bytes()));
+        assert!(contains(&records[3], b"text"));
+        assert!(matches!(
+            logger.read_numeric("Loss", 1).unwrap()[..],
+            [NumericEntry::Aggregated(0.5, 2)]
+        ));
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+}
@@ -16,6 +16,9 @@ pub struct MetricMetadata {
 
     /// The current learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The global norm of the gradients before clipping, when the optimizer computes it.
+    pub grad_norm: Option<f64>,
 }
 
 impl MetricMetadata {
@@ -31,6 +34,7 @@ impl MetricMetadata {
             epoch_total: 1,
             iteration: 0,
             lr: None,
+            grad_norm: None,
         }
     }
 }
@@ -89,11 +93,16 @@ pub struct MetricEntry {
 }
 
 /// Numeric metric entry.
+#[non_exhaustive]
 pub enum NumericEntry {
     /// Single numeric value.
     Value(f64),
     /// Aggregated numeric (value, number of elements).
     Aggregated(f64, usize),
+    /// Numeric value over all the elements of the epoch so far, for metrics whose value isn't the
+    /// mean of their values on each batch, such as the root mean squared error. The value of the
+    /// epoch is the last one.
+    Running(f64),
 }
 
 impl NumericEntry {
@@ -101,6 +110,7 @@ impl NumericEntry {
         match self {
             Self::Value(v) => v.to_string(),
             Self::Aggregated(v, n) => format!("{v},{n}"),
+            Self::Running(v) => format!("{v},running"),
         }
     }
 
@@ -115,6 +125,12 @@ impl NumericEntry {
                 Ok(value) => Ok(NumericEntry::Value(value)),
                 Err(err) => Err(err.to_string()),
             }
+        } else if num_values == 2 && values[1] == "running" {
+            // Running numeric value
+            match values[0].parse::<f64>() {
+                Ok(value) => Ok(NumericEntry::Running(value)),
+                Err(err) => Err(err.to_string()),
+            }
         } else if num_values == 2 {
             // Aggregated numeric (value, number of elements)
             let (value, numel) = (values[0], values[1]);
@@ -0,0 +1,76 @@
+use super::{
+    MetricMetadata, Numeric,
+    state::{FormatOptions, NumericMetricState},
+};
+use crate::metric::{Metric, MetricEntry};
+
+/// Track the global norm of the gradients before clipping, reported by optimizers
+/// [clipping the gradients by global norm](burn_core::grad_clipping::GradientClipping::GlobalNorm).
+///
+/// Iterations without optimizer step, such as with gradient accumulation, repeat the last norm.
+pub struct GradNormMetric {
+    state: NumericMetricState,
+    last: f64,
+}
+
+impl GradNormMetric {
+    /// Creates a new gradient norm metric.
+    pub fn new() -> Self {
+        Self {
+            state: NumericMetricState::new(),
+            last: 0.0,
+        }
+    }
+}
+
+impl Default for GradNormMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metric for GradNormMetric {
+    type Input = ();
+
+    fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
+        if let Some(norm) = metadata.grad_norm {
+            self.last = norm;
+        }
+
+        self.state
+            .update(self.last, 1, FormatOptions::new(self.name()).precision(3))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+
+    fn name(&self) -> String {
+        "Gradient Norm".to_string()
+    }
+}
+
+impl Numeric for GradNormMetric {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_repeat_the_last_norm_without_optimizer_step() {
+        let mut metric = GradNormMetric::new();
+        let mut metadata = MetricMetadata::fake();
+
+        metadata.grad_norm = Some(2.5);
+        metric.update(&(), &metadata);
+        assert_eq!(metric.value(), 2.5);
+
+        metadata.grad_norm = None;
+        metric.update(&(), &metadata);
+        assert_eq!(metric.value(), 2.5);
+    }
+}
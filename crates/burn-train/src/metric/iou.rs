@@ -0,0 +1,94 @@
+use core::marker::PhantomData;
+
+use super::segmentation::SegmentationStats;
+use super::state::{FormatOptions, RunningMetricState};
+use super::{MetricEntry, MetricMetadata, SegmentationInput};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+
+/// The mean intersection over union (IoU) metric of a semantic segmentation.
+///
+/// The intersection and the union of each class are summed over all the pixels of the epoch, and
+/// the IoU is averaged over the classes that are predicted or present.
+#[derive(Default)]
+pub struct IouMetric<B: Backend> {
+    state: RunningMetricState,
+    stats: SegmentationStats,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> IouMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for IouMetric<B> {
+    type Input = SegmentationInput<B>;
+
+    fn update(&mut self, input: &SegmentationInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        self.stats.update(input);
+
+        let iou = self.stats.mean(|intersection, predicted, target| {
+            intersection / (predicted + target - intersection)
+        });
+
+        self.state.update(
+            100.0 * iou,
+            FormatOptions::new(self.name()).unit("%").precision(2),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.stats.clear();
+    }
+
+    fn name(&self) -> String {
+        "Mean IoU".to_string()
+    }
+}
+
+impl<B: Backend> Numeric for IouMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_mean_iou_over_batches() {
+        let device = Default::default();
+        let mut metric = IouMetric::<TestBackend>::new();
+
+        // Predictions [[0, 1], [1, 1]] with the targets [[0, 0], [1, 1]].
+        let input = SegmentationInput::new(
+            Tensor::from_data(
+                [[[[0.9, 0.2], [0.1, 0.3]], [[0.1, 0.8], [0.9, 0.7]]]],
+                &device,
+            ),
+            Tensor::from_data([[[0, 0], [1, 1]]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        // Class 0: 1 / 2, class 1: 2 / 3
+        assert!((metric.value() - 100.0 * (0.5 + 2.0 / 3.0) / 2.0).abs() < 1e-4);
+
+        // Predictions [[0, 0], [0, 0]] with the same targets.
+        let input = SegmentationInput::new(
+            Tensor::from_data(
+                [[[[0.9, 0.8], [0.9, 0.7]], [[0.1, 0.2], [0.1, 0.3]]]],
+                &device,
+            ),
+            Tensor::from_data([[[0, 0], [1, 1]]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        // Class 0: 3 / 6, class 1: 2 / 5
+        assert!((metric.value() - 100.0 * (0.5 + 0.4) / 2.0).abs() < 1e-4);
+    }
+}
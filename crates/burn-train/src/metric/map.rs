@@ -0,0 +1,358 @@
+use core::marker::PhantomData;
+use std::collections::BTreeMap;
+
+use super::state::{FormatOptions, RunningMetricState};
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Int, Tensor, Transaction};
+
+/// The input of the [mean average precision metric](MeanAveragePrecisionMetric).
+///
+/// The boxes are `[x_min, y_min, x_max, y_max]` and the labels that are negative are padding, so
+/// images with different numbers of boxes can be batched.
+#[derive(new, Debug, Clone)]
+pub struct DetectionInput<B: Backend> {
+    /// The predicted boxes (batch_size, num_predictions, 4).
+    pub boxes: Tensor<B, 3>,
+    /// The confidence scores of the predicted boxes (batch_size, num_predictions).
+    pub scores: Tensor<B, 2>,
+    /// The classes of the predicted boxes (batch_size, num_predictions).
+    pub labels: Tensor<B, 2, Int>,
+    /// The ground truth boxes (batch_size, num_targets, 4).
+    pub target_boxes: Tensor<B, 3>,
+    /// The classes of the ground truth boxes (batch_size, num_targets).
+    pub target_labels: Tensor<B, 2, Int>,
+}
+
+/// The mean average precision (mAP) metric of an object detection, at a single IoU threshold.
+///
+/// A prediction is a true positive when its IoU with a ground truth box of the same class is at
+/// least the threshold, and the ground truth isn't matched by a prediction with a higher score.
+/// The average precision of each class is the area under its interpolated precision-recall curve
+/// over all the images of the epoch, and is averaged over the classes with ground truth boxes.
+/// The metric is NaN until a ground truth box is processed.
+pub struct MeanAveragePrecisionMetric<B: Backend> {
+    state: RunningMetricState,
+    iou_threshold: f64,
+    classes: BTreeMap<i64, ClassDetections>,
+    _b: PhantomData<B>,
+}
+
+/// The detections of a class, sorted by decreasing score, and its number of ground truth boxes.
+#[derive(Default)]
+struct ClassDetections {
+    detections: Vec<(f64, bool)>,
+    num_targets: usize,
+    /// The average precision, computed again only when the class is updated.
+    average_precision: Option<f64>,
+}
+
+impl<B: Backend> MeanAveragePrecisionMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the IoU threshold of the true positives.
+    pub fn with_iou_threshold(mut self, iou_threshold: f64) -> Self {
+        self.iou_threshold = iou_threshold;
+        self
+    }
+
+    fn mean_average_precision(&mut self) -> f64 {
+        let (sum, num_classes) = self
+            .classes
+            .values_mut()
+            .filter(|class| class.num_targets > 0)
+            .map(ClassDetections::average_precision)
+            .fold((0.0, 0), |(acc, n), value| (acc + value, n + 1));
+
+        match num_classes {
+            0 => f64::NAN,
+            _ => sum / num_classes as f64,
+        }
+    }
+}
+
+impl<B: Backend> Default for MeanAveragePrecisionMetric<B> {
+    fn default() -> Self {
+        Self {
+            state: RunningMetricState::default(),
+            iou_threshold: 0.5,
+            classes: BTreeMap::new(),
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for MeanAveragePrecisionMetric<B> {
+    type Input = DetectionInput<B>;
+
+    fn update(&mut self, input: &DetectionInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, num_predictions, _] = input.boxes.dims();
+        let [_, num_targets, _] = input.target_boxes.dims();
+
+        let [boxes, scores, labels, target_boxes, target_labels] = Transaction::default()
+            .register(input.boxes.clone())
+            .register(input.scores.clone())
+            .register(input.labels.clone())
+            .register(input.target_boxes.clone())
+            .register(input.target_labels.clone())
+            .execute()
+            .try_into()
+            .expect("Correct amount of tensor data");
+
+        let boxes = boxes.iter::<f64>().collect::<Vec<_>>();
+        let scores = scores.iter::<f64>().collect::<Vec<_>>();
+        let labels = labels.iter::<i64>().collect::<Vec<_>>();
+        let target_boxes = target_boxes.iter::<f64>().collect::<Vec<_>>();
+        let target_labels = target_labels.iter::<i64>().collect::<Vec<_>>();
+
+        let mut batch = BTreeMap::<i64, Vec<(f64, bool)>>::new();
+        for image in 0..batch_size {
+            let targets = (image * num_targets..(image + 1) * num_targets)
+                .filter(|&i| target_labels[i] >= 0)
+                .map(|i| (target_labels[i], &target_boxes[4 * i..4 * i + 4]))
+                .collect::<Vec<_>>();
+            for (label, _) in targets.iter() {
+                self.classes.entry(*label).or_default().add_target();
+            }
+
+            let mut predictions = (image * num_predictions..(image + 1) * num_predictions)
+                .filter(|&i| labels[i] >= 0)
+                .collect::<Vec<_>>();
+            predictions.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+
+            let mut matched = vec![false; targets.len()];
+            for i in predictions {
+                let best = targets
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (label, _))| *label == labels[i])
+                    .map(|(j, (_, target))| (j, iou(&boxes[4 * i..4 * i + 4], target)))
+                    .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+                let true_positive = match best {
+                    Some((j, iou)) if iou >= self.iou_threshold && !matched[j] => {
+                        matched[j] = true;
+                        true
+                    }
+                    _ => false,
+                };
+
+                batch
+                    .entry(labels[i])
+                    .or_default()
+                    .push((scores[i], true_positive));
+            }
+        }
+
+        for (label, detections) in batch {
+            self.classes.entry(label).or_default().insert(detections);
+        }
+
+        let value = self.mean_average_precision();
+        self.state.update(
+            100.0 * value,
+            FormatOptions::new(self.name()).unit("%").precision(2),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.classes.clear();
+    }
+
+    fn name(&self) -> String {
+        format!("mAP @ IoU({})", self.iou_threshold)
+    }
+}
+
+impl<B: Backend> Numeric for MeanAveragePrecisionMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+impl ClassDetections {
+    fn add_target(&mut self) {
+        self.num_targets += 1;
+        self.average_precision = None;
+    }
+
+    /// Merge the detections of a batch into the sorted detections of the epoch.
+    ///
+    /// The detections of the batch are sorted with a stable sort, and come after the previous
+    /// ones with the same score, so the order is the same as sorting all the detections at once.
+    fn insert(&mut self, mut detections: Vec<(f64, bool)>) {
+        detections.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+
+        let previous = core::mem::take(&mut self.detections);
+        self.detections.reserve(previous.len() + detections.len());
+        let mut previous = previous.into_iter().peekable();
+        let mut detections = detections.into_iter().peekable();
+        loop {
+            let next = match (previous.peek(), detections.peek()) {
+                (Some((a, _)), Some((b, _))) if b.total_cmp(a).is_gt() => detections.next(),
+                (Some(_), _) => previous.next(),
+                (None, _) => detections.next(),
+            };
+            match next {
+                Some(detection) => self.detections.push(detection),
+                None => break,
+            }
+        }
+
+        self.average_precision = None;
+    }
+
+    fn average_precision(&mut self) -> f64 {
+        let value = self
+            .average_precision
+            .unwrap_or_else(|| self.area_under_curve());
+        self.average_precision = Some(value);
+        value
+    }
+
+    /// The area under the precision-recall curve, where the precision at each recall is the
+    /// highest precision at the same or a higher recall.
+    fn area_under_curve(&self) -> f64 {
+        let mut true_positives = 0;
+        let mut points = Vec::with_capacity(self.detections.len());
+        for (rank, (_, true_positive)) in self.detections.iter().enumerate() {
+            if *true_positive {
+                true_positives += 1;
+            }
+            let precision = true_positives as f64 / (rank + 1) as f64;
+            let recall = true_positives as f64 / self.num_targets as f64;
+            points.push((recall, precision));
+        }
+
+        let mut precision_max: f64 = 0.0;
+        for (_, precision) in points.iter_mut().rev() {
+            precision_max = precision_max.max(*precision);
+            *precision = precision_max;
+        }
+
+        let mut recall_previous = 0.0;
+        let mut area = 0.0;
+        for (recall, precision) in points {
+            area += (recall - recall_previous) * precision;
+            recall_previous = recall;
+        }
+        area
+    }
+}
+
+/// The intersection over union of two `[x_min, y_min, x_max, y_max]` boxes.
+fn iou(a: &[f64], b: &[f64]) -> f64 {
+    let width = (a[2].min(b[2]) - a[0].max(b[0])).max(0.0);
+    let height = (a[3].min(b[3]) - a[1].max(b[1])).max(0.0);
+    let intersection = width * height;
+    let area = |x: &[f64]| (x[2] - x[0]).max(0.0) * (x[3] - x[1]).max(0.0);
+    let union = area(a) + area(b) - intersection;
+
+    if union > 0.0 {
+        intersection / union
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_map_over_batches() {
+        let device = Default::default();
+        let mut metric = MeanAveragePrecisionMetric::<TestBackend>::new();
+
+        // The first prediction matches the target, the second one is a duplicate and the last one
+        // is padding.
+        let input = DetectionInput::new(
+            Tensor::from_data(
+                [[
+                    [0.0, 0.0, 10.0, 10.0],
+                    [1.0, 1.0, 10.0, 10.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ]],
+                &device,
+            ),
+            Tensor::from_data([[0.9, 0.8, 0.0]], &device),
+            Tensor::from_data([[0, 0, -1]], &device),
+            Tensor::from_data([[[0.0, 0.0, 10.0, 10.0], [0.0, 0.0, 0.0, 0.0]]], &device),
+            Tensor::from_data([[0, -1]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 100.0).abs() < 1e-6);
+
+        // A target of class 0 that is missed, with a wrong prediction of class 1 without target.
+        let input = DetectionInput::new(
+            Tensor::from_data(
+                [[
+                    [20.0, 20.0, 30.0, 30.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                    [0.0, 0.0, 0.0, 0.0],
+                ]],
+                &device,
+            ),
+            Tensor::from_data([[0.7, 0.0, 0.0]], &device),
+            Tensor::from_data([[1, -1, -1]], &device),
+            Tensor::from_data([[[0.0, 0.0, 5.0, 5.0], [0.0, 0.0, 0.0, 0.0]]], &device),
+            Tensor::from_data([[0, -1]], &device),
+        );
+        let entry = metric.update(&input, &MetricMetadata::fake());
+
+        // Class 0 has a recall of 0.5 at a precision of 1, and class 1 has no target.
+        assert!((metric.value() - 50.0).abs() < 1e-6);
+        assert!(entry.serialize.ends_with(",running"));
+    }
+
+    #[test]
+    fn test_detections_are_merged_in_score_order() {
+        let mut class = ClassDetections::default();
+        class.insert(vec![(0.5, true), (0.9, false), (0.5, false)]);
+        class.insert(vec![(0.5, true), (0.7, true), (1.0, false)]);
+
+        assert_eq!(
+            class.detections,
+            vec![
+                (1.0, false),
+                (0.9, false),
+                (0.7, true),
+                (0.5, true),
+                (0.5, false),
+                (0.5, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_without_targets_is_nan() {
+        let device = Default::default();
+        let mut metric = MeanAveragePrecisionMetric::<TestBackend>::new();
+
+        let input = DetectionInput::new(
+            Tensor::from_data([[[0.0, 0.0, 10.0, 10.0]]], &device),
+            Tensor::from_data([[0.9]], &device),
+            Tensor::from_data([[0]], &device),
+            Tensor::from_data([[[0.0, 0.0, 0.0, 0.0]]], &device),
+            Tensor::from_data([[-1]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert!(metric.value().is_nan());
+    }
+
+    #[test]
+    fn test_iou() {
+        let a = [0.0, 0.0, 2.0, 2.0];
+        let b = [1.0, 0.0, 3.0, 2.0];
+
+        assert!((iou(&a, &b) - 2.0 / 6.0).abs() < 1e-9);
+        assert_eq!(iou(&a, &[5.0, 5.0, 6.0, 6.0]), 0.0);
+    }
+}
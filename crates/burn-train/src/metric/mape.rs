@@ -0,0 +1,82 @@
+use core::marker::PhantomData;
+
+use super::state::{FormatOptions, NumericMetricState};
+use super::{MetricEntry, MetricMetadata, RegressionInput};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::ElementConversion;
+use burn_core::tensor::backend::Backend;
+
+/// The mean absolute percentage error metric.
+///
+/// The absolute value of the targets is clamped to a small epsilon, so the error stays finite
+/// when a target is zero.
+#[derive(Default)]
+pub struct MapeMetric<B: Backend> {
+    state: NumericMetricState,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> MapeMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for MapeMetric<B> {
+    type Input = RegressionInput<B>;
+
+    fn update(&mut self, input: &RegressionInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, num_outputs] = input.outputs.dims();
+        let targets = input.targets.clone();
+
+        let error = (input.outputs.clone() - targets.clone())
+            .abs()
+            .div(targets.abs().clamp_min(f32::EPSILON))
+            .mean()
+            .into_scalar()
+            .elem::<f64>();
+
+        self.state.update(
+            100.0 * error,
+            batch_size * num_outputs,
+            FormatOptions::new(self.name()).unit("%").precision(2),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+
+    fn name(&self) -> String {
+        "MAPE".to_string()
+    }
+}
+
+impl<B: Backend> Numeric for MapeMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_mape() {
+        let device = Default::default();
+        let mut metric = MapeMetric::<TestBackend>::new();
+
+        // Relative errors: [0.1, 0.5, 0.25, 0.0]
+        let input = RegressionInput::new(
+            Tensor::from_data([[1.1, 3.0], [3.0, -2.0]], &device),
+            Tensor::from_data([[1.0, 2.0], [4.0, -2.0]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 21.25).abs() < 1e-4);
+    }
+}
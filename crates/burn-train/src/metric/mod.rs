@@ -26,26 +26,53 @@ mod acc;
 mod auroc;
 mod base;
 mod confusion_stats;
+mod dice;
 mod fbetascore;
+mod grad_norm;
 mod hamming;
+mod iou;
 mod iteration;
 mod learning_rate;
 mod loss;
+mod mae;
+mod map;
+mod mape;
+mod perplexity;
 mod precision;
+mod r_squared;
 mod recall;
+mod regression;
+mod rmse;
+mod segmentation;
+mod sequence;
+mod token_acc;
 mod top_k_acc;
 
 pub use acc::*;
 pub use auroc::*;
 pub use base::*;
+pub(crate) use confusion_stats::ConfusionStats;
 pub use confusion_stats::ConfusionStatsInput;
+pub use dice::*;
 pub use fbetascore::*;
+pub use grad_norm::*;
 pub use hamming::*;
+pub use iou::*;
 pub use iteration::*;
 pub use learning_rate::*;
 pub use loss::*;
+pub use mae::*;
+pub use map::*;
+pub use mape::*;
+pub use perplexity::*;
 pub use precision::*;
+pub use r_squared::*;
 pub use recall::*;
+pub use regression::*;
+pub use rmse::*;
+pub use segmentation::SegmentationInput;
+pub use sequence::*;
+pub use token_acc::*;
 pub use top_k_acc::*;
 
 pub(crate) mod classification;
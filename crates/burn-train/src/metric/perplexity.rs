@@ -0,0 +1,143 @@
+use core::marker::PhantomData;
+
+use super::state::{FormatOptions, RunningMetricState};
+use super::{MetricEntry, MetricMetadata, SequenceInput};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::ElementConversion;
+use burn_core::tensor::activation::log_softmax;
+use burn_core::tensor::backend::Backend;
+
+/// The perplexity metric of a language model.
+///
+/// The value of an epoch is the exponential of the mean negative log-likelihood over all its
+/// tokens, not the mean of the perplexities of its batches. It is NaN until a token that isn't
+/// padding is processed.
+#[derive(Default)]
+pub struct PerplexityMetric<B: Backend> {
+    state: RunningMetricState,
+    sum_nll: f64,
+    num_tokens: usize,
+    /// If specified, targets equal to this value will be considered padding and will not count
+    /// towards the metric
+    pad_token: Option<usize>,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> PerplexityMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the pad token.
+    pub fn with_pad_token(mut self, index: usize) -> Self {
+        self.pad_token = Some(index);
+        self
+    }
+}
+
+impl<B: Backend> Metric for PerplexityMetric<B> {
+    type Input = SequenceInput<B>;
+
+    fn update(&mut self, input: &SequenceInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, seq_length, vocab_size] = input.outputs.dims();
+        let num_tokens = batch_size * seq_length;
+
+        let logits = input.outputs.clone().reshape([num_tokens, vocab_size]);
+        let targets = input.targets.clone().reshape([num_tokens]);
+
+        let nll = log_softmax(logits, 1)
+            .gather(1, targets.clone().reshape([num_tokens, 1]))
+            .reshape([num_tokens])
+            .neg();
+
+        let (nll, num_pad) = match self.pad_token {
+            Some(pad_token) => {
+                // we ignore the tokens where the target is equal to the pad token
+                let mask = targets.equal_elem(pad_token as i64);
+                let num_pad = mask.clone().int().sum().into_scalar().elem::<i64>();
+                (nll.mask_fill(mask, 0.0), num_pad as usize)
+            }
+            None => (nll, 0),
+        };
+
+        self.sum_nll += nll.sum().into_scalar().elem::<f64>();
+        self.num_tokens += num_tokens - num_pad;
+
+        let perplexity = match self.num_tokens {
+            0 => f64::NAN,
+            num_tokens => (self.sum_nll / num_tokens as f64).exp(),
+        };
+        self.state
+            .update(perplexity, FormatOptions::new(self.name()).precision(2))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.sum_nll = 0.0;
+        self.num_tokens = 0;
+    }
+
+    fn name(&self) -> String {
+        "Perplexity".to_string()
+    }
+}
+
+impl<B: Backend> Numeric for PerplexityMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_perplexity_with_padding() {
+        let device = Default::default();
+        let mut metric = PerplexityMetric::<TestBackend>::new().with_pad_token(2);
+
+        // Uniform logits over 4 tokens give a perplexity of 4, whatever the targets.
+        let input = SequenceInput::new(
+            Tensor::zeros([2, 3, 4], &device),
+            Tensor::from_data([[0, 1, 2], [3, 2, 2]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 4.0).abs() < 1e-4);
+
+        // The target of the first token has a probability of 2 / 5, the second one is padding.
+        let ln2 = 2.0f32.ln();
+        let input = SequenceInput::new(
+            Tensor::from_data([[[ln2, 0.0, 0.0, 0.0], [0.0, 0.0, 0.0, 0.0]]], &device),
+            Tensor::from_data([[0, 2]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        // The mean negative log-likelihood of the 4 tokens is (3 ln(4) + ln(5 / 2)) / 4.
+        let expected = ((3.0 * 4.0f64.ln() + 2.5f64.ln()) / 4.0).exp();
+        assert!((metric.value() - expected).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_perplexity_with_padding_only() {
+        let device = Default::default();
+        let mut metric = PerplexityMetric::<TestBackend>::new().with_pad_token(2);
+
+        let input = SequenceInput::new(
+            Tensor::zeros([1, 2, 4], &device),
+            Tensor::from_data([[2, 2]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!(metric.value().is_nan());
+
+        let input = SequenceInput::new(
+            Tensor::zeros([1, 2, 4], &device),
+            Tensor::from_data([[0, 2]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 4.0).abs() < 1e-4);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::metric::store::MetricsUpdate;
 use burn_core::LearningRate;
 use burn_core::data::dataloader::Progress;
 
@@ -5,6 +6,9 @@ use burn_core::data::dataloader::Progress;
 pub enum Event<T> {
     /// Signal that an item have been processed.
     ProcessedItem(LearnerItem<T>),
+    /// Signal that entries have been logged outside of the registered metrics, such as by a
+    /// [callback](crate::LearnerCallback).
+    Log(MetricsUpdate),
     /// Signal the end of an epoch.
     EndEpoch(usize),
     /// Signal the end of the process (e.g., training end).
@@ -55,6 +59,9 @@ pub struct LearnerItem<T> {
 
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The global norm of the gradients before clipping, when the optimizer computes it.
+    pub grad_norm: Option<f64>,
 }
 
 impl<T: ItemLazy> ItemLazy for LearnerItem<T> {
@@ -68,6 +75,7 @@ impl<T: ItemLazy> ItemLazy for LearnerItem<T> {
             epoch_total: self.epoch_total,
             iteration: self.iteration,
             lr: self.lr,
+            grad_norm: self.grad_norm,
         }
     }
 }
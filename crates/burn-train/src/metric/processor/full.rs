@@ -57,6 +57,23 @@ impl<T: ItemLazy, V: ItemLazy> EventProcessor for FullEventProcessor<T, V> {
 
                 self.renderer.render_train(progress);
             }
+            Event::Log(update) => {
+                self.store
+                    .add_event_train(crate::metric::store::Event::MetricsUpdate(update.clone()));
+
+                update
+                    .entries
+                    .into_iter()
+                    .for_each(|entry| self.renderer.update_train(MetricState::Generic(entry)));
+
+                update
+                    .entries_numeric
+                    .into_iter()
+                    .for_each(|(entry, value)| {
+                        self.renderer
+                            .update_train(MetricState::Numeric(entry, value))
+                    });
+            }
             Event::EndEpoch(epoch) => {
                 self.metrics.end_epoch_train();
                 self.store
@@ -95,6 +112,23 @@ impl<T: ItemLazy, V: ItemLazy> EventProcessor for FullEventProcessor<T, V> {
 
                 self.renderer.render_valid(progress);
             }
+            Event::Log(update) => {
+                self.store
+                    .add_event_valid(crate::metric::store::Event::MetricsUpdate(update.clone()));
+
+                update
+                    .entries
+                    .into_iter()
+                    .for_each(|entry| self.renderer.update_valid(MetricState::Generic(entry)));
+
+                update
+                    .entries_numeric
+                    .into_iter()
+                    .for_each(|(entry, value)| {
+                        self.renderer
+                            .update_valid(MetricState::Numeric(entry, value))
+                    });
+            }
             Event::EndEpoch(epoch) => {
                 self.metrics.end_epoch_valid();
                 self.store
@@ -25,6 +25,10 @@ impl<T: ItemLazy, V: ItemLazy> EventProcessor for MinimalEventProcessor<T, V> {
                 self.store
                     .add_event_train(crate::metric::store::Event::MetricsUpdate(update));
             }
+            Event::Log(update) => {
+                self.store
+                    .add_event_train(crate::metric::store::Event::MetricsUpdate(update));
+            }
             Event::EndEpoch(epoch) => {
                 self.metrics.end_epoch_train();
                 self.store
@@ -45,6 +49,10 @@ impl<T: ItemLazy, V: ItemLazy> EventProcessor for MinimalEventProcessor<T, V> {
                 self.store
                     .add_event_valid(crate::metric::store::Event::MetricsUpdate(update));
             }
+            Event::Log(update) => {
+                self.store
+                    .add_event_valid(crate::metric::store::Event::MetricsUpdate(update));
+            }
             Event::EndEpoch(epoch) => {
                 self.metrics.end_epoch_valid();
                 self.store
@@ -0,0 +1,200 @@
+use core::marker::PhantomData;
+
+use super::state::{FormatOptions, RunningMetricState};
+use super::{MetricEntry, MetricMetadata, RegressionInput};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::Transaction;
+
+/// The coefficient of determination (R²) metric.
+///
+/// The coefficient is computed for each output over all the items of the epoch, then averaged
+/// over the outputs. The outputs whose targets are constant are ignored, and the coefficient is
+/// NaN when the targets of every output are constant.
+#[derive(Default)]
+pub struct RSquaredMetric<B: Backend> {
+    state: RunningMetricState,
+    count: usize,
+    mean_targets: Vec<f64>,
+    // The sum of the squared deviations of the targets from their mean.
+    m2_targets: Vec<f64>,
+    sum_squared_residuals: Vec<f64>,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> RSquaredMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn r_squared(&self) -> f64 {
+        let (sum, num_outputs) = self
+            .m2_targets
+            .iter()
+            .zip(self.sum_squared_residuals.iter())
+            .filter(|(ss_tot, _)| **ss_tot > 0.0)
+            .fold((0.0, 0), |(acc, n), (ss_tot, ss_res)| {
+                (acc + 1.0 - ss_res / ss_tot, n + 1)
+            });
+
+        match num_outputs {
+            0 => f64::NAN,
+            _ => sum / num_outputs as f64,
+        }
+    }
+}
+
+impl<B: Backend> Metric for RSquaredMetric<B> {
+    type Input = RegressionInput<B>;
+
+    fn update(&mut self, input: &RegressionInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, num_outputs] = input.outputs.dims();
+        let targets = input.targets.clone();
+        let residuals = input.outputs.clone() - targets.clone();
+        // The targets are shifted by the first one, which keeps the sums of the deviations small
+        // and exactly zero for constant targets.
+        let shift = targets.clone().narrow(0, 0, 1);
+        let deviations = targets - shift.clone();
+
+        let [shift, sum_d, sum_d2, ss_res] = Transaction::default()
+            .register(shift)
+            .register(deviations.clone().sum_dim(0))
+            .register(deviations.powi_scalar(2).sum_dim(0))
+            .register(residuals.powi_scalar(2).sum_dim(0))
+            .execute()
+            .try_into()
+            .expect("Correct amount of tensor data");
+
+        if self.mean_targets.len() != num_outputs {
+            self.mean_targets = vec![0.0; num_outputs];
+            self.m2_targets = vec![0.0; num_outputs];
+            self.sum_squared_residuals = vec![0.0; num_outputs];
+        }
+
+        // Merge the mean and the squared deviations of the batch with the ones of the previous
+        // batches (Chan et al.).
+        let count_a = self.count as f64;
+        let count_b = batch_size as f64;
+        let count = count_a + count_b;
+        let batch = shift
+            .iter::<f64>()
+            .zip(sum_d.iter::<f64>())
+            .zip(sum_d2.iter::<f64>());
+        for ((mean, m2), ((shift, sum_d), sum_d2)) in self
+            .mean_targets
+            .iter_mut()
+            .zip(self.m2_targets.iter_mut())
+            .zip(batch)
+        {
+            let mean_b = shift + sum_d / count_b;
+            let m2_b = f64::max(sum_d2 - sum_d * sum_d / count_b, 0.0);
+            let delta = mean_b - *mean;
+            *mean += delta * count_b / count;
+            *m2 += m2_b + delta * delta * count_a * count_b / count;
+        }
+        self.sum_squared_residuals
+            .iter_mut()
+            .zip(ss_res.iter::<f64>())
+            .for_each(|(acc, value)| *acc += value);
+        self.count += batch_size;
+
+        let value = self.r_squared();
+        self.state
+            .update(value, FormatOptions::new(self.name()).precision(4))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.count = 0;
+        self.mean_targets.clear();
+        self.m2_targets.clear();
+        self.sum_squared_residuals.clear();
+    }
+
+    fn name(&self) -> String {
+        "R2".to_string()
+    }
+}
+
+impl<B: Backend> Numeric for RSquaredMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_r_squared_over_batches() {
+        let device = Default::default();
+        let mut metric = RSquaredMetric::<TestBackend>::new();
+
+        // Targets [1, 2, 3, 4] with mean 2.5: ss_tot = 5, ss_res = 0.25 + 0.25 = 0.5
+        let input = RegressionInput::<TestBackend>::new(
+            Tensor::from_data([[1.5], [2.0]], &device),
+            Tensor::from_data([[1.0], [2.0]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        let input = RegressionInput::new(
+            Tensor::from_data([[3.0], [3.5]], &device),
+            Tensor::from_data([[3.0], [4.0]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert!((metric.value() - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_r_squared_ignores_constant_outputs() {
+        let device = Default::default();
+        let mut metric = RSquaredMetric::<TestBackend>::new();
+
+        let input = RegressionInput::<TestBackend>::new(
+            Tensor::from_data([[1.0, 0.0], [2.0, 1.0]], &device),
+            Tensor::from_data([[1.0, 1.0], [2.0, 1.0]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert!((metric.value() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_r_squared_with_large_targets() {
+        let device = Default::default();
+        let mut metric = RSquaredMetric::<TestBackend>::new();
+
+        // The same deviations as the targets [1, 2, 3, 4], offset by 10 000.
+        let input = RegressionInput::<TestBackend>::new(
+            Tensor::from_data([[10_001.5], [10_002.0]], &device),
+            Tensor::from_data([[10_001.0], [10_002.0]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        let input = RegressionInput::new(
+            Tensor::from_data([[10_003.0], [10_003.5]], &device),
+            Tensor::from_data([[10_003.0], [10_004.0]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert!((metric.value() - 0.9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_r_squared_constant_targets_is_nan() {
+        let device = Default::default();
+        let mut metric = RSquaredMetric::<TestBackend>::new();
+
+        let input = RegressionInput::<TestBackend>::new(
+            Tensor::from_data([[0.1], [0.2]], &device),
+            Tensor::from_data([[0.1], [0.1]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert!(metric.value().is_nan());
+    }
+}
@@ -0,0 +1,10 @@
+use burn_core::tensor::{Tensor, backend::Backend};
+
+/// The input of the regression metrics, such as the [mean absolute error](super::MaeMetric).
+#[derive(new, Debug, Clone)]
+pub struct RegressionInput<B: Backend> {
+    /// The predictions (batch_size, num_outputs).
+    pub outputs: Tensor<B, 2>,
+    /// The targets (batch_size, num_outputs).
+    pub targets: Tensor<B, 2>,
+}
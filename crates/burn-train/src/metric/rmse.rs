@@ -0,0 +1,97 @@
+use core::marker::PhantomData;
+
+use super::state::{FormatOptions, RunningMetricState};
+use super::{MetricEntry, MetricMetadata, RegressionInput};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::ElementConversion;
+use burn_core::tensor::backend::Backend;
+
+/// The root mean squared error metric.
+///
+/// The value of an epoch is the root of the mean squared error over all its items, not the mean
+/// of the values of its batches.
+#[derive(Default)]
+pub struct RmseMetric<B: Backend> {
+    state: RunningMetricState,
+    sum_squared_error: f64,
+    count: usize,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> RmseMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<B: Backend> Metric for RmseMetric<B> {
+    type Input = RegressionInput<B>;
+
+    fn update(&mut self, input: &RegressionInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, num_outputs] = input.outputs.dims();
+
+        let squared_error = (input.outputs.clone() - input.targets.clone())
+            .powi_scalar(2)
+            .sum()
+            .into_scalar()
+            .elem::<f64>();
+
+        self.sum_squared_error += squared_error;
+        self.count += batch_size * num_outputs;
+
+        let rmse = (self.sum_squared_error / self.count as f64).sqrt();
+
+        self.state
+            .update(rmse, FormatOptions::new(self.name()).precision(4))
+    }
+
+    fn clear(&mut self) {
+        self.state.reset();
+        self.sum_squared_error = 0.0;
+        self.count = 0;
+    }
+
+    fn name(&self) -> String {
+        "RMSE".to_string()
+    }
+}
+
+impl<B: Backend> Numeric for RmseMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_rmse_over_batches() {
+        let device = Default::default();
+        let mut metric = RmseMetric::<TestBackend>::new();
+
+        // Squared errors: [0, 4]
+        let input = RegressionInput::new(
+            Tensor::from_data([[1.0], [2.0]], &device),
+            Tensor::from_data([[1.0], [4.0]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - 2.0f64.sqrt()).abs() < 1e-6);
+
+        // Squared errors: [16], so the epoch value isn't the mean of the batch values.
+        let input = RegressionInput::new(
+            Tensor::from_data([[0.0]], &device),
+            Tensor::from_data([[4.0]], &device),
+        );
+        let entry = metric.update(&input, &MetricMetadata::fake());
+        assert!((metric.value() - (20.0f64 / 3.0).sqrt()).abs() < 1e-6);
+        assert!(entry.serialize.ends_with(",running"));
+
+        metric.clear();
+        assert!(metric.value().is_nan());
+    }
+}
@@ -0,0 +1,90 @@
+use burn_core::tensor::{Int, Tensor, TensorData, Transaction, backend::Backend};
+
+/// The input of the segmentation metrics, such as the [IoU metric](super::IouMetric).
+#[derive(new, Debug, Clone)]
+pub struct SegmentationInput<B: Backend> {
+    /// The scores of each class (batch_size, num_classes, height, width).
+    pub outputs: Tensor<B, 4>,
+    /// The class of each pixel (batch_size, height, width).
+    pub targets: Tensor<B, 3, Int>,
+}
+
+/// The number of pixels of each class accumulated over the batches, predicted as the class with
+/// the highest score.
+#[derive(Default, Debug)]
+pub(crate) struct SegmentationStats {
+    /// The pixels of the class that are correctly predicted.
+    pub intersection: Vec<f64>,
+    /// The pixels predicted as the class.
+    pub predicted: Vec<f64>,
+    /// The pixels of the class.
+    pub target: Vec<f64>,
+}
+
+impl SegmentationStats {
+    pub fn update<B: Backend>(&mut self, input: &SegmentationInput<B>) {
+        let [batch_size, num_classes, height, width] = input.outputs.dims();
+        let num_pixels = batch_size * height * width;
+
+        let predictions = input
+            .outputs
+            .clone()
+            .argmax(1)
+            .reshape([num_pixels])
+            .one_hot::<2>(num_classes)
+            .float();
+        let targets = input
+            .targets
+            .clone()
+            .reshape([num_pixels])
+            .one_hot::<2>(num_classes)
+            .float();
+
+        let [intersection, predicted, target] = Transaction::default()
+            .register((predictions.clone() * targets.clone()).sum_dim(0))
+            .register(predictions.sum_dim(0))
+            .register(targets.sum_dim(0))
+            .execute()
+            .try_into()
+            .expect("Correct amount of tensor data");
+
+        if self.intersection.len() != num_classes {
+            self.intersection = vec![0.0; num_classes];
+            self.predicted = vec![0.0; num_classes];
+            self.target = vec![0.0; num_classes];
+        }
+
+        let accumulate = |acc: &mut Vec<f64>, data: TensorData| {
+            acc.iter_mut()
+                .zip(data.iter::<f64>())
+                .for_each(|(acc, value)| *acc += value);
+        };
+        accumulate(&mut self.intersection, intersection);
+        accumulate(&mut self.predicted, predicted);
+        accumulate(&mut self.target, target);
+    }
+
+    /// The mean over the classes that are predicted or present of the score computed from the
+    /// intersection, the predicted and the target pixels of each class.
+    pub fn mean<F>(&self, score: F) -> f64
+    where
+        F: Fn(f64, f64, f64) -> f64,
+    {
+        let (sum, num_classes) = self
+            .intersection
+            .iter()
+            .zip(self.predicted.iter())
+            .zip(self.target.iter())
+            .filter(|((_, predicted), target)| **predicted + **target > 0.0)
+            .map(|((intersection, predicted), target)| score(*intersection, *predicted, *target))
+            .fold((0.0, 0), |(acc, n), value| (acc + value, n + 1));
+
+        sum / num_classes as f64
+    }
+
+    pub fn clear(&mut self) {
+        self.intersection.clear();
+        self.predicted.clear();
+        self.target.clear();
+    }
+}
@@ -0,0 +1,10 @@
+use burn_core::tensor::{Int, Tensor, backend::Backend};
+
+/// The input of the sequence metrics, such as the [perplexity metric](super::PerplexityMetric).
+#[derive(new, Debug, Clone)]
+pub struct SequenceInput<B: Backend> {
+    /// The logits of each token (batch_size, seq_length, vocab_size).
+    pub outputs: Tensor<B, 3>,
+    /// The target tokens (batch_size, seq_length).
+    pub targets: Tensor<B, 2, Int>,
+}
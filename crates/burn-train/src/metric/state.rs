@@ -40,6 +40,18 @@ impl FormatOptions {
         self.precision = Some(precision);
         self
     }
+
+    fn format(&self, value: f64) -> String {
+        let value = match self.precision {
+            Some(precision) => format_float(value, precision),
+            None => format!("{value}"),
+        };
+
+        match &self.unit {
+            Some(unit) => format!("{value} {unit}"),
+            None => value,
+        }
+    }
 }
 
 impl NumericMetricState {
@@ -60,30 +72,26 @@ impl NumericMetricState {
     }
 
     /// Update the state.
+    ///
+    /// A batch without elements, such as a batch of padding tokens, leaves the state unchanged
+    /// and its entry is ignored by the mean of the epoch.
     pub fn update(&mut self, value: f64, batch_size: usize, format: FormatOptions) -> MetricEntry {
-        self.sum += value * batch_size as f64;
-        self.count += batch_size;
-        self.current = value;
+        if batch_size > 0 {
+            self.sum += value * batch_size as f64;
+            self.count += batch_size;
+            self.current = value;
+        }
 
-        let value_current = value;
+        let value_current = self.current;
         let value_running = self.sum / self.count as f64;
         // Numeric metric state is an aggregated value
         let serialized = NumericEntry::Aggregated(value_current, batch_size).serialize();
 
-        let (formatted_current, formatted_running) = match format.precision {
-            Some(precision) => (
-                format_float(value_current, precision),
-                format_float(value_running, precision),
-            ),
-            None => (format!("{value_current}"), format!("{value_running}")),
-        };
-
-        let formatted = match format.unit {
-            Some(unit) => {
-                format!("epoch {formatted_running} {unit} - batch {formatted_current} {unit}")
-            }
-            None => format!("epoch {formatted_running} - batch {formatted_current}"),
-        };
+        let formatted = format!(
+            "epoch {} - batch {}",
+            format.format(value_running),
+            format.format(value_current)
+        );
 
         MetricEntry::new(format.name, formatted, serialized)
     }
@@ -100,3 +108,46 @@ impl Default for NumericMetricState {
         Self::new()
     }
 }
+
+/// Useful utility to implement numeric metrics whose value over an epoch isn't the mean of their
+/// values on each batch, such as the root mean squared error.
+///
+/// The metric computes its value over all the items of the epoch so far, which is saved as a
+/// [running entry](NumericEntry::Running), so the value of the epoch is the last one.
+pub struct RunningMetricState {
+    value: f64,
+}
+
+impl RunningMetricState {
+    /// Create a new [running metric state](RunningMetricState).
+    pub fn new() -> Self {
+        Self { value: f64::NAN }
+    }
+
+    /// Reset the state.
+    pub fn reset(&mut self) {
+        self.value = f64::NAN;
+    }
+
+    /// Update the state with the value over all the items of the epoch so far.
+    pub fn update(&mut self, value: f64, format: FormatOptions) -> MetricEntry {
+        self.value = value;
+
+        let serialized = NumericEntry::Running(value).serialize();
+        let formatted = format!("epoch {}", format.format(value));
+
+        MetricEntry::new(format.name, formatted, serialized)
+    }
+}
+
+impl Numeric for RunningMetricState {
+    fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl Default for RunningMetricState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -42,18 +42,30 @@ impl NumericMetricsAggregate {
             Err(errors.join(" "))
         };
 
-        let points = points().expect("Can read values");
+        // The entries of batches without elements don't count towards the mean.
+        let points = points()
+            .expect("Can read values")
+            .into_iter()
+            .filter(|entry| !matches!(entry, NumericEntry::Aggregated(_, 0)))
+            .collect::<Vec<_>>();
 
         if points.is_empty() {
             return None;
         }
 
+        // A running entry is already computed over all the elements of the epoch so far, such as
+        // the root of the mean of the squared errors, which isn't the mean of the batch values.
+        if let Some(&NumericEntry::Running(value)) = points.last() {
+            self.value_for_each_epoch.insert(key, value);
+            return Some(value);
+        }
+
         // Accurately compute the aggregated value based on the *actual* number of points
         // since not all mini-batches are guaranteed to have the specified batch size
         let (sum, num_points) = points
             .into_iter()
             .map(|entry| match entry {
-                NumericEntry::Value(v) => (v, 1),
+                NumericEntry::Value(v) | NumericEntry::Running(v) => (v, 1),
                 // Right now the mean is the only aggregate available, so we can assume that the sum
                 // of an entry corresponds to (value * number of elements)
                 NumericEntry::Aggregated(v, n) => (v * n as f64, n),
@@ -202,4 +214,50 @@ mod tests {
         // Average should be (0.5 + 1.25 * 2) / 3 = 1.0, not (0.5 + 1.25) / 2 = 0.875
         assert_eq!(value, 1.0);
     }
+
+    #[test]
+    fn should_aggregate_running_entry_as_the_last_value() {
+        let mut logger = InMemoryMetricLogger::default();
+        let mut aggregate = NumericMetricsAggregate::default();
+        let metric_name = "RMSE";
+
+        // The root of the mean of the squared errors over the epoch so far.
+        for rmse in [2.0, 1.5, 1.25] {
+            let entry = MetricEntry::new(
+                metric_name.to_string(),
+                rmse.to_string(),
+                NumericEntry::Running(rmse).serialize(),
+            );
+            logger.log(&entry);
+        }
+
+        let value = aggregate
+            .aggregate(metric_name, 1, Aggregate::Mean, &mut [Box::new(logger)])
+            .unwrap();
+
+        assert_eq!(value, 1.25);
+    }
+
+    #[test]
+    fn should_ignore_aggregated_entries_without_elements() {
+        let mut logger = InMemoryMetricLogger::default();
+        let mut aggregate = NumericMetricsAggregate::default();
+        let metric_name = "Token Accuracy";
+
+        // A batch of padding tokens, whose accuracy is undefined.
+        for (accuracy, num_tokens) in [(f64::NAN, 0), (50.0, 4), (50.0, 0)] {
+            let entry = MetricEntry::new(
+                metric_name.to_string(),
+                accuracy.to_string(),
+                NumericEntry::Aggregated(accuracy, num_tokens).serialize(),
+            );
+            logger.log(&entry);
+        }
+
+        let value = aggregate
+            .aggregate(metric_name, 1, Aggregate::Mean, &mut [Box::new(logger)])
+            .unwrap();
+
+        assert_eq!(value, 50.0);
+    }
 }
@@ -1,5 +1,5 @@
 use super::{Aggregate, Direction, Event, EventStore, Split, aggregate::NumericMetricsAggregate};
-use crate::logger::MetricLogger;
+use crate::logger::{Hyperparameters, MetricLogger};
 
 #[derive(Default)]
 pub(crate) struct LogEventStore {
@@ -98,4 +98,12 @@ impl LogEventStore {
     pub(crate) fn register_logger_valid<ML: MetricLogger + 'static>(&mut self, logger: ML) {
         self.loggers_valid.push(Box::new(logger));
     }
+
+    /// Log the hyperparameters of the experiment with all the loggers.
+    pub(crate) fn log_hyperparameters(&mut self, hyperparameters: &Hyperparameters) {
+        self.loggers_train
+            .iter_mut()
+            .chain(self.loggers_valid.iter_mut())
+            .for_each(|logger| logger.log_hyperparameters(hyperparameters));
+    }
 }
@@ -0,0 +1,131 @@
+use core::marker::PhantomData;
+
+use super::state::{FormatOptions, NumericMetricState};
+use super::{MetricEntry, MetricMetadata, SequenceInput};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::ElementConversion;
+use burn_core::tensor::backend::Backend;
+
+/// The accuracy of the predicted tokens of a sequence, weighted by the number of tokens of each
+/// batch.
+#[derive(Default)]
+pub struct TokenAccuracyMetric<B: Backend> {
+    state: NumericMetricState,
+    /// If specified, targets equal to this value will be considered padding and will not count
+    /// towards the metric
+    pad_token: Option<usize>,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> TokenAccuracyMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the pad token.
+    pub fn with_pad_token(mut self, index: usize) -> Self {
+        self.pad_token = Some(index);
+        self
+    }
+}
+
+impl<B: Backend> Metric for TokenAccuracyMetric<B> {
+    type Input = SequenceInput<B>;
+
+    fn update(&mut self, input: &SequenceInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, seq_length, _vocab_size] = input.outputs.dims();
+        let num_tokens = batch_size * seq_length;
+
+        let outputs = input.outputs.clone().argmax(2).reshape([num_tokens]);
+        let targets = input.targets.clone().reshape([num_tokens]);
+        let matches = outputs.equal(targets.clone()).int();
+
+        let (matches, num_pad) = match self.pad_token {
+            Some(pad_token) => {
+                let mask = targets.equal_elem(pad_token as i64);
+                let num_pad = mask.clone().int().sum().into_scalar().elem::<i64>();
+                (matches.mask_fill(mask, 0), num_pad as usize)
+            }
+            None => (matches, 0),
+        };
+
+        let num_tokens = num_tokens - num_pad;
+        // A batch of padding tokens has no accuracy, and leaves the state unchanged.
+        let accuracy = match num_tokens {
+            0 => f64::NAN,
+            _ => matches.sum().into_scalar().elem::<f64>() / num_tokens as f64,
+        };
+
+        self.state.update(
+            100.0 * accuracy,
+            num_tokens,
+            FormatOptions::new(self.name()).unit("%").precision(2),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+
+    fn name(&self) -> String {
+        "Token Accuracy".to_string()
+    }
+}
+
+impl<B: Backend> Numeric for TokenAccuracyMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use crate::metric::NumericEntry;
+    use burn_core::tensor::Tensor;
+
+    #[test]
+    fn test_token_accuracy_with_padding() {
+        let device = Default::default();
+        let mut metric = TokenAccuracyMetric::<TestBackend>::new().with_pad_token(2);
+
+        let input = SequenceInput::new(
+            Tensor::from_data(
+                [
+                    [[0.9, 0.1, 0.0], [0.1, 0.9, 0.0], [0.9, 0.1, 0.0]], // [0, 1, 0]
+                    [[0.1, 0.9, 0.0], [0.1, 0.9, 0.0], [0.9, 0.1, 0.0]], // [1, 1, 0]
+                ],
+                &device,
+            ),
+            Tensor::from_data([[0, 1, 1], [0, 2, 2]], &device),
+        );
+
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(50.0, metric.value());
+    }
+
+    #[test]
+    fn test_token_accuracy_with_padding_only() {
+        let device = Default::default();
+        let mut metric = TokenAccuracyMetric::<TestBackend>::new().with_pad_token(2);
+
+        let input = SequenceInput::new(
+            Tensor::from_data([[[0.9, 0.1, 0.0], [0.1, 0.9, 0.0]]], &device),
+            Tensor::from_data([[0, 0]], &device),
+        );
+        let _entry = metric.update(&input, &MetricMetadata::fake());
+        let input = SequenceInput::new(
+            Tensor::from_data([[[0.9, 0.1, 0.0], [0.1, 0.9, 0.0]]], &device),
+            Tensor::from_data([[2, 2]], &device),
+        );
+        let entry = metric.update(&input, &MetricMetadata::fake());
+
+        assert_eq!(50.0, metric.value());
+        assert!(matches!(
+            NumericEntry::deserialize(&entry.serialize),
+            Ok(NumericEntry::Aggregated(50.0, 0))
+        ));
+    }
+}
@@ -96,6 +96,7 @@
 //!   - `std`: Activates the standard library (deactivate for no_std)
 //!   - `server`: Enables the remote server.
 //!   - `network`: Enables network utilities (currently, only a file downloader with progress bar)
+//!   - `collective-tcp`: Enables collective operations between processes over TCP
 //!   - `experimental-named-tensor`: Enables named tensors (experimental)
 //!
 //! You can also check the details in sub-crates [`burn-core`](https://docs.rs/burn-core) and [`burn-train`](https://docs.rs/burn-train).
@@ -4,7 +4,7 @@ use std::{
     path::Path,
 };
 
-use crate::node_remap::remap_node_type;
+use crate::node_remap::{remap_node_type, remap_opset_defaults};
 
 use super::{
     coalesce::coalesce,
@@ -18,11 +18,13 @@ use super::rank_inference::rank_inference;
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 13] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 16] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::CumSum,
+    NodeType::DFT,
     NodeType::Dropout,
     NodeType::Expand,
     NodeType::OneHot,
@@ -32,6 +34,7 @@ const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 13] = [
     NodeType::ReduceSum,
     NodeType::Slice,
     NodeType::Squeeze,
+    NodeType::STFT,
 ];
 
 #[derive(Debug, Clone)]
@@ -209,12 +212,14 @@ impl OnnxGraphBuilder {
             &model_proto.graph.initializer,
         );
 
+        let opset_version = default_opset_version(model_proto);
         let mut node_iter = model_proto.graph.node.iter().peekable();
 
         while let Some(node_proto) = node_iter.next() {
             let mut node = convert_node_proto(node_proto, &graph_data);
 
             remap_node_type(&mut node);
+            remap_opset_defaults(&mut node, opset_version);
             self.handle_node_renaming(&mut node);
             coalesce(&mut node, &mut node_iter, &graph_data);
             self.handle_identity(&mut node, &graph_data);
@@ -375,6 +380,17 @@ pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
     graph
 }
 
+/// The version of the default ONNX operator set imported by the model, models without one being
+/// treated as using the latest version
+fn default_opset_version(model_proto: &ModelProto) -> i64 {
+    model_proto
+        .opset_import
+        .iter()
+        .find(|opset| opset.domain.is_empty() || opset.domain == "ai.onnx")
+        .map(|opset| opset.version)
+        .unwrap_or(i64::MAX)
+}
+
 /// Remap the unsqueeze node to a reshape node, Should only be called after
 /// node renaming has been done. avoids marking rhs as passed so that it can be
 /// properly deleted if nothing else uses it
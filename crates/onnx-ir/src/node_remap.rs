@@ -48,3 +48,17 @@ pub fn remap_node_type(node: &mut Node) {
         _ => (),
     }
 }
+
+/// Set the attributes whose default value changed across opset versions explicitly, so that the
+/// node configurations only need to handle the defaults of the latest opset
+pub fn remap_opset_defaults(node: &mut Node, opset_version: i64) {
+    match node.node_type {
+        // Before opset 20, the axis is an attribute which defaults to 1 instead of -2
+        NodeType::DFT if opset_version < 20 => {
+            node.attrs
+                .entry("axis".to_string())
+                .or_insert(AttributeValue::Int64(1));
+        }
+        _ => (),
+    }
+}
@@ -18,6 +18,7 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::AveragePool1d => same_as_input(node),
         NodeType::AveragePool2d => same_as_input(node),
         NodeType::BatchNormalization => same_as_input(node),
+        NodeType::BlackmanWindow => window_update_outputs(node),
         NodeType::Cast => cast_update_outputs(node),
         NodeType::Clip => same_as_input(node),
         NodeType::Concat => concat_update_outputs(node),
@@ -27,8 +28,12 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Conv2d => conv2d_update_outputs(node),
         NodeType::Cos => same_as_input(node),
         NodeType::Cosh => same_as_input(node),
+        NodeType::CumSum => same_as_input(node),
+        NodeType::Det => det_update_outputs(node),
+        NodeType::DFT => dft_update_outputs(node),
         NodeType::Div => same_as_input_broadcast(node),
         NodeType::Dropout => same_as_input(node),
+        NodeType::Einsum => einsum_update_outputs(node),
         NodeType::Equal => elementwise_comparison_outputs(node),
         NodeType::Erf => same_as_input(node),
         NodeType::Exp => same_as_input(node),
@@ -41,6 +46,8 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Gemm => gemm_output_shape(node),
         NodeType::Greater => elementwise_comparison_outputs(node),
         NodeType::GreaterOrEqual => elementwise_comparison_outputs(node),
+        NodeType::HammingWindow => window_update_outputs(node),
+        NodeType::HannWindow => window_update_outputs(node),
         NodeType::HardSigmoid => same_as_input(node),
         NodeType::GlobalAveragePool => same_as_input(node),
         NodeType::ConvTranspose1d => conv_transpose1d_update_outputs(node),
@@ -88,6 +95,7 @@ pub fn rank_inference(node: &mut Node) {
         NodeType::Split => split_update_outputs(node),
         NodeType::Squeeze => squeeze_update_output(node),
         NodeType::Sqrt => same_as_input(node),
+        NodeType::STFT => stft_update_outputs(node),
         NodeType::Sub => same_as_input_broadcast(node),
         NodeType::Sum => same_as_input_broadcast(node),
         NodeType::Tan => same_as_input(node),
@@ -418,6 +426,128 @@ fn argmax_update_outputs(node: &mut Node) {
     });
 }
 
+/// Infers the shape of a Det node and replaces the shape of the output tensor.
+fn det_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Det: only tensor input is valid"),
+    };
+
+    if tensor.rank < 2 {
+        panic!("Det: the input should have a rank of at least 2");
+    }
+
+    // NOTE: The determinant of a single matrix is a scalar value, but Burn doesn't have 0-dim
+    // tensors, so a tensor of rank 1 is returned instead.
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        rank: usize::max(tensor.rank - 2, 1),
+        shape: None,
+        ..tensor
+    });
+}
+
+/// Infers the rank of the output of an Einsum node from its equation.
+fn einsum_update_outputs(node: &mut Node) {
+    let equation: String = match node.attrs.get("equation") {
+        Some(value) => value.clone().into_string(),
+        None => panic!("Einsum: the equation attribute is required"),
+    };
+    let equation: String = equation.chars().filter(|c| !c.is_whitespace()).collect();
+
+    let input_ranks: Vec<usize> = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => tensor.rank,
+            _ => panic!("Einsum: only tensor inputs are valid"),
+        })
+        .collect();
+
+    let (lhs, rhs) = match equation.split_once("->") {
+        Some((lhs, rhs)) => (lhs, Some(rhs)),
+        None => (equation.as_str(), None),
+    };
+
+    // The number of dimensions covered by the ellipsis, if any.
+    let ellipsis_rank = lhs
+        .split(',')
+        .zip(input_ranks.iter())
+        .filter(|(term, _)| term.contains("..."))
+        .map(|(term, rank)| rank - term.chars().filter(char::is_ascii_alphabetic).count())
+        .max()
+        .unwrap_or(0);
+
+    let rank = match rhs {
+        Some(rhs) => {
+            let letters = rhs.chars().filter(char::is_ascii_alphabetic).count();
+            match rhs.contains("...") {
+                true => letters + ellipsis_rank,
+                false => letters,
+            }
+        }
+        // The implicit output has the subscripts appearing only once.
+        None => {
+            let letters: Vec<char> = lhs.chars().filter(char::is_ascii_alphabetic).collect();
+            let unique = letters
+                .iter()
+                .filter(|c| letters.iter().filter(|other| other == c).count() == 1)
+                .count();
+            unique + ellipsis_rank
+        }
+    };
+
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("Einsum: only tensor inputs are valid"),
+    };
+
+    // NOTE: A full contraction gives a scalar value, but Burn doesn't have 0-dim tensors, so a
+    // tensor of rank 1 is returned instead.
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        rank: usize::max(rank, 1),
+        shape: None,
+    });
+}
+
+/// Infers the rank of the output of a DFT node, the last dimension holding the real and
+/// imaginary parts.
+fn dft_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("DFT: only tensor input is valid"),
+    };
+
+    // The size of the transformed and last dimensions can change.
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        shape: None,
+        ..tensor
+    });
+}
+
+/// Infers the rank of the output of a STFT node, `[batch, frames, bins, 2]`.
+fn stft_update_outputs(node: &mut Node) {
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => panic!("STFT: only tensor input is valid"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        rank: 4,
+        shape: None,
+    });
+}
+
+/// Infers the output of the HannWindow, HammingWindow and BlackmanWindow nodes, a float vector.
+fn window_update_outputs(node: &mut Node) {
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: ElementType::Float32,
+        rank: 1,
+        shape: None,
+    });
+}
+
 /// Update the output tensor dimension
 fn squeeze_update_output(node: &mut Node) {
     let axes = if node.inputs.len() == 2 {